@@ -122,6 +122,11 @@ pub struct FixOptions {
     /// Fix as many issues as possible. Only unfixed issues are reported in the output
     #[bpaf(switch)]
     pub fix: bool,
+
+    /// Also apply suggestions, fixes which may change the behavior of the program.
+    /// Only used together with `--fix`
+    #[bpaf(switch, hide_usage)]
+    pub fix_suggestions: bool,
 }
 
 /// Handle Warnings
@@ -131,6 +136,11 @@ pub struct WarningOptions {
     #[bpaf(switch, hide_usage)]
     pub quiet: bool,
 
+    /// Report directive comments like `// eslint-disable-line` when no errors would have been
+    /// reported on that line anyway
+    #[bpaf(switch, hide_usage)]
+    pub report_unused_disable_directives: bool,
+
     /// Ensure warnings produce a non-zero exit code
     #[bpaf(switch, hide_usage)]
     pub deny_warnings: bool,
@@ -144,7 +154,7 @@ pub struct WarningOptions {
 /// Output
 #[derive(Debug, Clone, Bpaf)]
 pub struct OutputOptions {
-    /// Use a specific output format (default, json, unix, checkstyle, github)
+    /// Use a specific output format (default, json, unix, checkstyle, github, sarif)
     #[bpaf(long, short, fallback(OutputFormat::Default), hide_usage)]
     pub format: OutputFormat,
 }
@@ -158,6 +168,9 @@ pub enum OutputFormat {
     Json,
     Unix,
     Checkstyle,
+    /// Static Analysis Results Interchange Format
+    /// <https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html>
+    Sarif,
 }
 
 impl FromStr for OutputFormat {
@@ -170,6 +183,7 @@ impl FromStr for OutputFormat {
             "unix" => Ok(Self::Unix),
             "checkstyle" => Ok(Self::Checkstyle),
             "github" => Ok(Self::Github),
+            "sarif" => Ok(Self::Sarif),
             _ => Err(format!("'{s}' is not a known format")),
         }
     }
@@ -359,6 +373,12 @@ mod lint_options {
         assert!(options.paths.is_empty());
     }
 
+    #[test]
+    fn format_sarif() {
+        let options = get_lint_options("--format sarif");
+        assert_eq!(options.output_options.format, OutputFormat::Sarif);
+    }
+
     #[test]
     fn format_error() {
         let args = "-f asdf".split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
@@ -25,6 +25,10 @@ pub struct MiscOptions {
     /// Number of threads to use. Set to 1 for using only 1 CPU core
     #[bpaf(argument("INT"), hide_usage)]
     pub threads: Option<usize>,
+
+    /// Cache lint results in .oxc/lint-cache and skip unchanged files
+    #[bpaf(switch, hide_usage)]
+    pub cache: bool,
 }
 
 #[allow(clippy::ptr_arg)]
@@ -76,6 +80,7 @@ mod misc_options {
     fn default() {
         let options = get_misc_options(".");
         assert!(options.threads.is_none());
+        assert!(!options.cache);
     }
 
     #[test]
@@ -83,4 +88,10 @@ mod misc_options {
         let options = get_misc_options("--threads 4 .");
         assert_eq!(options.threads, Some(4));
     }
+
+    #[test]
+    fn cache() {
+        let options = get_misc_options("--cache .");
+        assert!(options.cache);
+    }
 }
@@ -3,7 +3,8 @@ use std::{env, io::BufWriter, time::Instant};
 use ignore::gitignore::Gitignore;
 use oxc_diagnostics::{DiagnosticService, GraphicalReportHandler};
 use oxc_linter::{
-    partial_loader::LINT_PARTIAL_LOADER_EXT, LintOptions, LintService, LintServiceOptions, Linter,
+    partial_loader::LINT_PARTIAL_LOADER_EXT, LintCache, LintOptions, LintService,
+    LintServiceOptions, Linter,
 };
 use oxc_span::VALID_EXTENSIONS;
 
@@ -94,6 +95,8 @@ impl Runner for LintRunner {
             .with_filter(filter)
             .with_config_path(basic_options.config)
             .with_fix(fix_options.fix)
+            .with_fix_suggestions(fix_options.fix_suggestions)
+            .with_report_unused_directives(warning_options.report_unused_disable_directives)
             .with_react_plugin(enable_plugins.react_plugin)
             .with_unicorn_plugin(enable_plugins.unicorn_plugin)
             .with_typescript_plugin(enable_plugins.typescript_plugin)
@@ -130,7 +133,8 @@ impl Runner for LintRunner {
             }
         }
 
-        let options = LintServiceOptions { cwd, paths, tsconfig };
+        let cache_dir = misc_options.cache.then(|| cwd.join(LintCache::DEFAULT_DIR));
+        let options = LintServiceOptions { cwd, paths, tsconfig, cache_dir };
         let lint_service = LintService::new(linter, options);
         let mut diagnostic_service =
             Self::get_diagnostic_service(&warning_options, &output_options, &misc_options);
@@ -175,6 +179,7 @@ impl LintRunner {
             OutputFormat::Unix => diagnostic_service.set_unix_reporter(),
             OutputFormat::Checkstyle => diagnostic_service.set_checkstyle_reporter(),
             OutputFormat::Github => diagnostic_service.set_github_reporter(),
+            OutputFormat::Sarif => diagnostic_service.set_sarif_reporter(),
         }
         diagnostic_service
     }
@@ -417,6 +422,21 @@ mod test {
         assert_eq!(result.number_of_errors, 0);
     }
 
+    #[test]
+    fn report_unused_disable_directives() {
+        let args = &["fixtures/disable_directives/test.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 0);
+
+        // `no-console` is not enabled, so its directive is unused.
+        let args = &["--report-unused-disable-directives", "fixtures/disable_directives/test.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
     #[test]
     fn typescript_eslint() {
         let args = &[
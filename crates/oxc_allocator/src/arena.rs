@@ -131,6 +131,20 @@ impl<'alloc, T> Vec<'alloc, T> {
         vec.extend(iter);
         Self(vec)
     }
+
+    /// Splits the vec into two at `at`, like [std::vec::Vec::split_off],
+    /// but returns an arena [Vec] instead of the inner `allocator_api2` vec.
+    ///
+    /// Other `std::vec::Vec` and slice methods are available through `Deref` and `DerefMut`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`.
+    #[inline]
+    #[must_use]
+    pub fn split_off(&mut self, at: usize) -> Self {
+        Self(self.0.split_off(at))
+    }
 }
 
 impl<'alloc, T> ops::Deref for Vec<'alloc, T> {
@@ -260,6 +274,24 @@ mod test {
         assert_eq!(v, "Vec([\"x\"])");
     }
 
+    #[test]
+    fn vec_methods() {
+        let allocator = Allocator::default();
+        let mut v = Vec::from_iter_in([3, 1, 2, 2, 5, 4], &allocator);
+        v.sort_unstable_by_key(|x| *x);
+        assert_eq!(v.as_slice(), [1, 2, 2, 3, 4, 5]);
+        v.dedup_by_key(|x| *x);
+        assert_eq!(v.as_slice(), [1, 2, 3, 4, 5]);
+        assert_eq!(v.binary_search_by_key(&3, |x| *x), Ok(2));
+        v.extend_from_slice(&[6, 7]);
+        v.retain(|x| x % 2 == 1);
+        assert_eq!(v.as_slice(), [1, 3, 5, 7]);
+        assert_eq!(v.drain(..1).collect::<std::vec::Vec<_>>(), [1]);
+        let tail: Vec<i32> = v.split_off(1);
+        assert_eq!(v.as_slice(), [3]);
+        assert_eq!(tail.as_slice(), [5, 7]);
+    }
+
     #[test]
     fn box_serialize() {
         let allocator = Allocator::default();
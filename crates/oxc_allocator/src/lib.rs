@@ -4,18 +4,68 @@ use std::{
 };
 
 mod arena;
+mod clone_in;
 
 pub use arena::{Box, String, Vec};
 use bumpalo::Bump;
+pub use clone_in::CloneIn;
 
 #[derive(Default)]
 pub struct Allocator {
     bump: Bump,
+    /// The largest `allocated_bytes` before a [Allocator::reset].
+    peak_allocated_bytes: usize,
+}
+
+/// Memory usage of an [Allocator], see [Allocator::stats].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AllocatorStats {
+    /// Bytes currently allocated for chunks, including unused capacity.
+    pub allocated_bytes: usize,
+    /// The largest `allocated_bytes` since the allocator was created, across resets.
+    pub peak_allocated_bytes: usize,
+    /// The number of chunks currently allocated.
+    pub chunk_count: usize,
+}
+
+impl Allocator {
+    /// Deallocates all allocations but the last chunk, which is kept for reuse, e.g. when
+    /// parsing the same file again in a language server.
+    ///
+    /// This is safe: it takes `&mut self`, while all arena references borrow the allocator,
+    /// so the borrow checker rejects calling it while any of them is alive.
+    pub fn reset(&mut self) {
+        self.peak_allocated_bytes = self.peak_allocated_bytes.max(self.allocated_bytes());
+        self.bump.reset();
+    }
+
+    /// Bytes currently allocated for chunks, including unused capacity.
+    pub fn allocated_bytes(&self) -> usize {
+        self.bump.allocated_bytes()
+    }
+
+    /// The number of chunks currently allocated.
+    pub fn chunk_count(&self) -> usize {
+        // SAFETY: nothing is allocated while iterating, and the chunks are only counted.
+        #[allow(unsafe_code)]
+        unsafe {
+            self.bump.iter_allocated_chunks_raw().count()
+        }
+    }
+
+    pub fn stats(&self) -> AllocatorStats {
+        let allocated_bytes = self.allocated_bytes();
+        AllocatorStats {
+            allocated_bytes,
+            peak_allocated_bytes: self.peak_allocated_bytes.max(allocated_bytes),
+            chunk_count: self.chunk_count(),
+        }
+    }
 }
 
 impl From<Bump> for Allocator {
     fn from(bump: Bump) -> Self {
-        Self { bump }
+        Self { bump, peak_allocated_bytes: 0 }
     }
 }
 
@@ -50,4 +100,31 @@ mod test {
             _ = allocator.deref();
         }
     }
+
+    #[test]
+    fn reset() {
+        let mut allocator = Allocator::default();
+        {
+            let vec = crate::Vec::from_iter_in([1, 2, 3], &allocator);
+            assert_eq!(vec.len(), 3);
+        }
+        allocator.reset();
+        assert_eq!(allocator.chunk_count(), 1);
+        let boxed = crate::Box::new_in(1, &allocator);
+        assert_eq!(*boxed, 1);
+    }
+
+    #[test]
+    fn stats() {
+        let mut allocator = Allocator::default();
+        allocator.alloc_slice_fill_copy(1 << 16, 0u8);
+        let stats = allocator.stats();
+        assert!(stats.chunk_count >= 1);
+        assert!(stats.allocated_bytes >= 1 << 16);
+        assert_eq!(stats.peak_allocated_bytes, stats.allocated_bytes);
+        allocator.reset();
+        let stats_after_reset = allocator.stats();
+        assert_eq!(stats_after_reset.chunk_count, 1);
+        assert!(stats_after_reset.peak_allocated_bytes >= stats.allocated_bytes);
+    }
 }
@@ -1,5 +1,5 @@
-// NB: `#[visited_node]` and `#[scope]` attributes on AST nodes do not do anything to the code in this file.
-// They are purely markers for codegen used in `oxc_traverse`. See docs in that crate.
+// NB: `#[visited_node]`, `#[scope]` and `#[ast]` attributes on AST nodes do not do anything to the code in this file.
+// They are purely markers for codegen used in `oxc_traverse` and `ast_codegen`. See docs in those crates.
 
 // Silence erroneous warnings from Rust Analyser for `#[derive(Tsify)]`
 #![allow(non_snake_case)]
@@ -51,6 +51,7 @@ inherit_variants! {
 ///
 /// [`ast` module docs]: `super`
 #[visited_node]
+#[ast(no_kind)]
 #[repr(C, u8)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
@@ -101,6 +102,8 @@ pub enum Expression<'a> {
     TSNonNullExpression(Box<'a, TSNonNullExpression<'a>>) = 37,
     TSInstantiationExpression(Box<'a, TSInstantiationExpression<'a>>) = 38,
 
+    PipelinePlaceholder(Box<'a, PipelinePlaceholder>) = 39,
+
     // `MemberExpression` variants added here by `inherit_variants!` macro
     @inherit MemberExpression
 }
@@ -150,6 +153,7 @@ macro_rules! match_expression {
             | $ty::TSTypeAssertion(_)
             | $ty::TSNonNullExpression(_)
             | $ty::TSInstantiationExpression(_)
+            | $ty::PipelinePlaceholder(_)
             | $ty::ComputedMemberExpression(_)
             | $ty::StaticMemberExpression(_)
             | $ty::PrivateFieldExpression(_)
@@ -276,6 +280,7 @@ pub struct ObjectExpression<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
@@ -355,6 +360,7 @@ pub struct TaggedTemplateExpression<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
@@ -408,6 +414,7 @@ pub use match_member_expression;
 
 /// `MemberExpression[?Yield, ?Await] [ Expression[+In, ?Yield, ?Await] ]`
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
@@ -421,6 +428,7 @@ pub struct ComputedMemberExpression<'a> {
 
 /// `MemberExpression[?Yield, ?Await] . IdentifierName`
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
@@ -434,6 +442,7 @@ pub struct StaticMemberExpression<'a> {
 
 /// `MemberExpression[?Yield, ?Await] . PrivateIdentifier`
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
@@ -564,6 +573,18 @@ pub struct PrivateInExpression<'a> {
     pub right: Expression<'a>,
 }
 
+/// Topic reference `%` in the body of a Hack-style pipeline expression `a |> f(%)`
+///
+/// <https://tc39.es/proposal-pipeline-operator>
+#[visited_node]
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[cfg_attr(feature = "serialize", serde(tag = "type"))]
+pub struct PipelinePlaceholder {
+    #[cfg_attr(feature = "serialize", serde(flatten))]
+    pub span: Span,
+}
+
 /// Binary Logical Operators
 #[visited_node]
 #[derive(Debug, Hash)]
@@ -685,6 +706,7 @@ macro_rules! match_simple_assignment_target {
 pub use match_simple_assignment_target;
 
 #[visited_node]
+#[ast(no_kind)]
 #[repr(C, u8)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
@@ -705,6 +727,7 @@ pub use match_assignment_target_pattern;
 
 // See serializer in serialize.rs
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
@@ -724,6 +747,7 @@ pub struct ArrayAssignmentTarget<'a> {
 
 // See serializer in serialize.rs
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
@@ -740,6 +764,7 @@ pub struct ObjectAssignmentTarget<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename = "RestElement"))]
@@ -757,6 +782,7 @@ inherit_variants! {
 ///
 /// [`ast` module docs]: `super`
 #[visited_node]
+#[ast(no_kind)]
 #[repr(C, u8)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
@@ -780,6 +806,7 @@ pub struct AssignmentTargetWithDefault<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
@@ -790,6 +817,7 @@ pub enum AssignmentTargetProperty<'a> {
 
 /// Assignment Property - Identifier Reference
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
@@ -802,6 +830,7 @@ pub struct AssignmentTargetPropertyIdentifier<'a> {
 
 /// Assignment Property - Property Name
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
@@ -860,6 +889,7 @@ inherit_variants! {
 ///
 /// [`ast` module docs]: `super`
 #[visited_node]
+#[ast(no_kind)]
 #[repr(C, u8)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
@@ -890,6 +920,7 @@ inherit_variants! {
 ///
 /// [`ast` module docs]: `super`
 #[visited_node]
+#[ast(no_kind)]
 #[repr(C, u8)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
@@ -961,6 +992,7 @@ pub struct BlockStatement<'a> {
 
 /// Declarations and the Variable Statement
 #[visited_node]
+#[ast(no_kind)]
 #[repr(C, u8)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
@@ -1015,6 +1047,8 @@ pub enum VariableDeclarationKind {
     Var,
     Const,
     Let,
+    /// Declarators of a [`UsingDeclaration`], block scoped like `const`
+    Using,
 }
 
 #[visited_node]
@@ -1160,6 +1194,7 @@ inherit_variants! {
 ///
 /// [`ast` module docs]: `super`
 #[visited_node]
+#[ast(no_kind)]
 #[repr(C, u8)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
@@ -1330,6 +1365,7 @@ pub struct DebuggerStatement {
 /// Destructuring Binding Patterns
 /// * <https://tc39.es/ecma262/#prod-BindingPattern>
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
@@ -1346,6 +1382,7 @@ pub struct BindingPattern<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
@@ -1389,6 +1426,7 @@ pub struct ObjectPattern<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
@@ -1614,6 +1652,7 @@ pub struct ClassBody<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
@@ -1757,6 +1796,7 @@ pub enum AccessorPropertyType {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub struct AccessorProperty<'a> {
@@ -1798,6 +1838,7 @@ pub struct ImportDeclaration<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
@@ -1848,6 +1889,7 @@ pub struct ImportNamespaceSpecifier<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
@@ -1859,6 +1901,7 @@ pub struct WithClause<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
@@ -1870,6 +1913,7 @@ pub struct ImportAttribute<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
@@ -1941,6 +1985,7 @@ inherit_variants! {
 ///
 /// [`ast` module docs]: `super`
 #[visited_node]
+#[ast(no_kind)]
 #[repr(C, u8)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
@@ -1962,6 +2007,7 @@ pub enum ExportDefaultDeclarationKind<'a> {
 /// * es2022: <https://github.com/estree/estree/blob/master/es2022.md#modules>
 /// * <https://github.com/tc39/ecma262/pull/2154>
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Clone, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
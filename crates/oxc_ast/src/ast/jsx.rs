@@ -1,7 +1,7 @@
 //! [JSX](https://facebook.github.io/jsx)
 
-// NB: `#[visited_node]` and `#[scope]` attributes on AST nodes do not do anything to the code in this file.
-// They are purely markers for codegen used in `oxc_traverse`. See docs in that crate.
+// NB: `#[visited_node]`, `#[scope]` and `#[ast]` attributes on AST nodes do not do anything to the code in this file.
+// They are purely markers for codegen used in `oxc_traverse` and `ast_codegen`. See docs in those crates.
 
 // Silence erroneous warnings from Rust Analyser for `#[derive(Tsify)]`
 #![allow(non_snake_case)]
@@ -149,6 +149,7 @@ inherit_variants! {
 ///
 /// [`ast` module docs]: `super`
 #[visited_node]
+#[ast(no_kind)]
 #[repr(C, u8)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
@@ -161,6 +162,7 @@ pub enum JSXExpression<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
@@ -183,6 +185,7 @@ pub enum JSXAttributeItem<'a> {
 
 /// JSX Attribute
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
@@ -206,6 +209,7 @@ pub struct JSXSpreadAttribute<'a> {
 
 /// JSX Attribute Name
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
@@ -216,6 +220,7 @@ pub enum JSXAttributeName<'a> {
 
 /// JSX Attribute Value
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
@@ -240,6 +245,7 @@ pub struct JSXIdentifier<'a> {
 
 /// JSX Child
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
@@ -252,6 +258,7 @@ pub enum JSXChild<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
@@ -1,7 +1,7 @@
 //! Literals
 
-// NB: `#[visited_node]` and `#[scope]` attributes on AST nodes do not do anything to the code in this file.
-// They are purely markers for codegen used in `oxc_traverse`. See docs in that crate.
+// NB: `#[visited_node]`, `#[scope]` and `#[ast]` attributes on AST nodes do not do anything to the code in this file.
+// They are purely markers for codegen used in `oxc_traverse` and `ast_codegen`. See docs in those crates.
 
 // Silence erroneous warnings from Rust Analyser for `#[derive(Tsify)]`
 #![allow(non_snake_case)]
@@ -170,6 +170,9 @@ macro_rules! inherit_variants {
                 /// Inherited from [`Expression`]
                 TSInstantiationExpression(Box<'a, TSInstantiationExpression<'a>>) = 38,
 
+                /// Inherited from [`Expression`]
+                PipelinePlaceholder(Box<'a, PipelinePlaceholder>) = 39,
+
                 // Inherited from `MemberExpression`
                 @inherit MemberExpression
 
@@ -225,6 +228,7 @@ macro_rules! inherit_variants {
                 TSTypeAssertion,
                 TSNonNullExpression,
                 TSInstantiationExpression,
+                PipelinePlaceholder,
                 ComputedMemberExpression,
                 StaticMemberExpression,
                 PrivateFieldExpression,
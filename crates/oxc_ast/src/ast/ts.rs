@@ -3,8 +3,8 @@
 //! [AST Spec](https://github.com/typescript-eslint/typescript-eslint/tree/main/packages/ast-spec)
 //! [Archived TypeScript spec](https://github.com/microsoft/TypeScript/blob/3c99d50da5a579d9fa92d02664b1b66d4ff55944/doc/spec-ARCHIVED.md)
 
-// NB: `#[visited_node]` and `#[scope]` attributes on AST nodes do not do anything to the code in this file.
-// They are purely markers for codegen used in `oxc_traverse`. See docs in that crate.
+// NB: `#[visited_node]`, `#[scope]` and `#[ast]` attributes on AST nodes do not do anything to the code in this file.
+// They are purely markers for codegen used in `oxc_traverse` and `ast_codegen`. See docs in those crates.
 
 // Silence erroneous warnings from Rust Analyser for `#[derive(Tsify)]`
 #![allow(non_snake_case)]
@@ -80,6 +80,7 @@ inherit_variants! {
 ///
 /// [`ast` module docs]: `super`
 #[visited_node]
+#[ast(no_kind)]
 #[repr(C, u8)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
@@ -116,6 +117,7 @@ pub struct TSLiteralType<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged, rename_all = "camelCase"))]
@@ -131,6 +133,7 @@ pub enum TSLiteral<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[repr(C, u8)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
@@ -229,6 +232,7 @@ pub use match_ts_type;
 ///
 /// <https://www.typescriptlang.org/docs/handbook/2/conditional-types.html#handbook-content>
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
@@ -281,6 +285,7 @@ pub struct TSParenthesizedType<'a> {
 ///
 /// <https://www.typescriptlang.org/docs/handbook/2/keyof-types.html>
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
@@ -304,6 +309,7 @@ pub enum TSTypeOperatorOperator {
 ///
 /// <https://www.typescriptlang.org/docs/handbook/2/objects.html#the-array-type>
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
@@ -331,6 +337,7 @@ pub struct TSIndexedAccessType<'a> {
 ///
 /// <https://www.typescriptlang.org/docs/handbook/2/objects.html#tuple-types>
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
@@ -353,6 +360,7 @@ pub struct TSNamedTupleMember<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
@@ -363,6 +371,7 @@ pub struct TSOptionalType<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
@@ -379,6 +388,7 @@ inherit_variants! {
 ///
 /// [`ast` module docs]: `super`
 #[visited_node]
+#[ast(no_kind)]
 #[repr(C, u8)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
@@ -655,6 +665,7 @@ pub struct TSInterfaceDeclaration<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
@@ -679,6 +690,7 @@ pub struct TSPropertySignature<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged, rename_all = "camelCase"))]
@@ -691,6 +703,7 @@ pub enum TSSignature<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
@@ -703,6 +716,7 @@ pub struct TSIndexSignature<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
@@ -742,6 +756,7 @@ pub struct TSMethodSignature<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
@@ -754,6 +769,7 @@ pub struct TSConstructSignatureDeclaration<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(
@@ -779,6 +795,7 @@ pub struct TSInterfaceHeritage<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
@@ -791,6 +808,7 @@ pub struct TSTypePredicate<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged, rename_all = "camelCase"))]
@@ -843,6 +861,7 @@ impl TSModuleDeclarationKind {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
@@ -852,6 +871,7 @@ pub enum TSModuleDeclarationName<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
@@ -911,6 +931,7 @@ inherit_variants! {
 ///
 /// [`ast` module docs]: `super`
 #[visited_node]
+#[ast(no_kind)]
 #[repr(C, u8)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
@@ -937,6 +958,7 @@ pub struct TSImportType<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
@@ -947,6 +969,7 @@ pub struct TSImportAttributes<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
@@ -958,6 +981,7 @@ pub struct TSImportAttribute<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
@@ -967,6 +991,7 @@ pub enum TSImportAttributeName<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
@@ -980,6 +1005,7 @@ pub struct TSFunctionType<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
@@ -993,6 +1019,7 @@ pub struct TSConstructorType<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
@@ -1081,6 +1108,7 @@ inherit_variants! {
 ///
 /// [`ast` module docs]: `super`
 #[visited_node]
+#[ast(no_kind)]
 #[repr(C, u8)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
@@ -1126,6 +1154,7 @@ pub struct Decorator<'a> {
 ///
 /// `export = foo`
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
@@ -1139,6 +1168,7 @@ pub struct TSExportAssignment<'a> {
 ///
 /// `export as namespace foo`
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
@@ -1171,6 +1201,7 @@ pub enum ImportOrExportKind {
 
 /// `type foo = ty?` or `type foo = ?ty`
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
@@ -1183,6 +1214,7 @@ pub struct JSDocNullableType<'a> {
 
 /// `type foo = ty!` or `type foo = !ty`
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
@@ -1194,6 +1226,7 @@ pub struct JSDocNonNullableType<'a> {
 }
 
 #[visited_node]
+#[ast(no_kind)]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
@@ -862,6 +862,11 @@ impl<'a> AstBuilder<'a> {
         Expression::ThisExpression(self.alloc(ThisExpression { span }))
     }
 
+    #[inline]
+    pub fn pipeline_placeholder(self, span: Span) -> Expression<'a> {
+        Expression::PipelinePlaceholder(self.alloc(PipelinePlaceholder { span }))
+    }
+
     #[inline]
     pub fn unary_expression(
         self,
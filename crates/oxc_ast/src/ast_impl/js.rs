@@ -424,6 +424,14 @@ impl<'a> MemberExpression<'a> {
         }
     }
 
+    pub fn object_mut(&mut self) -> &mut Expression<'a> {
+        match self {
+            MemberExpression::ComputedMemberExpression(expr) => &mut expr.object,
+            MemberExpression::StaticMemberExpression(expr) => &mut expr.object,
+            MemberExpression::PrivateFieldExpression(expr) => &mut expr.object,
+        }
+    }
+
     pub fn static_property_name(&self) -> Option<&str> {
         match self {
             MemberExpression::ComputedMemberExpression(expr) => {
@@ -743,7 +751,7 @@ impl VariableDeclarationKind {
     }
 
     pub fn is_lexical(&self) -> bool {
-        matches!(self, Self::Const | Self::Let)
+        matches!(self, Self::Const | Self::Let | Self::Using)
     }
 
     pub fn as_str(&self) -> &'static str {
@@ -751,6 +759,7 @@ impl VariableDeclarationKind {
             Self::Var => "var",
             Self::Const => "const",
             Self::Let => "let",
+            Self::Using => "using",
         }
     }
 }
@@ -787,7 +796,11 @@ impl<'a> ForStatementInit<'a> {
     /// LexicalDeclaration[In, Yield, Await] :
     ///   LetOrConst BindingList[?In, ?Yield, ?Await] ;
     pub fn is_lexical_declaration(&self) -> bool {
-        matches!(self, Self::VariableDeclaration(decl) if decl.kind.is_lexical())
+        match self {
+            Self::VariableDeclaration(decl) => decl.kind.is_lexical(),
+            Self::UsingDeclaration(_) => true,
+            _ => false,
+        }
     }
 }
 
@@ -835,7 +848,11 @@ impl<'a> ForStatementLeft<'a> {
     /// LexicalDeclaration[In, Yield, Await] :
     ///   LetOrConst BindingList[?In, ?Yield, ?Await] ;
     pub fn is_lexical_declaration(&self) -> bool {
-        matches!(self, Self::VariableDeclaration(decl) if decl.kind.is_lexical())
+        match self {
+            Self::VariableDeclaration(decl) => decl.kind.is_lexical(),
+            Self::UsingDeclaration(_) => true,
+            _ => false,
+        }
     }
 }
 
@@ -13,6 +13,7 @@ use std::{
     hash::{Hash, Hasher},
 };
 
+use oxc_allocator::{Allocator, CloneIn};
 use oxc_span::{Atom, Span};
 use oxc_syntax::number::NumberBase;
 
@@ -93,6 +94,14 @@ impl<'a> fmt::Display for RegExp<'a> {
     }
 }
 
+impl<'alloc> CloneIn<'alloc> for RegExpFlags {
+    type Cloned = Self;
+
+    fn clone_in(&self, _: &'alloc Allocator) -> Self::Cloned {
+        *self
+    }
+}
+
 impl TryFrom<char> for RegExpFlags {
     type Error = char;
 
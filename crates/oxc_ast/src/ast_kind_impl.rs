@@ -23,6 +23,43 @@ impl<'a> AstKind<'a> {
         )
     }
 
+    /// Whether the node is one of the [`Statement`] variants, including declarations and module
+    /// declarations, see [`AstType::is_statement`](crate::AstType::is_statement).
+    ///
+    /// [`AstKind::is_statement`] only matches `VariableDeclaration` of the declarations.
+    pub fn is_statement_variant(self) -> bool {
+        match self {
+            Self::Function(func) => func.is_declaration(),
+            Self::Class(class) => class.is_declaration(),
+            _ => self.ty().is_statement(),
+        }
+    }
+
+    /// Whether the node is one of the [`Declaration`] variants, see [`AstType::is_declaration`](crate::AstType::is_declaration).
+    ///
+    /// Unlike [`AstKind::is_declaration`], this excludes module declarations and class properties.
+    pub fn is_declaration_variant(self) -> bool {
+        match self {
+            Self::Function(func) => func.is_declaration(),
+            Self::Class(class) => class.is_declaration(),
+            _ => self.ty().is_declaration(),
+        }
+    }
+
+    /// Whether the node is one of the [`Expression`] variants, see [`AstType::is_expression`](crate::AstType::is_expression).
+    pub fn is_expression(self) -> bool {
+        match self {
+            Self::Function(func) => func.is_expression(),
+            Self::Class(class) => class.is_expression(),
+            _ => self.ty().is_expression(),
+        }
+    }
+
+    /// Whether the node's type is one of the [`BindingPatternKind`] or [`AssignmentTargetPattern`] variants.
+    pub fn is_pattern(self) -> bool {
+        self.ty().is_pattern()
+    }
+
     #[rustfmt::skip]
     pub fn is_iteration_statement(self) -> bool {
         matches!(self, Self::DoWhileStatement(_) | Self::WhileStatement(_) | Self::ForInStatement(_)
@@ -60,7 +97,22 @@ impl<'a> AstKind<'a> {
     }
 
     pub fn is_function_like(self) -> bool {
-        matches!(self, Self::Function(_) | Self::ArrowFunctionExpression(_))
+        self.ty().is_function_like()
+    }
+
+    /// Whether the node may create a scope, see [`AstType::is_scope_creating`](crate::AstType::is_scope_creating).
+    pub fn is_scope_creating(self) -> bool {
+        self.ty().is_scope_creating()
+    }
+
+    /// Whether the node's bindings are hoisted to the top of the enclosing function or script:
+    /// function declarations and `var` declarations.
+    pub fn is_hoistable_declaration(self) -> bool {
+        match self {
+            Self::Function(func) => func.is_declaration(),
+            Self::VariableDeclaration(decl) => decl.kind.is_var(),
+            _ => false,
+        }
     }
 
     pub fn identifier_name(self) -> Option<Atom<'a>> {
@@ -74,16 +126,11 @@ impl<'a> AstKind<'a> {
     }
 
     pub fn is_jsx(self) -> bool {
-        matches!(
-            self,
-            Self::JSXElement(_)
-                | Self::JSXOpeningElement(_)
-                | Self::JSXElementName(_)
-                | Self::JSXFragment(_)
-                | Self::JSXAttributeItem(_)
-                | Self::JSXText(_)
-                | Self::JSXExpressionContainer(_)
-        )
+        self.ty().is_jsx()
+    }
+
+    pub fn is_typescript(self) -> bool {
+        self.ty().is_typescript()
     }
 
     pub fn is_specific_id_reference(&self, name: &str) -> bool {
@@ -130,6 +177,7 @@ impl<'a> AstKind<'a> {
             Expression::UpdateExpression(e) => Self::UpdateExpression(e),
             Expression::YieldExpression(e) => Self::YieldExpression(e),
             Expression::PrivateInExpression(e) => Self::PrivateInExpression(e),
+            Expression::PipelinePlaceholder(e) => Self::PipelinePlaceholder(e),
             Expression::JSXElement(e) => Self::JSXElement(e),
             Expression::JSXFragment(e) => Self::JSXFragment(e),
             Expression::TSAsExpression(e) => Self::TSAsExpression(e),
@@ -225,6 +273,7 @@ impl<'a> AstKind<'a> {
             Self::YieldExpression(_) => "YieldExpression".into(),
             Self::ImportExpression(_) => "ImportExpression".into(),
             Self::PrivateInExpression(_) => "PrivateInExpression".into(),
+            Self::PipelinePlaceholder(_) => "PipelinePlaceholder".into(),
 
             Self::ObjectProperty(_) => "ObjectProperty".into(),
             Self::PropertyKey(_) => "PropertyKey".into(),
@@ -36,6 +36,7 @@ pub enum AstType {
     UnaryExpression,
     BinaryExpression,
     PrivateInExpression,
+    PipelinePlaceholder,
     LogicalExpression,
     ConditionalExpression,
     AssignmentExpression,
@@ -169,6 +170,565 @@ pub enum AstType {
     ExpressionArrayElement,
 }
 
+impl AstType {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::BooleanLiteral => "BooleanLiteral",
+            Self::NullLiteral => "NullLiteral",
+            Self::NumericLiteral => "NumericLiteral",
+            Self::BigIntLiteral => "BigIntLiteral",
+            Self::RegExpLiteral => "RegExpLiteral",
+            Self::StringLiteral => "StringLiteral",
+            Self::Program => "Program",
+            Self::IdentifierName => "IdentifierName",
+            Self::IdentifierReference => "IdentifierReference",
+            Self::BindingIdentifier => "BindingIdentifier",
+            Self::LabelIdentifier => "LabelIdentifier",
+            Self::ThisExpression => "ThisExpression",
+            Self::ArrayExpression => "ArrayExpression",
+            Self::ArrayExpressionElement => "ArrayExpressionElement",
+            Self::Elision => "Elision",
+            Self::ObjectExpression => "ObjectExpression",
+            Self::ObjectProperty => "ObjectProperty",
+            Self::PropertyKey => "PropertyKey",
+            Self::TemplateLiteral => "TemplateLiteral",
+            Self::TaggedTemplateExpression => "TaggedTemplateExpression",
+            Self::MemberExpression => "MemberExpression",
+            Self::CallExpression => "CallExpression",
+            Self::NewExpression => "NewExpression",
+            Self::MetaProperty => "MetaProperty",
+            Self::SpreadElement => "SpreadElement",
+            Self::Argument => "Argument",
+            Self::UpdateExpression => "UpdateExpression",
+            Self::UnaryExpression => "UnaryExpression",
+            Self::BinaryExpression => "BinaryExpression",
+            Self::PrivateInExpression => "PrivateInExpression",
+            Self::PipelinePlaceholder => "PipelinePlaceholder",
+            Self::LogicalExpression => "LogicalExpression",
+            Self::ConditionalExpression => "ConditionalExpression",
+            Self::AssignmentExpression => "AssignmentExpression",
+            Self::AssignmentTarget => "AssignmentTarget",
+            Self::SimpleAssignmentTarget => "SimpleAssignmentTarget",
+            Self::AssignmentTargetWithDefault => "AssignmentTargetWithDefault",
+            Self::SequenceExpression => "SequenceExpression",
+            Self::Super => "Super",
+            Self::AwaitExpression => "AwaitExpression",
+            Self::ChainExpression => "ChainExpression",
+            Self::ParenthesizedExpression => "ParenthesizedExpression",
+            Self::Directive => "Directive",
+            Self::Hashbang => "Hashbang",
+            Self::BlockStatement => "BlockStatement",
+            Self::VariableDeclaration => "VariableDeclaration",
+            Self::VariableDeclarator => "VariableDeclarator",
+            Self::UsingDeclaration => "UsingDeclaration",
+            Self::EmptyStatement => "EmptyStatement",
+            Self::ExpressionStatement => "ExpressionStatement",
+            Self::IfStatement => "IfStatement",
+            Self::DoWhileStatement => "DoWhileStatement",
+            Self::WhileStatement => "WhileStatement",
+            Self::ForStatement => "ForStatement",
+            Self::ForStatementInit => "ForStatementInit",
+            Self::ForInStatement => "ForInStatement",
+            Self::ForOfStatement => "ForOfStatement",
+            Self::ContinueStatement => "ContinueStatement",
+            Self::BreakStatement => "BreakStatement",
+            Self::ReturnStatement => "ReturnStatement",
+            Self::WithStatement => "WithStatement",
+            Self::SwitchStatement => "SwitchStatement",
+            Self::SwitchCase => "SwitchCase",
+            Self::LabeledStatement => "LabeledStatement",
+            Self::ThrowStatement => "ThrowStatement",
+            Self::TryStatement => "TryStatement",
+            Self::CatchClause => "CatchClause",
+            Self::CatchParameter => "CatchParameter",
+            Self::DebuggerStatement => "DebuggerStatement",
+            Self::AssignmentPattern => "AssignmentPattern",
+            Self::ObjectPattern => "ObjectPattern",
+            Self::ArrayPattern => "ArrayPattern",
+            Self::BindingRestElement => "BindingRestElement",
+            Self::Function => "Function",
+            Self::FormalParameters => "FormalParameters",
+            Self::FormalParameter => "FormalParameter",
+            Self::FunctionBody => "FunctionBody",
+            Self::ArrowFunctionExpression => "ArrowFunctionExpression",
+            Self::YieldExpression => "YieldExpression",
+            Self::Class => "Class",
+            Self::ClassBody => "ClassBody",
+            Self::MethodDefinition => "MethodDefinition",
+            Self::PropertyDefinition => "PropertyDefinition",
+            Self::PrivateIdentifier => "PrivateIdentifier",
+            Self::StaticBlock => "StaticBlock",
+            Self::ModuleDeclaration => "ModuleDeclaration",
+            Self::ImportExpression => "ImportExpression",
+            Self::ImportDeclaration => "ImportDeclaration",
+            Self::ImportSpecifier => "ImportSpecifier",
+            Self::ImportDefaultSpecifier => "ImportDefaultSpecifier",
+            Self::ImportNamespaceSpecifier => "ImportNamespaceSpecifier",
+            Self::ExportNamedDeclaration => "ExportNamedDeclaration",
+            Self::ExportDefaultDeclaration => "ExportDefaultDeclaration",
+            Self::ExportAllDeclaration => "ExportAllDeclaration",
+            Self::ExportSpecifier => "ExportSpecifier",
+            Self::TSThisParameter => "TSThisParameter",
+            Self::TSEnumDeclaration => "TSEnumDeclaration",
+            Self::TSEnumMember => "TSEnumMember",
+            Self::TSTypeAnnotation => "TSTypeAnnotation",
+            Self::TSLiteralType => "TSLiteralType",
+            Self::TSUnionType => "TSUnionType",
+            Self::TSIntersectionType => "TSIntersectionType",
+            Self::TSParenthesizedType => "TSParenthesizedType",
+            Self::TSIndexedAccessType => "TSIndexedAccessType",
+            Self::TSNamedTupleMember => "TSNamedTupleMember",
+            Self::TSAnyKeyword => "TSAnyKeyword",
+            Self::TSStringKeyword => "TSStringKeyword",
+            Self::TSBooleanKeyword => "TSBooleanKeyword",
+            Self::TSNumberKeyword => "TSNumberKeyword",
+            Self::TSNeverKeyword => "TSNeverKeyword",
+            Self::TSIntrinsicKeyword => "TSIntrinsicKeyword",
+            Self::TSUnknownKeyword => "TSUnknownKeyword",
+            Self::TSNullKeyword => "TSNullKeyword",
+            Self::TSUndefinedKeyword => "TSUndefinedKeyword",
+            Self::TSVoidKeyword => "TSVoidKeyword",
+            Self::TSSymbolKeyword => "TSSymbolKeyword",
+            Self::TSThisType => "TSThisType",
+            Self::TSObjectKeyword => "TSObjectKeyword",
+            Self::TSBigIntKeyword => "TSBigIntKeyword",
+            Self::TSTypeReference => "TSTypeReference",
+            Self::TSTypeName => "TSTypeName",
+            Self::TSQualifiedName => "TSQualifiedName",
+            Self::TSTypeParameterInstantiation => "TSTypeParameterInstantiation",
+            Self::TSTypeParameter => "TSTypeParameter",
+            Self::TSTypeParameterDeclaration => "TSTypeParameterDeclaration",
+            Self::TSTypeAliasDeclaration => "TSTypeAliasDeclaration",
+            Self::TSClassImplements => "TSClassImplements",
+            Self::TSInterfaceDeclaration => "TSInterfaceDeclaration",
+            Self::TSPropertySignature => "TSPropertySignature",
+            Self::TSMethodSignature => "TSMethodSignature",
+            Self::TSInterfaceHeritage => "TSInterfaceHeritage",
+            Self::TSModuleDeclaration => "TSModuleDeclaration",
+            Self::TSModuleBlock => "TSModuleBlock",
+            Self::TSTypeLiteral => "TSTypeLiteral",
+            Self::TSInferType => "TSInferType",
+            Self::TSTypeQuery => "TSTypeQuery",
+            Self::TSImportType => "TSImportType",
+            Self::TSTemplateLiteralType => "TSTemplateLiteralType",
+            Self::TSAsExpression => "TSAsExpression",
+            Self::TSSatisfiesExpression => "TSSatisfiesExpression",
+            Self::TSTypeAssertion => "TSTypeAssertion",
+            Self::TSImportEqualsDeclaration => "TSImportEqualsDeclaration",
+            Self::TSExternalModuleReference => "TSExternalModuleReference",
+            Self::TSNonNullExpression => "TSNonNullExpression",
+            Self::Decorator => "Decorator",
+            Self::TSInstantiationExpression => "TSInstantiationExpression",
+            Self::JSXElement => "JSXElement",
+            Self::JSXOpeningElement => "JSXOpeningElement",
+            Self::JSXClosingElement => "JSXClosingElement",
+            Self::JSXFragment => "JSXFragment",
+            Self::JSXElementName => "JSXElementName",
+            Self::JSXNamespacedName => "JSXNamespacedName",
+            Self::JSXMemberExpression => "JSXMemberExpression",
+            Self::JSXMemberExpressionObject => "JSXMemberExpressionObject",
+            Self::JSXExpressionContainer => "JSXExpressionContainer",
+            Self::JSXAttributeItem => "JSXAttributeItem",
+            Self::JSXSpreadAttribute => "JSXSpreadAttribute",
+            Self::JSXIdentifier => "JSXIdentifier",
+            Self::JSXText => "JSXText",
+            Self::FinallyClause => "FinallyClause",
+            Self::ClassHeritage => "ClassHeritage",
+            Self::ExpressionArrayElement => "ExpressionArrayElement",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "BooleanLiteral" => Some(Self::BooleanLiteral),
+            "NullLiteral" => Some(Self::NullLiteral),
+            "NumericLiteral" => Some(Self::NumericLiteral),
+            "BigIntLiteral" => Some(Self::BigIntLiteral),
+            "RegExpLiteral" => Some(Self::RegExpLiteral),
+            "StringLiteral" => Some(Self::StringLiteral),
+            "Program" => Some(Self::Program),
+            "IdentifierName" => Some(Self::IdentifierName),
+            "IdentifierReference" => Some(Self::IdentifierReference),
+            "BindingIdentifier" => Some(Self::BindingIdentifier),
+            "LabelIdentifier" => Some(Self::LabelIdentifier),
+            "ThisExpression" => Some(Self::ThisExpression),
+            "ArrayExpression" => Some(Self::ArrayExpression),
+            "ArrayExpressionElement" => Some(Self::ArrayExpressionElement),
+            "Elision" => Some(Self::Elision),
+            "ObjectExpression" => Some(Self::ObjectExpression),
+            "ObjectProperty" => Some(Self::ObjectProperty),
+            "PropertyKey" => Some(Self::PropertyKey),
+            "TemplateLiteral" => Some(Self::TemplateLiteral),
+            "TaggedTemplateExpression" => Some(Self::TaggedTemplateExpression),
+            "MemberExpression" => Some(Self::MemberExpression),
+            "CallExpression" => Some(Self::CallExpression),
+            "NewExpression" => Some(Self::NewExpression),
+            "MetaProperty" => Some(Self::MetaProperty),
+            "SpreadElement" => Some(Self::SpreadElement),
+            "Argument" => Some(Self::Argument),
+            "UpdateExpression" => Some(Self::UpdateExpression),
+            "UnaryExpression" => Some(Self::UnaryExpression),
+            "BinaryExpression" => Some(Self::BinaryExpression),
+            "PrivateInExpression" => Some(Self::PrivateInExpression),
+            "PipelinePlaceholder" => Some(Self::PipelinePlaceholder),
+            "LogicalExpression" => Some(Self::LogicalExpression),
+            "ConditionalExpression" => Some(Self::ConditionalExpression),
+            "AssignmentExpression" => Some(Self::AssignmentExpression),
+            "AssignmentTarget" => Some(Self::AssignmentTarget),
+            "SimpleAssignmentTarget" => Some(Self::SimpleAssignmentTarget),
+            "AssignmentTargetWithDefault" => Some(Self::AssignmentTargetWithDefault),
+            "SequenceExpression" => Some(Self::SequenceExpression),
+            "Super" => Some(Self::Super),
+            "AwaitExpression" => Some(Self::AwaitExpression),
+            "ChainExpression" => Some(Self::ChainExpression),
+            "ParenthesizedExpression" => Some(Self::ParenthesizedExpression),
+            "Directive" => Some(Self::Directive),
+            "Hashbang" => Some(Self::Hashbang),
+            "BlockStatement" => Some(Self::BlockStatement),
+            "VariableDeclaration" => Some(Self::VariableDeclaration),
+            "VariableDeclarator" => Some(Self::VariableDeclarator),
+            "UsingDeclaration" => Some(Self::UsingDeclaration),
+            "EmptyStatement" => Some(Self::EmptyStatement),
+            "ExpressionStatement" => Some(Self::ExpressionStatement),
+            "IfStatement" => Some(Self::IfStatement),
+            "DoWhileStatement" => Some(Self::DoWhileStatement),
+            "WhileStatement" => Some(Self::WhileStatement),
+            "ForStatement" => Some(Self::ForStatement),
+            "ForStatementInit" => Some(Self::ForStatementInit),
+            "ForInStatement" => Some(Self::ForInStatement),
+            "ForOfStatement" => Some(Self::ForOfStatement),
+            "ContinueStatement" => Some(Self::ContinueStatement),
+            "BreakStatement" => Some(Self::BreakStatement),
+            "ReturnStatement" => Some(Self::ReturnStatement),
+            "WithStatement" => Some(Self::WithStatement),
+            "SwitchStatement" => Some(Self::SwitchStatement),
+            "SwitchCase" => Some(Self::SwitchCase),
+            "LabeledStatement" => Some(Self::LabeledStatement),
+            "ThrowStatement" => Some(Self::ThrowStatement),
+            "TryStatement" => Some(Self::TryStatement),
+            "CatchClause" => Some(Self::CatchClause),
+            "CatchParameter" => Some(Self::CatchParameter),
+            "DebuggerStatement" => Some(Self::DebuggerStatement),
+            "AssignmentPattern" => Some(Self::AssignmentPattern),
+            "ObjectPattern" => Some(Self::ObjectPattern),
+            "ArrayPattern" => Some(Self::ArrayPattern),
+            "BindingRestElement" => Some(Self::BindingRestElement),
+            "Function" => Some(Self::Function),
+            "FormalParameters" => Some(Self::FormalParameters),
+            "FormalParameter" => Some(Self::FormalParameter),
+            "FunctionBody" => Some(Self::FunctionBody),
+            "ArrowFunctionExpression" => Some(Self::ArrowFunctionExpression),
+            "YieldExpression" => Some(Self::YieldExpression),
+            "Class" => Some(Self::Class),
+            "ClassBody" => Some(Self::ClassBody),
+            "MethodDefinition" => Some(Self::MethodDefinition),
+            "PropertyDefinition" => Some(Self::PropertyDefinition),
+            "PrivateIdentifier" => Some(Self::PrivateIdentifier),
+            "StaticBlock" => Some(Self::StaticBlock),
+            "ModuleDeclaration" => Some(Self::ModuleDeclaration),
+            "ImportExpression" => Some(Self::ImportExpression),
+            "ImportDeclaration" => Some(Self::ImportDeclaration),
+            "ImportSpecifier" => Some(Self::ImportSpecifier),
+            "ImportDefaultSpecifier" => Some(Self::ImportDefaultSpecifier),
+            "ImportNamespaceSpecifier" => Some(Self::ImportNamespaceSpecifier),
+            "ExportNamedDeclaration" => Some(Self::ExportNamedDeclaration),
+            "ExportDefaultDeclaration" => Some(Self::ExportDefaultDeclaration),
+            "ExportAllDeclaration" => Some(Self::ExportAllDeclaration),
+            "ExportSpecifier" => Some(Self::ExportSpecifier),
+            "TSThisParameter" => Some(Self::TSThisParameter),
+            "TSEnumDeclaration" => Some(Self::TSEnumDeclaration),
+            "TSEnumMember" => Some(Self::TSEnumMember),
+            "TSTypeAnnotation" => Some(Self::TSTypeAnnotation),
+            "TSLiteralType" => Some(Self::TSLiteralType),
+            "TSUnionType" => Some(Self::TSUnionType),
+            "TSIntersectionType" => Some(Self::TSIntersectionType),
+            "TSParenthesizedType" => Some(Self::TSParenthesizedType),
+            "TSIndexedAccessType" => Some(Self::TSIndexedAccessType),
+            "TSNamedTupleMember" => Some(Self::TSNamedTupleMember),
+            "TSAnyKeyword" => Some(Self::TSAnyKeyword),
+            "TSStringKeyword" => Some(Self::TSStringKeyword),
+            "TSBooleanKeyword" => Some(Self::TSBooleanKeyword),
+            "TSNumberKeyword" => Some(Self::TSNumberKeyword),
+            "TSNeverKeyword" => Some(Self::TSNeverKeyword),
+            "TSIntrinsicKeyword" => Some(Self::TSIntrinsicKeyword),
+            "TSUnknownKeyword" => Some(Self::TSUnknownKeyword),
+            "TSNullKeyword" => Some(Self::TSNullKeyword),
+            "TSUndefinedKeyword" => Some(Self::TSUndefinedKeyword),
+            "TSVoidKeyword" => Some(Self::TSVoidKeyword),
+            "TSSymbolKeyword" => Some(Self::TSSymbolKeyword),
+            "TSThisType" => Some(Self::TSThisType),
+            "TSObjectKeyword" => Some(Self::TSObjectKeyword),
+            "TSBigIntKeyword" => Some(Self::TSBigIntKeyword),
+            "TSTypeReference" => Some(Self::TSTypeReference),
+            "TSTypeName" => Some(Self::TSTypeName),
+            "TSQualifiedName" => Some(Self::TSQualifiedName),
+            "TSTypeParameterInstantiation" => Some(Self::TSTypeParameterInstantiation),
+            "TSTypeParameter" => Some(Self::TSTypeParameter),
+            "TSTypeParameterDeclaration" => Some(Self::TSTypeParameterDeclaration),
+            "TSTypeAliasDeclaration" => Some(Self::TSTypeAliasDeclaration),
+            "TSClassImplements" => Some(Self::TSClassImplements),
+            "TSInterfaceDeclaration" => Some(Self::TSInterfaceDeclaration),
+            "TSPropertySignature" => Some(Self::TSPropertySignature),
+            "TSMethodSignature" => Some(Self::TSMethodSignature),
+            "TSInterfaceHeritage" => Some(Self::TSInterfaceHeritage),
+            "TSModuleDeclaration" => Some(Self::TSModuleDeclaration),
+            "TSModuleBlock" => Some(Self::TSModuleBlock),
+            "TSTypeLiteral" => Some(Self::TSTypeLiteral),
+            "TSInferType" => Some(Self::TSInferType),
+            "TSTypeQuery" => Some(Self::TSTypeQuery),
+            "TSImportType" => Some(Self::TSImportType),
+            "TSTemplateLiteralType" => Some(Self::TSTemplateLiteralType),
+            "TSAsExpression" => Some(Self::TSAsExpression),
+            "TSSatisfiesExpression" => Some(Self::TSSatisfiesExpression),
+            "TSTypeAssertion" => Some(Self::TSTypeAssertion),
+            "TSImportEqualsDeclaration" => Some(Self::TSImportEqualsDeclaration),
+            "TSExternalModuleReference" => Some(Self::TSExternalModuleReference),
+            "TSNonNullExpression" => Some(Self::TSNonNullExpression),
+            "Decorator" => Some(Self::Decorator),
+            "TSInstantiationExpression" => Some(Self::TSInstantiationExpression),
+            "JSXElement" => Some(Self::JSXElement),
+            "JSXOpeningElement" => Some(Self::JSXOpeningElement),
+            "JSXClosingElement" => Some(Self::JSXClosingElement),
+            "JSXFragment" => Some(Self::JSXFragment),
+            "JSXElementName" => Some(Self::JSXElementName),
+            "JSXNamespacedName" => Some(Self::JSXNamespacedName),
+            "JSXMemberExpression" => Some(Self::JSXMemberExpression),
+            "JSXMemberExpressionObject" => Some(Self::JSXMemberExpressionObject),
+            "JSXExpressionContainer" => Some(Self::JSXExpressionContainer),
+            "JSXAttributeItem" => Some(Self::JSXAttributeItem),
+            "JSXSpreadAttribute" => Some(Self::JSXSpreadAttribute),
+            "JSXIdentifier" => Some(Self::JSXIdentifier),
+            "JSXText" => Some(Self::JSXText),
+            "FinallyClause" => Some(Self::FinallyClause),
+            "ClassHeritage" => Some(Self::ClassHeritage),
+            "ExpressionArrayElement" => Some(Self::ExpressionArrayElement),
+            _ => None,
+        }
+    }
+
+    /// Types which can be an [`Expression`], see `AstKind::is_expression` for `Function` and `Class`.
+    pub fn is_expression(self) -> bool {
+        matches!(
+            self,
+            Self::BooleanLiteral
+                | Self::NullLiteral
+                | Self::NumericLiteral
+                | Self::BigIntLiteral
+                | Self::RegExpLiteral
+                | Self::StringLiteral
+                | Self::IdentifierReference
+                | Self::ThisExpression
+                | Self::ArrayExpression
+                | Self::ObjectExpression
+                | Self::TemplateLiteral
+                | Self::TaggedTemplateExpression
+                | Self::MemberExpression
+                | Self::CallExpression
+                | Self::NewExpression
+                | Self::MetaProperty
+                | Self::UpdateExpression
+                | Self::UnaryExpression
+                | Self::BinaryExpression
+                | Self::PrivateInExpression
+                | Self::PipelinePlaceholder
+                | Self::LogicalExpression
+                | Self::ConditionalExpression
+                | Self::AssignmentExpression
+                | Self::SequenceExpression
+                | Self::Super
+                | Self::AwaitExpression
+                | Self::ChainExpression
+                | Self::ParenthesizedExpression
+                | Self::Function
+                | Self::ArrowFunctionExpression
+                | Self::YieldExpression
+                | Self::Class
+                | Self::ImportExpression
+                | Self::TSAsExpression
+                | Self::TSSatisfiesExpression
+                | Self::TSTypeAssertion
+                | Self::TSNonNullExpression
+                | Self::TSInstantiationExpression
+                | Self::JSXElement
+                | Self::JSXFragment
+        )
+    }
+
+    /// Types which can be a [`Statement`], see `AstKind::is_statement_variant` for `Function` and `Class`.
+    pub fn is_statement(self) -> bool {
+        matches!(
+            self,
+            Self::BlockStatement
+                | Self::VariableDeclaration
+                | Self::UsingDeclaration
+                | Self::EmptyStatement
+                | Self::ExpressionStatement
+                | Self::IfStatement
+                | Self::DoWhileStatement
+                | Self::WhileStatement
+                | Self::ForStatement
+                | Self::ForInStatement
+                | Self::ForOfStatement
+                | Self::ContinueStatement
+                | Self::BreakStatement
+                | Self::ReturnStatement
+                | Self::WithStatement
+                | Self::SwitchStatement
+                | Self::LabeledStatement
+                | Self::ThrowStatement
+                | Self::TryStatement
+                | Self::DebuggerStatement
+                | Self::Function
+                | Self::Class
+                | Self::ModuleDeclaration
+                | Self::ImportDeclaration
+                | Self::ExportNamedDeclaration
+                | Self::ExportDefaultDeclaration
+                | Self::ExportAllDeclaration
+                | Self::TSEnumDeclaration
+                | Self::TSTypeAliasDeclaration
+                | Self::TSInterfaceDeclaration
+                | Self::TSModuleDeclaration
+                | Self::TSImportEqualsDeclaration
+        )
+    }
+
+    /// Types which can be a [`Declaration`], see `AstKind::is_declaration_variant` for `Function` and `Class`.
+    pub fn is_declaration(self) -> bool {
+        matches!(
+            self,
+            Self::VariableDeclaration
+                | Self::UsingDeclaration
+                | Self::Function
+                | Self::Class
+                | Self::TSEnumDeclaration
+                | Self::TSTypeAliasDeclaration
+                | Self::TSInterfaceDeclaration
+                | Self::TSModuleDeclaration
+                | Self::TSImportEqualsDeclaration
+        )
+    }
+
+    /// Types which can be a [`BindingPatternKind`] or an [`AssignmentTargetPattern`].
+    pub fn is_pattern(self) -> bool {
+        matches!(
+            self,
+            Self::BindingIdentifier
+                | Self::AssignmentPattern
+                | Self::ObjectPattern
+                | Self::ArrayPattern
+        )
+    }
+
+    pub fn is_function_like(self) -> bool {
+        matches!(self, Self::Function | Self::ArrowFunctionExpression)
+    }
+
+    /// Types which may create a scope, conditional scopes (e.g. `for` statements
+    /// with lexical declarations) depend on the node.
+    pub fn is_scope_creating(self) -> bool {
+        matches!(
+            self,
+            Self::Program
+                | Self::BlockStatement
+                | Self::ForStatement
+                | Self::ForInStatement
+                | Self::ForOfStatement
+                | Self::SwitchStatement
+                | Self::CatchClause
+                | Self::Function
+                | Self::ArrowFunctionExpression
+                | Self::Class
+                | Self::StaticBlock
+                | Self::TSEnumDeclaration
+                | Self::TSTypeParameter
+                | Self::TSModuleDeclaration
+        )
+    }
+
+    pub fn is_jsx(self) -> bool {
+        matches!(
+            self,
+            Self::JSXElement
+                | Self::JSXOpeningElement
+                | Self::JSXClosingElement
+                | Self::JSXFragment
+                | Self::JSXElementName
+                | Self::JSXNamespacedName
+                | Self::JSXMemberExpression
+                | Self::JSXMemberExpressionObject
+                | Self::JSXExpressionContainer
+                | Self::JSXAttributeItem
+                | Self::JSXSpreadAttribute
+                | Self::JSXIdentifier
+                | Self::JSXText
+        )
+    }
+
+    pub fn is_typescript(self) -> bool {
+        matches!(
+            self,
+            Self::TSThisParameter
+                | Self::TSEnumDeclaration
+                | Self::TSEnumMember
+                | Self::TSTypeAnnotation
+                | Self::TSLiteralType
+                | Self::TSUnionType
+                | Self::TSIntersectionType
+                | Self::TSParenthesizedType
+                | Self::TSIndexedAccessType
+                | Self::TSNamedTupleMember
+                | Self::TSAnyKeyword
+                | Self::TSStringKeyword
+                | Self::TSBooleanKeyword
+                | Self::TSNumberKeyword
+                | Self::TSNeverKeyword
+                | Self::TSIntrinsicKeyword
+                | Self::TSUnknownKeyword
+                | Self::TSNullKeyword
+                | Self::TSUndefinedKeyword
+                | Self::TSVoidKeyword
+                | Self::TSSymbolKeyword
+                | Self::TSThisType
+                | Self::TSObjectKeyword
+                | Self::TSBigIntKeyword
+                | Self::TSTypeReference
+                | Self::TSTypeName
+                | Self::TSQualifiedName
+                | Self::TSTypeParameterInstantiation
+                | Self::TSTypeParameter
+                | Self::TSTypeParameterDeclaration
+                | Self::TSTypeAliasDeclaration
+                | Self::TSClassImplements
+                | Self::TSInterfaceDeclaration
+                | Self::TSPropertySignature
+                | Self::TSMethodSignature
+                | Self::TSInterfaceHeritage
+                | Self::TSModuleDeclaration
+                | Self::TSModuleBlock
+                | Self::TSTypeLiteral
+                | Self::TSInferType
+                | Self::TSTypeQuery
+                | Self::TSImportType
+                | Self::TSTemplateLiteralType
+                | Self::TSAsExpression
+                | Self::TSSatisfiesExpression
+                | Self::TSTypeAssertion
+                | Self::TSImportEqualsDeclaration
+                | Self::TSExternalModuleReference
+                | Self::TSNonNullExpression
+                | Self::Decorator
+                | Self::TSInstantiationExpression
+        )
+    }
+}
+
+impl std::fmt::Display for AstType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
 /// Untyped AST Node Kind
 #[derive(Debug, Clone, Copy)]
 pub enum AstKind<'a> {
@@ -202,6 +762,7 @@ pub enum AstKind<'a> {
     UnaryExpression(&'a UnaryExpression<'a>),
     BinaryExpression(&'a BinaryExpression<'a>),
     PrivateInExpression(&'a PrivateInExpression<'a>),
+    PipelinePlaceholder(&'a PipelinePlaceholder),
     LogicalExpression(&'a LogicalExpression<'a>),
     ConditionalExpression(&'a ConditionalExpression<'a>),
     AssignmentExpression(&'a AssignmentExpression<'a>),
@@ -335,6 +896,181 @@ pub enum AstKind<'a> {
     ExpressionArrayElement(&'a Expression<'a>),
 }
 
+impl<'a> AstKind<'a> {
+    pub fn ty(&self) -> AstType {
+        match self {
+            Self::BooleanLiteral(_) => AstType::BooleanLiteral,
+            Self::NullLiteral(_) => AstType::NullLiteral,
+            Self::NumericLiteral(_) => AstType::NumericLiteral,
+            Self::BigIntLiteral(_) => AstType::BigIntLiteral,
+            Self::RegExpLiteral(_) => AstType::RegExpLiteral,
+            Self::StringLiteral(_) => AstType::StringLiteral,
+            Self::Program(_) => AstType::Program,
+            Self::IdentifierName(_) => AstType::IdentifierName,
+            Self::IdentifierReference(_) => AstType::IdentifierReference,
+            Self::BindingIdentifier(_) => AstType::BindingIdentifier,
+            Self::LabelIdentifier(_) => AstType::LabelIdentifier,
+            Self::ThisExpression(_) => AstType::ThisExpression,
+            Self::ArrayExpression(_) => AstType::ArrayExpression,
+            Self::ArrayExpressionElement(_) => AstType::ArrayExpressionElement,
+            Self::Elision(_) => AstType::Elision,
+            Self::ObjectExpression(_) => AstType::ObjectExpression,
+            Self::ObjectProperty(_) => AstType::ObjectProperty,
+            Self::PropertyKey(_) => AstType::PropertyKey,
+            Self::TemplateLiteral(_) => AstType::TemplateLiteral,
+            Self::TaggedTemplateExpression(_) => AstType::TaggedTemplateExpression,
+            Self::MemberExpression(_) => AstType::MemberExpression,
+            Self::CallExpression(_) => AstType::CallExpression,
+            Self::NewExpression(_) => AstType::NewExpression,
+            Self::MetaProperty(_) => AstType::MetaProperty,
+            Self::SpreadElement(_) => AstType::SpreadElement,
+            Self::Argument(_) => AstType::Argument,
+            Self::UpdateExpression(_) => AstType::UpdateExpression,
+            Self::UnaryExpression(_) => AstType::UnaryExpression,
+            Self::BinaryExpression(_) => AstType::BinaryExpression,
+            Self::PrivateInExpression(_) => AstType::PrivateInExpression,
+            Self::PipelinePlaceholder(_) => AstType::PipelinePlaceholder,
+            Self::LogicalExpression(_) => AstType::LogicalExpression,
+            Self::ConditionalExpression(_) => AstType::ConditionalExpression,
+            Self::AssignmentExpression(_) => AstType::AssignmentExpression,
+            Self::AssignmentTarget(_) => AstType::AssignmentTarget,
+            Self::SimpleAssignmentTarget(_) => AstType::SimpleAssignmentTarget,
+            Self::AssignmentTargetWithDefault(_) => AstType::AssignmentTargetWithDefault,
+            Self::SequenceExpression(_) => AstType::SequenceExpression,
+            Self::Super(_) => AstType::Super,
+            Self::AwaitExpression(_) => AstType::AwaitExpression,
+            Self::ChainExpression(_) => AstType::ChainExpression,
+            Self::ParenthesizedExpression(_) => AstType::ParenthesizedExpression,
+            Self::Directive(_) => AstType::Directive,
+            Self::Hashbang(_) => AstType::Hashbang,
+            Self::BlockStatement(_) => AstType::BlockStatement,
+            Self::VariableDeclaration(_) => AstType::VariableDeclaration,
+            Self::VariableDeclarator(_) => AstType::VariableDeclarator,
+            Self::UsingDeclaration(_) => AstType::UsingDeclaration,
+            Self::EmptyStatement(_) => AstType::EmptyStatement,
+            Self::ExpressionStatement(_) => AstType::ExpressionStatement,
+            Self::IfStatement(_) => AstType::IfStatement,
+            Self::DoWhileStatement(_) => AstType::DoWhileStatement,
+            Self::WhileStatement(_) => AstType::WhileStatement,
+            Self::ForStatement(_) => AstType::ForStatement,
+            Self::ForStatementInit(_) => AstType::ForStatementInit,
+            Self::ForInStatement(_) => AstType::ForInStatement,
+            Self::ForOfStatement(_) => AstType::ForOfStatement,
+            Self::ContinueStatement(_) => AstType::ContinueStatement,
+            Self::BreakStatement(_) => AstType::BreakStatement,
+            Self::ReturnStatement(_) => AstType::ReturnStatement,
+            Self::WithStatement(_) => AstType::WithStatement,
+            Self::SwitchStatement(_) => AstType::SwitchStatement,
+            Self::SwitchCase(_) => AstType::SwitchCase,
+            Self::LabeledStatement(_) => AstType::LabeledStatement,
+            Self::ThrowStatement(_) => AstType::ThrowStatement,
+            Self::TryStatement(_) => AstType::TryStatement,
+            Self::CatchClause(_) => AstType::CatchClause,
+            Self::CatchParameter(_) => AstType::CatchParameter,
+            Self::DebuggerStatement(_) => AstType::DebuggerStatement,
+            Self::AssignmentPattern(_) => AstType::AssignmentPattern,
+            Self::ObjectPattern(_) => AstType::ObjectPattern,
+            Self::ArrayPattern(_) => AstType::ArrayPattern,
+            Self::BindingRestElement(_) => AstType::BindingRestElement,
+            Self::Function(_) => AstType::Function,
+            Self::FormalParameters(_) => AstType::FormalParameters,
+            Self::FormalParameter(_) => AstType::FormalParameter,
+            Self::FunctionBody(_) => AstType::FunctionBody,
+            Self::ArrowFunctionExpression(_) => AstType::ArrowFunctionExpression,
+            Self::YieldExpression(_) => AstType::YieldExpression,
+            Self::Class(_) => AstType::Class,
+            Self::ClassBody(_) => AstType::ClassBody,
+            Self::MethodDefinition(_) => AstType::MethodDefinition,
+            Self::PropertyDefinition(_) => AstType::PropertyDefinition,
+            Self::PrivateIdentifier(_) => AstType::PrivateIdentifier,
+            Self::StaticBlock(_) => AstType::StaticBlock,
+            Self::ModuleDeclaration(_) => AstType::ModuleDeclaration,
+            Self::ImportExpression(_) => AstType::ImportExpression,
+            Self::ImportDeclaration(_) => AstType::ImportDeclaration,
+            Self::ImportSpecifier(_) => AstType::ImportSpecifier,
+            Self::ImportDefaultSpecifier(_) => AstType::ImportDefaultSpecifier,
+            Self::ImportNamespaceSpecifier(_) => AstType::ImportNamespaceSpecifier,
+            Self::ExportNamedDeclaration(_) => AstType::ExportNamedDeclaration,
+            Self::ExportDefaultDeclaration(_) => AstType::ExportDefaultDeclaration,
+            Self::ExportAllDeclaration(_) => AstType::ExportAllDeclaration,
+            Self::ExportSpecifier(_) => AstType::ExportSpecifier,
+            Self::TSThisParameter(_) => AstType::TSThisParameter,
+            Self::TSEnumDeclaration(_) => AstType::TSEnumDeclaration,
+            Self::TSEnumMember(_) => AstType::TSEnumMember,
+            Self::TSTypeAnnotation(_) => AstType::TSTypeAnnotation,
+            Self::TSLiteralType(_) => AstType::TSLiteralType,
+            Self::TSUnionType(_) => AstType::TSUnionType,
+            Self::TSIntersectionType(_) => AstType::TSIntersectionType,
+            Self::TSParenthesizedType(_) => AstType::TSParenthesizedType,
+            Self::TSIndexedAccessType(_) => AstType::TSIndexedAccessType,
+            Self::TSNamedTupleMember(_) => AstType::TSNamedTupleMember,
+            Self::TSAnyKeyword(_) => AstType::TSAnyKeyword,
+            Self::TSStringKeyword(_) => AstType::TSStringKeyword,
+            Self::TSBooleanKeyword(_) => AstType::TSBooleanKeyword,
+            Self::TSNumberKeyword(_) => AstType::TSNumberKeyword,
+            Self::TSNeverKeyword(_) => AstType::TSNeverKeyword,
+            Self::TSIntrinsicKeyword(_) => AstType::TSIntrinsicKeyword,
+            Self::TSUnknownKeyword(_) => AstType::TSUnknownKeyword,
+            Self::TSNullKeyword(_) => AstType::TSNullKeyword,
+            Self::TSUndefinedKeyword(_) => AstType::TSUndefinedKeyword,
+            Self::TSVoidKeyword(_) => AstType::TSVoidKeyword,
+            Self::TSSymbolKeyword(_) => AstType::TSSymbolKeyword,
+            Self::TSThisType(_) => AstType::TSThisType,
+            Self::TSObjectKeyword(_) => AstType::TSObjectKeyword,
+            Self::TSBigIntKeyword(_) => AstType::TSBigIntKeyword,
+            Self::TSTypeReference(_) => AstType::TSTypeReference,
+            Self::TSTypeName(_) => AstType::TSTypeName,
+            Self::TSQualifiedName(_) => AstType::TSQualifiedName,
+            Self::TSTypeParameterInstantiation(_) => AstType::TSTypeParameterInstantiation,
+            Self::TSTypeParameter(_) => AstType::TSTypeParameter,
+            Self::TSTypeParameterDeclaration(_) => AstType::TSTypeParameterDeclaration,
+            Self::TSTypeAliasDeclaration(_) => AstType::TSTypeAliasDeclaration,
+            Self::TSClassImplements(_) => AstType::TSClassImplements,
+            Self::TSInterfaceDeclaration(_) => AstType::TSInterfaceDeclaration,
+            Self::TSPropertySignature(_) => AstType::TSPropertySignature,
+            Self::TSMethodSignature(_) => AstType::TSMethodSignature,
+            Self::TSInterfaceHeritage(_) => AstType::TSInterfaceHeritage,
+            Self::TSModuleDeclaration(_) => AstType::TSModuleDeclaration,
+            Self::TSModuleBlock(_) => AstType::TSModuleBlock,
+            Self::TSTypeLiteral(_) => AstType::TSTypeLiteral,
+            Self::TSInferType(_) => AstType::TSInferType,
+            Self::TSTypeQuery(_) => AstType::TSTypeQuery,
+            Self::TSImportType(_) => AstType::TSImportType,
+            Self::TSTemplateLiteralType(_) => AstType::TSTemplateLiteralType,
+            Self::TSAsExpression(_) => AstType::TSAsExpression,
+            Self::TSSatisfiesExpression(_) => AstType::TSSatisfiesExpression,
+            Self::TSTypeAssertion(_) => AstType::TSTypeAssertion,
+            Self::TSImportEqualsDeclaration(_) => AstType::TSImportEqualsDeclaration,
+            Self::TSExternalModuleReference(_) => AstType::TSExternalModuleReference,
+            Self::TSNonNullExpression(_) => AstType::TSNonNullExpression,
+            Self::Decorator(_) => AstType::Decorator,
+            Self::TSInstantiationExpression(_) => AstType::TSInstantiationExpression,
+            Self::JSXElement(_) => AstType::JSXElement,
+            Self::JSXOpeningElement(_) => AstType::JSXOpeningElement,
+            Self::JSXClosingElement(_) => AstType::JSXClosingElement,
+            Self::JSXFragment(_) => AstType::JSXFragment,
+            Self::JSXElementName(_) => AstType::JSXElementName,
+            Self::JSXNamespacedName(_) => AstType::JSXNamespacedName,
+            Self::JSXMemberExpression(_) => AstType::JSXMemberExpression,
+            Self::JSXMemberExpressionObject(_) => AstType::JSXMemberExpressionObject,
+            Self::JSXExpressionContainer(_) => AstType::JSXExpressionContainer,
+            Self::JSXAttributeItem(_) => AstType::JSXAttributeItem,
+            Self::JSXSpreadAttribute(_) => AstType::JSXSpreadAttribute,
+            Self::JSXIdentifier(_) => AstType::JSXIdentifier,
+            Self::JSXText(_) => AstType::JSXText,
+            Self::FinallyClause(_) => AstType::FinallyClause,
+            Self::ClassHeritage(_) => AstType::ClassHeritage,
+            Self::ExpressionArrayElement(_) => AstType::ExpressionArrayElement,
+        }
+    }
+}
+
+impl<'a> std::fmt::Display for AstKind<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.ty(), f)
+    }
+}
+
 impl<'a> GetSpan for AstKind<'a> {
     #[allow(clippy::match_same_arms)]
     fn span(&self) -> Span {
@@ -369,6 +1105,7 @@ impl<'a> GetSpan for AstKind<'a> {
             Self::UnaryExpression(it) => it.span(),
             Self::BinaryExpression(it) => it.span(),
             Self::PrivateInExpression(it) => it.span(),
+            Self::PipelinePlaceholder(it) => it.span(),
             Self::LogicalExpression(it) => it.span(),
             Self::ConditionalExpression(it) => it.span(),
             Self::AssignmentExpression(it) => it.span(),
@@ -97,6 +97,7 @@ impl<'a> GetSpan for Expression<'a> {
             Self::TSTypeAssertion(it) => it.span(),
             Self::TSNonNullExpression(it) => it.span(),
             Self::TSInstantiationExpression(it) => it.span(),
+            Self::PipelinePlaceholder(it) => it.span(),
             Self::ComputedMemberExpression(it) => it.span(),
             Self::StaticMemberExpression(it) => it.span(),
             Self::PrivateFieldExpression(it) => it.span(),
@@ -190,6 +191,7 @@ impl<'a> GetSpan for ArrayExpressionElement<'a> {
             Self::TSTypeAssertion(it) => it.span(),
             Self::TSNonNullExpression(it) => it.span(),
             Self::TSInstantiationExpression(it) => it.span(),
+            Self::PipelinePlaceholder(it) => it.span(),
             Self::ComputedMemberExpression(it) => it.span(),
             Self::StaticMemberExpression(it) => it.span(),
             Self::PrivateFieldExpression(it) => it.span(),
@@ -271,6 +273,7 @@ impl<'a> GetSpan for PropertyKey<'a> {
             Self::TSTypeAssertion(it) => it.span(),
             Self::TSNonNullExpression(it) => it.span(),
             Self::TSInstantiationExpression(it) => it.span(),
+            Self::PipelinePlaceholder(it) => it.span(),
             Self::ComputedMemberExpression(it) => it.span(),
             Self::StaticMemberExpression(it) => it.span(),
             Self::PrivateFieldExpression(it) => it.span(),
@@ -401,6 +404,7 @@ impl<'a> GetSpan for Argument<'a> {
             Self::TSTypeAssertion(it) => it.span(),
             Self::TSNonNullExpression(it) => it.span(),
             Self::TSInstantiationExpression(it) => it.span(),
+            Self::PipelinePlaceholder(it) => it.span(),
             Self::ComputedMemberExpression(it) => it.span(),
             Self::StaticMemberExpression(it) => it.span(),
             Self::PrivateFieldExpression(it) => it.span(),
@@ -436,6 +440,13 @@ impl<'a> GetSpan for PrivateInExpression<'a> {
     }
 }
 
+impl GetSpan for PipelinePlaceholder {
+    #[inline]
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
 impl<'a> GetSpan for LogicalExpression<'a> {
     #[inline]
     fn span(&self) -> Span {
@@ -800,6 +811,7 @@ impl<'a> GetSpan for ForStatementInit<'a> {
             Self::TSTypeAssertion(it) => it.span(),
             Self::TSNonNullExpression(it) => it.span(),
             Self::TSInstantiationExpression(it) => it.span(),
+            Self::PipelinePlaceholder(it) => it.span(),
             Self::ComputedMemberExpression(it) => it.span(),
             Self::StaticMemberExpression(it) => it.span(),
             Self::PrivateFieldExpression(it) => it.span(),
@@ -1228,6 +1240,7 @@ impl<'a> GetSpan for ExportDefaultDeclarationKind<'a> {
             Self::TSTypeAssertion(it) => it.span(),
             Self::TSNonNullExpression(it) => it.span(),
             Self::TSInstantiationExpression(it) => it.span(),
+            Self::PipelinePlaceholder(it) => it.span(),
             Self::ComputedMemberExpression(it) => it.span(),
             Self::StaticMemberExpression(it) => it.span(),
             Self::PrivateFieldExpression(it) => it.span(),
@@ -1311,6 +1324,7 @@ impl<'a> GetSpan for TSEnumMemberName<'a> {
             Self::TSTypeAssertion(it) => it.span(),
             Self::TSNonNullExpression(it) => it.span(),
             Self::TSInstantiationExpression(it) => it.span(),
+            Self::PipelinePlaceholder(it) => it.span(),
             Self::ComputedMemberExpression(it) => it.span(),
             Self::StaticMemberExpression(it) => it.span(),
             Self::PrivateFieldExpression(it) => it.span(),
@@ -2096,6 +2110,7 @@ impl<'a> GetSpan for JSXExpression<'a> {
             Self::TSTypeAssertion(it) => it.span(),
             Self::TSNonNullExpression(it) => it.span(),
             Self::TSInstantiationExpression(it) => it.span(),
+            Self::PipelinePlaceholder(it) => it.span(),
             Self::ComputedMemberExpression(it) => it.span(),
             Self::StaticMemberExpression(it) => it.span(),
             Self::PrivateFieldExpression(it) => it.span(),
@@ -23,6 +23,7 @@ pub mod visit;
 
 mod generated {
     pub mod ast_kind;
+    pub mod derive_clone_in;
     pub mod span;
 }
 
@@ -345,6 +345,10 @@ pub trait Visit<'a>: Sized {
         walk_this_expression(self, expr);
     }
 
+    fn visit_pipeline_placeholder(&mut self, expr: &PipelinePlaceholder) {
+        walk_pipeline_placeholder(self, expr);
+    }
+
     fn visit_unary_expression(&mut self, expr: &UnaryExpression<'a>) {
         walk_unary_expression(self, expr);
     }
@@ -1511,6 +1515,7 @@ pub mod walk {
                 visitor.visit_tagged_template_expression(expr);
             }
             Expression::ThisExpression(expr) => visitor.visit_this_expression(expr),
+            Expression::PipelinePlaceholder(expr) => visitor.visit_pipeline_placeholder(expr),
             Expression::UnaryExpression(expr) => visitor.visit_unary_expression(expr),
             Expression::UpdateExpression(expr) => visitor.visit_update_expression(expr),
             Expression::YieldExpression(expr) => visitor.visit_yield_expression(expr),
@@ -1837,6 +1842,15 @@ pub mod walk {
         visitor.leave_node(kind);
     }
 
+    pub fn walk_pipeline_placeholder<'a, V: Visit<'a>>(
+        visitor: &mut V,
+        expr: &PipelinePlaceholder,
+    ) {
+        let kind = AstKind::PipelinePlaceholder(visitor.alloc(expr));
+        visitor.enter_node(kind);
+        visitor.leave_node(kind);
+    }
+
     pub fn walk_unary_expression<'a, V: Visit<'a>>(visitor: &mut V, expr: &UnaryExpression<'a>) {
         let kind = AstKind::UnaryExpression(visitor.alloc(expr));
         visitor.enter_node(kind);
@@ -328,6 +328,10 @@ pub trait VisitMut<'a>: Sized {
         walk_this_expression_mut(self, expr);
     }
 
+    fn visit_pipeline_placeholder(&mut self, expr: &mut PipelinePlaceholder) {
+        walk_pipeline_placeholder_mut(self, expr);
+    }
+
     fn visit_unary_expression(&mut self, expr: &mut UnaryExpression<'a>) {
         walk_unary_expression_mut(self, expr);
     }
@@ -1496,6 +1500,7 @@ pub mod walk_mut {
                 visitor.visit_tagged_template_expression(expr);
             }
             Expression::ThisExpression(expr) => visitor.visit_this_expression(expr),
+            Expression::PipelinePlaceholder(expr) => visitor.visit_pipeline_placeholder(expr),
             Expression::UnaryExpression(expr) => visitor.visit_unary_expression(expr),
             Expression::UpdateExpression(expr) => visitor.visit_update_expression(expr),
             Expression::YieldExpression(expr) => visitor.visit_yield_expression(expr),
@@ -1862,6 +1867,15 @@ pub mod walk_mut {
         visitor.leave_node(kind);
     }
 
+    pub fn walk_pipeline_placeholder_mut<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        _expr: &mut PipelinePlaceholder,
+    ) {
+        let kind = AstType::PipelinePlaceholder;
+        visitor.enter_node(kind);
+        visitor.leave_node(kind);
+    }
+
     pub fn walk_unary_expression_mut<'a, V: VisitMut<'a>>(
         visitor: &mut V,
         expr: &mut UnaryExpression<'a>,
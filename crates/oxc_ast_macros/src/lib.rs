@@ -6,7 +6,8 @@ use std::str::FromStr;
 /// Macro does not generate any code - it's purely a means to communicate information to the codegen.
 ///
 /// Only thing macro does is add `#[derive(VisitedNode)]` to the item.
-/// Deriving `VisitedNode` does nothing, but supports the `#[scope]` attr on struct fields.
+/// Deriving `VisitedNode` does nothing, but supports the `#[scope]` attr on struct fields,
+/// and the `#[ast]` attr on the type (e.g. `#[ast(no_kind)]` to omit the type from `AstKind`).
 /// This is a workaround for Rust not supporting helper attributes for `proc_macro_attribute` macros,
 /// so we need to use a derive macro to get that support.
 ///
@@ -21,8 +22,8 @@ pub fn visited_node(_args: TokenStream, input: TokenStream) -> TokenStream {
 
 /// Dummy derive macro for a non-existent trait `VisitedNode`.
 ///
-/// Does not generate any code, only purpose is to allow using `#[scope]` attr in the type def.
-#[proc_macro_derive(VisitedNode, attributes(scope))]
+/// Does not generate any code, only purpose is to allow using `#[scope]` and `#[ast]` attrs in the type def.
+#[proc_macro_derive(VisitedNode, attributes(scope, ast))]
 pub fn visited_node_derive(_item: TokenStream) -> TokenStream {
     TokenStream::new()
 }
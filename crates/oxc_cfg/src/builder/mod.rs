@@ -28,7 +28,7 @@ pub struct ControlFlowGraphBuilder<'a> {
 
 impl<'a> ControlFlowGraphBuilder<'a> {
     pub fn build(self) -> ControlFlowGraph {
-        ControlFlowGraph { graph: self.graph, basic_blocks: self.basic_blocks }
+        ControlFlowGraph::new(self.graph, self.basic_blocks)
     }
 
     pub fn current_basic_block(&mut self) -> &mut BasicBlock {
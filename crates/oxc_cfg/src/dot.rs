@@ -19,6 +19,16 @@ pub trait DisplayDot {
 
 impl DisplayDot for ControlFlowGraph {
     fn display_dot(&self) -> String {
+        self.dot_with_labels(DisplayDot::display_dot)
+    }
+}
+
+impl ControlFlowGraph {
+    /// Print the graph in Graphviz dot format, labeling each basic block with `label`.
+    ///
+    /// Unreachable edges are dotted and loop headers (see [`ControlFlowGraph::loops`]) are
+    /// double circled.
+    pub fn dot_with_labels<F: Fn(&BasicBlock) -> String>(&self, label: F) -> String {
         format!(
             "{:?}",
             Dot::with_attr_getters(
@@ -35,10 +45,14 @@ impl DisplayDot for ControlFlowGraph {
                         label
                     }
                 },
-                &|_graph, node| format!(
-                    "label = {:?} ",
-                    self.basic_blocks[*node.1].display_dot().trim()
-                ),
+                &|_graph, node| {
+                    let label = format!("label = {:?} ", label(&self.basic_blocks[*node.1]).trim());
+                    if self.is_loop_header(node.0) {
+                        format!("{label}, peripheries = 2 ")
+                    } else {
+                        label
+                    }
+                },
             )
         )
     }
@@ -2,13 +2,17 @@ mod builder;
 mod dot;
 pub mod visit;
 
+use std::cell::OnceCell;
+
 use itertools::Itertools;
 use oxc_syntax::node::AstNodeId;
 use petgraph::{
+    algo::dominators::{self, Dominators},
     stable_graph::NodeIndex,
-    visit::{Control, DfsEvent, EdgeRef},
+    visit::{Control, DfsEvent, EdgeFiltered, EdgeRef},
     Direction, Graph,
 };
+use rustc_hash::FxHashSet;
 
 pub mod graph {
     pub use petgraph::*;
@@ -41,6 +45,17 @@ impl BasicBlock {
     }
 }
 
+/// A natural loop, see [ControlFlowGraph::loops].
+#[derive(Debug, Clone)]
+pub struct LoopInfo {
+    /// The block dominating all blocks of the loop, where each iteration starts.
+    pub header: BasicBlockId,
+    /// The sources of the back edges to `header`.
+    pub latches: Vec<BasicBlockId>,
+    /// All blocks of the loop, including `header` and `latches`.
+    pub body: FxHashSet<BasicBlockId>,
+}
+
 #[derive(Debug, Clone)]
 pub struct Instruction {
     pub kind: InstructionKind,
@@ -119,17 +134,179 @@ pub enum EvalConstConditionResult {
     Eval(bool),
 }
 
+/// The graph is immutable once built, so the dominator tree and the values derived
+/// from it are computed lazily and cached.
 #[derive(Debug)]
 pub struct ControlFlowGraph {
-    pub graph: Graph<usize, EdgeType>,
-    pub basic_blocks: Vec<BasicBlock>,
+    graph: Graph<usize, EdgeType>,
+    basic_blocks: Vec<BasicBlock>,
+    /// Computed on first access, see [ControlFlowGraph::immediate_dominator].
+    dominators: OnceCell<Option<Dominators<BasicBlockId>>>,
+    /// Computed on first access, see [ControlFlowGraph::dominance_frontiers].
+    dominance_frontiers: OnceCell<Vec<FxHashSet<BasicBlockId>>>,
+    /// Computed on first access, see [ControlFlowGraph::loops].
+    loops: OnceCell<Loops>,
+}
+
+#[derive(Debug)]
+struct Loops {
+    loops: Vec<LoopInfo>,
+    /// The loop depth of each basic block, indexed by [BasicBlockId::index].
+    depths: Vec<u32>,
 }
 
 impl ControlFlowGraph {
+    pub(crate) fn new(graph: Graph<usize, EdgeType>, basic_blocks: Vec<BasicBlock>) -> Self {
+        Self {
+            graph,
+            basic_blocks,
+            dominators: OnceCell::new(),
+            dominance_frontiers: OnceCell::new(),
+            loops: OnceCell::new(),
+        }
+    }
+
     pub fn graph(&self) -> &Graph<usize, EdgeType> {
         &self.graph
     }
 
+    /// The basic blocks, indexed by the weights of the graph nodes.
+    pub fn basic_blocks(&self) -> &[BasicBlock] {
+        &self.basic_blocks
+    }
+
+    /// The first basic block without incoming edges, where the program starts.
+    pub fn entry(&self) -> Option<BasicBlockId> {
+        self.graph.externals(Direction::Incoming).next()
+    }
+
+    /// Returns the immediate dominator of `id`,
+    /// or `None` for the entry and blocks which can not be reached from it.
+    ///
+    /// `Unreachable` edges are ignored, `NewFunction` edges are followed,
+    /// so the entry of a function is dominated by the block defining it.
+    pub fn immediate_dominator(&self, id: BasicBlockId) -> Option<BasicBlockId> {
+        self.dominators().as_ref()?.immediate_dominator(id)
+    }
+
+    /// Returns `true` if every path from the entry to `b` goes through `a`.
+    pub fn dominates(&self, a: BasicBlockId, b: BasicBlockId) -> bool {
+        self.dominators()
+            .as_ref()
+            .and_then(|dominators| dominators.dominators(b))
+            .is_some_and(|mut iter| iter.any(|id| id == a))
+    }
+
+    fn dominators(&self) -> &Option<Dominators<BasicBlockId>> {
+        self.dominators.get_or_init(|| {
+            let graph = EdgeFiltered::from_fn(&self.graph, |edge| {
+                !matches!(edge.weight(), EdgeType::Unreachable)
+            });
+            self.entry().map(|entry| dominators::simple_fast(&graph, entry))
+        })
+    }
+
+    /// Returns the dominance frontier of each basic block, indexed by [BasicBlockId::index].
+    ///
+    /// The dominance frontier of `a` are the blocks `b` where `a` dominates a predecessor
+    /// of `b`, but does not strictly dominate `b`.
+    ///
+    /// Computed with the algorithm from "A Simple, Fast Dominance Algorithm"
+    /// by Cooper, Harvey and Kennedy.
+    pub fn dominance_frontiers(&self) -> &[FxHashSet<BasicBlockId>] {
+        self.dominance_frontiers.get_or_init(|| {
+            let mut frontiers = vec![FxHashSet::default(); self.graph.node_count()];
+            let Some(dominators) = self.dominators() else { return frontiers };
+            for id in self.graph.node_indices() {
+                let Some(idom) = dominators.immediate_dominator(id) else { continue };
+                let predecessors = self
+                    .graph
+                    .edges_directed(id, Direction::Incoming)
+                    .filter(|edge| !matches!(edge.weight(), EdgeType::Unreachable))
+                    .map(|edge| edge.source())
+                    // skip predecessors which can not be reached from the entry
+                    .filter(|pred| dominators.dominators(*pred).is_some())
+                    .collect::<FxHashSet<_>>();
+                if predecessors.len() < 2 {
+                    continue;
+                }
+                for pred in predecessors {
+                    let mut runner = pred;
+                    while runner != idom {
+                        frontiers[runner.index()].insert(id);
+                        let Some(next) = dominators.immediate_dominator(runner) else { break };
+                        runner = next;
+                    }
+                }
+            }
+            frontiers
+        })
+    }
+
+    /// Returns the natural loops of the graph.
+    ///
+    /// An edge is a back edge if its target dominates its source. The target is the header
+    /// of a loop, whose body are the blocks which reach a back edge without going through the
+    /// header. Back edges to the same header form a single loop.
+    pub fn loops(&self) -> &[LoopInfo] {
+        &self.loop_info().loops
+    }
+
+    /// Returns `true` if `id` is the header of a loop, the target of a back edge.
+    pub fn is_loop_header(&self, id: BasicBlockId) -> bool {
+        self.loops().iter().any(|loop_info| loop_info.header == id)
+    }
+
+    /// The number of loops containing `id`.
+    pub fn loop_depth(&self, id: BasicBlockId) -> u32 {
+        self.loop_info().depths.get(id.index()).copied().unwrap_or(0)
+    }
+
+    fn loop_info(&self) -> &Loops {
+        self.loops.get_or_init(|| {
+            let mut loops: Vec<LoopInfo> = vec![];
+            for edge in self.graph.edge_references() {
+                let (latch, header) = (edge.source(), edge.target());
+                if matches!(edge.weight(), EdgeType::Unreachable) || !self.dominates(header, latch)
+                {
+                    continue;
+                }
+                match loops.iter_mut().find(|it| it.header == header) {
+                    Some(loop_info) => loop_info.latches.push(latch),
+                    None => loops.push(LoopInfo {
+                        header,
+                        latches: vec![latch],
+                        body: FxHashSet::default(),
+                    }),
+                }
+            }
+
+            for loop_info in &mut loops {
+                let header = loop_info.header;
+                loop_info.body.insert(header);
+                let mut stack = loop_info.latches.clone();
+                while let Some(id) = stack.pop() {
+                    if !loop_info.body.insert(id) {
+                        continue;
+                    }
+                    stack.extend(
+                        self.graph
+                            .edges_directed(id, Direction::Incoming)
+                            .filter(|edge| !matches!(edge.weight(), EdgeType::Unreachable))
+                            .map(|edge| edge.source())
+                            .filter(|pred| self.dominates(header, *pred)),
+                    );
+                }
+            }
+
+            let mut depths = vec![0; self.graph.node_count()];
+            for id in loops.iter().flat_map(|loop_info| &loop_info.body) {
+                depths[id.index()] += 1;
+            }
+            Loops { loops, depths }
+        })
+    }
+
     /// # Panics
     pub fn basic_block(&self, id: BasicBlockId) -> &BasicBlock {
         let ix = *self.graph.node_weight(id).expect("expected a valid node id in self.graph");
@@ -0,0 +1,91 @@
+use oxc_cfg::{BasicBlockId, ControlFlowGraph, EdgeType};
+use rustc_hash::FxHashSet;
+
+use crate::build;
+
+fn frontier(cfg: &ControlFlowGraph, id: BasicBlockId) -> FxHashSet<BasicBlockId> {
+    cfg.dominance_frontiers()[id.index()].clone()
+}
+
+/// ```text
+///   a
+///  / \
+/// b   c
+///  \ /
+///   d
+/// ```
+#[test]
+fn diamond() {
+    let (cfg, blocks, error) = build(
+        4,
+        &[
+            (0, 1, EdgeType::Jump),
+            (0, 2, EdgeType::Normal),
+            (1, 3, EdgeType::Normal),
+            (2, 3, EdgeType::Normal),
+        ],
+    );
+    let [a, b, c, d] = blocks[..] else { unreachable!() };
+
+    assert_eq!(cfg.entry(), Some(a));
+    assert_eq!(cfg.immediate_dominator(a), None);
+    assert_eq!(cfg.immediate_dominator(b), Some(a));
+    assert_eq!(cfg.immediate_dominator(c), Some(a));
+    assert_eq!(cfg.immediate_dominator(d), Some(a));
+    assert_eq!(cfg.immediate_dominator(error), Some(a));
+
+    assert!(cfg.dominates(a, d));
+    assert!(cfg.dominates(d, d));
+    assert!(!cfg.dominates(b, d));
+    assert!(!cfg.dominates(c, d));
+    assert!(!cfg.dominates(d, a));
+
+    assert_eq!(frontier(&cfg, a), FxHashSet::default());
+    assert_eq!(frontier(&cfg, b), FxHashSet::from_iter([d, error]));
+    assert_eq!(frontier(&cfg, c), FxHashSet::from_iter([d, error]));
+    assert_eq!(frontier(&cfg, d), FxHashSet::from_iter([error]));
+}
+
+/// ```text
+/// a -> header -> exit
+///       ^  |
+///       body
+/// ```
+#[test]
+fn single_loop() {
+    let (cfg, blocks, error) = build(
+        4,
+        &[
+            (0, 1, EdgeType::Normal),
+            (1, 2, EdgeType::Jump),
+            (2, 1, EdgeType::Backedge),
+            (1, 3, EdgeType::Normal),
+        ],
+    );
+    let [a, header, body, exit] = blocks[..] else { unreachable!() };
+
+    assert_eq!(cfg.immediate_dominator(header), Some(a));
+    assert_eq!(cfg.immediate_dominator(body), Some(header));
+    assert_eq!(cfg.immediate_dominator(exit), Some(header));
+
+    assert!(cfg.dominates(header, body));
+    assert!(cfg.dominates(header, exit));
+    assert!(!cfg.dominates(body, header));
+    assert!(!cfg.dominates(body, exit));
+
+    assert_eq!(frontier(&cfg, a), FxHashSet::default());
+    assert_eq!(frontier(&cfg, header), FxHashSet::from_iter([header, error]));
+    assert_eq!(frontier(&cfg, body), FxHashSet::from_iter([header, error]));
+    assert_eq!(frontier(&cfg, exit), FxHashSet::from_iter([error]));
+}
+
+#[test]
+fn unreachable_edges_are_ignored() {
+    let (cfg, blocks, _) = build(3, &[(0, 1, EdgeType::Normal), (0, 2, EdgeType::Unreachable)]);
+    let [a, b, c] = blocks[..] else { unreachable!() };
+
+    assert_eq!(cfg.immediate_dominator(b), Some(a));
+    assert_eq!(cfg.immediate_dominator(c), None);
+    assert!(!cfg.dominates(a, c));
+    assert_eq!(frontier(&cfg, c), FxHashSet::default());
+}
@@ -0,0 +1,106 @@
+use oxc_cfg::{DisplayDot, EdgeType};
+use rustc_hash::FxHashSet;
+
+use crate::build;
+
+/// `while (outer) { while (inner) { inner_body } inner_exit; latch } exit`
+#[test]
+fn nested_loops() {
+    let (cfg, blocks, error) = build(
+        7,
+        &[
+            (0, 1, EdgeType::Normal),
+            // outer loop
+            (1, 2, EdgeType::Jump),
+            (1, 6, EdgeType::Normal),
+            // inner loop
+            (2, 3, EdgeType::Jump),
+            (3, 2, EdgeType::Backedge),
+            (2, 4, EdgeType::Normal),
+            (4, 5, EdgeType::Normal),
+            (5, 1, EdgeType::Backedge),
+        ],
+    );
+    let [entry, outer, inner, inner_body, inner_exit, latch, exit] = blocks[..] else {
+        unreachable!()
+    };
+
+    let loops = cfg.loops();
+    assert_eq!(loops.len(), 2);
+    let outer_loop = loops.iter().find(|it| it.header == outer).unwrap();
+    assert_eq!(outer_loop.latches, [latch]);
+    assert_eq!(
+        outer_loop.body,
+        FxHashSet::from_iter([outer, inner, inner_body, inner_exit, latch])
+    );
+    let inner_loop = loops.iter().find(|it| it.header == inner).unwrap();
+    assert_eq!(inner_loop.latches, [inner_body]);
+    assert_eq!(inner_loop.body, FxHashSet::from_iter([inner, inner_body]));
+
+    assert!(cfg.is_loop_header(outer));
+    assert!(cfg.is_loop_header(inner));
+    assert!(!cfg.is_loop_header(inner_body));
+
+    assert_eq!(cfg.loop_depth(entry), 0);
+    assert_eq!(cfg.loop_depth(outer), 1);
+    assert_eq!(cfg.loop_depth(inner), 2);
+    assert_eq!(cfg.loop_depth(inner_body), 2);
+    assert_eq!(cfg.loop_depth(inner_exit), 1);
+    assert_eq!(cfg.loop_depth(latch), 1);
+    assert_eq!(cfg.loop_depth(exit), 0);
+    assert_eq!(cfg.loop_depth(error), 0);
+}
+
+/// Back edges to the same header, e.g. from `continue`, form a single loop.
+#[test]
+fn multiple_latches() {
+    let (cfg, blocks, _) = build(
+        4,
+        &[
+            (0, 1, EdgeType::Normal),
+            (1, 2, EdgeType::Jump),
+            (2, 1, EdgeType::Backedge),
+            (2, 3, EdgeType::Jump),
+            (3, 1, EdgeType::Backedge),
+        ],
+    );
+    let [entry, header, a, b] = blocks[..] else { unreachable!() };
+
+    let [loop_info] = cfg.loops() else { panic!("expected a single loop") };
+    assert_eq!(loop_info.header, header);
+    assert_eq!(
+        loop_info.latches.iter().copied().collect::<FxHashSet<_>>(),
+        FxHashSet::from_iter([a, b])
+    );
+    assert_eq!(cfg.loop_depth(entry), 0);
+    assert_eq!(cfg.loop_depth(b), 1);
+}
+
+#[test]
+fn no_loops() {
+    let (cfg, blocks, _) = build(2, &[(0, 1, EdgeType::Normal)]);
+    assert!(cfg.loops().is_empty());
+    assert!(blocks.iter().all(|id| cfg.loop_depth(*id) == 0 && !cfg.is_loop_header(*id)));
+}
+
+#[test]
+fn dot_double_circles_loop_headers() {
+    let (cfg, _, _) =
+        build(3, &[(0, 1, EdgeType::Normal), (1, 2, EdgeType::Jump), (2, 1, EdgeType::Backedge)]);
+    assert_eq!(
+        cfg.display_dot(),
+        r#"digraph {
+    0 [ label = "" ]
+    1 [ label = "" ]
+    2 [ label = "" , peripheries = 2 ]
+    3 [ label = "" ]
+    1 -> 0 [ label = "Error(Implicit)" ]
+    2 -> 0 [ label = "Error(Implicit)" ]
+    3 -> 0 [ label = "Error(Implicit)" ]
+    1 -> 2 [ label = "Normal" ]
+    2 -> 3 [ label = "Jump" ]
+    3 -> 2 [ label = "Backedge" ]
+}
+"#
+    );
+}
@@ -0,0 +1,19 @@
+mod dominators;
+mod loops;
+
+use oxc_cfg::{BasicBlockId, ControlFlowGraph, ControlFlowGraphBuilder, EdgeType, ErrorEdgeKind};
+
+/// Builds a graph of `count` blocks connected by `edges`, which are indices into the returned
+/// blocks. Every block also has an error edge to the implicit error block, returned last.
+pub fn build(
+    count: usize,
+    edges: &[(usize, usize, EdgeType)],
+) -> (ControlFlowGraph, Vec<BasicBlockId>, BasicBlockId) {
+    let mut builder = ControlFlowGraphBuilder::default();
+    let error = builder.attach_error_harness(ErrorEdgeKind::Implicit);
+    let blocks = (0..count).map(|_| builder.new_basic_block_normal()).collect::<Vec<_>>();
+    for (a, b, weight) in edges {
+        builder.add_edge(blocks[*a], blocks[*b], weight.clone());
+    }
+    (builder.build(), blocks, error)
+}
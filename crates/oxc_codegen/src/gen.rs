@@ -601,6 +601,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for VariableDeclaration<'a> {
             VariableDeclarationKind::Const => "const",
             VariableDeclarationKind::Let => "let",
             VariableDeclarationKind::Var => "var",
+            VariableDeclarationKind::Using => "using",
         });
         if !self.declarations.is_empty() {
             p.print_hard_space();
@@ -1038,6 +1039,7 @@ impl<'a, const MINIFY: bool> GenExpr<MINIFY> for Expression<'a> {
             Self::UnaryExpression(expr) => expr.gen_expr(p, precedence, ctx),
             Self::BinaryExpression(expr) => expr.gen_expr(p, precedence, ctx),
             Self::PrivateInExpression(expr) => expr.gen(p, ctx),
+            Self::PipelinePlaceholder(expr) => expr.gen(p, ctx),
             Self::LogicalExpression(expr) => expr.gen_expr(p, precedence, ctx),
             Self::ConditionalExpression(expr) => expr.gen_expr(p, precedence, ctx),
             Self::AssignmentExpression(expr) => expr.gen_expr(p, precedence, ctx),
@@ -1099,14 +1101,14 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for IdentifierReference<'a> {
         // }
         // }
         p.add_source_mapping_for_name(self.span, &self.name);
-        p.print_str(self.name.as_bytes());
+        p.print_identifier(&self.name);
     }
 }
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for IdentifierName<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
         p.add_source_mapping(self.span.start);
-        p.print_str(self.name.as_bytes());
+        p.print_identifier(&self.name);
     }
 }
 
@@ -1119,7 +1121,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for BindingIdentifier<'a> {
 impl<'a, const MINIFY: bool> Gen<MINIFY> for LabelIdentifier<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
         p.add_source_mapping_for_name(self.span, &self.name);
-        p.print_str(self.name.as_bytes());
+        p.print_identifier(&self.name);
     }
 }
 
@@ -1269,7 +1271,8 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for RegExpLiteral<'a> {
             p.print_hard_space();
         }
         p.print(b'/');
-        p.print_str(self.regex.pattern.as_bytes());
+        let flags = self.regex.flags;
+        p.print_raw(&self.regex.pattern, !flags.intersects(RegExpFlags::U | RegExpFlags::V));
         p.print(b'/');
         p.print_str(self.regex.flags.to_string().as_bytes());
         p.prev_reg_exp_end = p.code().len();
@@ -1351,6 +1354,7 @@ fn print_unquoted_str<const MINIFY: bool>(s: &str, quote: char, p: &mut Codegen<
             '\u{a0}' => {
                 p.print_str(b"\\xA0");
             }
+            _ if p.options.ascii_only && !c.is_ascii() => p.print_unicode_escape(c, false),
             _ => {
                 p.print_str(c.encode_utf8([0; 4].as_mut()).as_bytes());
             }
@@ -1376,6 +1380,13 @@ impl<const MINIFY: bool> Gen<MINIFY> for ThisExpression {
     }
 }
 
+impl<const MINIFY: bool> Gen<MINIFY> for PipelinePlaceholder {
+    fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
+        p.add_source_mapping(self.span.start);
+        p.print(b'%');
+    }
+}
+
 impl<'a, const MINIFY: bool> GenExpr<MINIFY> for MemberExpression<'a> {
     fn gen_expr(&self, p: &mut Codegen<{ MINIFY }>, precedence: Precedence, ctx: Context) {
         p.wrap(precedence > self.precedence(), |p| match self {
@@ -1699,6 +1710,20 @@ impl<'a, const MINIFY: bool> GenExpr<MINIFY> for UnaryExpression<'a> {
 
 impl<'a, const MINIFY: bool> GenExpr<MINIFY> for BinaryExpression<'a> {
     fn gen_expr(&self, p: &mut Codegen<{ MINIFY }>, precedence: Precedence, ctx: Context) {
+        if self.operator == BinaryOperator::Pipeline {
+            // `ShortCircuitExpression |> AssignmentExpression`
+            p.wrap(precedence > self.precedence(), |p| {
+                let wrap_left = matches!(
+                    self.left.without_parenthesized(),
+                    Expression::ConditionalExpression(_)
+                );
+                p.wrap(wrap_left, |p| self.left.gen_expr(p, Precedence::Conditional, ctx));
+                p.print_soft_space();
+                self.operator.gen(p, ctx);
+                self.right.gen_expr(p, Precedence::Assign, ctx);
+            });
+            return;
+        }
         let wrap_in = self.operator == BinaryOperator::In && !ctx.has_in();
         let wrap = precedence >= self.precedence() || wrap_in;
         p.wrap(wrap, |p| {
@@ -2007,7 +2032,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for TemplateLiteral<'a> {
 
         for quasi in &self.quasis {
             p.add_source_mapping(quasi.span.start);
-            p.print_str(quasi.value.raw.as_bytes());
+            p.print_raw(&quasi.value.raw, false);
 
             if let Some(expr) = expressions.next() {
                 p.print_str(b"${");
@@ -2503,7 +2528,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for PrivateIdentifier<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
         p.add_source_mapping_for_name(self.span, &self.name);
         p.print(b'#');
-        p.print_str(self.name.as_bytes());
+        p.print_identifier(&self.name);
     }
 }
 
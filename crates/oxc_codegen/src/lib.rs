@@ -9,15 +9,15 @@ mod gen;
 mod operator;
 mod sourcemap_builder;
 
-use std::{borrow::Cow, ops::Range};
+use std::{borrow::Cow, ops::Range, path::PathBuf};
 
 use oxc_ast::{
     ast::{BlockStatement, Directive, Expression, Program, Statement},
-    Comment, Trivias,
+    Comment, CommentKind, Trivias,
 };
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 use oxc_syntax::{
-    identifier::is_identifier_part,
+    identifier::{is_identifier_part, LS, PS},
     operator::{BinaryOperator, UnaryOperator, UpdateOperator},
     precedence::Precedence,
     symbol::SymbolId,
@@ -42,12 +42,52 @@ pub struct CommentOptions {
     pub preserve_annotate_comments: bool,
 }
 
+#[derive(Default, Clone)]
+pub struct CodegenOptions {
+    /// Escape non-ASCII characters in strings, templates, identifiers and regular expressions
+    /// with `\uXXXX` or `\u{XXXXXX}`, for environments which can only handle ASCII.
+    pub ascii_only: bool,
+
+    /// What to do with legal comments, see [LegalComments].
+    pub legal_comments: LegalComments,
+}
+
+/// Legal comments are comments starting with `/*!` or `//!`, or containing `@license` or `@preserve`.
+///
+/// They are read from the comments passed to [Codegen::enable_comment],
+/// only comments in front of a statement are considered.
+///
+/// See <https://esbuild.github.io/api/#legal-comments>
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum LegalComments {
+    /// Do not preserve any legal comments.
+    #[default]
+    None,
+    /// Preserve all legal comments in place.
+    Inline,
+    /// Move all legal comments to the end of the file.
+    EndOfFile,
+    /// Move all legal comments to [CodegenReturn::legal_comments], to be written to `file`,
+    /// and link to `file` in a comment at the end of the file.
+    Linked { file: PathBuf },
+}
+
+impl LegalComments {
+    pub fn is_none(&self) -> bool {
+        matches!(self, Self::None)
+    }
+}
+
 pub struct CodegenReturn {
     pub source_text: String,
     pub source_map: Option<oxc_sourcemap::SourceMap>,
+    /// The legal comments moved out of the output, with [LegalComments::Linked].
+    pub legal_comments: Vec<String>,
 }
 
 pub struct Codegen<'a, const MINIFY: bool> {
+    options: CodegenOptions,
+
     comment_options: CommentOptions,
 
     source_text: &'a str,
@@ -75,6 +115,11 @@ pub struct Codegen<'a, const MINIFY: bool> {
     /// Track the current indentation level
     indent: u8,
 
+    /// Legal comments before this position have been handled
+    legal_comments_end: u32,
+    /// Legal comments to print at the end of the file, or to return
+    legal_comments: Vec<String>,
+
     // Builders
     sourcemap_builder: Option<SourcemapBuilder>,
 
@@ -107,6 +152,7 @@ impl<'a, const MINIFY: bool> Codegen<'a, MINIFY> {
     #[must_use]
     pub fn new() -> Self {
         Self {
+            options: CodegenOptions::default(),
             comment_options: CommentOptions::default(),
             source_text: "",
             trivias: Trivias::default(),
@@ -121,11 +167,19 @@ impl<'a, const MINIFY: bool> Codegen<'a, MINIFY> {
             start_of_arrow_expr: 0,
             start_of_default_export: 0,
             indent: 0,
+            legal_comments_end: 0,
+            legal_comments: vec![],
             sourcemap_builder: None,
             move_comment_map: MoveCommentMap::default(),
         }
     }
 
+    #[must_use]
+    pub fn with_options(mut self, options: CodegenOptions) -> Self {
+        self.options = options;
+        self
+    }
+
     #[must_use]
     pub fn enable_comment(
         mut self,
@@ -159,9 +213,28 @@ impl<'a, const MINIFY: bool> Codegen<'a, MINIFY> {
     #[must_use]
     pub fn build(mut self, program: &Program<'_>) -> CodegenReturn {
         program.gen(&mut self, Context::default());
+        self.print_legal_comments_at_end(program.span.end);
         let source_text = self.into_source_text();
         let source_map = self.sourcemap_builder.map(SourcemapBuilder::into_sourcemap);
-        CodegenReturn { source_text, source_map }
+        CodegenReturn { source_text, source_map, legal_comments: self.legal_comments }
+    }
+
+    /// Print a single expression, e.g. a node created by a transform.
+    #[must_use]
+    pub fn print_expression_to_string(expr: &Expression<'_>, options: CodegenOptions) -> String {
+        let mut codegen = Self::new().with_options(options);
+        codegen.print_expression(expr);
+        codegen.into_source_text()
+    }
+
+    /// Print a single statement, without the trailing newline.
+    #[must_use]
+    pub fn print_statement_to_string(stmt: &Statement<'_>, options: CodegenOptions) -> String {
+        let mut codegen = Self::new().with_options(options);
+        stmt.gen(&mut codegen, Context::default());
+        let mut source_text = codegen.into_source_text();
+        source_text.truncate(source_text.trim_end_matches('\n').len());
+        source_text
     }
 
     #[must_use]
@@ -404,7 +477,66 @@ impl<'a, const MINIFY: bool> Codegen<'a, MINIFY> {
         // }
         // }
         self.add_source_mapping_for_name(span, fallback);
-        self.print_str(fallback.as_bytes());
+        self.print_identifier(fallback);
+    }
+
+    /// Print an identifier, escaping non-ASCII characters with `ascii_only`.
+    fn print_identifier(&mut self, name: &str) {
+        if !self.options.ascii_only || name.is_ascii() {
+            self.print_str(name.as_bytes());
+            return;
+        }
+        for c in name.chars() {
+            if c.is_ascii() {
+                self.print(c as u8);
+            } else {
+                self.print_unicode_escape(c, false);
+            }
+        }
+    }
+
+    /// Print the raw text of a template or regular expression, escaping non-ASCII characters
+    /// with `ascii_only`.
+    fn print_raw(&mut self, raw: &str, surrogate_pairs: bool) {
+        if !self.options.ascii_only || raw.is_ascii() {
+            self.print_str(raw.as_bytes());
+            return;
+        }
+        let mut chars = raw.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => match chars.next() {
+                    // A line continuation, which is removed from the cooked value
+                    Some(LS | PS) => {}
+                    // `\é` is the same as `é`
+                    Some(next) if !next.is_ascii() => {
+                        self.print_unicode_escape(next, surrogate_pairs);
+                    }
+                    Some(next) => {
+                        self.print(b'\\');
+                        self.print(next as u8);
+                    }
+                    None => self.print(b'\\'),
+                },
+                c if c.is_ascii() => self.print(c as u8),
+                c => self.print_unicode_escape(c, surrogate_pairs),
+            }
+        }
+    }
+
+    /// `\u00E9`, or `\u{1F600}` for code points above `U+FFFF`.
+    ///
+    /// `surrogate_pairs` prints `\uD83D\uDE00` instead, for regular expressions
+    /// without the `u` flag where `\u{...}` is not an escape.
+    fn print_unicode_escape(&mut self, c: char, surrogate_pairs: bool) {
+        let code_point = c as u32;
+        if code_point <= 0xFFFF || surrogate_pairs {
+            for code_unit in c.encode_utf16(&mut [0; 2]) {
+                self.print_str(format!("\\u{code_unit:04X}").as_bytes());
+            }
+        } else {
+            self.print_str(format!("\\u{{{code_point:X}}}").as_bytes());
+        }
     }
 
     fn print_space_before_operator(&mut self, next: Operator) {
@@ -474,6 +606,7 @@ impl<'a, const MINIFY: bool> Codegen<'a, MINIFY> {
                 }
             } else {
                 for directive in directives {
+                    self.print_legal_comments(directive.span.start);
                     directive.gen(self, ctx);
                     self.print_semicolon_if_needed();
                 }
@@ -481,10 +614,62 @@ impl<'a, const MINIFY: bool> Codegen<'a, MINIFY> {
         }
         for stmt in statements {
             self.print_semicolon_if_needed();
+            self.print_legal_comments(stmt.span().start);
             stmt.gen(self, ctx);
         }
     }
 
+    /// Handle the legal comments before `end`, which have not been handled yet.
+    fn print_legal_comments(&mut self, end: u32) {
+        if self.options.legal_comments.is_none() || end <= self.legal_comments_end {
+            return;
+        }
+        let comments = self
+            .trivias
+            .comments_range(self.legal_comments_end..end)
+            .map(|(start, comment)| (*start, *comment))
+            .filter(|(start, comment)| {
+                is_legal_comment(&self.source_text[*start as usize..comment.end as usize])
+            })
+            .collect::<Vec<_>>();
+        self.legal_comments_end = end;
+        for (start, comment) in comments {
+            let text = match comment.kind {
+                CommentKind::SingleLine => {
+                    format!("//{}", &self.source_text[start as usize..comment.end as usize])
+                }
+                CommentKind::MultiLine => {
+                    format!("/*{}*/", &self.source_text[start as usize..comment.end as usize])
+                }
+            };
+            if self.options.legal_comments == LegalComments::Inline {
+                self.print_indent();
+                self.print_str(&text);
+                self.print(b'\n');
+            } else {
+                self.legal_comments.push(text);
+            }
+        }
+    }
+
+    fn print_legal_comments_at_end(&mut self, end: u32) {
+        self.print_legal_comments(end);
+        match &self.options.legal_comments {
+            LegalComments::EndOfFile => {
+                for text in std::mem::take(&mut self.legal_comments) {
+                    self.print_str(&text);
+                    self.print(b'\n');
+                }
+            }
+            LegalComments::Linked { file } if !self.legal_comments.is_empty() => {
+                let link =
+                    format!("/*! For license information please see {} */\n", file.display());
+                self.print_str(&link);
+            }
+            _ => {}
+        }
+    }
+
     fn add_source_mapping(&mut self, position: u32) {
         if let Some(sourcemap_builder) = self.sourcemap_builder.as_mut() {
             sourcemap_builder.add_source_mapping(&self.code, position, None);
@@ -552,3 +737,8 @@ impl<'a, const MINIFY: bool> Codegen<'a, MINIFY> {
         self.move_comment_map.remove(&node_start)
     }
 }
+
+/// `/*! ... */`, `//! ...`, or a comment containing `@license` or `@preserve`.
+fn is_legal_comment(content: &str) -> bool {
+    content.starts_with('!') || content.contains("@license") || content.contains("@preserve")
+}
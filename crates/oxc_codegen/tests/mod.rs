@@ -1,5 +1,8 @@
 use oxc_allocator::Allocator;
-use oxc_codegen::{CodeGenerator, CommentOptions};
+use oxc_ast::ast::Statement;
+use oxc_codegen::{
+    CodeGenerator, CodegenOptions, CommentOptions, LegalComments, WhitespaceRemover,
+};
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 
@@ -362,3 +365,93 @@ fn unicode_escape() {
     test("console.log('안녕하세요');", "console.log('안녕하세요');\n");
     test("console.log('🧑‍🤝‍🧑');", "console.log('🧑‍🤝‍🧑');\n");
 }
+
+fn test_ascii_only(source_text: &str, expected: &str) {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_module(true);
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let result = CodeGenerator::new()
+        .with_options(CodegenOptions { ascii_only: true, ..CodegenOptions::default() })
+        .build(&ret.program)
+        .source_text;
+    assert_eq!(expected, result, "for source {source_text}, expect {expected}, got {result}");
+}
+
+#[test]
+fn ascii_only() {
+    test_ascii_only("console.log('你好');", "console.log('\\u4F60\\u597D');\n");
+    test_ascii_only("console.log('🧑');", "console.log('\\u{1F9D1}');\n");
+    test_ascii_only("let café = 1;", "let caf\\u00E9 = 1;\n");
+    test_ascii_only("a.é;", "a.\\u00E9;\n");
+    test_ascii_only("class A { #é; }", "class A {\n\t#\\u00E9;\n}\n");
+    test_ascii_only("let x = `é${0}ü`;", "let x = `\\u00E9${0}\\u00FC`;\n");
+    test_ascii_only("let x = `\\é`;", "let x = `\\u00E9`;\n");
+    test_ascii_only("let x = /é/;", "let x = /\\u00E9/;\n");
+    test_ascii_only("let x = /🧑/;", "let x = /\\uD83E\\uDDD1/;\n");
+    test_ascii_only("let x = /🧑/u;", "let x = /\\u{1F9D1}/u;\n");
+}
+
+fn test_legal_comments(source_text: &str, legal_comments: LegalComments, expected: &str) {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_module(true);
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let result = CodeGenerator::new()
+        .enable_comment(source_text, ret.trivias, CommentOptions::default())
+        .with_options(CodegenOptions { legal_comments, ..CodegenOptions::default() })
+        .build(&ret.program)
+        .source_text;
+    assert_eq!(expected, result, "for source {source_text}, expect {expected}, got {result}");
+}
+
+#[test]
+fn legal_comments() {
+    let source = "/*! Copyright */\n// @license MIT\n/* not legal */\nfoo();\nfunction bar() {\n\t//! inner\n\tbaz();\n}\n";
+    test_legal_comments(source, LegalComments::None, "foo();\nfunction bar() {\n\tbaz();\n}\n");
+    test_legal_comments(
+        source,
+        LegalComments::Inline,
+        "/*! Copyright */\n// @license MIT\nfoo();\nfunction bar() {\n\t//! inner\n\tbaz();\n}\n",
+    );
+    test_legal_comments(
+        source,
+        LegalComments::EndOfFile,
+        "foo();\nfunction bar() {\n\tbaz();\n}\n/*! Copyright */\n// @license MIT\n//! inner\n",
+    );
+
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source, SourceType::default()).parse();
+    let ret = CodeGenerator::new()
+        .enable_comment(source, ret.trivias, CommentOptions::default())
+        .with_options(CodegenOptions {
+            legal_comments: LegalComments::Linked { file: "foo.js.LEGAL.txt".into() },
+            ..CodegenOptions::default()
+        })
+        .build(&ret.program);
+    assert_eq!(
+        ret.source_text,
+        "foo();\nfunction bar() {\n\tbaz();\n}\n/*! For license information please see foo.js.LEGAL.txt */\n"
+    );
+    assert_eq!(ret.legal_comments, vec!["/*! Copyright */", "// @license MIT", "//! inner"]);
+}
+
+#[test]
+fn print_to_string() {
+    let allocator = Allocator::default();
+    let source_text = "if (a) { b = 1 + 2 * c; }";
+    let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+    let Statement::IfStatement(if_stmt) = &ret.program.body[0] else { unreachable!() };
+    assert_eq!(
+        CodeGenerator::print_expression_to_string(&if_stmt.test, CodegenOptions::default()),
+        "a"
+    );
+    assert_eq!(
+        CodeGenerator::print_statement_to_string(&if_stmt.consequent, CodegenOptions::default()),
+        "{\n\tb = 1 + 2 * c;\n}"
+    );
+    let Statement::BlockStatement(block) = &if_stmt.consequent else { unreachable!() };
+    let Statement::ExpressionStatement(stmt) = &block.body[0] else { unreachable!() };
+    assert_eq!(
+        WhitespaceRemover::print_expression_to_string(&stmt.expression, CodegenOptions::default()),
+        "b=1+2*c"
+    );
+}
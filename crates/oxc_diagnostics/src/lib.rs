@@ -46,6 +46,8 @@ pub struct OxcDiagnosticInner {
     pub labels: Option<Vec<LabeledSpan>>,
     pub help: Option<String>,
     pub severity: Severity,
+    /// Link to the documentation of the rule or error, e.g. an ESLint rule page.
+    pub url: Option<String>,
 }
 
 impl fmt::Display for OxcDiagnostic {
@@ -65,6 +67,10 @@ impl Diagnostic for OxcDiagnostic {
         Some(self.severity)
     }
 
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.url.as_ref().map(Box::new).map(|c| c as Box<dyn Display>)
+    }
+
     fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
         self.labels
             .as_ref()
@@ -83,6 +89,7 @@ impl OxcDiagnostic {
                 labels: None,
                 help: None,
                 severity: Severity::Error,
+                url: None,
             }),
         }
     }
@@ -95,6 +102,7 @@ impl OxcDiagnostic {
                 labels: None,
                 help: None,
                 severity: Severity::Warning,
+                url: None,
             }),
         }
     }
@@ -111,6 +119,13 @@ impl OxcDiagnostic {
         self
     }
 
+    /// Link to the documentation of the rule or error that produced this diagnostic.
+    #[must_use]
+    pub fn with_url<T: Into<String>>(mut self, url: T) -> Self {
+        self.inner.url = Some(url.into());
+        self
+    }
+
     #[must_use]
     pub fn with_label<T: Into<LabeledSpan>>(mut self, label: T) -> Self {
         self.inner.labels = Some(vec![label.into()]);
@@ -1,7 +1,9 @@
-use miette::JSONReportHandler;
+use std::collections::HashMap;
 
-use super::DiagnosticReporter;
-use crate::Error;
+use serde::Serialize;
+
+use super::{DiagnosticReporter, Info};
+use crate::{Error, Severity};
 
 #[derive(Default)]
 pub struct JsonReporter {
@@ -9,10 +11,9 @@ pub struct JsonReporter {
 }
 
 impl DiagnosticReporter for JsonReporter {
-    // NOTE: this output does not conform to eslint json format yet
-    // https://eslint.org/docs/latest/use/formatters/#json
+    #[allow(clippy::print_stdout)]
     fn finish(&mut self) {
-        format_json(&mut self.diagnostics);
+        println!("{}", format_json(&self.diagnostics));
     }
 
     fn render_diagnostics(&mut self, _s: &[u8]) {}
@@ -23,18 +24,90 @@ impl DiagnosticReporter for JsonReporter {
     }
 }
 
-/// <https://github.com/fregante/eslint-formatters/tree/main/packages/eslint-formatter-json>
-#[allow(clippy::print_stdout)]
-fn format_json(diagnostics: &mut Vec<Error>) {
-    let handler = JSONReportHandler::new();
-    let messages = diagnostics
-        .drain(..)
-        .map(|error| {
-            let mut output = String::from("\t");
-            handler.render_report(&mut output, error.as_ref()).unwrap();
-            output
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonResult {
+    file_path: String,
+    messages: Vec<JsonMessage>,
+    error_count: usize,
+    warning_count: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonMessage {
+    rule_id: Option<String>,
+    severity: u8,
+    message: String,
+    line: usize,
+    column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+/// Diagnostics don't carry their fixes, so the optional `fix` and the fixable counts are omitted.
+///
+/// <https://eslint.org/docs/latest/use/formatters/#json>
+fn format_json(diagnostics: &[Error]) -> String {
+    let mut grouped: HashMap<String, Vec<Info>> = HashMap::new();
+    for info in diagnostics.iter().map(Info::new) {
+        grouped.entry(info.filename.clone()).or_default().push(info);
+    }
+    let mut grouped = grouped.into_iter().collect::<Vec<_>>();
+    grouped.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+    let results = grouped
+        .into_iter()
+        .map(|(file_path, infos)| {
+            let error_count =
+                infos.iter().filter(|info| matches!(info.severity, Severity::Error)).count();
+            let warning_count = infos.len() - error_count;
+            let messages = infos.into_iter().map(format_message).collect::<Vec<_>>();
+            JsonResult { file_path, messages, error_count, warning_count }
         })
-        .collect::<Vec<_>>()
-        .join(",\n");
-    println!("[\n{messages}\n]");
+        .collect::<Vec<_>>();
+    serde_json::to_string_pretty(&results).unwrap()
+}
+
+fn format_message(info: Info) -> JsonMessage {
+    let Info { line, column, end_line, end_column, message, severity, rule_id, .. } = info;
+    let severity = match severity {
+        Severity::Error => 2,
+        _ => 1,
+    };
+    JsonMessage { rule_id, severity, message, line, column, end_line, end_column }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::format_json;
+    use crate::{DiagnosticService, Error, LabeledSpan, OxcDiagnostic};
+
+    fn wrap(path: &str, source_text: &str, diagnostics: Vec<OxcDiagnostic>) -> Vec<Error> {
+        DiagnosticService::wrap_diagnostics(Path::new(path), source_text, diagnostics).1
+    }
+
+    #[test]
+    fn json() {
+        let mut diagnostics = wrap(
+            "b.js",
+            "debugger;\nlet a = NaN == 1;\n",
+            vec![
+                OxcDiagnostic::warn("eslint(no-debugger): `debugger` statement is not allowed")
+                    .with_label(LabeledSpan::underline(0..9)),
+                OxcDiagnostic::error(
+                    "eslint(use-isnan): Requires calls to isNaN() when checking for NaN",
+                )
+                .with_label(LabeledSpan::underline(18..26)),
+            ],
+        );
+        diagnostics.extend(wrap(
+            "a.js",
+            "debugger;",
+            vec![OxcDiagnostic::warn("eslint(no-debugger): `debugger` statement is not allowed")
+                .with_label(LabeledSpan::underline(0..9))],
+        ));
+        insta::assert_snapshot!(format_json(&diagnostics));
+    }
 }
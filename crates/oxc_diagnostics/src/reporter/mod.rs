@@ -2,14 +2,17 @@ mod checkstyle;
 mod github;
 mod graphical;
 mod json;
+mod sarif;
 mod unix;
 
 use std::io::{BufWriter, Stdout};
 
 pub use self::{
     checkstyle::CheckstyleReporter, github::GithubReporter, graphical::GraphicalReporter,
-    json::JsonReporter, unix::UnixReporter,
+    json::JsonReporter, sarif::SarifReporter, unix::UnixReporter,
 };
+use miette::SourceSpan;
+
 use crate::{Error, Severity};
 
 /// stdio is blocked by LineWriter, use a BufWriter to reduce syscalls.
@@ -27,26 +30,36 @@ pub trait DiagnosticReporter {
 struct Info {
     line: usize,
     column: usize,
+    end_line: usize,
+    end_column: usize,
     filename: String,
     message: String,
     severity: Severity,
     rule_id: Option<String>,
+    url: Option<String>,
 }
 
 impl Info {
     fn new(diagnostic: &Error) -> Self {
         let mut line = 0;
         let mut column = 0;
+        let mut end_line = 0;
+        let mut end_column = 0;
         let mut filename = String::new();
         let mut message = String::new();
         let mut severity = Severity::Warning;
         let mut rule_id = None;
+        let url = diagnostic.url().map(|url| url.to_string());
         if let Some(mut labels) = diagnostic.labels() {
             if let Some(source) = diagnostic.source_code() {
                 if let Some(label) = labels.next() {
                     if let Ok(span_content) = source.read_span(label.inner(), 0, 0) {
                         line = span_content.line() + 1;
                         column = span_content.column() + 1;
+                        let end = SourceSpan::from(label.offset() + label.len());
+                        (end_line, end_column) = source
+                            .read_span(&end, 0, 0)
+                            .map_or((line, column), |end| (end.line() + 1, end.column() + 1));
                         if let Some(name) = span_content.name() {
                             filename = name.to_string();
                         };
@@ -63,6 +76,6 @@ impl Info {
                 }
             }
         }
-        Self { line, column, filename, message, severity, rule_id }
+        Self { line, column, end_line, end_column, filename, message, severity, rule_id, url }
     }
 }
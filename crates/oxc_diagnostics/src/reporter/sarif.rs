@@ -0,0 +1,200 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use super::{DiagnosticReporter, Info};
+use crate::{Error, Severity};
+
+#[derive(Default)]
+pub struct SarifReporter {
+    diagnostics: Vec<Error>,
+}
+
+impl DiagnosticReporter for SarifReporter {
+    #[allow(clippy::print_stdout)]
+    fn finish(&mut self) {
+        println!("{}", format_sarif(&self.diagnostics));
+    }
+
+    fn render_diagnostics(&mut self, _s: &[u8]) {}
+
+    fn render_error(&mut self, error: Error) -> Option<String> {
+        self.diagnostics.push(error);
+        None
+    }
+}
+
+#[derive(Serialize)]
+struct SarifLog<'a> {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: [SarifRun<'a>; 1],
+}
+
+#[derive(Serialize)]
+struct SarifRun<'a> {
+    tool: SarifTool<'a>,
+    results: Vec<SarifResult<'a>>,
+}
+
+#[derive(Serialize)]
+struct SarifTool<'a> {
+    driver: SarifDriver<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver<'a> {
+    name: &'static str,
+    information_uri: &'static str,
+    rules: Vec<SarifRule<'a>>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRule<'a> {
+    id: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult<'a> {
+    level: &'static str,
+    message: SarifMessage<'a>,
+    locations: [SarifLocation<'a>; 1],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+}
+
+#[derive(Serialize)]
+struct SarifMessage<'a> {
+    text: &'a str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation<'a> {
+    physical_location: SarifPhysicalLocation<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation<'a> {
+    artifact_location: SarifArtifactLocation<'a>,
+    region: SarifRegion,
+}
+
+#[derive(Serialize)]
+struct SarifArtifactLocation<'a> {
+    uri: &'a str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+/// <https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html>
+fn format_sarif(diagnostics: &[Error]) -> String {
+    let infos = diagnostics.iter().map(Info::new).collect::<Vec<_>>();
+    // Rule id to its help uri, sorted for a stable `ruleIndex`.
+    let mut rules: BTreeMap<&str, Option<&str>> = BTreeMap::new();
+    for info in &infos {
+        if let Some(rule_id) = &info.rule_id {
+            rules.entry(rule_id.as_str()).or_insert_with(|| info.url.as_deref());
+        }
+    }
+    let rule_indices =
+        rules.keys().enumerate().map(|(i, rule_id)| (*rule_id, i)).collect::<BTreeMap<_, _>>();
+
+    let results = infos
+        .iter()
+        .map(|info| {
+            let Info {
+                line,
+                column,
+                end_line,
+                end_column,
+                filename,
+                message,
+                severity,
+                rule_id,
+                ..
+            } = info;
+            let level = match severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+                Severity::Advice => "note",
+            };
+            let rule_id = rule_id.as_deref();
+            SarifResult {
+                level,
+                message: SarifMessage { text: message },
+                locations: [SarifLocation {
+                    physical_location: SarifPhysicalLocation {
+                        artifact_location: SarifArtifactLocation { uri: filename },
+                        region: SarifRegion {
+                            start_line: *line,
+                            start_column: *column,
+                            end_line: *end_line,
+                            end_column: *end_column,
+                        },
+                    },
+                }],
+                rule_id,
+                rule_index: rule_id.map(|rule_id| rule_indices[rule_id]),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let rules =
+        rules.into_iter().map(|(id, help_uri)| SarifRule { id, help_uri }).collect::<Vec<_>>();
+
+    let sarif = SarifLog {
+        schema: "https://json.schemastore.org/sarif-2.1.0.json",
+        version: "2.1.0",
+        runs: [SarifRun {
+            tool: SarifTool {
+                driver: SarifDriver { name: "oxlint", information_uri: "https://oxc.rs", rules },
+            },
+            results,
+        }],
+    };
+    serde_json::to_string_pretty(&sarif).unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::format_sarif;
+    use crate::{DiagnosticService, LabeledSpan, OxcDiagnostic};
+
+    #[test]
+    fn sarif() {
+        let source_text = "debugger;\nlet a = NaN == 1;\n";
+        let diagnostics = vec![
+            OxcDiagnostic::warn("eslint(no-debugger): `debugger` statement is not allowed")
+                .with_label(LabeledSpan::underline(0..9))
+                .with_url("https://oxc.rs/docs/guide/usage/linter/rules/eslint/no-debugger.html"),
+            OxcDiagnostic::error(
+                "eslint(use-isnan): Requires calls to isNaN() when checking for NaN",
+            )
+            .with_label(LabeledSpan::underline(18..26)),
+            OxcDiagnostic::warn("eslint(no-debugger): `debugger` statement is not allowed")
+                .with_label(LabeledSpan::underline(0..9)),
+        ];
+        let (_, diagnostics) =
+            DiagnosticService::wrap_diagnostics(Path::new("test.js"), source_text, diagnostics);
+        insta::assert_snapshot!(format_sarif(&diagnostics));
+    }
+}
@@ -39,12 +39,14 @@ impl DiagnosticReporter for UnixReporter {
 
 /// <https://github.com/fregante/eslint-formatters/tree/main/packages/eslint-formatter-unix>
 fn format_unix(diagnostic: &Error) -> String {
-    let Info { line, column, filename, message, severity, rule_id } = Info::new(diagnostic);
+    let Info { line, column, filename, message, severity, rule_id, url, .. } =
+        Info::new(diagnostic);
     let severity = match severity {
         Severity::Error => "Error",
         _ => "Warning",
     };
     let rule_id =
         rule_id.map_or_else(|| Cow::Borrowed(""), |rule_id| Cow::Owned(format!("/{rule_id}")));
-    format!("{filename}:{line}:{column}: {message} [{severity}{rule_id}]\n")
+    let url = url.map_or_else(|| Cow::Borrowed(""), |url| Cow::Owned(format!(" (see: {url})")));
+    format!("{filename}:{line}:{column}: {message}{url} [{severity}{rule_id}]\n")
 }
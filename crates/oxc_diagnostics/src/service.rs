@@ -7,7 +7,7 @@ use std::{
 use crate::{
     reporter::{
         CheckstyleReporter, DiagnosticReporter, GithubReporter, GraphicalReporter, JsonReporter,
-        UnixReporter,
+        SarifReporter, UnixReporter,
     },
     Error, NamedSource, OxcDiagnostic, Severity,
 };
@@ -72,6 +72,10 @@ impl DiagnosticService {
         self.reporter = Box::<GithubReporter>::default();
     }
 
+    pub fn set_sarif_reporter(&mut self) {
+        self.reporter = Box::<SarifReporter>::default();
+    }
+
     #[must_use]
     pub fn with_quiet(mut self, yes: bool) -> Self {
         self.quiet = yes;
@@ -0,0 +1,224 @@
+use std::{
+    fs,
+    hash::{Hash, Hasher},
+    path::{Path, PathBuf},
+};
+
+use oxc_diagnostics::{LabeledSpan, OxcDiagnostic, Severity};
+use rustc_hash::FxHasher;
+use serde::{Deserialize, Serialize};
+
+use crate::Linter;
+
+/// Persists the diagnostics of each linted file, so unchanged files are not linted again.
+///
+/// There is one entry per file, a flat file named after the hash of the file's path. The entry
+/// records the hash of the source text and of the rule set (enabled rules, their severity and
+/// configuration, and the lint options) it was linted with, and is overwritten when either
+/// changes, so stale entries are never read and the cache does not grow over time.
+pub struct LintCache {
+    dir: PathBuf,
+    rule_set_hash: u64,
+}
+
+impl LintCache {
+    /// Default cache directory, relative to the current working directory.
+    pub const DEFAULT_DIR: &'static str = ".oxc/lint-cache";
+
+    pub fn new(dir: PathBuf, linter: &Linter) -> Self {
+        let mut hasher = FxHasher::default();
+        for rule in &linter.rules {
+            // `Debug` includes the rule configuration.
+            format!("{:?} {:?}", rule.rule, rule.severity).hash(&mut hasher);
+        }
+        format!("{:?} {:?}", linter.options, linter.eslint_config).hash(&mut hasher);
+        env!("CARGO_PKG_VERSION").hash(&mut hasher);
+        Self { dir, rule_set_hash: hasher.finish() }
+    }
+
+    /// Returns the cached diagnostics of `source_text`, or `None` on a cache miss.
+    pub fn get(&self, path: &Path, source_text: &str) -> Option<Vec<OxcDiagnostic>> {
+        let content = fs::read(self.entry_path(path)).ok()?;
+        let entry: CacheEntry = serde_json::from_slice(&content).ok()?;
+        if entry.key != self.key(source_text) {
+            return None;
+        }
+        Some(entry.diagnostics.into_iter().map(SerializedDiagnostic::into_diagnostic).collect())
+    }
+
+    /// Stores the diagnostics of `source_text`.
+    ///
+    /// Caching is best effort, failing to write an entry is not an error.
+    pub fn insert<'d, I: IntoIterator<Item = &'d OxcDiagnostic>>(
+        &self,
+        path: &Path,
+        source_text: &str,
+        diagnostics: I,
+    ) {
+        let diagnostics = diagnostics.into_iter().map(SerializedDiagnostic::from).collect();
+        let entry = CacheEntry { key: self.key(source_text), diagnostics };
+        let Ok(content) = serde_json::to_vec(&entry) else { return };
+        if fs::create_dir_all(&self.dir).is_ok() {
+            let _ = fs::write(self.entry_path(path), content);
+        }
+    }
+
+    fn entry_path(&self, path: &Path) -> PathBuf {
+        let mut hasher = FxHasher::default();
+        path.hash(&mut hasher);
+        self.dir.join(format!("{:016x}.json", hasher.finish()))
+    }
+
+    fn key(&self, source_text: &str) -> u64 {
+        let mut hasher = FxHasher::default();
+        source_text.hash(&mut hasher);
+        self.rule_set_hash.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    /// Hash of the source text and the rule set the diagnostics were produced with.
+    key: u64,
+    diagnostics: Vec<SerializedDiagnostic>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SerializedDiagnostic {
+    message: String,
+    labels: Vec<SerializedLabel>,
+    help: Option<String>,
+    severity: SerializedSeverity,
+    url: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SerializedLabel {
+    label: Option<String>,
+    offset: usize,
+    len: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+enum SerializedSeverity {
+    Advice,
+    Warning,
+    Error,
+}
+
+impl From<&OxcDiagnostic> for SerializedDiagnostic {
+    fn from(diagnostic: &OxcDiagnostic) -> Self {
+        let labels = diagnostic
+            .labels
+            .iter()
+            .flatten()
+            .map(|label| SerializedLabel {
+                label: label.label().map(ToString::to_string),
+                offset: label.offset(),
+                len: label.len(),
+            })
+            .collect();
+        let severity = match diagnostic.severity {
+            Severity::Advice => SerializedSeverity::Advice,
+            Severity::Warning => SerializedSeverity::Warning,
+            Severity::Error => SerializedSeverity::Error,
+        };
+        Self {
+            message: diagnostic.message.clone(),
+            labels,
+            help: diagnostic.help.clone(),
+            severity,
+            url: diagnostic.url.clone(),
+        }
+    }
+}
+
+impl SerializedDiagnostic {
+    fn into_diagnostic(self) -> OxcDiagnostic {
+        let severity = match self.severity {
+            SerializedSeverity::Advice => Severity::Advice,
+            SerializedSeverity::Warning => Severity::Warning,
+            SerializedSeverity::Error => Severity::Error,
+        };
+        let mut diagnostic =
+            OxcDiagnostic::error(self.message).with_severity(severity).with_labels(
+                self.labels
+                    .into_iter()
+                    .map(|label| LabeledSpan::new(label.label, label.offset, label.len)),
+            );
+        if let Some(help) = self.help {
+            diagnostic = diagnostic.with_help(help);
+        }
+        if let Some(url) = self.url {
+            diagnostic = diagnostic.with_url(url);
+        }
+        diagnostic
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::Path};
+
+    use oxc_diagnostics::OxcDiagnostic;
+    use oxc_span::Span;
+
+    use super::LintCache;
+    use crate::{AllowWarnDeny, LintOptions, Linter};
+
+    #[test]
+    fn round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = LintCache::new(dir.path().to_path_buf(), &Linter::default());
+        let path = Path::new("foo.js");
+
+        assert!(cache.get(path, "debugger;").is_none());
+
+        let diagnostic =
+            OxcDiagnostic::warn("eslint(no-debugger): `debugger` statement is not allowed")
+                .with_help("Delete this code.")
+                .with_label(Span::new(0, 9));
+        cache.insert(path, "debugger;", [&diagnostic]);
+
+        let cached = cache.get(path, "debugger;").unwrap();
+        assert_eq!(cached.len(), 1);
+        assert_eq!(cached[0].message, diagnostic.message);
+        assert_eq!(cached[0].help, diagnostic.help);
+        assert_eq!(cached[0].severity, diagnostic.severity);
+        assert_eq!(cached[0].labels, diagnostic.labels);
+
+        // A changed file misses the cache.
+        assert!(cache.get(path, "debugger; ").is_none());
+        assert!(cache.get(Path::new("bar.js"), "debugger;").is_none());
+
+        // Files without diagnostics are cached too.
+        cache.insert(path, "let a;", []);
+        assert!(cache.get(path, "let a;").is_some_and(|diagnostics| diagnostics.is_empty()));
+    }
+
+    #[test]
+    fn one_entry_per_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let entries = || fs::read_dir(dir.path()).unwrap().count();
+        let path = Path::new("foo.js");
+
+        let cache = LintCache::new(dir.path().to_path_buf(), &Linter::default());
+        cache.insert(path, "debugger;", []);
+        cache.insert(path, "let a;", []);
+        cache.insert(Path::new("bar.js"), "let a;", []);
+        assert_eq!(entries(), 2);
+        assert!(cache.get(path, "debugger;").is_none());
+        assert!(cache.get(path, "let a;").is_some());
+
+        // Changing the rule set replaces the entries instead of adding new ones.
+        let options = LintOptions::default()
+            .with_filter(vec![(AllowWarnDeny::Deny, "no-debugger".to_string())]);
+        let linter = Linter::from_options(options).unwrap();
+        let cache = LintCache::new(dir.path().to_path_buf(), &linter);
+        assert!(cache.get(path, "let a;").is_none());
+        cache.insert(path, "let a;", []);
+        assert_eq!(entries(), 2);
+        assert!(cache.get(path, "let a;").is_some());
+    }
+}
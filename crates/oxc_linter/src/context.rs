@@ -25,6 +25,9 @@ pub struct LintContext<'a> {
     /// Whether or not to apply code fixes during linting.
     fix: bool,
 
+    /// Whether or not to also apply suggestions, fixes which may change the program's behavior.
+    fix_suggestions: bool,
+
     file_path: Rc<Path>,
 
     eslint_config: Arc<OxlintConfig>,
@@ -53,6 +56,7 @@ impl<'a> LintContext<'a> {
             diagnostics: RefCell::new(vec![]),
             disable_directives: Rc::new(disable_directives),
             fix: false,
+            fix_suggestions: false,
             file_path: file_path.into(),
             eslint_config: Arc::new(OxlintConfig::default()),
             current_rule_name: "",
@@ -66,6 +70,12 @@ impl<'a> LintContext<'a> {
         self
     }
 
+    #[must_use]
+    pub fn with_fix_suggestions(mut self, fix_suggestions: bool) -> Self {
+        self.fix_suggestions = fix_suggestions;
+        self
+    }
+
     #[must_use]
     pub fn with_eslint_config(mut self, eslint_config: &Arc<OxlintConfig>) -> Self {
         self.eslint_config = Arc::clone(eslint_config);
@@ -168,7 +178,8 @@ impl<'a> LintContext<'a> {
 
     /// Report a lint rule violation.
     ///
-    /// Use [`LintContext::diagnostic_with_fix`] to provide an automatic fix.
+    /// Use [`LintContext::diagnostic_with_fix`] to provide an automatic fix, or
+    /// [`LintContext::diagnostic_with_suggestion`] for a fix which may change the program's behavior.
     pub fn diagnostic(&self, diagnostic: OxcDiagnostic) {
         self.add_diagnostic(Message::new(diagnostic, None));
     }
@@ -189,6 +200,20 @@ impl<'a> LintContext<'a> {
         }
     }
 
+    /// Report a lint rule violation and provide a suggestion, a fix which may change the
+    /// program's behavior. Suggestions are only applied when fixing suggestions is enabled.
+    pub fn diagnostic_with_suggestion<C, F>(&self, diagnostic: OxcDiagnostic, fix: F)
+    where
+        C: Into<CompositeFix<'a>>,
+        F: FnOnce(RuleFixer<'_, 'a>) -> C,
+    {
+        if self.fix_suggestions {
+            self.diagnostic_with_fix(diagnostic, fix);
+        } else {
+            self.diagnostic(diagnostic);
+        }
+    }
+
     pub fn nodes(&self) -> &AstNodes<'a> {
         self.semantic().nodes()
     }
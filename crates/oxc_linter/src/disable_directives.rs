@@ -1,12 +1,28 @@
+use std::cell::RefCell;
+
 use oxc_ast::Trivias;
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::Span;
 use rust_lapper::{Interval, Lapper};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+pub(crate) fn unused_disable_directive_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unused eslint-disable directive (no problems were reported).")
+        .with_label(span0)
+}
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 enum DisabledRule<'a> {
-    All,
-    Single(&'a str),
+    All { comment_span: Span },
+    Single { rule_name: &'a str, comment_span: Span },
+}
+
+impl<'a> DisabledRule<'a> {
+    fn comment_span(self) -> Span {
+        match self {
+            Self::All { comment_span } | Self::Single { comment_span, .. } => comment_span,
+        }
+    }
 }
 
 /// A comment which disables one or more specific rules
@@ -24,17 +40,47 @@ pub struct DisableDirectives<'a> {
     disable_all_comments: Vec<Span>,
     /// All comments that disable one or more specific rules
     disable_rule_comments: Vec<DisableRuleComment<'a>>,
+    /// Start offsets of the comments which suppressed at least one diagnostic
+    used_comments: RefCell<FxHashSet<u32>>,
 }
 
 impl<'a> DisableDirectives<'a> {
+    /// Whether diagnostics of `rule_name` at `start` are suppressed.
+    ///
+    /// The suppressing comments are recorded as used, see [DisableDirectives::unused_comments].
     pub fn contains(&self, rule_name: &'static str, start: u32) -> bool {
-        self.intervals.find(start, start + 1).any(|interval| {
-            interval.val == DisabledRule::All
+        let mut contains = false;
+        for interval in self.intervals.find(start, start + 1) {
+            let matched = match interval.val {
+                DisabledRule::All { .. } => true,
                 // Our rule name currently does not contain the prefix.
                 // For example, this will match `@typescript-eslint/no-var-requires` given
                 // our rule_name is `no-var-requires`.
-                || matches!(interval.val, DisabledRule::Single(name) if name.contains(rule_name))
-        })
+                DisabledRule::Single { rule_name: name, .. } => name.contains(rule_name),
+            };
+            if matched {
+                self.used_comments.borrow_mut().insert(interval.val.comment_span().start);
+                contains = true;
+            }
+        }
+        contains
+    }
+
+    /// Spans of the disable comments which have not suppressed any diagnostic so far,
+    /// sorted by position.
+    ///
+    /// Only meaningful after all rules have been run.
+    pub fn unused_comments(&self) -> Vec<Span> {
+        let used_comments = self.used_comments.borrow();
+        let mut unused = self
+            .disable_all_comments
+            .iter()
+            .copied()
+            .chain(self.disable_rule_comments.iter().map(|comment| comment.span))
+            .filter(|span| !used_comments.contains(&span.start))
+            .collect::<Vec<_>>();
+        unused.sort_unstable();
+        unused
     }
 
     pub fn disable_all_comments(&self) -> &Vec<Span> {
@@ -51,10 +97,10 @@ pub struct DisableDirectivesBuilder<'a> {
     trivias: Trivias,
     /// All the disabled rules with their corresponding covering spans
     intervals: Lapper<u32, DisabledRule<'a>>,
-    /// Start of `eslint-disable` or `oxlint-disable`
-    disable_all_start: Option<u32>,
-    /// Start of `eslint-disable` or `oxlint-disable` rule_name`
-    disable_start_map: FxHashMap<&'a str, u32>,
+    /// Start and comment span of `eslint-disable` or `oxlint-disable`
+    disable_all_start: Option<(u32, Span)>,
+    /// Start and comment span of `eslint-disable` or `oxlint-disable` rule_name`
+    disable_start_map: FxHashMap<&'a str, (u32, Span)>,
     /// Spans of comments that disable all rules
     disable_all_comments: Vec<Span>,
     /// All comments that disable one or more specific rules
@@ -80,6 +126,7 @@ impl<'a> DisableDirectivesBuilder<'a> {
             intervals: self.intervals,
             disable_all_comments: self.disable_all_comments,
             disable_rule_comments: self.disable_rule_comments,
+            used_comments: RefCell::default(),
         }
     }
 
@@ -103,7 +150,7 @@ impl<'a> DisableDirectivesBuilder<'a> {
                 // `eslint-disable`
                 if text.trim().is_empty() {
                     if self.disable_all_start.is_none() {
-                        self.disable_all_start = Some(span.end);
+                        self.disable_all_start = Some((span.end, span));
                     }
                     self.disable_all_comments.push(span);
                     continue;
@@ -117,13 +164,17 @@ impl<'a> DisableDirectivesBuilder<'a> {
                         .take(2)
                         .fold(span.end, |acc, line| acc + line.len() as u32);
                     if text.trim().is_empty() {
-                        self.add_interval(span.end, stop, DisabledRule::All);
+                        self.add_interval(span.end, stop, DisabledRule::All { comment_span: span });
                         self.disable_all_comments.push(span);
                     } else {
                         // `eslint-disable-next-line rule_name1, rule_name2`
                         let mut rules = vec![];
                         Self::get_rule_names(text, |rule_name| {
-                            self.add_interval(span.end, stop, DisabledRule::Single(rule_name));
+                            self.add_interval(
+                                span.end,
+                                stop,
+                                DisabledRule::Single { rule_name, comment_span: span },
+                            );
                             rules.push(rule_name);
                         });
                         self.disable_rule_comments.push(DisableRuleComment { span, rules });
@@ -142,13 +193,17 @@ impl<'a> DisableDirectivesBuilder<'a> {
 
                     // `eslint-disable-line`
                     if text.trim().is_empty() {
-                        self.add_interval(start, stop, DisabledRule::All);
+                        self.add_interval(start, stop, DisabledRule::All { comment_span: span });
                         self.disable_all_comments.push(span);
                     } else {
                         // `eslint-disable-line rule-name1, rule-name2`
                         let mut rules = vec![];
                         Self::get_rule_names(text, |rule_name| {
-                            self.add_interval(start, stop, DisabledRule::Single(rule_name));
+                            self.add_interval(
+                                start,
+                                stop,
+                                DisabledRule::Single { rule_name, comment_span: span },
+                            );
                             rules.push(rule_name);
                         });
                         self.disable_rule_comments.push(DisableRuleComment { span, rules });
@@ -159,7 +214,7 @@ impl<'a> DisableDirectivesBuilder<'a> {
                 // `eslint-disable rule-name1, rule-name2`
                 let mut rules = vec![];
                 Self::get_rule_names(text, |rule_name| {
-                    self.disable_start_map.entry(rule_name).or_insert(span.end);
+                    self.disable_start_map.entry(rule_name).or_insert((span.end, span));
                     rules.push(rule_name);
                 });
                 self.disable_rule_comments.push(DisableRuleComment { span, rules });
@@ -172,14 +227,20 @@ impl<'a> DisableDirectivesBuilder<'a> {
             {
                 // `eslint-enable`
                 if text.trim().is_empty() {
-                    if let Some(start) = self.disable_all_start.take() {
-                        self.add_interval(start, span.start, DisabledRule::All);
+                    if let Some((start, comment_span)) = self.disable_all_start.take() {
+                        self.add_interval(start, span.start, DisabledRule::All { comment_span });
                     }
                 } else {
                     // `eslint-enable rule-name1, rule-name2`
                     Self::get_rule_names(text, |rule_name| {
-                        if let Some(start) = self.disable_start_map.remove(rule_name) {
-                            self.add_interval(start, span.start, DisabledRule::Single(rule_name));
+                        if let Some((start, comment_span)) =
+                            self.disable_start_map.remove(rule_name)
+                        {
+                            self.add_interval(
+                                start,
+                                span.start,
+                                DisabledRule::Single { rule_name, comment_span },
+                            );
                         }
                     });
                 }
@@ -188,14 +249,14 @@ impl<'a> DisableDirectivesBuilder<'a> {
         }
 
         // Lone `eslint-disable`
-        if let Some(start) = self.disable_all_start {
-            self.add_interval(start, source_len, DisabledRule::All);
+        if let Some((start, comment_span)) = self.disable_all_start {
+            self.add_interval(start, source_len, DisabledRule::All { comment_span });
         }
 
         // Lone `eslint-disable rule_name`
         let disable_start_map = self.disable_start_map.drain().collect::<Vec<_>>();
-        for (rule_name, start) in disable_start_map {
-            self.add_interval(start, source_len, DisabledRule::Single(rule_name));
+        for (rule_name, (start, comment_span)) in disable_start_map {
+            self.add_interval(start, source_len, DisabledRule::Single { rule_name, comment_span });
         }
     }
 
@@ -389,3 +450,34 @@ fn test() {
         Tester::new("no-debugger", pass, fail).test();
     }
 }
+
+#[test]
+fn test_unused_comments() {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    let source_text = "
+        // eslint-disable-next-line no-debugger
+        debugger;
+        // eslint-disable-next-line no-console
+        debugger;
+        /* eslint-disable */
+    ";
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+    let directives = DisableDirectivesBuilder::new(source_text, ret.trivias).build();
+    assert_eq!(directives.unused_comments().len(), 3);
+
+    let first_debugger = u32::try_from(source_text.find("debugger;").unwrap()).unwrap();
+    let second_debugger = u32::try_from(source_text.rfind("debugger;").unwrap()).unwrap();
+    assert!(directives.contains("no-debugger", first_debugger));
+    assert!(!directives.contains("no-debugger", second_debugger));
+
+    let unused = directives
+        .unused_comments()
+        .into_iter()
+        .map(|span| span.source_text(source_text))
+        .collect::<Vec<_>>();
+    assert_eq!(unused, vec![" eslint-disable-next-line no-console", " eslint-disable "]);
+}
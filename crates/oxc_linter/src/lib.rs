@@ -4,6 +4,7 @@
 mod tester;
 
 mod ast_util;
+mod cache;
 mod config;
 mod context;
 mod disable_directives;
@@ -25,14 +26,17 @@ use oxc_diagnostics::Error;
 use oxc_semantic::AstNode;
 
 pub use crate::{
+    cache::LintCache,
     config::OxlintConfig,
     context::LintContext,
+    disable_directives::{DisableDirectives, DisableRuleComment},
     options::{AllowWarnDeny, LintOptions},
     rule::{RuleCategory, RuleMeta, RuleWithSeverity},
     service::{LintService, LintServiceOptions},
 };
 use crate::{
     config::{OxlintEnv, OxlintGlobals, OxlintSettings},
+    disable_directives::unused_disable_directive_diagnostic,
     fixer::{Fix, Fixer, Message},
     rules::RuleEnum,
     table::RuleTable,
@@ -100,7 +104,10 @@ impl Linter {
     pub fn run<'a>(&self, ctx: LintContext<'a>) -> Vec<Message<'a>> {
         let semantic = Rc::clone(ctx.semantic());
 
-        let ctx = ctx.with_fix(self.options.fix).with_eslint_config(&self.eslint_config);
+        let ctx = ctx
+            .with_fix(self.options.fix)
+            .with_fix_suggestions(self.options.fix_suggestions)
+            .with_eslint_config(&self.eslint_config);
         let rules = self
             .rules
             .iter()
@@ -125,7 +132,19 @@ impl Linter {
             }
         }
 
-        rules.into_iter().flat_map(|(_, ctx)| ctx.into_message()).collect::<Vec<_>>()
+        let mut messages =
+            rules.into_iter().flat_map(|(_, ctx)| ctx.into_message()).collect::<Vec<_>>();
+
+        if self.options.report_unused_directives {
+            messages.extend(
+                ctx.disable_directives()
+                    .unused_comments()
+                    .into_iter()
+                    .map(|span| Message::new(unused_disable_directive_diagnostic(span), None)),
+            );
+        }
+
+        messages
     }
 
     /// # Panics
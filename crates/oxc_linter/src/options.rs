@@ -16,6 +16,10 @@ pub struct LintOptions {
     pub filter: Vec<(AllowWarnDeny, String)>,
     pub config_path: Option<PathBuf>,
     pub fix: bool,
+    /// Also apply suggestions when fixing, see [`crate::LintContext::diagnostic_with_suggestion`].
+    pub fix_suggestions: bool,
+    /// Report disable directives which did not suppress any diagnostic.
+    pub report_unused_directives: bool,
 
     pub react_plugin: bool,
     pub unicorn_plugin: bool,
@@ -36,6 +40,8 @@ impl Default for LintOptions {
             filter: vec![(AllowWarnDeny::Warn, String::from("correctness"))],
             config_path: None,
             fix: false,
+            fix_suggestions: false,
+            report_unused_directives: false,
             react_plugin: true,
             unicorn_plugin: true,
             typescript_plugin: true,
@@ -72,6 +78,18 @@ impl LintOptions {
         self
     }
 
+    #[must_use]
+    pub fn with_fix_suggestions(mut self, yes: bool) -> Self {
+        self.fix_suggestions = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_report_unused_directives(mut self, yes: bool) -> Self {
+        self.report_unused_directives = yes;
+        self
+    }
+
     #[must_use]
     pub fn with_react_plugin(mut self, yes: bool) -> Self {
         self.react_plugin = yes;
@@ -25,6 +25,7 @@ mod import {
 
 mod eslint {
     pub mod array_callback_return;
+    pub mod consistent_return;
     pub mod constructor_super;
     pub mod default_case;
     pub mod default_case_last;
@@ -86,6 +87,7 @@ mod eslint {
     pub mod no_redeclare;
     pub mod no_regex_spaces;
     pub mod no_restricted_globals;
+    pub mod no_restricted_imports;
     pub mod no_script_url;
     pub mod no_self_assign;
     pub mod no_self_compare;
@@ -136,13 +138,16 @@ mod typescript {
     pub mod no_empty_interface;
     pub mod no_explicit_any;
     pub mod no_extra_non_null_assertion;
+    pub mod no_floating_promises;
     pub mod no_import_type_side_effects;
     pub mod no_misused_new;
+    pub mod no_misused_promises;
     pub mod no_namespace;
     pub mod no_non_null_asserted_nullish_coalescing;
     pub mod no_non_null_asserted_optional_chain;
     pub mod no_non_null_assertion;
     pub mod no_this_alias;
+    pub mod no_unnecessary_type_assertion;
     pub mod no_unnecessary_type_constraint;
     pub mod no_unsafe_declaration_merging;
     pub mod no_useless_empty_export;
@@ -207,6 +212,7 @@ mod jest {
 mod react {
     pub mod button_has_type;
     pub mod checked_requires_onchange_or_readonly;
+    pub mod exhaustive_deps;
     pub mod jsx_key;
     pub mod jsx_no_comment_textnodes;
     pub mod jsx_no_duplicate_props;
@@ -426,6 +432,7 @@ mod tree_shaking {
 
 oxc_macros::declare_all_lint_rules! {
     eslint::array_callback_return,
+    eslint::consistent_return,
     eslint::constructor_super,
     eslint::default_case,
     eslint::default_case_last,
@@ -518,6 +525,7 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_await_in_loop,
     eslint::no_new_native_nonconstructor,
     eslint::no_restricted_globals,
+    eslint::no_restricted_imports,
     eslint::prefer_exponentiation_operator,
     eslint::no_constructor_return,
     typescript::adjacent_overload_signatures,
@@ -533,11 +541,14 @@ oxc_macros::declare_all_lint_rules! {
     typescript::no_empty_interface,
     typescript::no_explicit_any,
     typescript::no_extra_non_null_assertion,
+    typescript::no_floating_promises,
     typescript::no_import_type_side_effects,
     typescript::no_misused_new,
+    typescript::no_misused_promises,
     typescript::no_namespace,
     typescript::no_non_null_asserted_optional_chain,
     typescript::no_this_alias,
+    typescript::no_unnecessary_type_assertion,
     typescript::no_unnecessary_type_constraint,
     typescript::no_unsafe_declaration_merging,
     typescript::no_useless_empty_export,
@@ -680,6 +691,7 @@ oxc_macros::declare_all_lint_rules! {
     unicorn::throw_new_error,
     react::button_has_type,
     react::checked_requires_onchange_or_readonly,
+    react::exhaustive_deps,
     react::jsx_no_target_blank,
     react::jsx_key,
     react::jsx_no_comment_textnodes,
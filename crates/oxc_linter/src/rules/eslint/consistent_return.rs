@@ -0,0 +1,277 @@
+use oxc_ast::{
+    ast::{ArrowFunctionExpression, Function, ReturnStatement, TSType, TSTypeName},
+    AstKind, Visit,
+};
+use oxc_cfg::{
+    graph::{
+        visit::{set_depth_first_search, Control, DfsEvent},
+        Direction,
+    },
+    BasicBlockId, EdgeType, ErrorEdgeKind, EvalConstConditionResult, Instruction, InstructionKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::ScopeFlags;
+use oxc_span::Span;
+use rustc_hash::FxHashSet;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn missing_return_value_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("eslint(consistent-return): Function expected a return value.")
+        .with_help("Return a value here, as other return statements of this function do.")
+        .with_label(span0)
+}
+
+fn unexpected_return_value_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("eslint(consistent-return): Function expected no return value.")
+        .with_help("Remove the returned value, as other return statements of this function do.")
+        .with_label(span0)
+}
+
+fn missing_return_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "eslint(consistent-return): Expected to return a value at the end of function.",
+    )
+    .with_help("Return a value on every code path, the end of this function is reachable.")
+    .with_label(span0)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ConsistentReturn;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require `return` statements to either always or never specify values.
+    ///
+    /// Generators, and functions with a TypeScript return type that allows `undefined`
+    /// (or `Promise<undefined>` for async functions), are not checked.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A function that returns a value on some code paths and implicitly returns
+    /// `undefined` on others is most likely missing a `return` statement.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// function doSomething(condition) {
+    ///     if (condition) {
+    ///         return true;
+    ///     } else {
+    ///         return;
+    ///     }
+    /// }
+    ///
+    /// function doSomethingElse(condition) {
+    ///     if (condition) {
+    ///         return true;
+    ///     }
+    /// }
+    /// ```
+    ConsistentReturn,
+    suspicious
+);
+
+impl Rule for ConsistentReturn {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (body, return_type, is_async, span) = match node.kind() {
+            AstKind::Function(func) => {
+                if func.generator {
+                    return;
+                }
+                let Some(body) = &func.body else { return };
+                let span = func.id.as_ref().map_or(func.span, |id| id.span);
+                (body, func.return_type.as_ref(), func.r#async, span)
+            }
+            AstKind::ArrowFunctionExpression(arrow) if !arrow.expression => {
+                (&arrow.body, arrow.return_type.as_ref(), arrow.r#async, arrow.span)
+            }
+            _ => return,
+        };
+
+        if return_type.is_some_and(|ty| allows_undefined(&ty.type_annotation, is_async)) {
+            return;
+        }
+
+        let mut finder = ReturnFinder::default();
+        finder.visit_function_body(body);
+        let Some(first) = finder.returns.first() else { return };
+
+        // The first return statement decides whether the function returns a value.
+        let returns_value = first.has_value;
+        for ret in &finder.returns[1..] {
+            match (returns_value, ret.has_value) {
+                (true, false) => ctx.diagnostic(missing_return_value_diagnostic(ret.span)),
+                (false, true) => ctx.diagnostic(unexpected_return_value_diagnostic(ret.span)),
+                _ => {}
+            }
+        }
+
+        if returns_value && can_fall_through(node, ctx) {
+            ctx.diagnostic(missing_return_diagnostic(span));
+        }
+    }
+}
+
+struct FoundReturn {
+    span: Span,
+    has_value: bool,
+}
+
+/// Collects the return statements of a function body, excluding nested functions.
+#[derive(Default)]
+struct ReturnFinder {
+    returns: Vec<FoundReturn>,
+}
+
+impl<'a> Visit<'a> for ReturnFinder {
+    fn visit_return_statement(&mut self, stmt: &ReturnStatement<'a>) {
+        self.returns.push(FoundReturn { span: stmt.span, has_value: stmt.argument.is_some() });
+    }
+
+    fn visit_arrow_expression(&mut self, _expr: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: Option<ScopeFlags>) {}
+}
+
+/// `undefined`, `void`, `any` and `unknown` return types, or `Promise` of one for async functions.
+fn allows_undefined(ty: &TSType, is_async: bool) -> bool {
+    match ty {
+        TSType::TSUndefinedKeyword(_)
+        | TSType::TSVoidKeyword(_)
+        | TSType::TSAnyKeyword(_)
+        | TSType::TSUnknownKeyword(_) => true,
+        TSType::TSUnionType(union) => union.types.iter().any(|ty| allows_undefined(ty, is_async)),
+        TSType::TSParenthesizedType(ty) => allows_undefined(&ty.type_annotation, is_async),
+        TSType::TSTypeReference(reference) if is_async => {
+            matches!(&reference.type_name, TSTypeName::IdentifierReference(ident) if ident.name == "Promise")
+                && reference.type_parameters.as_ref().is_some_and(|params| {
+                    params.params.iter().any(|ty| allows_undefined(ty, false))
+                })
+        }
+        _ => false,
+    }
+}
+
+/// Whether the end of the function is reachable without a `return` or `throw`.
+fn can_fall_through(node: &AstNode, ctx: &LintContext) -> bool {
+    let cfg = ctx.cfg();
+    let graph = cfg.graph();
+    let is_normal_edge = |edge: &EdgeType| {
+        matches!(
+            edge,
+            EdgeType::Normal
+                | EdgeType::Jump
+                | EdgeType::Backedge
+                | EdgeType::Error(ErrorEdgeKind::Explicit)
+        )
+    };
+    // Blocks ending infinite loops, only a `break` (a `Jump` edge) leaves them
+    let mut infinite_loop_ends = FxHashSet::default();
+    let output = set_depth_first_search(graph, Some(node.cfg_id()), |event| match event {
+        DfsEvent::TreeEdge(a, b) => {
+            let is_exit = |edge: &EdgeType| {
+                infinite_loop_ends.contains(&a) && matches!(edge, EdgeType::Normal)
+            };
+            if graph
+                .edges_connecting(a, b)
+                .any(|e| is_normal_edge(e.weight()) && !is_exit(e.weight()))
+            {
+                Control::Continue
+            } else {
+                Control::Prune
+            }
+        }
+        DfsEvent::Discover(basic_block_id, _) => {
+            let basic_block = cfg.basic_block(basic_block_id);
+            // The edges after a `return` or `throw` lead to unreachable code
+            let returns = basic_block
+                .instructions()
+                .iter()
+                .any(|it| matches!(it.kind, InstructionKind::Return(_) | InstructionKind::Throw));
+            if returns || basic_block.unreachable {
+                return Control::Prune;
+            }
+            if let Some(end) = infinite_loop_end(basic_block_id, ctx) {
+                infinite_loop_ends.insert(end);
+            }
+            if graph
+                .edges_directed(basic_block_id, Direction::Outgoing)
+                .any(|e| is_normal_edge(e.weight()))
+            {
+                Control::Continue
+            } else {
+                Control::Break(())
+            }
+        }
+        _ => Control::Continue,
+    });
+    output.break_value().is_some()
+}
+
+/// The block ending the infinite loop starting at `basic_block_id`, whose `Normal` edges exit it.
+fn infinite_loop_end(basic_block_id: BasicBlockId, ctx: &LintContext) -> Option<BasicBlockId> {
+    let cfg = ctx.cfg();
+    let eval = |instruction: &Instruction| match instruction {
+        Instruction { kind: InstructionKind::Condition, node_id: Some(id) } => {
+            match ctx.nodes().kind(*id) {
+                AstKind::BooleanLiteral(lit) => EvalConstConditionResult::Eval(lit.value),
+                _ => EvalConstConditionResult::Fail,
+            }
+        }
+        _ => EvalConstConditionResult::NotFound,
+    };
+    if let Some((_, end)) = cfg.is_infinite_loop_start(basic_block_id, eval) {
+        return Some(end);
+    }
+    // `do { ... } while (true)` with more than one statement in the body
+    let is_loop_condition = cfg
+        .graph()
+        .edges_directed(basic_block_id, Direction::Outgoing)
+        .any(|e| matches!(e.weight(), EdgeType::Backedge));
+    let condition = cfg.basic_block(basic_block_id).instructions().last();
+    (is_loop_condition
+        && condition.is_some_and(|it| matches!(eval(it), EvalConstConditionResult::Eval(true))))
+    .then_some(basic_block_id)
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "function foo() { return; }",
+        "function foo() { if (true) return; }",
+        "function foo() { if (true) return; else return; }",
+        "function foo() { if (true) return true; else return false; }",
+        "function foo() { if (a) return 1; throw new Error(); }",
+        "function foo() { switch (a) { case 1: return 1; default: return 2; } }",
+        "function foo() { function bar() { return true; } return; }",
+        "function foo() { const bar = () => { return true; }; return; }",
+        "const foo = () => 1;",
+        "function* foo() { yield 1; return; }",
+        "function foo(): number | undefined { if (a) return 1; }",
+        "function foo(): void { if (a) return undefined; return; }",
+        "async function foo(): Promise<number | undefined> { if (a) return 1; }",
+        "function m(x) { for (const y of x) { if (y) return y; } return null; }",
+        "function g(x) { while (true) { if (x) return 1; } }",
+        "function g(x) { for (;;) { if (x) return 1; } }",
+        "function d(x) { do { if (x) return 1; } while (true); }",
+        "function p(x) { label: { if (x) break label; return 1; } return 2; }",
+        "function p(x) { try { return 1; } finally { x(); } }",
+    ];
+
+    let fail = vec![
+        "function foo() { if (true) return true; else return; }",
+        "function foo() { if (true) return; else return false; }",
+        "const foo = () => { if (a) return true; return; };",
+        "function foo() { if (a) return 1; }",
+        "async function foo(): Promise<number> { if (a) return 1; }",
+        "function g(x) { while (true) { if (x) return 1; if (y) break; } }",
+        "function m(x) { for (const y of x) { if (y) return y; } }",
+        "function p(x) { label: { if (x) break label; return 1; } }",
+    ];
+
+    Tester::new(ConsistentReturn::NAME, pass, fail).test_and_snapshot();
+}
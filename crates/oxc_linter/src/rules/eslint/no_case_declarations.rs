@@ -67,7 +67,9 @@ impl Rule for NoCaseDeclarations {
                     Statement::VariableDeclaration(var) if var.kind.is_lexical() => {
                         let start = var.span.start;
                         let end = match var.kind {
-                            VariableDeclarationKind::Var => unreachable!(),
+                            VariableDeclarationKind::Var | VariableDeclarationKind::Using => {
+                                unreachable!()
+                            }
                             VariableDeclarationKind::Const => 5,
                             VariableDeclarationKind::Let => 3,
                         };
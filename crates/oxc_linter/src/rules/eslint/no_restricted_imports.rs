@@ -0,0 +1,313 @@
+use oxc_ast::{ast::ImportDeclarationSpecifier, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use serde_json::Value;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn restricted_path_diagnostic(source: &str, message: &str, span2: Span) -> OxcDiagnostic {
+    let warn_text = if message.is_empty() {
+        format!("eslint(no-restricted-imports): '{source}' import is restricted from being used.")
+    } else {
+        format!(
+            "eslint(no-restricted-imports): '{source}' import is restricted from being used. {message}"
+        )
+    };
+
+    OxcDiagnostic::warn(warn_text).with_label(span2)
+}
+
+fn restricted_import_name_diagnostic(
+    name: &str,
+    source: &str,
+    message: &str,
+    span3: Span,
+) -> OxcDiagnostic {
+    let warn_text = if message.is_empty() {
+        format!("eslint(no-restricted-imports): '{name}' import from '{source}' is restricted.")
+    } else {
+        format!(
+            "eslint(no-restricted-imports): '{name}' import from '{source}' is restricted. {message}"
+        )
+    };
+
+    OxcDiagnostic::warn(warn_text).with_label(span3)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedImports(Box<NoRestrictedImportsConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedImportsConfig {
+    restricted_imports: Vec<RestrictedImport>,
+}
+
+impl std::ops::Deref for NoRestrictedImports {
+    type Target = NoRestrictedImportsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+struct RestrictedImport {
+    /// Module name, `*` matches any sequence of characters.
+    name: String,
+    message: String,
+    /// Only these named imports are restricted. `default` is the default import.
+    import_names: Vec<String>,
+}
+
+impl RestrictedImport {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            // "no-restricted-imports": ["error", "lodash"]
+            Value::String(name) => Some(Self { name: name.to_string(), ..Self::default() }),
+            // "no-restricted-imports": ["error", { "name": "lodash", "message": "Use lodash-es instead.", "importNames": ["map"] }]
+            Value::Object(obj) => {
+                let name = obj.get("name").and_then(Value::as_str)?;
+                let message = obj.get("message").and_then(Value::as_str).unwrap_or_default();
+                let import_names = obj
+                    .get("importNames")
+                    .and_then(Value::as_array)
+                    .map(|names| {
+                        names.iter().filter_map(Value::as_str).map(ToString::to_string).collect()
+                    })
+                    .unwrap_or_default();
+                Some(Self { name: name.to_string(), message: message.to_string(), import_names })
+            }
+            _ => None,
+        }
+    }
+
+    fn is_restricted_name(&self, name: &str) -> bool {
+        self.import_names.is_empty() || self.import_names.iter().any(|it| it == name)
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// This rule allows you to specify imports that you don't want to use in your application.
+    ///
+    /// A restricted module `name` is compared with the import source, and may contain `*`
+    /// wildcards, e.g. `lodash/*`. With `importNames`, only the listed named imports
+    /// (`default` for the default import) are restricted.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Some imports might not make sense in a particular environment, or a module may be
+    /// deprecated in favor of another one.
+    ///
+    /// ### Example
+    ///
+    /// If we have options:
+    ///
+    /// ```json
+    /// "no-restricted-imports": ["error", { "name": "lodash", "message": "Use lodash-es instead." }]
+    /// ```
+    ///
+    /// The following patterns are considered problems:
+    ///
+    /// ```javascript
+    /// import _ from 'lodash';
+    /// export { map } from 'lodash';
+    /// ```
+    NoRestrictedImports,
+    restriction,
+);
+
+impl Rule for NoRestrictedImports {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let list = match value {
+            Value::Array(arr) => arr
+                .iter()
+                .flat_map(|v| match v {
+                    // "no-restricted-imports": ["error", { "paths": [...], "patterns": [...] }]
+                    Value::Object(obj)
+                        if obj.contains_key("paths") || obj.contains_key("patterns") =>
+                    {
+                        ["paths", "patterns"]
+                            .iter()
+                            .filter_map(|key| obj.get(*key).and_then(Value::as_array))
+                            .flatten()
+                            .filter_map(RestrictedImport::from_value)
+                            .collect::<Vec<_>>()
+                    }
+                    v => RestrictedImport::from_value(v).into_iter().collect::<Vec<_>>(),
+                })
+                .collect(),
+            _ => vec![],
+        };
+
+        Self(Box::new(NoRestrictedImportsConfig { restricted_imports: list }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::ImportDeclaration(decl) => {
+                let source = decl.source.value.as_str();
+                let names = decl.specifiers.iter().flatten().map(|specifier| match specifier {
+                    ImportDeclarationSpecifier::ImportSpecifier(s) => {
+                        (Some(s.imported.name().to_string()), s.span)
+                    }
+                    ImportDeclarationSpecifier::ImportDefaultSpecifier(s) => {
+                        (Some("default".to_string()), s.span)
+                    }
+                    ImportDeclarationSpecifier::ImportNamespaceSpecifier(s) => (None, s.span),
+                });
+                self.check(source, decl.span, &names, ctx);
+            }
+            AstKind::ExportNamedDeclaration(decl) => {
+                let Some(source) = &decl.source else { return };
+                let names =
+                    decl.specifiers.iter().map(|s| (Some(s.local.name().to_string()), s.span));
+                self.check(source.value.as_str(), decl.span, &names, ctx);
+            }
+            AstKind::ExportAllDeclaration(decl) => {
+                self.check(
+                    decl.source.value.as_str(),
+                    decl.span,
+                    &std::iter::once((None, decl.span)),
+                    ctx,
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+impl NoRestrictedImports {
+    /// `names` are the imported names with their spans, `None` for a namespace import,
+    /// which brings in every name.
+    fn check(
+        &self,
+        source: &str,
+        span: Span,
+        names: &(impl Iterator<Item = (Option<String>, Span)> + Clone),
+        ctx: &LintContext,
+    ) {
+        for restricted in &self.restricted_imports {
+            if !glob_match(&restricted.name, source) {
+                continue;
+            }
+            if restricted.import_names.is_empty() {
+                ctx.diagnostic(restricted_path_diagnostic(source, &restricted.message, span));
+                continue;
+            }
+            for (name, name_span) in names.clone() {
+                match name {
+                    Some(name) if restricted.is_restricted_name(&name) => {
+                        ctx.diagnostic(restricted_import_name_diagnostic(
+                            &name,
+                            source,
+                            &restricted.message,
+                            name_span,
+                        ));
+                    }
+                    Some(_) => {}
+                    None => {
+                        for name in &restricted.import_names {
+                            ctx.diagnostic(restricted_import_name_diagnostic(
+                                name,
+                                source,
+                                &restricted.message,
+                                name_span,
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Matches `text` against `pattern`, where `*` matches any sequence of characters.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let Some((prefix, rest)) = pattern.split_once('*') else { return pattern == text };
+    let Some(mut text) = text.strip_prefix(prefix) else { return false };
+    let mut parts = rest.split('*').peekable();
+    while let Some(part) = parts.next() {
+        if parts.peek().is_none() {
+            return text.ends_with(part);
+        }
+        match text.find(part) {
+            Some(index) => text = &text[index + part.len()..],
+            None => return false,
+        }
+    }
+    true
+}
+
+#[test]
+fn test_glob_match() {
+    assert!(glob_match("lodash", "lodash"));
+    assert!(!glob_match("lodash", "lodash/map"));
+    assert!(glob_match("lodash/*", "lodash/map"));
+    assert!(!glob_match("lodash/*", "lodash-es"));
+    assert!(glob_match("*/internal/*", "foo/internal/bar"));
+    assert!(glob_match("@scope/*/private", "@scope/pkg/private"));
+    assert!(!glob_match("@scope/*/private", "@scope/pkg/public"));
+    assert!(glob_match("*", "anything"));
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("import os from 'os';", None),
+        ("import os from 'os';", Some(json!(["osx"]))),
+        ("import fs from 'fs';", Some(json!(["crypto"]))),
+        ("import 'foo';", Some(json!(["bar"]))),
+        ("import lodash from 'lodash-es';", Some(json!(["lodash"]))),
+        ("import map from 'lodash/map';", Some(json!(["lodash"]))),
+        (
+            "import { map } from 'lodash';",
+            Some(json!([{ "name": "lodash", "importNames": ["filter"] }])),
+        ),
+        (
+            "export { map } from 'lodash';",
+            Some(json!([{ "name": "lodash", "importNames": ["filter"] }])),
+        ),
+        ("import foo from 'foo/public';", Some(json!([{ "patterns": ["foo/private/*"] }]))),
+        ("const lodash = require('lodash');", Some(json!(["lodash"]))),
+    ];
+
+    let fail = vec![
+        ("import os from 'os';", Some(json!(["os"]))),
+        ("import 'os';", Some(json!(["fs", "os"]))),
+        (
+            "import * as os from 'os';",
+            Some(json!([{ "name": "os", "message": "Use node:os instead." }])),
+        ),
+        ("export * from 'os';", Some(json!(["os"]))),
+        ("export { tmpdir } from 'os';", Some(json!(["os"]))),
+        ("import map from 'lodash/map';", Some(json!(["lodash/*"]))),
+        ("import foo from 'foo/private/bar';", Some(json!([{ "patterns": ["foo/private/*"] }]))),
+        (
+            "import { map, filter } from 'lodash';",
+            Some(json!([{ "paths": [{ "name": "lodash", "importNames": ["filter"] }] }])),
+        ),
+        (
+            "import lodash from 'lodash';",
+            Some(
+                json!([{ "name": "lodash", "importNames": ["default"], "message": "Import functions by name." }]),
+            ),
+        ),
+        (
+            "import * as lodash from 'lodash';",
+            Some(json!([{ "name": "lodash", "importNames": ["map"] }])),
+        ),
+        (
+            "export { map as lodashMap } from 'lodash';",
+            Some(json!([{ "name": "lodash", "importNames": ["map"] }])),
+        ),
+    ];
+
+    Tester::new(NoRestrictedImports::NAME, pass, fail).test_and_snapshot();
+}
@@ -161,7 +161,7 @@ impl NoThisBeforeSuper {
         follow_join: bool,
     ) -> Vec<DefinitelyCallsThisBeforeSuper> {
         neighbors_filtered_by_edge_weight(
-            &cfg.graph,
+            cfg.graph(),
             id,
             &|edge| match edge {
                 EdgeType::Jump | EdgeType::Normal => None,
@@ -184,7 +184,7 @@ impl NoThisBeforeSuper {
                     // If super is called but we are in a try-catch(-finally) block mark it as a
                     // maybe, since we might throw on super call and still call this in
                     // `catch`/`finally` block(s).
-                    if cfg.graph.edges(*basic_block_id).any(|it| {
+                    if cfg.graph().edges(*basic_block_id).any(|it| {
                         matches!(
                             it.weight(),
                             EdgeType::Error(ErrorEdgeKind::Explicit) | EdgeType::Finalize
@@ -199,7 +199,7 @@ impl NoThisBeforeSuper {
                 // If we haven't visited a super call and we have a non-error/finalize path
                 // forward, continue visiting this branch.
                 } else if cfg
-                    .graph
+                    .graph()
                     .edges(*basic_block_id)
                     .any(|it| !matches!(it.weight(), EdgeType::Error(_) | EdgeType::Finalize))
                 {
@@ -224,7 +224,7 @@ impl NoThisBeforeSuper {
         output.into_iter().any(|y| match y {
             DefinitelyCallsThisBeforeSuper::Yes => true,
             DefinitelyCallsThisBeforeSuper::No => false,
-            DefinitelyCallsThisBeforeSuper::Maybe(id) => cfg.graph.edges(id).any(|edge| {
+            DefinitelyCallsThisBeforeSuper::Maybe(id) => cfg.graph().edges(id).any(|edge| {
                 let weight = edge.weight();
                 let is_explicit_error = matches!(weight, EdgeType::Error(ErrorEdgeKind::Explicit));
                 if is_explicit_error || matches!(weight, EdgeType::Finalize) {
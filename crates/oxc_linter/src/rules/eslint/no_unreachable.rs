@@ -39,7 +39,7 @@ impl Rule for NoUnreachable {
         // A pre-allocated vector containing the reachability status of all the basic blocks.
         // We initialize this vector with all nodes set to `unreachable` since if we don't visit a
         // node in our paths then it should be unreachable by definition.
-        let mut unreachables = vec![true; cfg.basic_blocks.len()];
+        let mut unreachables = vec![true; cfg.basic_blocks().len()];
 
         // All of the end points of infinite loops we encountered.
         let mut infinite_loops = Vec::new();
@@ -278,6 +278,9 @@ fn test() {
             b();
         }
         ",
+        // TypeScript declarations are not executed
+        "function foo() { return; type T = string; }",
+        "function foo() { return; interface I {} }",
     ];
 
     let fail = vec![
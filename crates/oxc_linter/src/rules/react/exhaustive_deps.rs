@@ -0,0 +1,430 @@
+use oxc_ast::{
+    ast::{
+        ArrayExpression, BindingPatternKind, CallExpression, Expression, IdentifierReference,
+        StaticMemberExpression, VariableDeclarationKind,
+    },
+    visit::walk::{walk_call_expression, walk_static_member_expression},
+    AstKind, Visit,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::SymbolId;
+use oxc_span::{GetSpan, Span};
+use regex::Regex;
+
+use crate::{context::LintContext, rule::Rule, utils::is_react_function_call, AstNode};
+
+fn missing_dependency_diagnostic(span0: Span, hook_name: &str, deps: &[String]) -> OxcDiagnostic {
+    let (noun, pronoun) = if deps.len() == 1 {
+        ("a missing dependency", "it")
+    } else {
+        ("missing dependencies", "them")
+    };
+    OxcDiagnostic::warn(format!(
+        "eslint-plugin-react-hooks(exhaustive-deps): React Hook {hook_name} has {noun}: {}. \
+        Either include {pronoun} or remove the dependency array.",
+        join_english(deps)
+    ))
+    .with_label(span0)
+}
+
+fn unnecessary_dependency_diagnostic(
+    span0: Span,
+    hook_name: &str,
+    deps: &[String],
+) -> OxcDiagnostic {
+    let (noun, pronoun) = if deps.len() == 1 {
+        ("an unnecessary dependency", "it")
+    } else {
+        ("unnecessary dependencies", "them")
+    };
+    OxcDiagnostic::warn(format!(
+        "eslint-plugin-react-hooks(exhaustive-deps): React Hook {hook_name} has {noun}: {}. \
+        Either exclude {pronoun} or remove the dependency array.",
+        join_english(deps)
+    ))
+    .with_label(span0)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ExhaustiveDeps(Box<ExhaustiveDepsConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct ExhaustiveDepsConfig {
+    /// Custom hooks matching this pattern are checked like `useEffect`.
+    additional_hooks: Option<Regex>,
+}
+
+impl std::ops::Deref for ExhaustiveDeps {
+    type Target = ExhaustiveDepsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Verifies the dependency arrays of `useEffect`, `useLayoutEffect`,
+    /// `useInsertionEffect`, `useCallback`, `useMemo` and `useImperativeHandle`.
+    ///
+    /// Values from the component scope read by the hook callback must be listed as
+    /// dependencies. State setters, refs and constant primitives are stable and
+    /// never need to be listed. Custom hooks can be checked with the `additionalHooks`
+    /// option, a regex matched against the hook name.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A missing dependency makes the callback read stale values from a previous render.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// function MyComponent(props) {
+    ///     useEffect(() => {
+    ///         console.log(props.foo);
+    ///     }, []); // `props.foo` is missing
+    /// }
+    /// ```
+    ExhaustiveDeps,
+    nursery
+);
+
+impl Rule for ExhaustiveDeps {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self(Box::new(ExhaustiveDepsConfig {
+            additional_hooks: value
+                .get(0)
+                .and_then(|config| config.get("additionalHooks"))
+                .and_then(serde_json::Value::as_str)
+                .and_then(|pattern| Regex::new(pattern).ok()),
+        }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else { return };
+        let Some(hook_name) = call.callee_name() else { return };
+        let Some((callback_index, is_effect)) = self.hook_kind(call, hook_name) else { return };
+
+        let Some(callback) = call.arguments.get(callback_index).and_then(|arg| arg.as_expression())
+        else {
+            return;
+        };
+        let callback_span = match callback.without_parenthesized() {
+            Expression::ArrowFunctionExpression(arrow) => arrow.span,
+            Expression::FunctionExpression(func) => func.span,
+            _ => return,
+        };
+        let Some(Expression::ArrayExpression(declared)) =
+            call.arguments.get(callback_index + 1).and_then(|arg| arg.as_expression())
+        else {
+            return;
+        };
+        let Some(component) =
+            ctx.nodes().iter_parents(node.id()).find(|parent| parent.kind().is_function_like())
+        else {
+            return;
+        };
+
+        let mut collector = DependencyCollector {
+            ctx,
+            component_span: component.kind().span(),
+            callback_span,
+            dependencies: vec![],
+        };
+        collector.visit_expression(callback);
+        let required = collector.dependencies;
+
+        let Some(declared_paths) = declared_dependencies(declared) else { return };
+
+        let mut missing = required
+            .iter()
+            .filter(|path| !declared_paths.iter().any(|declared| is_covered_by(path, declared)))
+            .cloned()
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            missing.sort_unstable();
+            // Adding a dependency changes when the hook runs, so this is only a suggestion.
+            ctx.diagnostic_with_suggestion(
+                missing_dependency_diagnostic(declared.span, hook_name, &missing),
+                |fixer| {
+                    let elements = declared
+                        .elements
+                        .iter()
+                        .map(|element| fixer.source_range(element.span()).to_string())
+                        .chain(missing.iter().cloned())
+                        .collect::<Vec<_>>();
+                    fixer.replace(declared.span, format!("[{}]", elements.join(", ")))
+                },
+            );
+        }
+
+        // Effects may list extra dependencies to re-run when they change.
+        if is_effect {
+            return;
+        }
+        let unnecessary = declared_paths
+            .iter()
+            .filter(|declared| !required.iter().any(|path| is_covered_by(path, declared)))
+            .cloned()
+            .collect::<Vec<_>>();
+        if !unnecessary.is_empty() {
+            ctx.diagnostic(unnecessary_dependency_diagnostic(
+                declared.span,
+                hook_name,
+                &unnecessary,
+            ));
+        }
+    }
+}
+
+impl ExhaustiveDeps {
+    /// Returns the index of the callback argument, and whether the hook is an effect.
+    fn hook_kind(&self, call: &CallExpression, hook_name: &str) -> Option<(usize, bool)> {
+        let kind = match hook_name {
+            "useEffect" | "useLayoutEffect" | "useInsertionEffect" => (0, true),
+            "useCallback" | "useMemo" => (0, false),
+            "useImperativeHandle" => (1, false),
+            _ => {
+                let additional_hooks = self.additional_hooks.as_ref()?;
+                if matches!(call.callee, Expression::Identifier(_))
+                    && additional_hooks.is_match(hook_name)
+                {
+                    return Some((0, true));
+                }
+                return None;
+            }
+        };
+        is_react_function_call(call, hook_name).then_some(kind)
+    }
+}
+
+/// Collects the paths of component scope values read by a hook callback,
+/// such as `count` or `props.user.name`.
+struct DependencyCollector<'a, 'c> {
+    ctx: &'c LintContext<'a>,
+    component_span: Span,
+    callback_span: Span,
+    dependencies: Vec<String>,
+}
+
+impl<'a, 'c> DependencyCollector<'a, 'c> {
+    fn add(&mut self, mut path: String, ident: &IdentifierReference) {
+        let Some(symbol_id) = ident
+            .reference_id
+            .get()
+            .and_then(|reference_id| self.ctx.symbols().get_reference(reference_id).symbol_id())
+        else {
+            return;
+        };
+        let declaration_span = self.ctx.symbols().get_span(symbol_id);
+        if !self.component_span.contains_span(declaration_span)
+            || self.callback_span.contains_span(declaration_span)
+            || is_stable_value(symbol_id, self.ctx)
+        {
+            return;
+        }
+        // `ref.current` is mutable, the dependency is the ref itself.
+        if let Some(stripped) = path.strip_suffix(".current") {
+            path = stripped.to_string();
+        }
+        if !self.dependencies.contains(&path) {
+            self.dependencies.push(path);
+        }
+    }
+}
+
+impl<'a, 'c> Visit<'a> for DependencyCollector<'a, 'c> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        self.add(ident.name.to_string(), ident);
+    }
+
+    fn visit_static_member_expression(&mut self, expr: &StaticMemberExpression<'a>) {
+        if let Some((path, ident)) = member_path(expr) {
+            self.add(path, ident);
+        } else {
+            walk_static_member_expression(self, expr);
+        }
+    }
+
+    fn visit_call_expression(&mut self, expr: &CallExpression<'a>) {
+        // Calling `props.onChange()` passes `props` as `this`, so `props` is the dependency.
+        if let Expression::StaticMemberExpression(callee) = &expr.callee {
+            if let Some((path, ident)) = dependency_path(&callee.object) {
+                self.add(path, ident);
+                for arg in &expr.arguments {
+                    self.visit_argument(arg);
+                }
+                return;
+            }
+        }
+        walk_call_expression(self, expr);
+    }
+}
+
+/// The dotted path of an identifier or a chain of static member accesses on one.
+fn dependency_path<'e, 'a>(
+    expr: &'e Expression<'a>,
+) -> Option<(String, &'e IdentifierReference<'a>)> {
+    match expr.without_parenthesized() {
+        Expression::Identifier(ident) => Some((ident.name.to_string(), ident)),
+        Expression::StaticMemberExpression(member) => member_path(member),
+        _ => None,
+    }
+}
+
+fn member_path<'e, 'a>(
+    member: &'e StaticMemberExpression<'a>,
+) -> Option<(String, &'e IdentifierReference<'a>)> {
+    let (path, ident) = dependency_path(&member.object)?;
+    Some((format!("{path}.{}", member.property.name), ident))
+}
+
+/// The paths listed in a dependency array, or `None` if an element can't be analyzed.
+fn declared_dependencies(declared: &ArrayExpression) -> Option<Vec<String>> {
+    declared
+        .elements
+        .iter()
+        .map(|element| element.as_expression().and_then(dependency_path).map(|(path, _)| path))
+        .collect()
+}
+
+/// `props.user.name` is covered by declaring `props.user.name`, `props.user` or `props`.
+fn is_covered_by(path: &str, declared: &str) -> bool {
+    path.strip_prefix(declared).is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+}
+
+/// Values which keep their identity across renders: `useRef` refs, the setters
+/// returned by `useState`, `useReducer` and `useTransition`, and constant primitives.
+fn is_stable_value(symbol_id: SymbolId, ctx: &LintContext) -> bool {
+    let AstKind::VariableDeclarator(decl) =
+        ctx.nodes().kind(ctx.symbols().get_declaration(symbol_id))
+    else {
+        return false;
+    };
+    let Some(init) = &decl.init else { return false };
+    match init.without_parenthesized() {
+        Expression::StringLiteral(_)
+        | Expression::NumericLiteral(_)
+        | Expression::BooleanLiteral(_)
+        | Expression::NullLiteral(_) => decl.kind == VariableDeclarationKind::Const,
+        Expression::CallExpression(call) => {
+            if is_react_function_call(call, "useRef") {
+                return matches!(decl.id.kind, BindingPatternKind::BindingIdentifier(_));
+            }
+            if !["useState", "useReducer", "useTransition"]
+                .iter()
+                .any(|hook| is_react_function_call(call, hook))
+            {
+                return false;
+            }
+            let BindingPatternKind::ArrayPattern(pattern) = &decl.id.kind else { return false };
+            matches!(
+                pattern.elements.get(1),
+                Some(Some(element)) if matches!(
+                    &element.kind,
+                    BindingPatternKind::BindingIdentifier(ident)
+                        if ident.span == ctx.symbols().get_span(symbol_id)
+                )
+            )
+        }
+        _ => false,
+    }
+}
+
+fn join_english(deps: &[String]) -> String {
+    let quoted = deps.iter().map(|dep| format!("'{dep}'")).collect::<Vec<_>>();
+    match quoted.as_slice() {
+        [] => String::new(),
+        [only] => only.clone(),
+        [first, second] => format!("{first} and {second}"),
+        [rest @ .., last] => format!("{}, and {last}", rest.join(", ")),
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function MyComponent() { const local = {}; useEffect(() => { console.log(local); }, [local]); }", None),
+        ("function MyComponent() { useEffect(() => { const local = {}; console.log(local); }, []); }", None),
+        ("const outer = {}; function MyComponent() { useEffect(() => { console.log(outer); }, []); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props.foo); }, [props.foo]); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props.foo.bar); }, [props]); }", None),
+        ("function MyComponent(props) { useEffect(() => { props.onChange(); }, [props]); }", None),
+        ("function MyComponent() { const [count, setCount] = useState(0); useEffect(() => { setCount(1); }, []); }", None),
+        ("function MyComponent() { const [state, dispatch] = React.useReducer(reducer, 0); useEffect(() => { dispatch(1); }, []); }", None),
+        ("function MyComponent() { const ref = useRef(); useEffect(() => { ref.current = 1; }, []); }", None),
+        ("function MyComponent() { const limit = 10; useEffect(() => { console.log(limit); }, []); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props.foo); }); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props.foo); }, [props.foo, props.bar]); }", None),
+        ("function MyComponent(props) { const fn = useCallback(() => props.foo, [props.foo]); }", None),
+        ("function MyComponent({ a }) { const value = useMemo(() => a * 2, [a]); }", None),
+        ("function MyComponent(props) { useCustomEffect(() => { console.log(props.foo); }, []); }", None),
+        ("function MyComponent(props, ref) { useImperativeHandle(ref, () => ({ value: props.value }), [props.value]); }", None),
+    ];
+
+    let fail = vec![
+        ("function MyComponent() { const local = {}; useEffect(() => { console.log(local); }, []); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props.foo); }, []); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props.foo, props.bar); }, [props.foo]); }", None),
+        ("function MyComponent({ a, b, c }) { useEffect(() => { console.log(a, b, c); }, []); }", None),
+        ("function MyComponent(props) { const fn = React.useCallback(() => props.foo, []); }", None),
+        ("function MyComponent({ a, b }) { const value = useMemo(() => a * 2, [a, b]); }", None),
+        ("const outer = {}; function MyComponent() { const fn = useCallback(() => outer, [outer]); }", None),
+        ("function MyComponent() { const [count, setCount] = useState(0); useEffect(() => { setCount(count + 1); }, []); }", None),
+        ("function MyComponent(props) { useCustomEffect(() => { console.log(props.foo); }, []); }", Some(json!([{ "additionalHooks": "^useCustomEffect$" }]))),
+    ];
+
+    let fix = vec![
+        (
+            "function MyComponent(props) { useEffect(() => { console.log(props.foo); }, []); }",
+            "function MyComponent(props) { useEffect(() => { console.log(props.foo); }, [props.foo]); }",
+            None,
+        ),
+        (
+            "function MyComponent({ a, b }) { useEffect(() => { console.log(a, b); }, [a]); }",
+            "function MyComponent({ a, b }) { useEffect(() => { console.log(a, b); }, [a, b]); }",
+            None,
+        ),
+    ];
+
+    Tester::new(ExhaustiveDeps::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
+
+#[test]
+fn test_fix_is_a_suggestion() {
+    use std::{path::Path, rc::Rc};
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use crate::{AllowWarnDeny, LintOptions, Linter};
+
+    let source_text =
+        "function MyComponent(props) { useEffect(() => { console.log(props.foo); }, []); }";
+    let fixes = |fix_suggestions: bool| {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_jsx(true);
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic =
+            SemanticBuilder::new(source_text, source_type).with_cfg(true).build(program).semantic;
+        let ctx = LintContext::new(Path::new("test.jsx").into(), Rc::new(semantic));
+        let options = LintOptions::default()
+            .with_filter(vec![(AllowWarnDeny::Deny, ExhaustiveDeps::NAME.to_string())])
+            .with_fix(true)
+            .with_fix_suggestions(fix_suggestions);
+        let messages = Linter::from_options(options).unwrap().run(ctx);
+        assert_eq!(messages.len(), 1);
+        messages.iter().filter(|message| message.fix.is_some()).count()
+    };
+
+    assert_eq!(fixes(false), 0);
+    assert_eq!(fixes(true), 1);
+}
@@ -0,0 +1,117 @@
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, utils::is_async_function_call, AstNode};
+
+fn no_floating_promises_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("typescript-eslint(no-floating-promises): Promises must be awaited, end with a call to .catch, or end with a call to .then with a rejection handler.")
+        .with_help("Add `await`, or prefix the call with `void` to explicitly ignore the promise.")
+        .with_label(span0)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoFloatingPromises;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require promises returned by calls to async functions to be handled appropriately.
+    ///
+    /// Without type information, only calls to functions declared in the same file with
+    /// `async function` or assigned an async function or arrow function are checked.
+    /// Method calls are only checked for methods of object literals assigned to a variable,
+    /// e.g. `obj.method()` with `const obj = { async method() {} }`, and static methods of
+    /// classes, e.g. `C.method()` with `class C { static async method() {} }`.
+    /// Calls through `this` or class instances, e.g. `this.method()` or `new C().method()`,
+    /// are not checked.
+    ///
+    /// The suggested fix prefixes the call with `void`, which ignores the promise's rejections,
+    /// so it is only applied when fixing suggestions is enabled.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A promise which is neither awaited nor returned is a "floating" promise,
+    /// its rejections are not handled and it may resolve in an unexpected order.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// async function save() {}
+    ///
+    /// // Bad
+    /// save();
+    ///
+    /// // Good
+    /// await save();
+    /// void save();
+    /// ```
+    NoFloatingPromises,
+    nursery
+);
+
+impl Rule for NoFloatingPromises {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ExpressionStatement(stmt) = node.kind() else { return };
+        if !is_async_function_call(&stmt.expression, ctx) {
+            return;
+        }
+        let Expression::CallExpression(call) = stmt.expression.without_parenthesized() else {
+            return;
+        };
+        // Dead code never creates the promise.
+        if ctx.cfg().basic_block(node.cfg_id()).unreachable {
+            return;
+        }
+        ctx.diagnostic_with_suggestion(no_floating_promises_diagnostic(call.span), |fixer| {
+            fixer.replace(call.span, format!("void {}", fixer.source_range(call.span)))
+        });
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "async function f() {} async function g() { await f(); }",
+        "async function f() {} void f();",
+        "async function f() {} f().then(() => {}, () => {});",
+        "async function f() {} f().catch(() => {});",
+        "async function f() {} const p = f();",
+        "async function f() {} function g() { return f(); }",
+        "async function f() {} function g() { return; f(); }",
+        "function f() {} f();",
+        "const f = () => {}; f();",
+        "g();",
+        "const obj = { async m() {} }; void obj.m();",
+        "const obj = { m() {} }; obj.m();",
+        "class C { async m() {} } C.m();",
+        "class C { static async m() {} } async function g() { await C.m(); }",
+        "obj.m();",
+    ];
+
+    let fail = vec![
+        "async function f() {} f();",
+        "const f = async () => {}; f();",
+        "const f = async function () {}; f();",
+        "async function f() {} function g() { f(); }",
+        "const obj = { async m() {} }; obj.m();",
+        "const obj = { m: async () => {} }; obj.m();",
+        "async function f() {} const obj = { f }; obj.f();",
+        "class C { static async m() {} } C.m();",
+        "class C { static m = async () => {}; } C.m();",
+    ];
+
+    let fix = vec![
+        ("async function f() {} f();", "async function f() {} void f();", None),
+        ("const f = async () => {}; f();", "const f = async () => {}; void f();", None),
+        (
+            "const obj = { async m() {} }; obj.m();",
+            "const obj = { async m() {} }; void obj.m();",
+            None,
+        ),
+    ];
+
+    Tester::new(NoFloatingPromises::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
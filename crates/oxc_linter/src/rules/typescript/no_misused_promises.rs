@@ -0,0 +1,169 @@
+use oxc_ast::{
+    ast::{Argument, CallExpression, Expression},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::{LogicalOperator, UnaryOperator};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{is_async_function, is_async_function_call},
+    AstNode,
+};
+
+fn conditional_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "typescript-eslint(no-misused-promises): Expected non-Promise value in a boolean conditional.",
+    )
+    .with_help("A Promise is always truthy, did you forget to `await` it?")
+    .with_label(span0)
+}
+
+fn void_return_argument_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("typescript-eslint(no-misused-promises): Promise returned in function argument where a void return was expected.")
+        .with_help("The returned Promise is ignored, so its rejections are never handled.")
+        .with_label(span0)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoMisusedPromises;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow promises in places not designed to handle them.
+    ///
+    /// This rule reports:
+    /// * async functions passed as callbacks to array methods and event listeners, which
+    ///   ignore the returned promise, and to `setTimeout` and `setInterval`.
+    /// * calls to async functions in boolean conditionals (`if`, `while`, `for`, `? :`,
+    ///   `!`, and the left operand of `&&` and `||`).
+    ///
+    /// Without type information, only async functions declared in the same file are
+    /// recognized.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A promise is always truthy, and a promise returned to a caller which doesn't await it
+    /// is never handled.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// async function isReady() {}
+    ///
+    /// if (isReady()) {}
+    /// [1, 2, 3].forEach(async (value) => { await save(value); });
+    /// setInterval(async () => { await poll(); }, 1000);
+    /// ```
+    NoMisusedPromises,
+    nursery
+);
+
+impl Rule for NoMisusedPromises {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::IfStatement(stmt) => check_conditional(&stmt.test, ctx),
+            AstKind::WhileStatement(stmt) => check_conditional(&stmt.test, ctx),
+            AstKind::DoWhileStatement(stmt) => check_conditional(&stmt.test, ctx),
+            AstKind::ForStatement(stmt) => {
+                if let Some(test) = &stmt.test {
+                    check_conditional(test, ctx);
+                }
+            }
+            AstKind::ConditionalExpression(expr) => check_conditional(&expr.test, ctx),
+            AstKind::LogicalExpression(expr)
+                if matches!(expr.operator, LogicalOperator::And | LogicalOperator::Or) =>
+            {
+                check_conditional(&expr.left, ctx);
+            }
+            AstKind::UnaryExpression(expr) if expr.operator == UnaryOperator::LogicalNot => {
+                check_conditional(&expr.argument, ctx);
+            }
+            AstKind::CallExpression(call) => check_void_return_argument(call, ctx),
+            _ => {}
+        }
+    }
+}
+
+fn check_conditional(test: &Expression, ctx: &LintContext) {
+    if is_async_function_call(test, ctx) {
+        ctx.diagnostic(conditional_diagnostic(test.span()));
+    }
+}
+
+/// Array methods which call their callback without awaiting the result.
+const ARRAY_METHODS: [&str; 8] =
+    ["every", "filter", "find", "findIndex", "findLast", "findLastIndex", "forEach", "some"];
+
+/// Event emitter methods whose listener is the second argument.
+const LISTENER_METHODS: [&str; 4] = ["addEventListener", "on", "once", "addListener"];
+
+fn check_void_return_argument(call: &CallExpression, ctx: &LintContext) {
+    let callback_index = match call.callee.without_parenthesized() {
+        Expression::Identifier(ident)
+            if matches!(ident.name.as_str(), "setTimeout" | "setInterval")
+                && ident.reference_id.get().is_some_and(|reference_id| {
+                    ctx.symbols().is_global_reference(reference_id)
+                }) =>
+        {
+            0
+        }
+        callee => {
+            let Some(member) = callee.as_member_expression() else { return };
+            match member.static_property_name() {
+                Some(name) if ARRAY_METHODS.contains(&name) => 0,
+                Some(name) if LISTENER_METHODS.contains(&name) => 1,
+                _ => return,
+            }
+        }
+    };
+    let Some(callback) = call.arguments.get(callback_index).and_then(Argument::as_expression)
+    else {
+        return;
+    };
+    if is_async_function(callback, ctx) {
+        ctx.diagnostic(void_return_argument_diagnostic(callback.span()));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "async function f() {} async function g() { if (await f()) {} }",
+        "async function f() {} async function g() { while (await f()) {} }",
+        "function f() {} if (f()) {}",
+        "if (g()) {}",
+        "async function f() {} function g(f) { if (f()) {} }",
+        "[1, 2, 3].forEach((x) => console.log(x));",
+        "const results = Promise.all([1, 2, 3].map(async (x) => x));",
+        "[1, 2, 3].forEach(function (x) {});",
+        "setTimeout(() => {}, 1000);",
+        "function setTimeout(cb) {} setTimeout(async () => {});",
+        "el.addEventListener('click', () => {});",
+        "foo.forEach;",
+    ];
+
+    let fail = vec![
+        "async function f() {} if (f()) {}",
+        "async function f() {} while (f()) {}",
+        "async function f() {} do {} while (f());",
+        "async function f() {} for (; f();) {}",
+        "const f = async () => true; const x = f() ? 1 : 2;",
+        "async function f() {} const x = f() && g();",
+        "async function f() {} const x = f() || g();",
+        "async function f() {} if (!f()) {}",
+        "[1, 2, 3].forEach(async (x) => { await save(x); });",
+        "items.filter(async function (x) { return await check(x); });",
+        "async function handler() {} el.addEventListener('click', handler);",
+        "emitter.on('data', async (data) => {});",
+        "setTimeout(async () => {}, 1000);",
+        "async function poll() {} setInterval(poll, 1000);",
+    ];
+
+    Tester::new(NoMisusedPromises::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,161 @@
+use oxc_ast::{
+    ast::{BindingPattern, Expression, TSLiteral, TSType},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn no_unnecessary_type_assertion_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("typescript-eslint(no-unnecessary-type-assertion): This assertion is unnecessary since it does not change the type of the expression.")
+        .with_help("Remove the type assertion.")
+        .with_label(span0)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnnecessaryTypeAssertion;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow type assertions that do not change the type of an expression.
+    ///
+    /// Without type information, only identifiers declared with an explicit type annotation
+    /// are checked: a non-null assertion on a variable whose declared type can't be
+    /// `null` or `undefined`, and an `as` or `<T>` assertion to exactly the declared type.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Unnecessary assertions are noise, and hide a real assertion when the declared
+    /// type later changes.
+    ///
+    /// ### Example
+    /// ```typescript
+    /// const foo: string = getFoo();
+    /// foo!;
+    /// foo as string;
+    /// <string>foo;
+    /// ```
+    NoUnnecessaryTypeAssertion,
+    nursery
+);
+
+impl Rule for NoUnnecessaryTypeAssertion {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (span, expression) = match node.kind() {
+            AstKind::TSNonNullExpression(expr) => {
+                let Some(declared) = declared_type(&expr.expression, ctx) else { return };
+                if !is_non_nullable(declared) {
+                    return;
+                }
+                (expr.span, &expr.expression)
+            }
+            AstKind::TSAsExpression(expr) => {
+                if !is_declared_type(&expr.expression, &expr.type_annotation, ctx) {
+                    return;
+                }
+                (expr.span, &expr.expression)
+            }
+            AstKind::TSTypeAssertion(expr) => {
+                if !is_declared_type(&expr.expression, &expr.type_annotation, ctx) {
+                    return;
+                }
+                (expr.span, &expr.expression)
+            }
+            _ => return,
+        };
+        ctx.diagnostic_with_fix(no_unnecessary_type_assertion_diagnostic(span), |fixer| {
+            fixer.replace(span, fixer.source_range(expression.span()).to_string())
+        });
+    }
+}
+
+/// The type annotation of the variable or parameter `expr` refers to, if it has one.
+fn declared_type<'a>(expr: &Expression, ctx: &LintContext<'a>) -> Option<&'a TSType<'a>> {
+    let Expression::Identifier(ident) = expr.without_parenthesized() else { return None };
+    let symbol_id = ctx.symbols().get_reference(ident.reference_id.get()?).symbol_id()?;
+    let pattern: &BindingPattern = match ctx.nodes().kind(ctx.symbols().get_declaration(symbol_id))
+    {
+        AstKind::VariableDeclarator(decl) => &decl.id,
+        AstKind::FormalParameter(param) => &param.pattern,
+        _ => return None,
+    };
+    // `x?: T` is `T | undefined`, and destructured bindings carry no annotation of their own.
+    if pattern.optional || !pattern.kind.is_binding_identifier() {
+        return None;
+    }
+    pattern.type_annotation.as_ref().map(|annotation| &annotation.type_annotation)
+}
+
+fn is_declared_type(expr: &Expression, asserted: &TSType, ctx: &LintContext) -> bool {
+    declared_type(expr, ctx).is_some_and(|declared| {
+        ctx.source_range(declared.span()) == ctx.source_range(asserted.span())
+    })
+}
+
+/// Types which can't hold `null` or `undefined`. Type references are not resolved,
+/// so they are assumed to be nullable.
+fn is_non_nullable(ty: &TSType) -> bool {
+    match ty {
+        TSType::TSStringKeyword(_)
+        | TSType::TSNumberKeyword(_)
+        | TSType::TSBooleanKeyword(_)
+        | TSType::TSBigIntKeyword(_)
+        | TSType::TSSymbolKeyword(_)
+        | TSType::TSObjectKeyword(_)
+        | TSType::TSTemplateLiteralType(_)
+        | TSType::TSArrayType(_)
+        | TSType::TSTupleType(_)
+        | TSType::TSFunctionType(_)
+        | TSType::TSConstructorType(_)
+        | TSType::TSTypeLiteral(_) => true,
+        // `null` is parsed as a literal type.
+        TSType::TSLiteralType(ty) => !matches!(ty.literal, TSLiteral::NullLiteral(_)),
+        TSType::TSUnionType(union) => union.types.iter().all(is_non_nullable),
+        TSType::TSParenthesizedType(ty) => is_non_nullable(&ty.type_annotation),
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "declare const foo: string | null; foo!;",
+        "declare const foo: string | undefined; foo!;",
+        "declare const foo: Foo; foo!;",
+        "declare const foo: any; foo!;",
+        "function f(foo?: string) { foo!; }",
+        "const { foo }: { foo: string | null } = bar; foo!;",
+        "bar!;",
+        "declare const foo: string | number; foo as string;",
+        "declare const foo: unknown; foo as string;",
+        "declare const foo: string; foo as const;",
+        "declare const foo: string; foo.length as number;",
+        "declare const foo: string; <number>(foo as unknown);",
+    ];
+
+    let fail = vec![
+        "declare const foo: string; foo!;",
+        "declare const foo: 'a' | 'b'; foo!;",
+        "function f(foo: number[]) { return foo!.length; }",
+        "declare const foo: string; foo as string;",
+        "declare const foo: string | number; const bar = foo as string | number;",
+        "function f(foo: Foo) { return foo as Foo; }",
+        "declare const foo: string; <string>foo;",
+    ];
+
+    let fix = vec![
+        ("declare const foo: string; foo!;", "declare const foo: string; foo;", None),
+        ("declare const foo: string; foo as string;", "declare const foo: string; foo;", None),
+        ("declare const foo: string; <string>foo;", "declare const foo: string; foo;", None),
+    ];
+
+    Tester::new(NoUnnecessaryTypeAssertion::NAME, pass, fail)
+        .change_rule_path_extension("ts")
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -19,7 +19,7 @@ use rustc_hash::FxHashSet;
 
 use crate::{
     partial_loader::{JavaScriptSource, PartialLoader, LINT_PARTIAL_LOADER_EXT},
-    Fixer, LintContext, Linter, Message,
+    Fixer, LintCache, LintContext, Linter, Message,
 };
 
 pub struct LintServiceOptions {
@@ -31,6 +31,10 @@ pub struct LintServiceOptions {
 
     /// TypeScript `tsconfig.json` path for reading path alias and project references
     pub tsconfig: Option<PathBuf>,
+
+    /// Directory for caching the diagnostics of unchanged files, see [`LintCache`].
+    /// The cache is not used with `--fix` or the import plugin.
+    pub cache_dir: Option<PathBuf>,
 }
 
 #[derive(Clone)]
@@ -130,6 +134,7 @@ pub struct Runtime {
     resolver: Option<Resolver>,
     module_map: ModuleMap,
     cache_state: CacheState,
+    lint_cache: Option<LintCache>,
 }
 
 impl Runtime {
@@ -137,6 +142,11 @@ impl Runtime {
         let resolver = linter.options().import_plugin.then(|| {
             Self::get_resolver(options.tsconfig.or_else(|| Some(options.cwd.join("tsconfig.json"))))
         });
+        // Diagnostics of the import plugin depend on other files, and `--fix` needs the fixes.
+        let lint_cache = options
+            .cache_dir
+            .filter(|_| !linter.options().fix && !linter.options().import_plugin)
+            .map(|dir| LintCache::new(dir, &linter));
         Self {
             cwd: options.cwd,
             paths: options.paths.iter().cloned().collect(),
@@ -144,6 +154,7 @@ impl Runtime {
             resolver,
             module_map: ModuleMap::default(),
             cache_state: CacheState::default(),
+            lint_cache,
         }
     }
 
@@ -222,20 +233,30 @@ impl Runtime {
         }
 
         for JavaScriptSource { source_text, source_type, .. } in sources {
-            let allocator = Allocator::default();
-            let mut messages =
-                self.process_source(path, &allocator, source_text, source_type, true, tx_error);
-
-            // TODO: Span is wrong, ban this feature for file process by `PartialLoader`.
-            if !is_processed_by_partial_loader && self.linter.options().fix {
-                let fix_result = Fixer::new(source_text, messages).fix();
-                fs::write(path, fix_result.fixed_code.as_bytes()).unwrap();
-                messages = fix_result.messages;
-            }
-
-            if !messages.is_empty() {
+            let cached = self.lint_cache.as_ref().and_then(|cache| cache.get(path, source_text));
+            let errors = if let Some(errors) = cached {
+                errors
+            } else {
+                let allocator = Allocator::default();
+                let mut messages =
+                    self.process_source(path, &allocator, source_text, source_type, true, tx_error);
+
+                // TODO: Span is wrong, ban this feature for file process by `PartialLoader`.
+                if !is_processed_by_partial_loader && self.linter.options().fix {
+                    let fix_result = Fixer::new(source_text, messages).fix();
+                    fs::write(path, fix_result.fixed_code.as_bytes()).unwrap();
+                    messages = fix_result.messages;
+                }
+
+                let errors = messages.into_iter().map(|m| m.error).collect::<Vec<_>>();
+                if let Some(cache) = &self.lint_cache {
+                    cache.insert(path, source_text, &errors);
+                }
+                errors
+            };
+
+            if !errors.is_empty() {
                 self.ignore_path(path);
-                let errors = messages.into_iter().map(|m| m.error).collect();
                 let path = path.strip_prefix(&self.cwd).unwrap_or(path);
                 let diagnostics = DiagnosticService::wrap_diagnostics(path, source_text, errors);
                 tx_error.send(Some(diagnostics)).unwrap();
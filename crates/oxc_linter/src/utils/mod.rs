@@ -4,12 +4,14 @@ mod nextjs;
 mod react;
 mod react_perf;
 mod tree_shaking;
+mod typescript;
 mod unicorn;
 
 use crate::LintContext;
 
 pub use self::{
-    jest::*, jsdoc::*, nextjs::*, react::*, react_perf::*, tree_shaking::*, unicorn::*,
+    jest::*, jsdoc::*, nextjs::*, react::*, react_perf::*, tree_shaking::*, typescript::*,
+    unicorn::*,
 };
 
 /// Check if the Jest rule is adapted to Vitest.
@@ -454,7 +454,9 @@ pub fn calculate_binary_operation(op: BinaryOperator, left: Value, right: Value)
             (Value::Number(a), Value::Number(b)) => Value::Number(f64::from(a as i32 >> b as i32)),
             _ => Value::Unknown,
         },
-        BinaryOperator::In | BinaryOperator::Instanceof => Value::Unknown,
+        BinaryOperator::In | BinaryOperator::Instanceof | BinaryOperator::Pipeline => {
+            Value::Unknown
+        }
     }
 }
 
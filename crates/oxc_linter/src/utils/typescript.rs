@@ -0,0 +1,97 @@
+use oxc_ast::{
+    ast::{ClassElement, Expression, IdentifierReference, ObjectPropertyKind},
+    AstKind,
+};
+
+use crate::LintContext;
+
+/// Whether `expr` is an async function or arrow function, or a reference to one
+/// declared in the current file.
+pub fn is_async_function(expr: &Expression, ctx: &LintContext) -> bool {
+    match expr.without_parenthesized() {
+        Expression::FunctionExpression(func) => func.r#async,
+        Expression::ArrowFunctionExpression(arrow) => arrow.r#async,
+        Expression::Identifier(ident) => is_async_function_reference(ident, ctx),
+        _ => false,
+    }
+}
+
+/// Whether `ident` resolves to an `async function` declaration, or a variable initialized
+/// with an async function or arrow function.
+pub fn is_async_function_reference(ident: &IdentifierReference, ctx: &LintContext) -> bool {
+    let Some(symbol_id) = ident
+        .reference_id
+        .get()
+        .and_then(|reference_id| ctx.symbols().get_reference(reference_id).symbol_id())
+    else {
+        return false;
+    };
+    match ctx.nodes().kind(ctx.symbols().get_declaration(symbol_id)) {
+        AstKind::Function(func) => func.r#async,
+        AstKind::VariableDeclarator(decl) => {
+            match decl.init.as_ref().map(Expression::without_parenthesized) {
+                Some(Expression::FunctionExpression(func)) => func.r#async,
+                Some(Expression::ArrowFunctionExpression(arrow)) => arrow.r#async,
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Whether `expr` is a call to a function that [`is_async_function_reference`], or to an async
+/// method of an object literal or a static async method of a class, e.g. `obj.method()` with
+/// `const obj = { async method() {} }`.
+pub fn is_async_function_call(expr: &Expression, ctx: &LintContext) -> bool {
+    let Expression::CallExpression(call) = expr.without_parenthesized() else { return false };
+    match call.callee.without_parenthesized() {
+        Expression::Identifier(ident) => is_async_function_reference(ident, ctx),
+        Expression::StaticMemberExpression(member) => {
+            let Expression::Identifier(object) = member.object.without_parenthesized() else {
+                return false;
+            };
+            is_async_method_reference(object, &member.property.name, ctx)
+        }
+        _ => false,
+    }
+}
+
+/// Whether `object` resolves to a variable initialized with an object literal, or a class
+/// declared in the current file, with an async method `name`.
+fn is_async_method_reference(object: &IdentifierReference, name: &str, ctx: &LintContext) -> bool {
+    let Some(symbol_id) = object
+        .reference_id
+        .get()
+        .and_then(|reference_id| ctx.symbols().get_reference(reference_id).symbol_id())
+    else {
+        return false;
+    };
+    match ctx.nodes().kind(ctx.symbols().get_declaration(symbol_id)) {
+        AstKind::VariableDeclarator(decl) => {
+            let Some(Expression::ObjectExpression(object)) =
+                decl.init.as_ref().map(Expression::without_parenthesized)
+            else {
+                return false;
+            };
+            object.properties.iter().any(|property| match property {
+                ObjectPropertyKind::ObjectProperty(property) => {
+                    property.key.is_specific_static_name(name)
+                        && is_async_function(&property.value, ctx)
+                }
+                ObjectPropertyKind::SpreadProperty(_) => false,
+            })
+        }
+        AstKind::Class(class) => class.body.body.iter().any(|element| match element {
+            ClassElement::MethodDefinition(method) => {
+                method.r#static && method.key.is_specific_static_name(name) && method.value.r#async
+            }
+            ClassElement::PropertyDefinition(property) => {
+                property.r#static
+                    && property.key.is_specific_static_name(name)
+                    && property.value.as_ref().is_some_and(|value| is_async_function(value, ctx))
+            }
+            _ => false,
+        }),
+        _ => false,
+    }
+}
@@ -1,9 +1,17 @@
 #![allow(clippy::wildcard_imports)]
 
+mod object_spread_optimizer;
 mod remove_dead_code;
 mod remove_parens;
 mod replace_global_defines;
+mod sequence_expression_flattener;
+mod statement_sequence_splitter;
+mod string_deduplication;
 
+pub use object_spread_optimizer::ObjectSpreadOptimizer;
 pub use remove_dead_code::RemoveDeadCode;
 pub use remove_parens::RemoveParens;
 pub use replace_global_defines::{ReplaceGlobalDefines, ReplaceGlobalDefinesConfig};
+pub use sequence_expression_flattener::SequenceExpressionFlattener;
+pub use statement_sequence_splitter::StatementSequenceSplitter;
+pub use string_deduplication::{StringDeduplication, StringDeduplicationOptions};
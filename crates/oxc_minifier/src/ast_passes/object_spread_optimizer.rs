@@ -0,0 +1,104 @@
+use oxc_allocator::Allocator;
+use oxc_ast::{ast::*, visit::walk_mut, AstBuilder, VisitMut};
+use oxc_semantic::{ReferenceId, SemanticBuilder};
+use oxc_span::GetSpan;
+use rustc_hash::FxHashSet;
+
+/// Simplify object spreads.
+///
+/// `Object.assign({}, a, b)` -> `{ ...a, ...b }`
+///
+/// `{ ...{}, a }` -> `{ a }`, spreads of `{}` and `null` add no properties.
+///
+/// `Object` must be the global `Object`, and calls with spread arguments such as
+/// `Object.assign({}, ...sources)` are kept.
+/// `Object.assign` sets properties on the target while spread defines them,
+/// the difference is only observable through setters on `Object.prototype`.
+pub struct ObjectSpreadOptimizer<'a> {
+    ast: AstBuilder<'a>,
+    /// References to the global `Object`
+    global_object_references: FxHashSet<ReferenceId>,
+}
+
+impl<'a> ObjectSpreadOptimizer<'a> {
+    pub fn new(allocator: &'a Allocator) -> Self {
+        Self { ast: AstBuilder::new(allocator), global_object_references: FxHashSet::default() }
+    }
+
+    pub fn build(&mut self, program: &mut Program<'a>) {
+        self.global_object_references = {
+            let semantic = SemanticBuilder::new("", program.source_type).build(program).semantic;
+            semantic
+                .symbols()
+                .references
+                .iter_enumerated()
+                .filter(|(_, reference)| {
+                    reference.symbol_id().is_none() && *reference.name() == "Object"
+                })
+                .map(|(reference_id, _)| reference_id)
+                .collect()
+        };
+        self.visit_program(program);
+    }
+
+    /// `Object.assign({}, a, b)`
+    fn is_object_assign(&self, call_expr: &CallExpression<'a>) -> bool {
+        let Some(MemberExpression::StaticMemberExpression(member_expr)) =
+            call_expr.callee.as_member_expression()
+        else {
+            return false;
+        };
+        let Expression::Identifier(ident) = &member_expr.object else {
+            return false;
+        };
+        !call_expr.optional
+            && !member_expr.optional
+            && member_expr.property.name == "assign"
+            && ident
+                .reference_id
+                .get()
+                .is_some_and(|id| self.global_object_references.contains(&id))
+            && matches!(
+                call_expr.arguments.first(),
+                Some(Argument::ObjectExpression(object_expr)) if object_expr.properties.is_empty()
+            )
+            && !call_expr.arguments.iter().any(Argument::is_spread)
+    }
+
+    fn object_assign_to_spread(&self, call_expr: &mut CallExpression<'a>) -> Expression<'a> {
+        let mut properties = self.ast.new_vec_with_capacity(call_expr.arguments.len() - 1);
+        for argument in call_expr.arguments.drain(1..) {
+            let argument = Expression::try_from(argument).unwrap();
+            let span = argument.span();
+            let spread = self.ast.spread_element(span, argument);
+            properties.push(ObjectPropertyKind::SpreadProperty(spread));
+        }
+        self.ast.object_expression(call_expr.span, properties, None)
+    }
+}
+
+impl<'a> VisitMut<'a> for ObjectSpreadOptimizer<'a> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        walk_mut::walk_expression_mut(self, expr);
+        if let Expression::CallExpression(call_expr) = expr {
+            if self.is_object_assign(call_expr) {
+                *expr = self.object_assign_to_spread(call_expr);
+            }
+        }
+        if let Expression::ObjectExpression(object_expr) = expr {
+            object_expr.properties.retain(|property| !is_empty_spread(property));
+        }
+    }
+}
+
+/// `...{}` or `...null`
+fn is_empty_spread(property: &ObjectPropertyKind) -> bool {
+    let ObjectPropertyKind::SpreadProperty(spread) = property else {
+        return false;
+    };
+    match spread.argument.without_parenthesized() {
+        Expression::ObjectExpression(object_expr) => object_expr.properties.is_empty(),
+        Expression::NullLiteral(_) => true,
+        _ => false,
+    }
+}
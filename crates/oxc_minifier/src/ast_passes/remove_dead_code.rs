@@ -1,10 +1,23 @@
-use oxc_allocator::Allocator;
-use oxc_ast::{ast::*, visit::walk_mut, AstBuilder, VisitMut};
-use oxc_span::SPAN;
+use oxc_allocator::{Allocator, Vec};
+use oxc_ast::{
+    ast::*, syntax_directed_operations::BoundNames, visit::walk_mut, AstBuilder, Visit, VisitMut,
+};
+use oxc_span::{GetSpan, Span, SPAN};
+use oxc_syntax::{
+    number::NumberBase,
+    operator::{BinaryOperator, UnaryOperator},
+    scope::ScopeFlags,
+};
 
 /// Remove Dead Code from the AST.
 ///
 /// Terser option: `dead_code: true`.
+///
+/// Branches of `if`, `?:`, loops and `switch` with constant tests are removed, and so are
+/// statements following a `return`, `throw`, `break` or `continue` in the same statement list.
+/// `typeof x === "undefined"` is evaluated when the type of `x` is known.
+///
+/// `var` declarations in removed code are kept as `var x;`, since they are hoisted.
 pub struct RemoveDeadCode<'a> {
     ast: AstBuilder<'a>,
 }
@@ -20,45 +33,391 @@ impl<'a> RemoveDeadCode<'a> {
 
     pub fn remove_if(&mut self, stmt: &mut Statement<'a>) {
         let Statement::IfStatement(if_stmt) = stmt else { return };
-        match if_stmt.test.get_boolean_value() {
+        let replacement = match evaluate_test(&if_stmt.test) {
             Some(true) => {
-                *stmt = self.ast.move_statement(&mut if_stmt.consequent);
+                let removed = if_stmt.alternate.as_ref().and_then(|alt| self.hoisted_vars(alt));
+                let consequent = self.ast.move_statement(&mut if_stmt.consequent);
+                self.statements(if_stmt.span, [Some(consequent), removed])
             }
             Some(false) => {
-                *stmt = if let Some(alternate) = &mut if_stmt.alternate {
-                    self.ast.move_statement(alternate)
-                } else {
-                    self.ast.empty_statement(SPAN)
+                let removed = self.hoisted_vars(&if_stmt.consequent);
+                let alternate = if_stmt.alternate.as_mut().map(|alt| self.ast.move_statement(alt));
+                self.statements(if_stmt.span, [alternate, removed])
+            }
+            None => return,
+        };
+        *stmt = replacement;
+    }
+
+    pub fn remove_conditional(&mut self, expr: &mut Expression<'a>) {
+        let Expression::ConditionalExpression(conditional_expr) = expr else { return };
+        match evaluate_test(&conditional_expr.test) {
+            Some(true) => *expr = self.ast.move_expression(&mut conditional_expr.consequent),
+            Some(false) => *expr = self.ast.move_expression(&mut conditional_expr.alternate),
+            None => {}
+        }
+    }
+
+    /// [`Self::remove_conditional`] for the callee of a call, the tag of a tagged template or the
+    /// operand of `delete`.
+    ///
+    /// A remaining reference is kept as `(0, a.b)`, since `(true ? a.b : c)()` calls `a.b` with
+    /// `this` undefined and `delete (true ? a.b : c)` does not delete the property.
+    fn remove_conditional_reference(&mut self, expr: &mut Expression<'a>, is_delete: bool) {
+        match expr {
+            Expression::ParenthesizedExpression(paren_expr) => {
+                self.remove_conditional_reference(&mut paren_expr.expression, is_delete);
+            }
+            Expression::ConditionalExpression(conditional_expr) => {
+                let branch = match evaluate_test(&conditional_expr.test) {
+                    Some(true) => &mut conditional_expr.consequent,
+                    Some(false) => &mut conditional_expr.alternate,
+                    None => return,
+                };
+                *expr = self.ast.move_expression(branch);
+                self.remove_conditional_reference(expr, is_delete);
+                let is_reference = match expr.without_parenthesized() {
+                    Expression::Identifier(ident) => is_delete || ident.name == "eval",
+                    Expression::ChainExpression(_) => true,
+                    inner => inner.is_member_expression(),
                 };
+                if is_reference {
+                    let zero = self.ast.number_literal(SPAN, 0.0, "0", NumberBase::Decimal);
+                    let mut expressions = self.ast.new_vec_with_capacity(2);
+                    expressions.push(self.ast.literal_number_expression(zero));
+                    expressions.push(self.ast.move_expression(expr));
+                    *expr = self.ast.sequence_expression(SPAN, expressions);
+                }
             }
             _ => {}
         }
     }
 
-    pub fn remove_conditional(&mut self, stmt: &mut Statement<'a>) {
-        let Statement::ExpressionStatement(expression_stmt) = stmt else { return };
-        let Expression::ConditionalExpression(conditional_expr) = &mut expression_stmt.expression
-        else {
-            return;
+    /// `while (false) { a }` -> ``, `do { a } while (false)` -> `{ a }`,
+    /// `for (init; false; update) { a }` -> `init`
+    pub fn remove_loop(&mut self, stmt: &mut Statement<'a>) {
+        let replacement = match stmt {
+            Statement::WhileStatement(while_stmt) => match evaluate_test(&while_stmt.test) {
+                Some(false) => {
+                    let removed = self.hoisted_vars(&while_stmt.body);
+                    self.statements(while_stmt.span, [removed])
+                }
+                _ => return,
+            },
+            // Only when `a` does not `break` or `continue`
+            Statement::DoWhileStatement(do_while_stmt) => {
+                match evaluate_test(&do_while_stmt.test) {
+                    Some(false) if !contains_break_or_continue(&do_while_stmt.body) => {
+                        self.ast.move_statement(&mut do_while_stmt.body)
+                    }
+                    _ => return,
+                }
+            }
+            Statement::ForStatement(for_stmt) => {
+                if for_stmt.test.as_ref().and_then(evaluate_test) != Some(false) {
+                    return;
+                }
+                let removed = self.hoisted_vars(&for_stmt.body);
+                let init = match for_stmt.init.take() {
+                    Some(ForStatementInit::VariableDeclaration(decl)) => {
+                        Some(Statement::VariableDeclaration(decl))
+                    }
+                    Some(ForStatementInit::UsingDeclaration(decl)) => {
+                        Some(Statement::UsingDeclaration(decl))
+                    }
+                    // The remaining variants are expressions
+                    Some(init) => Expression::try_from(init)
+                        .ok()
+                        .map(|expr| self.ast.expression_statement(expr.span(), expr)),
+                    None => None,
+                };
+                self.statements(for_stmt.span, [init, removed])
+            }
+            _ => return,
         };
-        match conditional_expr.test.get_boolean_value() {
-            Some(true) => {
-                expression_stmt.expression =
-                    self.ast.move_expression(&mut conditional_expr.consequent);
+        *stmt = replacement;
+    }
+
+    /// Remove the cases of a `switch` with a constant discriminant which are never entered.
+    ///
+    /// Cases before the matching case are removed, the matching case and all following cases
+    /// are kept since they may be reached by falling through.
+    pub fn remove_switch_cases(&mut self, stmt: &mut Statement<'a>) {
+        let Statement::SwitchStatement(switch_stmt) = stmt else { return };
+        let mut matched = None;
+        for (i, case) in switch_stmt.cases.iter().enumerate() {
+            let Some(test) = &case.test else { continue };
+            // Tests before the matching case are evaluated, and must not have side effects.
+            let Some(equals) = strict_equals(test, &switch_stmt.discriminant) else { return };
+            if equals && matched.is_none() {
+                matched = Some(i);
             }
-            Some(false) => {
-                expression_stmt.expression =
-                    self.ast.move_expression(&mut conditional_expr.alternate);
+        }
+        let matched = matched
+            .or_else(|| switch_stmt.cases.iter().position(|case| case.test.is_none()))
+            .unwrap_or(switch_stmt.cases.len());
+        if matched == 0 {
+            return;
+        }
+        let removed = self.hoisted_vars_in(|collector| {
+            for case in switch_stmt.cases.iter().take(matched) {
+                collector.visit_statements(&case.consequent);
             }
-            _ => {}
+        });
+        switch_stmt.cases.drain(..matched);
+        let span = switch_stmt.span;
+        if switch_stmt.cases.is_empty() {
+            *stmt = self.statements(span, [removed]);
+        } else if let Some(removed) = removed {
+            // `{ var a; switch (x) { ... } }`
+            let switch_stmt = self.ast.move_statement(stmt);
+            *stmt = self.statements(span, [Some(removed), Some(switch_stmt)]);
         }
     }
+
+    /// Remove the statements following a statement which always completes abruptly.
+    ///
+    /// Function declarations are kept since they are hoisted.
+    pub fn remove_unreachable_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        let Some(index) = stmts.iter().position(completes_abruptly) else { return };
+        if index + 1 == stmts.len() {
+            return;
+        }
+        let removed = stmts.drain(index + 1..).collect::<std::vec::Vec<_>>();
+        for stmt in removed {
+            if matches!(stmt, Statement::FunctionDeclaration(_)) {
+                stmts.push(stmt);
+            } else {
+                stmts.extend(self.hoisted_vars(&stmt));
+            }
+        }
+    }
+
+    /// Combines the remaining statements of a removed statement.
+    ///
+    /// Lexical declarations are wrapped in a block, to keep them scoped.
+    fn statements<const N: usize>(
+        &self,
+        span: Span,
+        stmts: [Option<Statement<'a>>; N],
+    ) -> Statement<'a> {
+        let mut stmts = self.ast.new_vec_from_iter(stmts.into_iter().flatten());
+        match stmts.len() {
+            0 => self.ast.empty_statement(span),
+            1 if !is_lexical_declaration(&stmts[0]) => stmts.remove(0),
+            _ => self.ast.block_statement(self.ast.block(span, stmts)),
+        }
+    }
+
+    /// `var a, b;` for the `var` declarations in a removed statement.
+    fn hoisted_vars(&self, stmt: &Statement<'a>) -> Option<Statement<'a>> {
+        self.hoisted_vars_in(|collector| collector.visit_statement(stmt))
+    }
+
+    fn hoisted_vars_in<F: FnOnce(&mut VarCollector<'a>)>(&self, visit: F) -> Option<Statement<'a>> {
+        let mut collector = VarCollector::default();
+        visit(&mut collector);
+        if collector.names.is_empty() {
+            return None;
+        }
+        let kind = VariableDeclarationKind::Var;
+        let declarations = self.ast.new_vec_from_iter(collector.names.into_iter().map(|ident| {
+            let id =
+                self.ast.binding_pattern(self.ast.binding_pattern_identifier(ident), None, false);
+            self.ast.variable_declarator(SPAN, kind, id, None, false)
+        }));
+        let decl = self.ast.variable_declaration(SPAN, kind, declarations, false);
+        Some(Statement::VariableDeclaration(decl))
+    }
 }
 
 impl<'a> VisitMut<'a> for RemoveDeadCode<'a> {
+    fn visit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        walk_mut::walk_statements_mut(self, stmts);
+        self.remove_unreachable_statements(stmts);
+    }
+
     fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
         self.remove_if(stmt);
-        self.remove_conditional(stmt);
+        self.remove_loop(stmt);
+        self.remove_switch_cases(stmt);
         walk_mut::walk_statement_mut(self, stmt);
     }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        self.remove_conditional(expr);
+        walk_mut::walk_expression_mut(self, expr);
+    }
+
+    fn visit_call_expression(&mut self, expr: &mut CallExpression<'a>) {
+        self.remove_conditional_reference(&mut expr.callee, false);
+        walk_mut::walk_call_expression_mut(self, expr);
+    }
+
+    fn visit_tagged_template_expression(&mut self, expr: &mut TaggedTemplateExpression<'a>) {
+        self.remove_conditional_reference(&mut expr.tag, false);
+        walk_mut::walk_tagged_template_expression_mut(self, expr);
+    }
+
+    fn visit_unary_expression(&mut self, expr: &mut UnaryExpression<'a>) {
+        if expr.operator == UnaryOperator::Delete {
+            self.remove_conditional_reference(&mut expr.argument, true);
+        }
+        walk_mut::walk_unary_expression_mut(self, expr);
+    }
+}
+
+/// Evaluates a test expression to a boolean, if it is a constant.
+fn evaluate_test(test: &Expression) -> Option<bool> {
+    match test.without_parenthesized() {
+        Expression::BinaryExpression(binary_expr) => evaluate_typeof_comparison(binary_expr),
+        test => test.get_boolean_value(),
+    }
+}
+
+/// `typeof x === "undefined"`, `"number" != typeof 1`
+fn evaluate_typeof_comparison(binary_expr: &BinaryExpression) -> Option<bool> {
+    let negate = match binary_expr.operator {
+        BinaryOperator::Equality | BinaryOperator::StrictEquality => false,
+        BinaryOperator::Inequality | BinaryOperator::StrictInequality => true,
+        _ => return None,
+    };
+    let (unary_expr, type_name) =
+        match (binary_expr.left.without_parenthesized(), binary_expr.right.without_parenthesized())
+        {
+            (Expression::UnaryExpression(unary_expr), Expression::StringLiteral(type_name))
+            | (Expression::StringLiteral(type_name), Expression::UnaryExpression(unary_expr))
+                if unary_expr.operator == UnaryOperator::Typeof =>
+            {
+                (unary_expr, type_name)
+            }
+            _ => return None,
+        };
+    let known_type_name = known_type_of(&unary_expr.argument)?;
+    Some((known_type_name == type_name.value.as_str()) != negate)
+}
+
+/// The result of `typeof expr`, for expressions without side effects.
+///
+/// The global `undefined`, `NaN` and `Infinity` are assumed not to be shadowed.
+fn known_type_of(expr: &Expression) -> Option<&'static str> {
+    match expr.without_parenthesized() {
+        Expression::NumericLiteral(_) => Some("number"),
+        Expression::StringLiteral(_) => Some("string"),
+        Expression::TemplateLiteral(lit) if lit.is_no_substitution_template() => Some("string"),
+        Expression::BooleanLiteral(_) => Some("boolean"),
+        Expression::BigIntLiteral(_) => Some("bigint"),
+        Expression::NullLiteral(_) | Expression::RegExpLiteral(_) => Some("object"),
+        Expression::FunctionExpression(_) | Expression::ArrowFunctionExpression(_) => {
+            Some("function")
+        }
+        Expression::UnaryExpression(unary_expr) => match unary_expr.operator {
+            UnaryOperator::Void => known_type_of(&unary_expr.argument).map(|_| "undefined"),
+            UnaryOperator::UnaryNegation => {
+                matches!(unary_expr.argument, Expression::NumericLiteral(_)).then_some("number")
+            }
+            _ => None,
+        },
+        Expression::Identifier(ident) => match ident.name.as_str() {
+            "undefined" => Some("undefined"),
+            "NaN" | "Infinity" => Some("number"),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// `left === right` for literals, `None` when either side is not a literal.
+#[allow(clippy::float_cmp)]
+fn strict_equals(left: &Expression, right: &Expression) -> Option<bool> {
+    match (left.without_parenthesized(), right.without_parenthesized()) {
+        (Expression::NumericLiteral(left), Expression::NumericLiteral(right)) => {
+            Some(left.value == right.value)
+        }
+        (Expression::StringLiteral(left), Expression::StringLiteral(right)) => {
+            Some(left.value == right.value)
+        }
+        (Expression::BooleanLiteral(left), Expression::BooleanLiteral(right)) => {
+            Some(left.value == right.value)
+        }
+        (Expression::NullLiteral(_), Expression::NullLiteral(_)) => Some(true),
+        (left, right) if is_primitive_literal(left) && is_primitive_literal(right) => Some(false),
+        _ => None,
+    }
+}
+
+fn is_primitive_literal(expr: &Expression) -> bool {
+    matches!(
+        expr,
+        Expression::NumericLiteral(_)
+            | Expression::StringLiteral(_)
+            | Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_)
+    )
+}
+
+fn completes_abruptly(stmt: &Statement) -> bool {
+    matches!(
+        stmt,
+        Statement::ReturnStatement(_)
+            | Statement::ThrowStatement(_)
+            | Statement::BreakStatement(_)
+            | Statement::ContinueStatement(_)
+    )
+}
+
+fn is_lexical_declaration(stmt: &Statement) -> bool {
+    match stmt {
+        Statement::VariableDeclaration(decl) => decl.kind.is_lexical(),
+        Statement::UsingDeclaration(_) | Statement::ClassDeclaration(_) => true,
+        _ => false,
+    }
+}
+
+fn contains_break_or_continue(stmt: &Statement) -> bool {
+    let mut finder = BreakOrContinueFinder::default();
+    finder.visit_statement(stmt);
+    finder.found
+}
+
+/// Collects the names of `var` declarations, outside of functions.
+#[derive(Default)]
+struct VarCollector<'a> {
+    names: std::vec::Vec<BindingIdentifier<'a>>,
+}
+
+impl<'a> Visit<'a> for VarCollector<'a> {
+    fn visit_variable_declaration(&mut self, decl: &VariableDeclaration<'a>) {
+        if decl.kind.is_var() {
+            decl.bound_names(&mut |ident| {
+                self.names.push(BindingIdentifier::new(ident.span, ident.name.clone()));
+            });
+        }
+    }
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: Option<ScopeFlags>) {}
+
+    fn visit_class(&mut self, _class: &Class<'a>) {}
+
+    fn visit_expression(&mut self, _expr: &Expression<'a>) {}
+}
+
+#[derive(Default)]
+struct BreakOrContinueFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for BreakOrContinueFinder {
+    fn visit_break_statement(&mut self, _stmt: &BreakStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_continue_statement(&mut self, _stmt: &ContinueStatement<'a>) {
+        self.found = true;
+    }
+
+    // `break` and `continue` can not cross function boundaries
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: Option<ScopeFlags>) {}
+
+    fn visit_arrow_expression(&mut self, _expr: &ArrowFunctionExpression<'a>) {}
 }
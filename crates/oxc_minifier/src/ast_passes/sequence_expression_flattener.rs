@@ -0,0 +1,48 @@
+use oxc_allocator::{Allocator, Vec};
+use oxc_ast::{ast::*, visit::walk_mut, AstBuilder, VisitMut};
+
+/// Flatten nested sequence expressions.
+///
+/// `(a, (b, c), d)` -> `(a, b, c, d)`
+pub struct SequenceExpressionFlattener<'a> {
+    ast: AstBuilder<'a>,
+}
+
+impl<'a> SequenceExpressionFlattener<'a> {
+    pub fn new(allocator: &'a Allocator) -> Self {
+        Self { ast: AstBuilder::new(allocator) }
+    }
+
+    pub fn build(&mut self, program: &mut Program<'a>) {
+        self.visit_program(program);
+    }
+
+    fn flatten(&self, expr: Expression<'a>, expressions: &mut Vec<'a, Expression<'a>>) {
+        match expr {
+            Expression::SequenceExpression(mut seq) => expressions.append(&mut seq.expressions),
+            Expression::ParenthesizedExpression(mut paren) if is_sequence(&paren.expression) => {
+                self.flatten(self.ast.move_expression(&mut paren.expression), expressions);
+            }
+            _ => expressions.push(expr),
+        }
+    }
+}
+
+impl<'a> VisitMut<'a> for SequenceExpressionFlattener<'a> {
+    fn visit_sequence_expression(&mut self, expr: &mut SequenceExpression<'a>) {
+        // Nested sequences are flattened first
+        walk_mut::walk_sequence_expression_mut(self, expr);
+        if !expr.expressions.iter().any(is_sequence) {
+            return;
+        }
+        let mut expressions = self.ast.new_vec_with_capacity(expr.expressions.len());
+        for element in expr.expressions.drain(..) {
+            self.flatten(element, &mut expressions);
+        }
+        expr.expressions = expressions;
+    }
+}
+
+fn is_sequence(expr: &Expression) -> bool {
+    matches!(expr.without_parenthesized(), Expression::SequenceExpression(_))
+}
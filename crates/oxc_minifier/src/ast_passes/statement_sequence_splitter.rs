@@ -0,0 +1,55 @@
+use oxc_allocator::{Allocator, Vec};
+use oxc_ast::{ast::*, visit::walk_mut, AstBuilder, VisitMut};
+use oxc_span::GetSpan;
+
+/// Split sequence expressions in expression statements into separate statements.
+///
+/// `a, b, c;` -> `a; b; c;`
+///
+/// Only statements in statement lists are split, `if (x) a, b;` is kept.
+pub struct StatementSequenceSplitter<'a> {
+    ast: AstBuilder<'a>,
+}
+
+impl<'a> StatementSequenceSplitter<'a> {
+    pub fn new(allocator: &'a Allocator) -> Self {
+        Self { ast: AstBuilder::new(allocator) }
+    }
+
+    pub fn build(&mut self, program: &mut Program<'a>) {
+        self.visit_program(program);
+    }
+}
+
+impl<'a> VisitMut<'a> for StatementSequenceSplitter<'a> {
+    fn visit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        walk_mut::walk_statements_mut(self, stmts);
+        if !stmts.iter().any(is_sequence_statement) {
+            return;
+        }
+        let mut new_stmts = self.ast.new_vec_with_capacity(stmts.len());
+        for stmt in stmts.drain(..) {
+            if !is_sequence_statement(&stmt) {
+                new_stmts.push(stmt);
+                continue;
+            }
+            let Statement::ExpressionStatement(mut expr_stmt) = stmt else { unreachable!() };
+            let mut expr = self.ast.move_expression(&mut expr_stmt.expression);
+            while let Expression::ParenthesizedExpression(paren) = &mut expr {
+                expr = self.ast.move_expression(&mut paren.expression);
+            }
+            let Expression::SequenceExpression(mut seq) = expr else { unreachable!() };
+            new_stmts.extend(
+                seq.expressions
+                    .drain(..)
+                    .map(|expr| self.ast.expression_statement(expr.span(), expr)),
+            );
+        }
+        *stmts = new_stmts;
+    }
+}
+
+fn is_sequence_statement(stmt: &Statement) -> bool {
+    matches!(stmt, Statement::ExpressionStatement(expr_stmt)
+        if matches!(expr_stmt.expression.without_parenthesized(), Expression::SequenceExpression(_)))
+}
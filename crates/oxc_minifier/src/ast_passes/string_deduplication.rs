@@ -0,0 +1,181 @@
+use oxc_allocator::Allocator;
+use oxc_ast::{
+    ast::*,
+    visit::{walk, walk_mut},
+    AstBuilder, Visit, VisitMut,
+};
+use oxc_semantic::SymbolTable;
+use oxc_span::{Atom, SPAN};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// Options for [StringDeduplication].
+#[derive(Debug, Clone, Copy)]
+pub struct StringDeduplicationOptions {
+    /// Only strings appearing more than this many times are extracted.
+    ///
+    /// Default `2`
+    pub occurrences_threshold: usize,
+}
+
+impl Default for StringDeduplicationOptions {
+    fn default() -> Self {
+        Self { occurrences_threshold: 2 }
+    }
+}
+
+/// Extract repeated string literals to constants.
+///
+/// `f("long string"); g("long string"); h("long string")` ->
+/// `const __s0 = "long string"; f(__s0); g(__s0); h(__s0)`
+///
+/// A string is extracted when the declaration is smaller than the bytes saved by referencing
+/// the constant: `name + literal + overhead < occurrences * (literal - name)`.
+/// Property keys and non-expression strings such as directives and module specifiers are kept.
+///
+/// This pass is opt-in, since it trades code size for a small runtime cost.
+/// Only JavaScript modules are changed: top-level `const` declarations of scripts are shared by
+/// all scripts of a page, so a constant in one minified script would clash with another's.
+pub struct StringDeduplication<'a> {
+    ast: AstBuilder<'a>,
+    options: StringDeduplicationOptions,
+    /// Symbols of the program, when semantic analysis has been run on it.
+    symbols: Option<SymbolTable>,
+    /// Names of the constants for the extracted strings
+    constants: FxHashMap<Atom<'a>, Atom<'a>>,
+}
+
+/// `=` and `,` in `const a = "a", b = "b"`
+const DECLARATOR_OVERHEAD: usize = 2;
+
+impl<'a> StringDeduplication<'a> {
+    pub fn new(allocator: &'a Allocator, options: StringDeduplicationOptions) -> Self {
+        Self {
+            ast: AstBuilder::new(allocator),
+            options,
+            symbols: None,
+            constants: FxHashMap::default(),
+        }
+    }
+
+    /// Also avoid the names of the symbols and references of a semantic analysis of the program.
+    #[must_use]
+    pub fn with_symbols(mut self, symbols: SymbolTable) -> Self {
+        self.symbols = Some(symbols);
+        self
+    }
+
+    pub fn build(&mut self, program: &mut Program<'a>) {
+        if program.source_type.is_typescript() || !program.source_type.is_module() {
+            return;
+        }
+        let mut counter = StringCounter::default();
+        counter.visit_program(program);
+
+        let mut declarations = self.ast.new_vec();
+        let mut index = 0;
+        for (value, occurrences) in counter.strings {
+            if occurrences <= self.options.occurrences_threshold {
+                continue;
+            }
+            let mut name_index = index;
+            let mut name = format!("__s{name_index}");
+            while counter.names.contains(name.as_str()) || self.is_symbol_name(&name) {
+                name_index += 1;
+                name = format!("__s{name_index}");
+            }
+            // Quotes are not escaped, this slightly underestimates the size of the literal
+            let literal_len = value.len() + 2;
+            let declaration_len = name.len() + literal_len + DECLARATOR_OVERHEAD;
+            if declaration_len >= occurrences * literal_len.saturating_sub(name.len()) {
+                continue;
+            }
+            index = name_index + 1;
+            let name = self.ast.new_atom(&name);
+            let id = self.ast.binding_pattern(
+                self.ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, name.clone())),
+                None,
+                false,
+            );
+            let init = self.ast.literal_string_expression(StringLiteral::new(SPAN, value.clone()));
+            let kind = VariableDeclarationKind::Const;
+            declarations.push(self.ast.variable_declarator(SPAN, kind, id, Some(init), false));
+            self.constants.insert(value, name);
+        }
+        if declarations.is_empty() {
+            return;
+        }
+
+        self.visit_program(program);
+        let decl = self.ast.variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Const,
+            declarations,
+            false,
+        );
+        program.body.insert(0, Statement::VariableDeclaration(decl));
+    }
+
+    fn is_symbol_name(&self, name: &str) -> bool {
+        self.symbols.as_ref().is_some_and(|symbols| {
+            symbols.names.iter().any(|symbol_name| symbol_name.as_str() == name)
+                || symbols.references.iter().any(|reference| reference.name().as_str() == name)
+        })
+    }
+}
+
+impl<'a> VisitMut<'a> for StringDeduplication<'a> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::StringLiteral(lit) = expr {
+            if let Some(name) = self.constants.get(&lit.value) {
+                let ident = self.ast.identifier_reference(lit.span, name.as_str());
+                *expr = self.ast.identifier_reference_expression(ident);
+            }
+            return;
+        }
+        walk_mut::walk_expression_mut(self, expr);
+    }
+
+    fn visit_property_key(&mut self, key: &mut PropertyKey<'a>) {
+        if !matches!(key, PropertyKey::StringLiteral(_)) {
+            walk_mut::walk_property_key_mut(self, key);
+        }
+    }
+}
+
+/// Counts string literals in expressions, and collects all identifier names.
+#[derive(Default)]
+struct StringCounter<'a> {
+    /// Occurrences of strings, in order of first appearance
+    strings: Vec<(Atom<'a>, usize)>,
+    indexes: FxHashMap<Atom<'a>, usize>,
+    names: FxHashSet<&'a str>,
+}
+
+impl<'a> Visit<'a> for StringCounter<'a> {
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        if let Expression::StringLiteral(lit) = expr {
+            if let Some(&index) = self.indexes.get(&lit.value) {
+                self.strings[index].1 += 1;
+            } else {
+                self.indexes.insert(lit.value.clone(), self.strings.len());
+                self.strings.push((lit.value.clone(), 1));
+            }
+            return;
+        }
+        walk::walk_expression(self, expr);
+    }
+
+    fn visit_property_key(&mut self, key: &PropertyKey<'a>) {
+        if !matches!(key, PropertyKey::StringLiteral(_)) {
+            walk::walk_property_key(self, key);
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        self.names.insert(ident.name.as_str());
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &BindingIdentifier<'a>) {
+        self.names.insert(ident.name.as_str());
+    }
+}
@@ -7,6 +7,7 @@ use std::{cmp::Ordering, mem};
 use num_bigint::BigInt;
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::ast::*;
+use oxc_semantic::Reference;
 use oxc_span::{Atom, GetSpan, Span};
 use oxc_syntax::{
     number::NumberBase,
@@ -178,6 +179,12 @@ impl<'a> From<&Expression<'a>> for Ty {
     }
 }
 
+/// Integers beyond `Number.MAX_SAFE_INTEGER` may have been rounded.
+fn is_unsafe_integer(value: f64) -> bool {
+    const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+    value.is_finite() && value.fract() == 0.0 && value.abs() > MAX_SAFE_INTEGER
+}
+
 impl<'a> Compressor<'a> {
     pub(crate) fn fold_expression<'b>(&mut self, expr: &'b mut Expression<'a>) {
         let folded_expr = match expr {
@@ -212,6 +219,23 @@ impl<'a> Compressor<'a> {
                 BinaryOperator::Addition if self.options.evaluate => {
                     self.try_fold_addition(binary_expr.span, &binary_expr.left, &binary_expr.right)
                 }
+                BinaryOperator::Subtraction
+                | BinaryOperator::Multiplication
+                | BinaryOperator::Division
+                | BinaryOperator::Remainder
+                | BinaryOperator::Exponential
+                | BinaryOperator::BitwiseOR
+                | BinaryOperator::BitwiseAnd
+                | BinaryOperator::BitwiseXOR
+                    if self.options.evaluate =>
+                {
+                    self.try_fold_arithmetic(
+                        binary_expr.span,
+                        binary_expr.operator,
+                        &binary_expr.left,
+                        &binary_expr.right,
+                    )
+                }
                 _ => None,
             },
             Expression::UnaryExpression(unary_expr) => match unary_expr.operator {
@@ -277,6 +301,9 @@ impl<'a> Compressor<'a> {
                 let left_number = get_number_value(left)?;
                 let right_number = get_number_value(right)?;
                 let Ok(value) = TryInto::<f64>::try_into(left_number + right_number) else { return None };
+                if is_unsafe_integer(value) {
+                    return None;
+                }
                 // Float if value has a fractional part, otherwise Decimal
                 let number_base = if is_exact_int64(value) { NumberBase::Decimal } else { NumberBase::Float };
                 let number_literal = self.ast.number_literal(span, value, "", number_base);
@@ -287,6 +314,59 @@ impl<'a> Compressor<'a> {
         }
     }
 
+    /// Folds arithmetic and bitwise operators with number, boolean or `null` operands, e.g.
+    /// `2 * 3` -> `6`, `2 ** -1` -> `.5`, `5 | 2` -> `7`
+    ///
+    /// Results which can not be written as a number literal are not folded: `NaN`, `Infinity`,
+    /// `-0` and integers which may have been rounded (`2 ** 53 + 1`).
+    fn try_fold_arithmetic<'b>(
+        &mut self,
+        span: Span,
+        op: BinaryOperator,
+        left: &'b Expression<'a>,
+        right: &'b Expression<'a>,
+    ) -> Option<Expression<'a>> {
+        // `ToNumber` of strings is not evaluated
+        if !matches!(Ty::from(left), Ty::Number | Ty::Boolean | Ty::Null)
+            || !matches!(Ty::from(right), Ty::Number | Ty::Boolean | Ty::Null)
+        {
+            return None;
+        }
+        let (Some(NumberValue::Number(left_val)), Some(NumberValue::Number(right_val))) =
+            (get_side_free_number_value(left), get_side_free_number_value(right))
+        else {
+            return None;
+        };
+        let int32 = NumericLiteral::ecmascript_to_int32;
+        let value = match op {
+            BinaryOperator::Subtraction => left_val - right_val,
+            BinaryOperator::Multiplication => left_val * right_val,
+            BinaryOperator::Division => left_val / right_val,
+            BinaryOperator::Remainder => left_val % right_val,
+            BinaryOperator::Exponential => left_val.powf(right_val),
+            BinaryOperator::BitwiseOR => f64::from(int32(left_val) | int32(right_val)),
+            BinaryOperator::BitwiseAnd => f64::from(int32(left_val) & int32(right_val)),
+            BinaryOperator::BitwiseXOR => f64::from(int32(left_val) ^ int32(right_val)),
+            _ => unreachable!("Unknown binary operator {:?}", op),
+        };
+        if !value.is_finite()
+            || is_unsafe_integer(value)
+            || (value == 0.0 && value.is_sign_negative())
+        {
+            return None;
+        }
+        let number_base =
+            if is_exact_int64(value) { NumberBase::Decimal } else { NumberBase::Float };
+        let number_literal = self.ast.number_literal(span, value.abs(), "", number_base);
+        let expr = self.ast.literal_number_expression(number_literal);
+        // A negative result is a unary expression so it is printed as `(-3) ** y` and
+        // `(-1).toString()` instead of `-3 ** y` and `-1 .toString()`
+        if value.is_sign_negative() {
+            return Some(self.ast.unary_expression(span, UnaryOperator::UnaryNegation, expr));
+        }
+        Some(expr)
+    }
+
     fn try_fold_comparison<'b>(
         &mut self,
         span: Span,
@@ -560,11 +640,21 @@ impl<'a> Compressor<'a> {
     /// Folds 'typeof(foo)' if foo is a literal, e.g.
     /// typeof("bar") --> "string"
     /// typeof(6) --> "number"
+    ///
+    /// or a function declaration, when the compressor has symbols:
+    /// function f() {}; typeof f --> "function"
     fn try_fold_typeof<'b>(
         &mut self,
         span: Span,
         argument: &'b Expression<'a>,
     ) -> Option<Expression<'a>> {
+        if let Expression::Identifier(ident) = argument {
+            if self.is_function_declaration_reference(ident) {
+                let string_literal = StringLiteral::new(span, Atom::from("function"));
+                return Some(self.ast.literal_string_expression(string_literal));
+            }
+        }
+
         if argument.is_literal_value(true) {
             let type_name = match argument {
                 Expression::FunctionExpression(_) | Expression::ArrowFunctionExpression(_) => {
@@ -597,6 +687,20 @@ impl<'a> Compressor<'a> {
         None
     }
 
+    /// Whether `ident` references a function declaration which is never reassigned or
+    /// redeclared.
+    fn is_function_declaration_reference(&self, ident: &IdentifierReference<'a>) -> bool {
+        let Some(symbols) = &self.symbols else { return false };
+        let Some(reference_id) = ident.reference_id.get() else { return false };
+        let Some(symbol_id) = symbols.get_reference(reference_id).symbol_id() else {
+            return false;
+        };
+        // `var f = 1` redeclaring `function f() {}` is not a write reference
+        self.function_declarations.contains(&symbol_id)
+            && symbols.get_redeclare_variables(symbol_id).is_empty()
+            && !symbols.get_resolved_references(symbol_id).any(Reference::is_write)
+    }
+
     fn try_fold_unary_operator(
         &mut self,
         unary_expr: &UnaryExpression<'a>,
@@ -6,18 +6,25 @@ mod options;
 mod util;
 
 use oxc_allocator::{Allocator, Vec};
-use oxc_ast::visit::walk_mut::{
-    walk_binary_expression_mut, walk_expression_mut, walk_return_statement_mut, walk_statement_mut,
-    walk_statements_mut,
+use oxc_ast::visit::{
+    walk::walk_function,
+    walk_mut::{
+        walk_binary_expression_mut, walk_call_expression_mut, walk_expression_mut,
+        walk_return_statement_mut, walk_statement_mut, walk_statements_mut,
+        walk_tagged_template_expression_mut, walk_unary_expression_mut,
+    },
 };
 #[allow(clippy::wildcard_imports)]
-use oxc_ast::{ast::*, AstBuilder, VisitMut};
+use oxc_ast::{ast::*, AstBuilder, Visit, VisitMut};
+use oxc_semantic::{SymbolId, SymbolTable};
 use oxc_span::Span;
 use oxc_syntax::{
     number::NumberBase,
     operator::{BinaryOperator, UnaryOperator},
     precedence::GetPrecedence,
+    scope::ScopeFlags,
 };
+use rustc_hash::FxHashSet;
 
 use crate::ast_passes::RemoveParens;
 
@@ -27,6 +34,12 @@ pub struct Compressor<'a> {
     ast: AstBuilder<'a>,
     options: CompressOptions,
 
+    /// Symbols of the program, when semantic analysis has been run on it.
+    symbols: Option<SymbolTable>,
+
+    /// Symbols bound by function declarations, collected when `symbols` is set.
+    function_declarations: FxHashSet<SymbolId>,
+
     prepass: RemoveParens<'a>,
 }
 
@@ -34,11 +47,30 @@ const SPAN: Span = Span::new(0, 0);
 
 impl<'a> Compressor<'a> {
     pub fn new(allocator: &'a Allocator, options: CompressOptions) -> Self {
-        Self { ast: AstBuilder::new(allocator), options, prepass: RemoveParens::new(allocator) }
+        Self {
+            ast: AstBuilder::new(allocator),
+            options,
+            symbols: None,
+            function_declarations: FxHashSet::default(),
+            prepass: RemoveParens::new(allocator),
+        }
+    }
+
+    /// Resolve identifiers with the symbols of a semantic analysis of the program, e.g. to fold
+    /// `typeof f` for a function declaration `f`.
+    #[must_use]
+    pub fn with_symbols(mut self, symbols: SymbolTable) -> Self {
+        self.symbols = Some(symbols);
+        self
     }
 
     pub fn build(mut self, program: &mut Program<'a>) {
         self.prepass.build(program);
+        if self.symbols.is_some() {
+            let mut collector = FunctionDeclarationCollector::default();
+            collector.visit_program(program);
+            self.function_declarations = collector.symbol_ids;
+        }
         self.visit_program(program);
     }
 
@@ -262,6 +294,26 @@ impl<'a> Compressor<'a> {
         None
     }
 
+    /// Keeps a reference folded out of a logical expression in callee, tag or `delete` position
+    /// as `(0, obj.m)`.
+    ///
+    /// `(true && obj.m)()` calls `obj.m` with `this` undefined, and `delete (true && obj.m)`
+    /// does not delete the property.
+    fn compress_folded_reference(&mut self, expr: &mut Expression<'a>) {
+        let is_reference = match &*expr {
+            Expression::Identifier(ident) => ident.name == "eval",
+            Expression::ChainExpression(_) => true,
+            _ => expr.is_member_expression(),
+        };
+        if is_reference {
+            let zero = self.ast.number_literal(SPAN, 0.0, "0", NumberBase::Decimal);
+            let mut expressions = self.ast.new_vec_with_capacity(2);
+            expressions.push(self.ast.literal_number_expression(zero));
+            expressions.push(self.ast.move_expression(expr));
+            *expr = self.ast.sequence_expression(SPAN, expressions);
+        }
+    }
+
     /// Removes redundant argument of `ReturnStatement`
     ///
     /// `return undefined` -> `return`
@@ -359,4 +411,47 @@ impl<'a> VisitMut<'a> for Compressor<'a> {
         walk_binary_expression_mut(self, expr);
         self.compress_typeof_undefined(expr);
     }
+
+    fn visit_call_expression(&mut self, expr: &mut CallExpression<'a>) {
+        let is_logical = matches!(expr.callee, Expression::LogicalExpression(_));
+        walk_call_expression_mut(self, expr);
+        if is_logical {
+            self.compress_folded_reference(&mut expr.callee);
+        }
+    }
+
+    fn visit_tagged_template_expression(&mut self, expr: &mut TaggedTemplateExpression<'a>) {
+        let is_logical = matches!(expr.tag, Expression::LogicalExpression(_));
+        walk_tagged_template_expression_mut(self, expr);
+        if is_logical {
+            self.compress_folded_reference(&mut expr.tag);
+        }
+    }
+
+    fn visit_unary_expression(&mut self, expr: &mut UnaryExpression<'a>) {
+        let is_logical = expr.operator == UnaryOperator::Delete
+            && matches!(expr.argument, Expression::LogicalExpression(_));
+        walk_unary_expression_mut(self, expr);
+        if is_logical {
+            self.compress_folded_reference(&mut expr.argument);
+        }
+    }
+}
+
+/// Collects the symbols of function declarations, whose symbol flags don't tell them apart from
+/// `var` declarations.
+#[derive(Default)]
+struct FunctionDeclarationCollector {
+    symbol_ids: FxHashSet<SymbolId>,
+}
+
+impl<'a> Visit<'a> for FunctionDeclarationCollector {
+    fn visit_function(&mut self, func: &Function<'a>, flags: Option<ScopeFlags>) {
+        if func.is_function_declaration() {
+            if let Some(symbol_id) = func.id.as_ref().and_then(|id| id.symbol_id.get()) {
+                self.symbol_ids.insert(symbol_id);
+            }
+        }
+        walk_function(self, func, flags);
+    }
 }
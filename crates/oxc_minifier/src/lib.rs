@@ -8,9 +8,13 @@ use oxc_allocator::Allocator;
 use oxc_ast::ast::Program;
 
 pub use crate::{
-    ast_passes::{RemoveDeadCode, RemoveParens, ReplaceGlobalDefines, ReplaceGlobalDefinesConfig},
+    ast_passes::{
+        ObjectSpreadOptimizer, RemoveDeadCode, RemoveParens, ReplaceGlobalDefines,
+        ReplaceGlobalDefinesConfig, SequenceExpressionFlattener, StatementSequenceSplitter,
+        StringDeduplication, StringDeduplicationOptions,
+    },
     compressor::{CompressOptions, Compressor},
-    mangler::ManglerBuilder,
+    mangler::{Mangler, ManglerBuilder, ManglerOptions, ManglerReport},
 };
 
 #[derive(Debug, Clone, Copy)]
@@ -37,7 +41,7 @@ impl Minifier {
     pub fn build<'a>(self, allocator: &'a Allocator, program: &mut Program<'a>) {
         Compressor::new(allocator, self.options.compress).build(program);
         // if self.options.mangle {
-        // let mangler = ManglerBuilder.build(program);
+        // let mangler = ManglerBuilder::default().build(program);
         // printer.with_mangler(mangler);
         // }
     }
@@ -1,14 +1,28 @@
+mod options;
+mod properties;
+mod report;
+
 use itertools::Itertools;
-use oxc_ast::ast::Program;
+use oxc_ast::{
+    ast::{Expression, Program},
+    AstKind,
+};
 use oxc_index::{index_vec, IndexVec};
-use oxc_semantic::{ReferenceId, SemanticBuilder, SymbolId, SymbolTable};
+use oxc_semantic::{
+    AstNode, ReferenceId, ScopeId, ScopeTree, Semantic, SemanticBuilder, SymbolId, SymbolTable,
+    TraversalOrder,
+};
 use oxc_span::CompactStr;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+pub use self::{options::ManglerOptions, report::ManglerReport};
 
 type Slot = usize;
 
 #[derive(Debug)]
 pub struct Mangler {
     symbol_table: SymbolTable,
+    property_names: FxHashMap<CompactStr, CompactStr>,
 }
 
 impl Mangler {
@@ -20,6 +34,12 @@ impl Mangler {
         let symbol_id = self.symbol_table.get_reference(reference_id).symbol_id()?;
         Some(self.symbol_table.get_name(symbol_id))
     }
+
+    /// Get the mangled name of a property name,
+    /// returns `None` when the property keeps its name or when [`ManglerOptions::mangle_properties`] is disabled.
+    pub fn get_property_name(&self, name: &str) -> Option<&str> {
+        self.property_names.get(name).map(CompactStr::as_str)
+    }
 }
 
 /// # Name Mangler / Symbol Minification
@@ -63,14 +83,55 @@ impl Mangler {
 ///     }
 /// }
 /// ```
-pub struct ManglerBuilder;
+#[derive(Debug, Default)]
+pub struct ManglerBuilder {
+    options: ManglerOptions,
+}
 
 impl ManglerBuilder {
+    pub fn new(options: ManglerOptions) -> Self {
+        Self { options }
+    }
+
     #[must_use]
     pub fn build<'a>(self, program: &'a Program<'a>) -> Mangler {
+        self.build_impl(program, None)
+    }
+
+    /// Same as [`ManglerBuilder::build`], but also reports the original and mangled names.
+    #[must_use]
+    pub fn build_with_report<'a>(self, program: &'a Program<'a>) -> (Mangler, ManglerReport) {
+        let mut report = ManglerReport::default();
+        let mangler = self.build_impl(program, Some(&mut report));
+        (mangler, report)
+    }
+
+    fn build_impl<'a>(
+        self,
+        program: &'a Program<'a>,
+        mut report: Option<&mut ManglerReport>,
+    ) -> Mangler {
         let semantic_ret = SemanticBuilder::new("", program.source_type).build(program);
         let semantic = semantic_ret.semantic;
 
+        // Symbols declared in scopes containing a direct `eval` call can be referenced by the
+        // `eval`'d code using their original names, and names inside a `with` statement cannot be
+        // statically resolved, so symbols in these scopes must not be renamed.
+        let unsafe_scopes = if self.options.unsafe_eval {
+            FxHashSet::default()
+        } else {
+            let mut scopes = Self::eval_scopes(&semantic);
+            scopes.extend(Self::with_scopes(&semantic));
+            scopes
+        };
+        let kept_symbols = self.kept_symbols(&semantic);
+
+        let property_names = if self.options.mangle_properties {
+            properties::mangle_properties(&semantic, &self.options)
+        } else {
+            FxHashMap::default()
+        };
+
         // Mangle the symbol table by computing slots from the scope tree.
         // A slot is the occurrence index of a binding identifier inside a scope.
         let (mut symbol_table, scope_tree) = semantic.into_symbol_table_and_scope_tree();
@@ -78,15 +139,20 @@ impl ManglerBuilder {
         // Total number of slots for all scopes
         let mut total_number_of_slots: Slot = 0;
 
-        // All symbols with their assigned slots
-        let mut slots: IndexVec<SymbolId, Slot> = index_vec![0; symbol_table.len()];
+        // All symbols with their assigned slots, `None` for symbols which keep their names
+        let mut slots: IndexVec<SymbolId, Option<Slot>> = index_vec![None; symbol_table.len()];
+
+        // Names of the symbols which are not renamed
+        let mut kept_names = FxHashSet::default();
 
         // Keep track of the maximum slot number for each scope
         let mut max_slot_for_scope = vec![0; scope_tree.len()];
 
         // Walk the scope tree and compute the slot number for each scope
+        let sorted_symbols = Self::sort_symbols_for_mangling(&symbol_table, &scope_tree);
+        let mut sorted_symbols = sorted_symbols.into_iter().peekable();
+
         for scope_id in scope_tree.descendants_from_root() {
-            let bindings = scope_tree.get_bindings(scope_id);
             // The current slot number is continued by the maximum slot from the parent scope
             let parent_max_slot = scope_tree
                 .get_parent_id(scope_id)
@@ -94,9 +160,17 @@ impl ManglerBuilder {
 
             let mut slot = parent_max_slot;
 
-            // `bindings` are stored in order, traverse and increment slot
-            for symbol_id in bindings.values() {
-                slots[*symbol_id] = slot;
+            // Symbols are sorted by scope and then by declaration, traverse and increment slot
+            while let Some((_, symbol_id)) = sorted_symbols.next_if(|(id, _)| *id == scope_id) {
+                if unsafe_scopes.contains(&scope_id) || kept_symbols.contains(&symbol_id) {
+                    let name = symbol_table.get_name(symbol_id);
+                    kept_names.insert(CompactStr::from(name));
+                    if let Some(report) = report.as_deref_mut() {
+                        report.kept.push((name.to_string(), symbol_id));
+                    }
+                    continue;
+                }
+                slots[symbol_id] = Some(slot);
                 slot += 1;
             }
 
@@ -107,8 +181,12 @@ impl ManglerBuilder {
             }
         }
 
-        let frequencies =
-            Self::tally_slot_frequencies(&symbol_table, total_number_of_slots, &slots);
+        let frequencies = Self::tally_slot_frequencies(
+            &symbol_table,
+            total_number_of_slots,
+            &slots,
+            self.options.frequency_based,
+        );
 
         let unresolved_references = scope_tree
             .root_unresolved_references()
@@ -124,8 +202,12 @@ impl ManglerBuilder {
             names.push(loop {
                 let name = base54(count);
                 count += 1;
-                // Do not mangle keywords and unresolved references
-                if !is_keyword(&name) && !unresolved_references.iter().any(|n| **n == name) {
+                // Do not mangle keywords, unresolved references and reserved names
+                if !is_keyword(&name)
+                    && !unresolved_references.iter().any(|n| **n == name)
+                    && !self.options.reserved_names.contains(name.as_str())
+                    && !kept_names.contains(&name)
+                {
                     break name;
                 }
             });
@@ -170,33 +252,136 @@ impl ManglerBuilder {
             // rename the variables
             for (symbol_to_rename, new_name) in symbols_to_rename_with_new_names {
                 for symbol_id in &symbol_to_rename.symbol_ids {
+                    if let Some(report) = report.as_deref_mut() {
+                        let original_name = symbol_table.get_name(*symbol_id);
+                        if original_name == new_name.as_str() {
+                            report.kept.push((original_name.to_string(), *symbol_id));
+                        } else {
+                            report.mangled.push((
+                                original_name.to_string(),
+                                new_name.to_string(),
+                                *symbol_id,
+                            ));
+                        }
+                    }
                     symbol_table.set_name(*symbol_id, new_name.clone());
                 }
             }
         }
 
-        Mangler { symbol_table }
+        if let Some(report) = report {
+            report.kept.sort_unstable_by_key(|(_, symbol_id)| *symbol_id);
+            report.mangled.sort_unstable_by_key(|(_, _, symbol_id)| *symbol_id);
+        }
+
+        Mangler { symbol_table, property_names }
     }
 
     fn tally_slot_frequencies(
         symbol_table: &SymbolTable,
         total_number_of_slots: usize,
-        slots: &IndexVec<SymbolId, Slot>,
+        slots: &IndexVec<SymbolId, Option<Slot>>,
+        sort_by_frequency: bool,
     ) -> Vec<SlotFrequency> {
         let mut frequencies = vec![SlotFrequency::default(); total_number_of_slots];
         for (symbol_id, slot) in slots.iter_enumerated() {
+            let Some(slot) = *slot else { continue };
             if !symbol_table.get_flag(symbol_id).is_variable() {
                 continue;
             }
-            let index = *slot;
-            frequencies[index].slot = *slot;
+            let index = slot;
+            frequencies[index].slot = slot;
             frequencies[index].frequency +=
                 symbol_table.get_resolved_reference_ids(symbol_id).len();
             frequencies[index].symbol_ids.push(symbol_id);
         }
-        frequencies.sort_by_key(|x| (std::cmp::Reverse(x.frequency)));
+        // Without sorting, slots stay in the order at which the vars first appear in the source.
+        if sort_by_frequency {
+            frequencies.sort_by_key(|x| (std::cmp::Reverse(x.frequency)));
+        }
         frequencies
     }
+
+    /// All bound symbols with their scope, in a canonical order independent of how the bindings were stored:
+    /// outer scopes first and then by declaration position.
+    fn sort_symbols_for_mangling(
+        symbol_table: &SymbolTable,
+        scope_tree: &ScopeTree,
+    ) -> Vec<(ScopeId, SymbolId)> {
+        let mut symbols = scope_tree
+            .descendants_from_root()
+            .flat_map(|scope_id| {
+                scope_tree
+                    .get_bindings(scope_id)
+                    .values()
+                    .map(move |symbol_id| (scope_id, *symbol_id))
+            })
+            .collect::<Vec<_>>();
+        symbols.sort_unstable_by_key(|(scope_id, symbol_id)| {
+            (*scope_id, symbol_table.get_span(*symbol_id).start, *symbol_id)
+        });
+        symbols
+    }
+
+    /// Symbols which keep their names:
+    /// * functions whose names match [`ManglerOptions::keep_fnames_regex`]
+    /// * TypeScript parameter properties, whose names are also the names of the class fields
+    fn kept_symbols(&self, semantic: &Semantic) -> FxHashSet<SymbolId> {
+        let symbol_table = semantic.symbols();
+        symbol_table
+            .iter()
+            .filter(|symbol_id| {
+                let declaration = symbol_table.get_declaration(*symbol_id);
+                match semantic.nodes().kind(declaration) {
+                    AstKind::Function(_) => self
+                        .options
+                        .keep_fnames_regex
+                        .as_ref()
+                        .is_some_and(|regex| regex.is_match(symbol_table.get_name(*symbol_id))),
+                    AstKind::FormalParameter(param) => {
+                        param.accessibility.is_some() || param.readonly || param.r#override
+                    }
+                    _ => false,
+                }
+            })
+            .collect()
+    }
+
+    /// Scopes containing a direct `eval` call, along with all their ancestors.
+    fn eval_scopes(semantic: &Semantic) -> FxHashSet<ScopeId> {
+        let scope_tree = semantic.scopes();
+        let mut eval_scopes = FxHashSet::default();
+        for node in semantic.nodes().iter() {
+            let AstKind::CallExpression(call_expr) = node.kind() else { continue };
+            if !matches!(&call_expr.callee, Expression::Identifier(ident) if ident.name == "eval") {
+                continue;
+            }
+            for scope_id in scope_tree.ancestors(node.scope_id()) {
+                if !eval_scopes.insert(scope_id) {
+                    break;
+                }
+            }
+        }
+        eval_scopes
+    }
+
+    /// Scopes inside the body of a `with` statement, along with the scopes its names resolve
+    /// through, i.e. the ancestors of the `with` statement.
+    fn with_scopes(semantic: &Semantic) -> FxHashSet<ScopeId> {
+        let scope_tree = semantic.scopes();
+        let nodes = semantic.nodes();
+        let mut with_scopes = FxHashSet::default();
+        for node in nodes.iter() {
+            if !matches!(node.kind(), AstKind::WithStatement(_)) {
+                continue;
+            }
+            with_scopes.extend(scope_tree.ancestors(node.scope_id()));
+            with_scopes.extend(
+                nodes.subtree_iter(node.id(), TraversalOrder::PreOrder).map(AstNode::scope_id),
+            );
+        }
+        with_scopes
+    }
 }
 
 #[derive(Debug, Default, Clone)]
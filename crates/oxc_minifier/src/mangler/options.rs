@@ -0,0 +1,106 @@
+use regex::Regex;
+use rustc_hash::FxHashSet;
+
+#[derive(Debug, Clone)]
+pub struct ManglerOptions {
+    /// Give the shortest names to the most referenced symbols,
+    /// otherwise names are assigned in the order the symbols are declared.
+    ///
+    /// Default `true`
+    pub frequency_based: bool,
+
+    /// Names that are never used as a mangled name,
+    /// e.g. globals provided by the runtime or by non-minified libraries.
+    ///
+    /// Default empty
+    pub reserved_names: FxHashSet<String>,
+
+    /// Rename symbols in scopes containing a direct `eval` call or a `with` statement.
+    /// Only safe when the `eval`'d code and the `with` objects never reference these symbols.
+    ///
+    /// Default `false`
+    pub unsafe_eval: bool,
+
+    /// Rename property names, see [`super::Mangler::get_property_name`].
+    /// Property names which appear in strings, in shorthand properties, on global objects such as `console`
+    /// or in `reserved_names` are kept.
+    /// Only safe when properties are never accessed by code outside of the program.
+    ///
+    /// Default `false`
+    pub mangle_properties: bool,
+
+    /// Keep the names of functions matching this regex,
+    /// e.g. functions relying on `Function.prototype.name` or `Function.prototype.toString`.
+    ///
+    /// Default `None`
+    pub keep_fnames_regex: Option<Regex>,
+}
+
+impl Default for ManglerOptions {
+    fn default() -> Self {
+        Self {
+            frequency_based: true,
+            reserved_names: FxHashSet::default(),
+            unsafe_eval: false,
+            mangle_properties: false,
+            keep_fnames_regex: None,
+        }
+    }
+}
+
+impl ManglerOptions {
+    /// Reserve the names of the common browser globals.
+    #[must_use]
+    pub fn with_browser_globals(mut self) -> Self {
+        self.reserved_names.extend(BROWSER_GLOBALS.iter().map(ToString::to_string));
+        self
+    }
+}
+
+const BROWSER_GLOBALS: &[&str] = &[
+    "$",
+    "_",
+    "alert",
+    "atob",
+    "blur",
+    "btoa",
+    "caches",
+    "close",
+    "confirm",
+    "console",
+    "crypto",
+    "customElements",
+    "define",
+    "document",
+    "event",
+    "exports",
+    "fetch",
+    "focus",
+    "frames",
+    "global",
+    "globalThis",
+    "history",
+    "indexedDB",
+    "length",
+    "localStorage",
+    "location",
+    "module",
+    "name",
+    "navigator",
+    "open",
+    "opener",
+    "origin",
+    "parent",
+    "performance",
+    "print",
+    "prompt",
+    "require",
+    "screen",
+    "scroll",
+    "self",
+    "sessionStorage",
+    "status",
+    "stop",
+    "top",
+    "window",
+];
@@ -0,0 +1,169 @@
+use oxc_ast::{
+    ast::{AssignmentTarget, AssignmentTargetProperty, Expression, MemberExpression, PropertyKey},
+    AstKind,
+};
+use oxc_semantic::{Semantic, SymbolTable};
+use oxc_span::CompactStr;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use super::{base54, is_keyword, ManglerOptions};
+
+/// Property names which must never be renamed because the runtime depends on them.
+const BUILTIN_PROPERTIES: &[&str] = &["__proto__", "constructor", "prototype"];
+
+/// Compute the mangled names for property names.
+///
+/// Property names are renamed from a separate namespace than symbols,
+/// the most used property names receive the shortest names.
+///
+/// A property name is kept when it appears in a string context (string literals and template literals),
+/// as it may be accessed dynamically, in a shorthand property, as renaming the key would rename the binding,
+/// or in a class field initialized with an anonymous function or class, as it sets the function's `name`.
+/// Properties of global objects such as `console.log` are also kept, as they are defined outside of the program.
+pub(super) fn mangle_properties(
+    semantic: &Semantic,
+    options: &ManglerOptions,
+) -> FxHashMap<CompactStr, CompactStr> {
+    let mut collector = PropertyCollector::default();
+    for node in semantic.nodes().iter() {
+        collector.collect(node.kind(), semantic.symbols());
+    }
+
+    // Sort by frequency, ties are broken by the order of first appearance.
+    let mut properties = collector
+        .properties
+        .into_iter()
+        .filter(|name| {
+            !collector.kept.contains(name)
+                && !options.reserved_names.contains(name.as_str())
+                && !BUILTIN_PROPERTIES.contains(&name.as_str())
+        })
+        .collect::<Vec<_>>();
+    properties.sort_by_key(|name| std::cmp::Reverse(collector.frequencies[name]));
+
+    let mut mangled_names = FxHashMap::default();
+    let mut count = 0;
+    for property in properties {
+        let name = loop {
+            let name = base54(count);
+            count += 1;
+            if !is_keyword(&name)
+                && !collector.kept.contains(&name)
+                && !options.reserved_names.contains(name.as_str())
+            {
+                break name;
+            }
+        };
+        mangled_names.insert(property, name);
+    }
+    mangled_names
+}
+
+#[derive(Default)]
+struct PropertyCollector {
+    /// Renameable property names in order of first appearance
+    properties: Vec<CompactStr>,
+    frequencies: FxHashMap<CompactStr, usize>,
+    /// Property names which must keep their names
+    kept: FxHashSet<CompactStr>,
+}
+
+impl PropertyCollector {
+    fn add(&mut self, name: &str) {
+        let frequency = self.frequencies.entry(CompactStr::from(name)).or_insert(0);
+        if *frequency == 0 {
+            self.properties.push(CompactStr::from(name));
+        }
+        *frequency += 1;
+    }
+
+    fn keep(&mut self, name: &str) {
+        self.kept.insert(CompactStr::from(name));
+    }
+
+    /// `keep` is set for keys whose names are observable, e.g. shorthand properties.
+    fn add_property_key(&mut self, key: &PropertyKey, computed: bool, keep: bool) {
+        if computed {
+            return;
+        }
+        match key {
+            PropertyKey::StaticIdentifier(ident) if keep => self.keep(&ident.name),
+            PropertyKey::StaticIdentifier(ident) => self.add(&ident.name),
+            PropertyKey::StringLiteral(lit) => self.keep(&lit.value),
+            _ => {}
+        }
+    }
+
+    fn collect(&mut self, kind: AstKind, symbols: &SymbolTable) {
+        match kind {
+            AstKind::MemberExpression(MemberExpression::StaticMemberExpression(expr)) => {
+                if is_global_object(&expr.object, symbols) {
+                    self.keep(&expr.property.name);
+                } else {
+                    self.add(&expr.property.name);
+                }
+            }
+            AstKind::ObjectProperty(prop) => {
+                self.add_property_key(&prop.key, prop.computed, prop.shorthand);
+            }
+            AstKind::MethodDefinition(def) => {
+                self.add_property_key(&def.key, def.computed, false);
+            }
+            AstKind::PropertyDefinition(def) => {
+                // `class C { foo = function() {} }` sets the function's `name` to the field name.
+                let sets_name = def.value.as_ref().is_some_and(is_anonymous_function_definition);
+                self.add_property_key(&def.key, def.computed, sets_name);
+            }
+            AstKind::ObjectPattern(pat) => {
+                for prop in &pat.properties {
+                    self.add_property_key(&prop.key, prop.computed, prop.shorthand);
+                }
+            }
+            AstKind::AssignmentTarget(AssignmentTarget::ObjectAssignmentTarget(target)) => {
+                for prop in &target.properties {
+                    match prop {
+                        AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(ident) => {
+                            self.keep(&ident.binding.name);
+                        }
+                        AssignmentTargetProperty::AssignmentTargetPropertyProperty(prop) => {
+                            self.add_property_key(&prop.name, false, false);
+                        }
+                    }
+                }
+            }
+            AstKind::StringLiteral(lit) => self.keep(&lit.value),
+            AstKind::TemplateLiteral(lit) => {
+                for quasi in &lit.quasis {
+                    self.keep(&quasi.value.raw);
+                    if let Some(cooked) = &quasi.value.cooked {
+                        self.keep(cooked);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Anonymous functions and classes receive their `name` from the binding or property they are assigned to.
+fn is_anonymous_function_definition(expr: &Expression) -> bool {
+    match expr.without_parenthesized() {
+        Expression::FunctionExpression(func) => func.id.is_none(),
+        Expression::ClassExpression(class) => class.id.is_none(),
+        Expression::ArrowFunctionExpression(_) => true,
+        _ => false,
+    }
+}
+
+/// Whether `expr` is an unresolved reference such as `console`, or a member of one such as `window.document`.
+fn is_global_object(expr: &Expression, symbols: &SymbolTable) -> bool {
+    match expr.without_parenthesized() {
+        Expression::Identifier(ident) => ident
+            .reference_id
+            .get()
+            .map_or(true, |reference_id| symbols.is_global_reference(reference_id)),
+        expr => expr
+            .as_member_expression()
+            .is_some_and(|member_expr| is_global_object(member_expr.object(), symbols)),
+    }
+}
@@ -0,0 +1,11 @@
+use oxc_semantic::SymbolId;
+
+/// Outcome of mangling, see [`super::ManglerBuilder::build_with_report`].
+#[derive(Debug, Default, Clone)]
+pub struct ManglerReport {
+    /// Symbols which kept their original name: `(name, symbol_id)`
+    pub kept: Vec<(String, SymbolId)>,
+
+    /// Symbols which were renamed: `(original_name, mangled_name, symbol_id)`
+    pub mangled: Vec<(String, String, SymbolId)>,
+}
@@ -1,6 +1,6 @@
 use oxc_minifier::{CompressOptions, MinifierOptions};
 
-use crate::{test, test_snapshot, test_with_options};
+use crate::{test, test_snapshot, test_with_options, test_without_compress_booleans};
 
 #[test]
 fn addition_folding() {
@@ -10,6 +10,78 @@ fn addition_folding() {
     test("x+''", "x+''");
 }
 
+#[test]
+fn arithmetic_folding() {
+    test("x = 1 - 2", "x=-1");
+    test("x = 2 * 3", "x=6");
+    test("x = 1 / 2", "x=.5");
+    test("x = -5 % 3", "x=-2");
+    test("x = 2 ** 8", "x=256");
+    test("x = 2 ** -1", "x=.5");
+    test("x = 5 | 2", "x=7");
+    test("x = 5 & 3", "x=1");
+    test("x = 5 ^ 3", "x=6");
+    test("x = 1.5 | 0", "x=1");
+    test("x = null * 3", "x=0");
+    test("x = (1 + 2) * 3", "x=9");
+    test("x = '1' * 2", "x='1'*2");
+    test("x = y * 2", "x=y*2");
+}
+
+#[test]
+fn arithmetic_folding_unrepresentable() {
+    // overflow
+    test("x = 2 ** 53 + 1", "x=2**53+1");
+    test("x = 9007199254740991 + 1", "x=9007199254740991+1");
+    // `NaN` and `Infinity`
+    test("x = 0 / 0", "x=0/0");
+    test("x = 1 % 0", "x=1%0");
+    test("x = 1 / -0", "x=1/-0");
+    // `-0`
+    test("x = 0 * -1", "x=0*-1");
+    test("x = -0 - 0", "x=-0-0");
+    test("x = (1 - 1) * -1", "x=0*-1");
+}
+
+#[test]
+fn arithmetic_folding_negative_result() {
+    test("x = (2 - 5) ** y", "x=(-3)**y");
+    test("x = y ** (2 - 5)", "x=y**-3");
+    test("x = (0 - 1).toString()", "x=(-1).toString()");
+    test("x = (0 - 1)()", "x=(-1)()");
+}
+
+#[test]
+fn arithmetic_folding_without_evaluate() {
+    let options = MinifierOptions {
+        mangle: false,
+        compress: CompressOptions { evaluate: false, ..CompressOptions::default() },
+    };
+    test_with_options("x = 2 * 3", "x=2*3", options);
+}
+
+#[test]
+fn logical_folding_reference() {
+    // `this` of the call and the `delete` operand are not changed
+    test("(true && obj.m)()", "(0,obj.m)()");
+    test("(0 || obj[k])()", "(0,obj[k])()");
+    test("(true && eval)('x')", "(0,eval)('x')");
+    test("(true && obj.m)``", "(0,obj.m)``");
+    test("delete (true && x.y)", "delete (0,x.y)");
+    test("(true && f)()", "f()");
+    test("x = (true && obj.m)", "x=obj.m");
+    test("f(true && obj.m)", "f(obj.m)");
+}
+
+#[test]
+fn boolean_folding() {
+    test("x = [true, false]", "x=[!0,!1]");
+    test("x = y === true", "x=y===!0");
+    test("x = !0 && y", "x=y");
+    test("x = !1 || y", "x=y");
+    test_without_compress_booleans("x = y === true", "x=y===true");
+}
+
 #[test]
 fn typeof_folding() {
     test("typeof x === 'undefined'", "void 0===x");
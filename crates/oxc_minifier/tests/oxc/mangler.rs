@@ -0,0 +1,223 @@
+use std::fmt::Write;
+
+use oxc_allocator::Allocator;
+use oxc_index::Idx;
+use oxc_minifier::{ManglerBuilder, ManglerOptions};
+use oxc_parser::Parser;
+use oxc_semantic::{SemanticBuilder, SymbolId};
+use oxc_span::SourceType;
+use regex::Regex;
+
+/// Mangle `source_text` and return the mangled names by symbol.
+fn mangle(source_text: &str, options: ManglerOptions) -> Vec<String> {
+    mangle_with_source_type(source_text, SourceType::default(), options)
+}
+
+fn mangle_with_source_type(
+    source_text: &str,
+    source_type: SourceType,
+    options: ManglerOptions,
+) -> Vec<String> {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let program = allocator.alloc(ret.program);
+    let symbol_count =
+        SemanticBuilder::new(source_text, source_type).build(program).semantic.symbols().len();
+    let mangler = ManglerBuilder::new(options).build(program);
+    (0..symbol_count)
+        .map(|i| mangler.get_symbol_name(SymbolId::from_usize(i)).to_string())
+        .collect()
+}
+
+#[test]
+fn frequency_based() {
+    // 54 single letter names are available, the 55th symbol needs two letters.
+    let mut source_text = (0..55).fold(String::new(), |mut s, i| {
+        let _ = write!(s, "var v{i};");
+        s
+    });
+    source_text.push_str(&"v54;".repeat(10));
+
+    let names = mangle(&source_text, ManglerOptions::default());
+    assert_eq!(names[54].len(), 1);
+    assert_eq!(names.iter().filter(|name| name.len() == 2).count(), 1);
+
+    let names = mangle(
+        &source_text,
+        ManglerOptions { frequency_based: false, ..ManglerOptions::default() },
+    );
+    assert_eq!(names[0], "a");
+    assert_eq!(names[54].len(), 2);
+}
+
+#[test]
+fn reserved_names() {
+    let source_text = "var foo, bar; foo; bar; bar;";
+    let names = mangle(source_text, ManglerOptions::default());
+    assert_eq!(names, ["a", "b"]);
+
+    let mut options = ManglerOptions::default();
+    options.reserved_names.insert("a".to_string());
+    let names = mangle(source_text, options);
+    assert_eq!(names, ["b", "c"]);
+
+    let source_text = (0..54).fold(String::new(), |mut s, i| {
+        let _ = write!(s, "var v{i};");
+        s
+    });
+    let names = mangle(&source_text, ManglerOptions::default().with_browser_globals());
+    assert!(!names.iter().any(|name| name == "$" || name == "_"));
+}
+
+#[test]
+fn report() {
+    let allocator = Allocator::default();
+    let source_text = "var a, foo; foo;";
+    let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+    let program = allocator.alloc(ret.program);
+    let (_, report) = ManglerBuilder::default().build_with_report(program);
+    assert_eq!(report.kept, [("a".to_string(), SymbolId::from_usize(0))]);
+    assert_eq!(report.mangled, [("foo".to_string(), "b".to_string(), SymbolId::from_usize(1))]);
+}
+
+#[test]
+fn eval() {
+    let source_text = "function foo(bar) { var baz; eval('bar'); } function qux(quux) {}";
+    let names = mangle(source_text, ManglerOptions::default());
+    assert_eq!(names, ["foo", "bar", "baz", "qux", "a"]);
+
+    let options = ManglerOptions { unsafe_eval: true, ..ManglerOptions::default() };
+    let names = mangle(source_text, options);
+    assert!(!names.iter().any(|name| name == "bar" || name == "baz"));
+}
+
+#[test]
+fn mangle_properties() {
+    let allocator = Allocator::default();
+    let source_text = "
+        const obj = { foo: 1, bar: 2, baz, qux: 3 };
+        obj.bar; obj.bar; obj['qux'];
+        class C { method() {} }
+        const { foo } = obj;
+    ";
+    let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+    let program = allocator.alloc(ret.program);
+
+    let mangler = ManglerBuilder::default().build(program);
+    assert_eq!(mangler.get_property_name("bar"), None);
+
+    let options = ManglerOptions { mangle_properties: true, ..ManglerOptions::default() };
+    let mangler = ManglerBuilder::new(options).build(program);
+    assert_eq!(mangler.get_property_name("bar"), Some("a"));
+    assert_eq!(mangler.get_property_name("method"), Some("b"));
+    // Kept because of the shorthand property or the string literal.
+    assert_eq!(mangler.get_property_name("foo"), None);
+    assert_eq!(mangler.get_property_name("baz"), None);
+    assert_eq!(mangler.get_property_name("qux"), None);
+}
+
+#[test]
+fn mangle_properties_of_globals() {
+    let allocator = Allocator::default();
+    let source_text = "
+        const obj = { log: 1, foo: 2 };
+        console.log(obj.foo); window.document.title; obj.foo;
+        function f(console) { console.bar; }
+    ";
+    let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+    let program = allocator.alloc(ret.program);
+    let options = ManglerOptions { mangle_properties: true, ..ManglerOptions::default() };
+    let mangler = ManglerBuilder::new(options).build(program);
+    // Kept everywhere because they are properties of global objects.
+    assert_eq!(mangler.get_property_name("log"), None);
+    assert_eq!(mangler.get_property_name("document"), None);
+    assert_eq!(mangler.get_property_name("title"), None);
+    // `console` is a parameter inside of `f`.
+    assert_eq!(mangler.get_property_name("foo"), Some("a"));
+    assert_eq!(mangler.get_property_name("bar"), Some("b"));
+}
+
+#[test]
+fn deterministic() {
+    let source_text = "
+        function foo(a, b) { let c = a + b; return () => c; }
+        var bar = 1, baz = 2; { let qux = bar; }
+        class A { method(x) { return x; } }
+    ";
+    let names = mangle(source_text, ManglerOptions::default());
+    assert_eq!(names, ["a", "e", "f", "g", "c", "d", "e", "A", "e"]);
+
+    // The mangled names only depend on the structure of the program, not on the original names.
+    // Class names are never mangled, so `A` is kept.
+    let renamed_source_text = "
+        function zz(q, p) { let o = q + p; return () => o; }
+        var yy = 1, xx = 2; { let ww = yy; }
+        class A { method(n) { return n; } }
+    ";
+    assert_eq!(mangle(renamed_source_text, ManglerOptions::default()), names);
+}
+
+#[test]
+fn keep_fnames_regex() {
+    let source_text =
+        "function __foo__() {} function bar() {} (function __baz__() {}); var __qux__;";
+    let options = ManglerOptions {
+        keep_fnames_regex: Some(Regex::new("^__.*__$").unwrap()),
+        ..ManglerOptions::default()
+    };
+    let names = mangle(source_text, options);
+    assert_eq!(names[0], "__foo__");
+    assert_ne!(names[1], "bar");
+    assert_eq!(names[2], "__baz__");
+    assert_ne!(names[3], "__qux__");
+}
+
+#[test]
+fn with_statement() {
+    let source_text =
+        "var foo; function bar() { var baz; with (obj) { (function() { var qux; }); } }";
+    let names = mangle(source_text, ManglerOptions::default());
+    assert_eq!(names, ["foo", "bar", "baz", "qux"]);
+
+    let options = ManglerOptions { unsafe_eval: true, ..ManglerOptions::default() };
+    let names = mangle(source_text, options);
+    assert!(!names.iter().any(|name| name == "foo" || name == "baz" || name == "qux"));
+
+    // Scopes which don't enclose or resolve through the `with` statement are still mangled.
+    let source_text =
+        "var foo; function bar() { with (obj) { baz; } function qux() { var quux; } }";
+    let names = mangle(source_text, ManglerOptions::default());
+    assert_eq!(names[..3], ["foo", "bar", "qux"]);
+    assert_ne!(names[3], "quux");
+}
+
+#[test]
+fn typescript_class_fields() {
+    let source_type = SourceType::default().with_typescript(true);
+
+    let source_text = "class C { constructor(public foo = class {}, readonly bar, baz) {} }";
+    let names = mangle_with_source_type(source_text, source_type, ManglerOptions::default());
+    assert_eq!(names[1], "foo");
+    assert_eq!(names[2], "bar");
+    assert_ne!(names[3], "baz");
+
+    let allocator = Allocator::default();
+    let source_text = "class C { foo = function() {}; bar = class {}; baz = () => {}; qux = 1 }";
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let program = allocator.alloc(ret.program);
+    let options = ManglerOptions { mangle_properties: true, ..ManglerOptions::default() };
+    let mangler = ManglerBuilder::new(options).build(program);
+    assert_eq!(mangler.get_property_name("foo"), None);
+    assert_eq!(mangler.get_property_name("bar"), None);
+    assert_eq!(mangler.get_property_name("baz"), None);
+    assert_eq!(mangler.get_property_name("qux"), Some("a"));
+}
+
+#[test]
+fn destructured_parameters() {
+    let source_text = "function f({ a: { b }, c = 1, ...d }, [e, [g = 2], ...h] = [], ...i) {}";
+    let names = mangle(source_text, ManglerOptions::default());
+    assert_eq!(names.len(), 8);
+    // Every parameter binding, at every nesting depth, receives a mangled name in the function scope.
+    assert_eq!(names[1..], ["b", "c", "d", "e", "f", "g", "h"]);
+}
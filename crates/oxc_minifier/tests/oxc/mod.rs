@@ -1,5 +1,10 @@
 mod code_removal;
 mod folding;
+mod mangler;
+mod object_spread_optimizer;
 mod precedence;
 mod remove_dead_code;
 mod replace_global_defines;
+mod sequence_expressions;
+mod string_deduplication;
+mod typeof_folding;
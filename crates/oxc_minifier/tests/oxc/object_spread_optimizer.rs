@@ -0,0 +1,47 @@
+use oxc_allocator::Allocator;
+use oxc_codegen::WhitespaceRemover;
+use oxc_minifier::ObjectSpreadOptimizer;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+fn minify(source_text: &str) -> String {
+    let source_type = SourceType::default();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let program = allocator.alloc(ret.program);
+    ObjectSpreadOptimizer::new(&allocator).build(program);
+    WhitespaceRemover::new().build(program).source_text
+}
+
+pub(crate) fn test(source_text: &str, expected: &str) {
+    let minified = minify(source_text);
+    assert_eq!(minified, expected, "for source {source_text}");
+}
+
+#[test]
+fn object_assign() {
+    test("x = Object.assign({}, a, b)", "x={...a,...b}");
+    test("x = Object.assign({}, a, { b: 1 })", "x={...a,...{b:1}}");
+    test("x = Object.assign({}, Object.assign({}, a), b)", "x={...{...a},...b}");
+    test("x = Object.assign({})", "x={}");
+}
+
+#[test]
+fn object_assign_kept() {
+    test("x = Object.assign(a, b)", "x=Object.assign(a,b)");
+    test("x = Object.assign({ a: 1 }, b)", "x=Object.assign({a:1},b)");
+    test("x = Object.assign({}, ...a)", "x=Object.assign({},...a)");
+    test("x = Object?.assign({}, a)", "x=Object?.assign({},a)");
+    test("x = Object.assign?.({}, a)", "x=Object.assign?.({},a)");
+    test("var Object; x = Object.assign({}, a)", "var Object;x=Object.assign({},a)");
+}
+
+#[test]
+fn empty_spread() {
+    test("x = { ...{} }", "x={}");
+    test("x = { a, ...{}, b }", "x={a,b}");
+    test("x = { ...null, a }", "x={a}");
+    test("x = { ...{ ...{} } }", "x={}");
+    test("x = Object.assign({}, {}, a)", "x={...a}");
+    test("x = { ...a }", "x={...a}");
+}
@@ -27,3 +27,75 @@ fn remove_dead_code() {
     test("false ? foo : bar;", "bar");
     test("true ? foo : bar;", "foo");
 }
+
+#[test]
+fn if_statement() {
+    test("if (false) { foo } bar", ";bar");
+    test("if (false) { var x = 1 }", "var x");
+    test("if (true) foo; else { var x = 1 }", "{foo;var x}");
+    test("if (false) { let x = 1; var y }", "var y");
+}
+
+#[test]
+fn conditional_expression() {
+    test("x = true ? a : b", "x=a");
+    test("x = 0 ? a : b", "x=b");
+    test("f(null ? a : b)", "f(b)");
+}
+
+#[test]
+fn conditional_expression_reference() {
+    // `this` of the call and the `delete` operand are not changed
+    test("(true ? a.b : c)()", "(0,a.b)()");
+    test("(false ? c : a[b])()", "(0,a[b])()");
+    test("(true ? a.b : c)``", "(0,a.b)``");
+    test("(true ? eval : c)('x')", "(0,eval)('x')");
+    test("delete (true ? a.b : c)", "delete (0,a.b)");
+    test("delete (true ? x : c)", "delete (0,x)");
+    test("(true ? (false ? c : a.b) : c)()", "(0,a.b)()");
+    test("(true ? f : c)()", "f()");
+    test("x = (true ? a.b : c)", "x=a.b");
+}
+
+#[test]
+fn loops() {
+    test("while (false) { foo }", ";");
+    test("while (false) { var x }", "var x");
+    test("do { foo } while (false)", "{foo}");
+    test("do { if (x) break; foo } while (false)", "do {if(x)break;foo}while(false)");
+    test("for (; false;) foo", ";");
+    test("for (var i = 0; false; i++) foo", "var i=0");
+    test("for (let i = 0; false;) foo", "{let i=0}");
+    test("for (f(); false;) foo", "f()");
+}
+
+#[test]
+fn switch_statement() {
+    test("switch (1) { case 2: foo }", ";");
+    test("switch (1) { case 0: foo; case 1: bar }", "switch(1){case 1:bar}");
+    test("switch (1) { case 0: var x; case 1: bar }", "{var x;switch(1){case 1:bar}}");
+    test("switch (1) { case 0: foo; default: bar }", "switch(1){default:bar}");
+    test("switch (1) { case x: foo; case 1: bar }", "switch(1){case x:foo;case 1:bar}");
+}
+
+#[test]
+fn typeof_comparison() {
+    test("if (typeof undefined === 'undefined') foo; else bar", "foo");
+    test("if (typeof 1 !== 'number') foo", ";");
+    test("x = typeof NaN == 'number' ? a : b", "x=a");
+    test("if (typeof x === 'undefined') foo", "if(typeof x==='undefined')foo");
+}
+
+#[test]
+fn unreachable_statements() {
+    test("function f() { return 1; foo() }", "function f(){return 1}");
+    test("function f() { if (true) return; foo(); var x = 1 }", "function f(){return;var x}");
+    test("function f() { return; function g() {} }", "function f(){return;function g(){}}");
+    test("function f() { throw e; foo() }", "function f(){throw e}");
+    test(
+        "switch (x) { case 1: break; foo(); case 2: bar() }",
+        "switch(x){case 1:break;case 2:bar()}",
+    );
+    test("for (;;) { continue; foo() }", "for(;;){continue}");
+    test("function f() { if (x) return; foo() }", "function f(){if(x)return;foo()}");
+}
@@ -0,0 +1,45 @@
+use oxc_allocator::Allocator;
+use oxc_codegen::WhitespaceRemover;
+use oxc_minifier::{SequenceExpressionFlattener, StatementSequenceSplitter};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+fn minify(source_text: &str, flatten: bool, split: bool) -> String {
+    let source_type = SourceType::default();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let program = allocator.alloc(ret.program);
+    if flatten {
+        SequenceExpressionFlattener::new(&allocator).build(program);
+    }
+    if split {
+        StatementSequenceSplitter::new(&allocator).build(program);
+    }
+    WhitespaceRemover::new().build(program).source_text
+}
+
+#[test]
+fn flatten() {
+    let test = |source_text: &str, expected: &str| {
+        assert_eq!(minify(source_text, true, false), expected, "for source {source_text}");
+    };
+    test("x = (a, (b, c), d)", "x=(a,b,c,d)");
+    test("x = (a, ((b, (c, d))), e)", "x=(a,b,c,d,e)");
+    test("x = (a, (b ? c : d))", "x=(a,b?c:d)");
+}
+
+#[test]
+fn split() {
+    let test = |source_text: &str, expected: &str| {
+        assert_eq!(minify(source_text, false, true), expected, "for source {source_text}");
+    };
+    test("a, b, c", "a;b;c");
+    test("(a, b); c", "a;b;c");
+    test("function f() { a, b }", "function f(){a;b}");
+    test("if (x) a, b", "if(x)a,b");
+}
+
+#[test]
+fn flatten_and_split() {
+    assert_eq!(minify("a, (b, c)", true, true), "a;b;c");
+}
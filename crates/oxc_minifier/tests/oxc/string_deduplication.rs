@@ -0,0 +1,100 @@
+use oxc_allocator::Allocator;
+use oxc_codegen::WhitespaceRemover;
+use oxc_minifier::{StringDeduplication, StringDeduplicationOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+
+fn minify(source_text: &str, options: StringDeduplicationOptions) -> String {
+    minify_with_source_type(source_text, SourceType::default().with_module(true), options)
+}
+
+fn minify_with_source_type(
+    source_text: &str,
+    source_type: SourceType,
+    options: StringDeduplicationOptions,
+) -> String {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let program = allocator.alloc(ret.program);
+    StringDeduplication::new(&allocator, options).build(program);
+    WhitespaceRemover::new().build(program).source_text
+}
+
+pub(crate) fn test(source_text: &str, expected: &str) {
+    let minified = minify(source_text, StringDeduplicationOptions::default());
+    assert_eq!(minified, expected, "for source {source_text}");
+}
+
+#[test]
+fn deduplicate() {
+    test(
+        "f('long string'); g('long string'); h('long string')",
+        "const __s0='long string';f(__s0);g(__s0);h(__s0)",
+    );
+    test(
+        "f('long string', 'other string'); g('other string', 'long string'); h('long string', 'other string')",
+        "const __s0='long string',__s1='other string';f(__s0,__s1);g(__s1,__s0);h(__s0,__s1)",
+    );
+}
+
+#[test]
+fn break_even() {
+    test("f('a'); g('a'); h('a')", "f('a');g('a');h('a')");
+    test("f('long string'); g('long string')", "f('long string');g('long string')");
+}
+
+#[test]
+fn occurrences_threshold() {
+    let options = StringDeduplicationOptions { occurrences_threshold: 1 };
+    assert_eq!(
+        minify("f('a very long string'); g('a very long string')", options),
+        "const __s0='a very long string';f(__s0);g(__s0)"
+    );
+}
+
+#[test]
+fn property_keys() {
+    test(
+        "x = { 'long string': 1 }; f('long string'); g('long string'); h('long string')",
+        "const __s0='long string';x={'long string':1};f(__s0);g(__s0);h(__s0)",
+    );
+}
+
+#[test]
+fn name_collision() {
+    test(
+        "var __s0; f('long string'); g('long string'); h('long string')",
+        "const __s1='long string';var __s0;f(__s1);g(__s1);h(__s1)",
+    );
+}
+
+#[test]
+fn name_collision_with_symbols() {
+    let source_text = "let __s0 = g; f('long string', __s1); g('long string'); h('long string')";
+    let source_type = SourceType::default().with_module(true);
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let program = allocator.alloc(ret.program);
+    let (symbols, _) = SemanticBuilder::new(source_text, source_type)
+        .build(program)
+        .semantic
+        .into_symbol_table_and_scope_tree();
+    StringDeduplication::new(&allocator, StringDeduplicationOptions::default())
+        .with_symbols(symbols)
+        .build(program);
+    assert_eq!(
+        WhitespaceRemover::new().build(program).source_text,
+        "const __s2='long string';let __s0=g;f(__s2,__s1);g(__s2);h(__s2)"
+    );
+}
+
+#[test]
+fn scripts() {
+    let source_text = "f('long string'); g('long string'); h('long string')";
+    let options = StringDeduplicationOptions::default();
+    assert_eq!(
+        minify_with_source_type(source_text, SourceType::default(), options),
+        "f('long string');g('long string');h('long string')"
+    );
+}
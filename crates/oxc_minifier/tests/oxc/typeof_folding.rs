@@ -0,0 +1,57 @@
+use oxc_allocator::Allocator;
+use oxc_codegen::WhitespaceRemover;
+use oxc_minifier::{CompressOptions, Compressor};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+
+use crate::test;
+
+fn minify_with_symbols(source_text: &str) -> String {
+    let source_type = SourceType::default();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let program = allocator.alloc(ret.program);
+    let (symbols, _) = SemanticBuilder::new(source_text, source_type)
+        .build(program)
+        .semantic
+        .into_symbol_table_and_scope_tree();
+    Compressor::new(&allocator, CompressOptions::default()).with_symbols(symbols).build(program);
+    WhitespaceRemover::new().build(program).source_text
+}
+
+fn test_with_symbols(source_text: &str, expected: &str) {
+    let minified = minify_with_symbols(source_text);
+    assert_eq!(minified, expected, "for source {source_text}");
+}
+
+#[test]
+fn function_declarations() {
+    test_with_symbols("function f() {} x = typeof f", "function f(){}x='function'");
+    test_with_symbols("x = typeof f; function f() {}", "x='function';function f(){}");
+    test_with_symbols("function f() {} f = 1; x = typeof f", "function f(){}f=1;x=typeof f");
+    test_with_symbols(
+        "function f() {} var f = 1; x = typeof f",
+        "function f(){}var f=1;x=typeof f",
+    );
+    test_with_symbols(
+        "var f = 1; function f() {} x = typeof f",
+        "var f=1;function f(){}x=typeof f",
+    );
+    test_with_symbols(
+        "function f() {} function f() {} x = typeof f",
+        "function f(){}function f(){}x=typeof f",
+    );
+}
+
+#[test]
+fn other_identifiers() {
+    test_with_symbols("var f = function() {}; x = typeof f", "var f=function(){};x=typeof f");
+    test_with_symbols("class C {} x = typeof C", "class C{}x=typeof C");
+    test_with_symbols("x = typeof y", "x=typeof y");
+}
+
+#[test]
+fn without_symbols() {
+    test("function f() {} x = typeof f", "function f(){}x=typeof f");
+}
@@ -480,8 +480,8 @@ fn ignore() {
     test("a=b?c:c", "a=(b,c)");
     test("a=b?b:c=f", "a=b?b:c=f"); // don't write as a=b||(c=f)
     test("a=b||(c=f)", "a=b||(c=f)");
-    test("a=(-5)**3", "a=(-5)**3");
-    test("a=5**(-3)", "a=5**-3");
+    test("a=(-5)**3", "a=-125");
+    test("a=5**(-3)", "a=.008");
     test("a=(-(+5))**3", "a=(-+5)**3"); // could remove +
     test("a=(b,c)+3", "a=(b,c)+3");
     test("(a,b)&&c", "a,b&&c");
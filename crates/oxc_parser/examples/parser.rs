@@ -9,9 +9,14 @@ use oxc_span::SourceType;
 // create a `test.js`,
 // run `cargo run -p oxc_parser --example parser`
 // or `cargo watch -x "run -p oxc_parser --example parser"`
+// pass `--print-memory-stats` to print the memory used by the allocator
 
 fn main() -> Result<(), String> {
-    let name = env::args().nth(1).unwrap_or_else(|| "test.js".to_string());
+    let print_memory_stats = env::args().any(|arg| arg == "--print-memory-stats");
+    let name = env::args()
+        .skip(1)
+        .find(|arg| !arg.starts_with("--"))
+        .unwrap_or_else(|| "test.js".to_string());
     let path = Path::new(&name);
     let source_text = std::fs::read_to_string(path).map_err(|_| format!("Missing '{name}'"))?;
     let allocator = Allocator::default();
@@ -29,6 +34,11 @@ fn main() -> Result<(), String> {
         ret.trivias.comments().map(|(_, span)| span.source_text(&source_text)).collect::<Vec<_>>();
     println!("{comments:?}");
 
+    if print_memory_stats {
+        println!("Memory:");
+        println!("{:?}", allocator.stats());
+    }
+
     if ret.errors.is_empty() {
         println!("Parsed Successfully.");
     } else {
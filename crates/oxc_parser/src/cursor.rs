@@ -291,6 +291,9 @@ impl<'a> ParserImpl<'a> {
         self.token = cur_token;
         self.prev_token_end = prev_span_end;
         self.errors.truncate(errors_lens);
+        // Errors reported while parsing ahead no longer count towards `max_errors`
+        self.partial = false;
+        self.check_max_errors();
     }
 
     /// # Errors
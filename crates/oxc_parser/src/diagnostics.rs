@@ -21,6 +21,12 @@ pub fn flow(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Flow is not supported").with_label(span0)
 }
 
+#[cold]
+pub fn max_errors_reached(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Parsing stopped after reaching the maximum number of errors")
+        .with_label(span0)
+}
+
 #[cold]
 pub fn unexpected_token(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Unexpected token").with_label(span0)
@@ -389,6 +395,28 @@ pub fn using_declarations_must_be_initialized(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Using declarations must have an initializer.").with_label(span0)
 }
 
+#[cold]
+pub fn pipeline_body_without_topic_reference(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Pipe body must contain a topic reference `%`.")
+        .with_help("Use `%` to refer to the value of the left hand side of `|>`")
+        .with_label(span0)
+}
+
+#[cold]
+pub fn pipeline_topic_outside_body(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Topic reference `%` is used outside of a pipe body.").with_label(span0)
+}
+
+#[cold]
+pub fn invalid_json_value(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Invalid JSON value").with_label(span0)
+}
+
+#[cold]
+pub fn json_trailing_comma(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Trailing commas are not allowed in JSON").with_label(span0)
+}
+
 #[cold]
 pub fn static_constructor(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("TS1089: `static` modifier cannot appear on a constructor declaration.")
@@ -0,0 +1,100 @@
+//! Incremental reparsing
+
+use oxc_ast::ast::Program;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::StatementContext, lexer::Kind, ParserImpl, ParserReturn};
+
+/// An edit of source text, replacing `old_length` bytes at `start_byte` with `new_text`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    pub start_byte: u32,
+    pub old_length: u32,
+    pub new_text: String,
+}
+
+impl TextEdit {
+    pub fn new<S: Into<String>>(start_byte: u32, old_length: u32, new_text: S) -> Self {
+        Self { start_byte, old_length, new_text: new_text.into() }
+    }
+
+    /// Apply this edit to `source_text`.
+    ///
+    /// # Panics
+    ///
+    /// * The edited range is out of bounds, or not on UTF-8 character boundaries.
+    pub fn apply(&self, source_text: &str) -> String {
+        let start = self.start_byte as usize;
+        let end = start + self.old_length as usize;
+        let mut text =
+            String::with_capacity(source_text.len() - (end - start) + self.new_text.len());
+        text.push_str(&source_text[..start]);
+        text.push_str(&self.new_text);
+        text.push_str(&source_text[end..]);
+        text
+    }
+}
+
+impl<'a> ParserImpl<'a> {
+    /// Reparse source text after `edit`, reusing top level statements of `previous`
+    /// which precede the edit.
+    ///
+    /// This is conservative: parsing restarts from the statement boundary before the edit.
+    /// The last statement before the edit is always reparsed too, as automatic semicolon
+    /// insertion may have ended it only because of the token which followed it.
+    ///
+    /// Errors and trivias of `previous` which precede the restart are kept, the reused
+    /// statements are moved out of `previous` without copying.
+    ///
+    /// Returns `None` if no statement can be reused, `edit` does not match `previous`,
+    /// or parsing fails with an unrecoverable error. Caller should fall back to a full parse.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn reparse(
+        mut self,
+        previous: ParserReturn<'a>,
+        edit: &TextEdit,
+    ) -> Option<ParserReturn<'a>> {
+        let ParserReturn { program: previous, errors: previous_errors, trivias, .. } = previous;
+        let old_len = previous.span.end as usize;
+        let edit_end = edit.start_byte as usize + edit.old_length as usize;
+        if edit_end > old_len
+            || old_len - edit.old_length as usize + edit.new_text.len() != self.source_text.len()
+        {
+            return None;
+        }
+
+        let reused = previous.body.iter().take_while(|stmt| stmt.span().end < edit.start_byte);
+        let reused_count = reused.count().checked_sub(1).filter(|&count| count > 0)?;
+        let reparse_start = previous.body[reused_count - 1].span().end;
+
+        let Program { directives, hashbang, body: mut statements, .. } = previous;
+        statements.truncate(reused_count);
+
+        self.errors.extend(previous_errors.into_iter().filter(|error| {
+            error.labels.as_ref().is_some_and(|labels| {
+                labels.iter().all(|label| label.offset() + label.len() <= reparse_start as usize)
+            })
+        }));
+        self.lexer.trivia_builder.extend_from(&trivias, reparse_start);
+
+        self.lexer.seek(reparse_start);
+        self.bump_any();
+        while !self.at(Kind::Eof) {
+            let stmt = self.parse_statement_list_item(StatementContext::StatementList).ok()?;
+            statements.push(stmt);
+        }
+
+        let span = Span::new(0, self.source_text.len() as u32);
+        let program = self.ast.program(span, self.source_type, directives, hashbang, statements);
+        let errors = self.lexer.errors.into_iter().chain(self.errors).collect();
+        let trivias = self.lexer.trivia_builder.build();
+        Some(ParserReturn {
+            program,
+            errors,
+            trivias,
+            panicked: false,
+            partial: false,
+            source_map_remapper: None,
+        })
+    }
+}
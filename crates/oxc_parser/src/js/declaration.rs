@@ -166,7 +166,7 @@ impl<'a> ParserImpl<'a> {
         loop {
             let declaration = self.parse_variable_declarator(
                 VariableDeclarationContext::new(VariableDeclarationParent::Statement),
-                VariableDeclarationKind::Var,
+                VariableDeclarationKind::Using,
             )?;
 
             match declaration.id.kind {
@@ -176,6 +176,8 @@ impl<'a> ParserImpl<'a> {
             Kind::Class => self.parse_class_expression(),
             // This
             Kind::This => Ok(self.parse_this_expression()),
+            // Topic reference of a Hack-style pipeline body
+            Kind::Percent if self.enable_pipeline_operator => Ok(self.parse_pipeline_placeholder()),
             // TemplateLiteral
             Kind::NoSubstitutionTemplate | Kind::TemplateHead => {
                 self.parse_template_literal_expression(false)
@@ -236,6 +238,17 @@ impl<'a> ParserImpl<'a> {
         self.ast.this_expression(self.end_span(span))
     }
 
+    fn parse_pipeline_placeholder(&mut self) -> Expression<'a> {
+        let span = self.start_span();
+        self.bump_any();
+        let span = self.end_span(span);
+        match &mut self.state.pipeline_topic_references {
+            Some(count) => *count += 1,
+            None => self.error(diagnostics::pipeline_topic_outside_body(span)),
+        }
+        self.ast.pipeline_placeholder(span)
+    }
+
     /// [Literal Expression](https://tc39.es/ecma262/#prod-Literal)
     /// parses string | true | false | null | number
     pub(crate) fn parse_literal_expression(&mut self) -> Result<Expression<'a>> {
@@ -932,6 +945,33 @@ impl<'a> ParserImpl<'a> {
         Ok(lhs)
     }
 
+    /// Hack-style pipeline expression `lhs |> body`
+    /// <https://tc39.es/proposal-pipeline-operator>
+    ///
+    /// `PipeExpression`[In, Yield, Await] :
+    ///     `ShortCircuitExpression`[?In, ?Yield, ?Await] |> `AssignmentExpression`[?In, ?Yield, ?Await]
+    ///
+    /// The body must contain at least one `%` topic reference, which refers to `lhs`.
+    fn parse_pipeline_expression_rest(
+        &mut self,
+        lhs_span: Span,
+        lhs: Expression<'a>,
+    ) -> Result<Expression<'a>> {
+        self.bump_any(); // bump `|>`
+        let body_span = self.start_span();
+        let outer_topic_references = self.state.pipeline_topic_references.replace(0);
+        let body = self.parse_assignment_expression_or_higher();
+        let topic_references = self.state.pipeline_topic_references.take();
+        self.state.pipeline_topic_references = outer_topic_references;
+        let body = body?;
+        if topic_references == Some(0) {
+            self.error(diagnostics::pipeline_body_without_topic_reference(
+                self.end_span(body_span),
+            ));
+        }
+        Ok(self.ast.binary_expression(self.end_span(lhs_span), lhs, BinaryOperator::Pipeline, body))
+    }
+
     /// Section 13.14 Conditional Expression
     /// `ConditionalExpression`[In, Yield, Await] :
     ///     `ShortCircuitExpression`[?In, ?Yield, ?Await]
@@ -982,6 +1022,11 @@ impl<'a> ParserImpl<'a> {
             return self.parse_assignment_expression_recursive(span, lhs);
         }
 
+        // `a |> f(%)`, only lexed when the pipeline operator is enabled
+        if kind == Kind::PipeGt {
+            return self.parse_pipeline_expression_rest(span, lhs);
+        }
+
         self.parse_conditional_expression_rest(span, lhs)
     }
 
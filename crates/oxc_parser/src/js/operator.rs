@@ -56,6 +56,7 @@ pub fn map_binary_operator(kind: Kind) -> BinaryOperator {
         Kind::In => BinaryOperator::In,
         Kind::Instanceof => BinaryOperator::Instanceof,
         Kind::Star2 => BinaryOperator::Exponential,
+        Kind::PipeGt => BinaryOperator::Pipeline,
         _ => unreachable!("Binary Operator: {kind:?}"),
     }
 }
@@ -40,7 +40,15 @@ impl<'a> ParserImpl<'a> {
             if !is_top_level && self.at(Kind::RCurly) {
                 break;
             }
-            let stmt = self.parse_statement_list_item(StatementContext::StatementList)?;
+            if is_top_level && self.reached_max_errors() {
+                break;
+            }
+            let stmt = match self.parse_statement_list_item(StatementContext::StatementList) {
+                Ok(stmt) => stmt,
+                // `max_errors` was reached inside of this statement, drop it
+                Err(_) if is_top_level && self.partial => break,
+                Err(error) => return Err(error),
+            };
             // Section 11.2.1 Directive Prologue
             // The only way to get a correct directive is to parse the statement first and check if it is a string literal.
             // All other method are flawed, see test cases in [babel](https://github.com/babel/babel/blob/main/packages/babel-parser/test/fixtures/core/categorized/not-directive/input.js)
@@ -73,6 +81,10 @@ impl<'a> ParserImpl<'a> {
         &mut self,
         stmt_ctx: StatementContext,
     ) -> Result<Statement<'a>> {
+        if self.reached_max_errors() {
+            return Err(diagnostics::max_errors_reached(self.cur_token().span()));
+        }
+
         let start_span = self.start_span();
 
         if self.at(Kind::At) {
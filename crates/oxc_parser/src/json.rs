@@ -0,0 +1,164 @@
+//! JSON values
+
+use oxc_ast::ast::*;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::UnaryOperator;
+
+use crate::{diagnostics, ParserImpl};
+
+impl<'a> ParserImpl<'a> {
+    /// Parse source text as a JSON value.
+    ///
+    /// The source text is parsed as an expression, which is then checked to only contain
+    /// JSON syntax.
+    pub fn parse_json_value(self) -> std::result::Result<Expression<'a>, Vec<OxcDiagnostic>> {
+        let source_text = self.source_text;
+        let expr = self.parse_expression()?;
+        let mut validator = JsonValidator { source_text, errors: vec![] };
+        validator.validate_value(&expr);
+        if validator.errors.is_empty() {
+            Ok(expr)
+        } else {
+            Err(validator.errors)
+        }
+    }
+}
+
+struct JsonValidator<'a> {
+    source_text: &'a str,
+    errors: Vec<OxcDiagnostic>,
+}
+
+impl<'a> JsonValidator<'a> {
+    fn validate_value(&mut self, expr: &Expression) {
+        match expr {
+            Expression::NullLiteral(_) | Expression::BooleanLiteral(_) => {}
+            Expression::StringLiteral(lit) => self.validate_string(lit.span),
+            Expression::NumericLiteral(lit) => self.validate_number(lit.span, lit.raw),
+            // `-1`, without whitespace after `-`
+            Expression::UnaryExpression(unary)
+                if unary.operator == UnaryOperator::UnaryNegation
+                    && unary.argument.span().start == unary.span.start + 1 =>
+            {
+                match &unary.argument {
+                    Expression::NumericLiteral(lit) => self.validate_number(unary.span, lit.raw),
+                    _ => self.invalid(unary.span),
+                }
+            }
+            Expression::ArrayExpression(array) => {
+                if let Some(span) = array.trailing_comma {
+                    self.errors.push(diagnostics::json_trailing_comma(span));
+                }
+                for element in &array.elements {
+                    match element {
+                        ArrayExpressionElement::SpreadElement(spread) => self.invalid(spread.span),
+                        ArrayExpressionElement::Elision(elision) => self.invalid(elision.span),
+                        match_expression!(ArrayExpressionElement) => {
+                            self.validate_value(element.to_expression());
+                        }
+                    }
+                }
+            }
+            Expression::ObjectExpression(object) => {
+                if let Some(span) = object.trailing_comma {
+                    self.errors.push(diagnostics::json_trailing_comma(span));
+                }
+                for property in &object.properties {
+                    match property {
+                        ObjectPropertyKind::ObjectProperty(prop)
+                            if prop.kind == PropertyKind::Init
+                                && !prop.method
+                                && !prop.shorthand
+                                && !prop.computed =>
+                        {
+                            match &prop.key {
+                                PropertyKey::StringLiteral(key) => self.validate_string(key.span),
+                                key => self.invalid(key.span()),
+                            }
+                            self.validate_value(&prop.value);
+                        }
+                        ObjectPropertyKind::ObjectProperty(prop) => self.invalid(prop.span),
+                        ObjectPropertyKind::SpreadProperty(spread) => self.invalid(spread.span),
+                    }
+                }
+            }
+            _ => self.invalid(expr.span()),
+        }
+    }
+
+    fn validate_string(&mut self, span: Span) {
+        if !is_json_string(span.source_text(self.source_text)) {
+            self.invalid(span);
+        }
+    }
+
+    fn validate_number(&mut self, span: Span, raw: &str) {
+        if !is_json_number(raw) {
+            self.invalid(span);
+        }
+    }
+
+    fn invalid(&mut self, span: Span) {
+        self.errors.push(diagnostics::invalid_json_value(span));
+    }
+}
+
+/// <https://www.json.org/json-en.html>
+fn is_json_string(raw: &str) -> bool {
+    let Some(content) = raw.strip_prefix('"').and_then(|raw| raw.strip_suffix('"')) else {
+        return false;
+    };
+    let mut chars = content.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('"' | '\\' | '/' | 'b' | 'f' | 'n' | 'r' | 't') => {}
+                Some('u') => {
+                    if !(0..4).all(|_| chars.next().is_some_and(|c| c.is_ascii_hexdigit())) {
+                        return false;
+                    }
+                }
+                _ => return false,
+            },
+            c if c < ' ' => return false,
+            _ => {}
+        }
+    }
+    true
+}
+
+/// <https://www.json.org/json-en.html>
+fn is_json_number(raw: &str) -> bool {
+    let bytes = raw.as_bytes();
+    let skip_digits = |mut i: usize| {
+        while bytes.get(i).is_some_and(u8::is_ascii_digit) {
+            i += 1;
+        }
+        i
+    };
+    let mut i = match bytes.first() {
+        Some(b'0') => 1,
+        Some(b'1'..=b'9') => skip_digits(1),
+        _ => return false,
+    };
+    if bytes.get(i) == Some(&b'.') {
+        let start = i + 1;
+        i = skip_digits(start);
+        if i == start {
+            return false;
+        }
+    }
+    if matches!(bytes.get(i), Some(b'e' | b'E')) {
+        i += 1;
+        if matches!(bytes.get(i), Some(b'+' | b'-')) {
+            i += 1;
+        }
+        let start = i;
+        i = skip_digits(start);
+        if i == start {
+            return false;
+        }
+    }
+    i == bytes.len()
+}
@@ -487,6 +487,8 @@ ascii_byte_handler!(PIP(lexer) {
         }
     } else if lexer.next_eq('=') {
         Kind::PipeEq
+    } else if lexer.pipeline_operator && lexer.next_eq('>') {
+        Kind::PipeGt
     } else {
         Kind::Pipe
     }
@@ -131,6 +131,7 @@ pub enum Kind {
     Pipe2,
     Pipe2Eq,
     PipeEq,
+    PipeGt,
     Plus,
     Plus2,
     PlusEq,
@@ -304,7 +305,7 @@ impl Kind {
     pub fn is_binary_operator(self) -> bool {
         matches!(self, Eq2 | Neq | Eq3 | Neq2 | LAngle | LtEq | RAngle | GtEq | ShiftLeft | ShiftRight
             | ShiftRight3 | Plus | Minus | Star | Slash | Percent | Pipe | Caret | Amp | In
-            | Instanceof | Star2)
+            | Instanceof | Star2 | PipeGt)
     }
 
     pub fn is_logical_operator(self) -> bool {
@@ -582,6 +583,7 @@ impl Kind {
             Pipe2 => "||",
             Pipe2Eq => "||=",
             PipeEq => "|=",
+            PipeGt => "|>",
             Plus => "+",
             Plus2 => "++",
             PlusEq => "+=",
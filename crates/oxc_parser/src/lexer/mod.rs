@@ -97,6 +97,10 @@ pub struct Lexer<'a> {
 
     /// `memchr` Finder for end of multi-line comments. Created lazily when first used.
     multi_line_comment_end_finder: Option<memchr::memmem::Finder<'static>>,
+
+    /// Lex `|>` as a single token, for the pipeline operator.
+    /// Otherwise it is lexed as `|` followed by `>`, as in ordinary JavaScript.
+    pub(crate) pipeline_operator: bool,
 }
 
 #[allow(clippy::unused_self)]
@@ -127,6 +131,7 @@ impl<'a> Lexer<'a> {
             escaped_strings: FxHashMap::default(),
             escaped_templates: FxHashMap::default(),
             multi_line_comment_end_finder: None,
+            pipeline_operator: false,
         }
     }
 
@@ -165,6 +170,15 @@ impl<'a> Lexer<'a> {
         self.lookahead.clear();
     }
 
+    /// Move the lexer to `offset` in source text, to resume lexing from there.
+    ///
+    /// Used by incremental reparsing to skip over statements reused from a previous AST.
+    pub fn seek(&mut self, offset: u32) {
+        self.source.set_offset(offset);
+        self.token = Token::default();
+        self.lookahead.clear();
+    }
+
     /// Find the nth lookahead token lazily
     pub fn lookahead(&mut self, n: u8) -> Token {
         let n = n as usize;
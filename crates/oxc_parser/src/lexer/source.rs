@@ -191,6 +191,23 @@ impl<'a> Source<'a> {
         self.ptr = pos.ptr;
     }
 
+    /// Move current position to `offset` bytes from start of source.
+    ///
+    /// # Panic
+    /// Panics if `offset` is out of bounds, or not on a UTF-8 character boundary.
+    #[inline]
+    pub(super) fn set_offset(&mut self, offset: u32) {
+        let offset = offset as usize;
+        assert!(
+            self.whole().is_char_boundary(offset),
+            "Offset is not on a UTF-8 character boundary"
+        );
+        // SAFETY: `is_char_boundary` returns `false` for offsets past end of source,
+        // so the assertion above guarantees `offset` is within bounds of source,
+        // and position is on a UTF-8 character boundary, upholding `Source`'s invariants.
+        self.ptr = unsafe { self.start.add(offset) };
+    }
+
     /// Advance `Source`'s cursor to end.
     #[inline]
     pub(super) fn advance_to_end(&mut self) {
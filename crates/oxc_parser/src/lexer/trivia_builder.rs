@@ -14,6 +14,15 @@ impl TriviaBuilder {
         Trivias::new(comments, self.irregular_whitespaces)
     }
 
+    /// Add the comments and irregular whitespaces of `trivias` which precede `end`,
+    /// e.g. from the source text reused by an incremental reparse.
+    pub fn extend_from(&mut self, trivias: &Trivias, end: u32) {
+        self.comments
+            .extend(trivias.comments_range(..end).map(|(start, comment)| (*start, *comment)));
+        self.irregular_whitespaces
+            .extend(trivias.irregular_whitespaces().iter().filter(|span| span.end <= end));
+    }
+
     pub fn add_single_line_comment(&mut self, start: u32, end: u32) {
         // skip leading `//`
         self.add_comment(start + 2, Comment::new(end, CommentKind::SingleLine));
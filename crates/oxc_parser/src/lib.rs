@@ -72,6 +72,9 @@ mod jsx;
 mod ts;
 
 mod diagnostics;
+mod incremental;
+mod json;
+mod source_map;
 
 // Expose lexer only in benchmarks
 #[cfg(not(feature = "benchmarking"))]
@@ -83,13 +86,17 @@ pub mod lexer;
 use context::{Context, StatementContext};
 use oxc_allocator::Allocator;
 use oxc_ast::{
-    ast::{Expression, Program},
+    ast::{Expression, Program, Statement},
     AstBuilder, Trivias,
 };
 use oxc_diagnostics::{OxcDiagnostic, Result};
 use oxc_span::{ModuleKind, SourceType, Span};
 
-pub use crate::lexer::Kind; // re-export for codegen
+pub use crate::{
+    incremental::TextEdit,
+    lexer::Kind, // re-export for codegen
+    source_map::{InputSourceMap, OriginalPosition, OriginalSpan, SourceMapRemapper},
+};
 use crate::{
     lexer::{Lexer, Token},
     state::ParserState,
@@ -114,11 +121,26 @@ pub const MAX_LEN: usize = if std::mem::size_of::<usize>() >= 8 {
 /// The parser always return a valid AST.
 /// When `panicked = true`, then program will always be empty.
 /// When `errors.len() > 0`, then program may or may not be empty due to error recovery.
+/// When `partial = true`, parsing stopped early after reaching `max_errors`,
+/// and program only contains the statements parsed up to that point.
 pub struct ParserReturn<'a> {
     pub program: Program<'a>,
     pub errors: Vec<OxcDiagnostic>,
     pub trivias: Trivias,
     pub panicked: bool,
+    pub partial: bool,
+    /// Set by [`Parser::parse_with_source_map`], see [`ParserReturn::remap_span`].
+    pub source_map_remapper: Option<SourceMapRemapper<'a>>,
+}
+
+impl<'a> ParserReturn<'a> {
+    /// Map `span` in the parsed source text back to the original source.
+    ///
+    /// Returns `None` if the program was not parsed with [`Parser::parse_with_source_map`],
+    /// or if `span` is not covered by the input source map.
+    pub fn remap_span(&self, span: Span) -> Option<OriginalSpan<'a>> {
+        self.source_map_remapper.as_ref()?.remap_span(span)
+    }
 }
 
 /// Parser options
@@ -133,11 +155,25 @@ struct ParserOptions {
     ///
     /// Default: true
     pub preserve_parens: bool,
+    /// Stop parsing once this many errors have been reported.
+    /// A program without any errors is always parsed completely.
+    ///
+    /// Default: `usize::MAX`
+    pub max_errors: usize,
+    /// Parse the Hack-style pipeline operator `a |> f(%)`.
+    ///
+    /// Default: false
+    pub enable_pipeline_operator: bool,
 }
 
 impl Default for ParserOptions {
     fn default() -> Self {
-        Self { allow_return_outside_function: false, preserve_parens: true }
+        Self {
+            allow_return_outside_function: false,
+            preserve_parens: true,
+            max_errors: usize::MAX,
+            enable_pipeline_operator: false,
+        }
     }
 }
 
@@ -177,6 +213,32 @@ impl<'a> Parser<'a> {
         self.options.preserve_parens = allow;
         self
     }
+
+    /// Stop parsing after `max_errors` errors have been reported.
+    ///
+    /// Parsing stops before the next statement, including statements nested in blocks and
+    /// function bodies. The top level statement which was not parsed completely and the
+    /// remaining top level statements are skipped, and [`ParserReturn::partial`] is set
+    /// to indicate that the returned program only contains the statements parsed so far.
+    ///
+    /// A program without any errors is always parsed completely.
+    #[must_use]
+    pub fn max_errors(mut self, max_errors: usize) -> Self {
+        self.options.max_errors = max_errors;
+        self
+    }
+
+    /// Enable the Hack-style pipeline operator proposal.
+    ///
+    /// When enabled, `a |> f(%)` is parsed as a [`BinaryOperator::Pipeline`] expression,
+    /// and `%` in the right hand side is parsed as a `PipelinePlaceholder` topic reference.
+    ///
+    /// [`BinaryOperator::Pipeline`]: oxc_syntax::operator::BinaryOperator::Pipeline
+    #[must_use]
+    pub fn enable_pipeline_operator(mut self, enable: bool) -> Self {
+        self.options.enable_pipeline_operator = enable;
+        self
+    }
 }
 
 mod parser_parse {
@@ -232,11 +294,50 @@ mod parser_parse {
             parser.parse()
         }
 
+        /// Parse transpiled source text, with the source map to its original source.
+        ///
+        /// Spans in the returned program and errors refer to the parsed source text,
+        /// use [`ParserReturn::remap_span`] to map them back to the original source.
+        pub fn parse_with_source_map(self, source_map: &'a InputSourceMap) -> ParserReturn<'a> {
+            let source_text = self.source_text;
+            let mut ret = self.parse();
+            ret.source_map_remapper = Some(SourceMapRemapper::new(source_text, source_map));
+            ret
+        }
+
+        /// Reparse source text after `edit` was applied to the source text of `previous`.
+        ///
+        /// The source text given to [`Parser::new`] must be the edited source text,
+        /// see [`TextEdit::apply`].
+        ///
+        /// Top level statements before the edit are moved out of `previous`, together with
+        /// their errors and trivias, and the rest of the source text is reparsed.
+        /// Falls back to a full parse if nothing can be reused.
+        pub fn reparse(self, previous: ParserReturn<'a>, edit: &TextEdit) -> ParserReturn<'a> {
+            let unique = UniquePromise::new();
+            let parser = ParserImpl::new(
+                self.allocator,
+                self.source_text,
+                self.source_type,
+                self.options,
+                unique,
+            );
+            // `parser` is consumed, so a new `UniquePromise` can be created for the full parse
+            if let Some(ret) = parser.reparse(previous, edit) {
+                return ret;
+            }
+            self.parse()
+        }
+
         /// Parse `Expression`
         ///
+        /// The whole source text must be a single expression, only whitespace and comments may
+        /// follow it. `a b` or `a;` are rejected instead of returning `a`.
+        ///
         /// # Errors
         ///
         /// * Syntax Error
+        /// * Tokens after the expression
         pub fn parse_expression(self) -> std::result::Result<Expression<'a>, Vec<OxcDiagnostic>> {
             let unique = UniquePromise::new();
             let parser = ParserImpl::new(
@@ -248,6 +349,48 @@ mod parser_parse {
             );
             parser.parse_expression()
         }
+
+        /// Parse a single `Statement`, e.g. for a REPL
+        ///
+        /// Like [`Parser::parse_expression`], only whitespace and comments may follow the statement.
+        ///
+        /// # Errors
+        ///
+        /// * Syntax Error
+        /// * Tokens after the statement
+        pub fn parse_statement(self) -> std::result::Result<Statement<'a>, Vec<OxcDiagnostic>> {
+            let unique = UniquePromise::new();
+            let parser = ParserImpl::new(
+                self.allocator,
+                self.source_text,
+                self.source_type,
+                self.options,
+                unique,
+            );
+            parser.parse_statement()
+        }
+
+        /// Parse source text as a JSON value, e.g. the contents of `package.json`
+        ///
+        /// Objects, arrays, strings, numbers, `true`, `false` and `null` are returned as their
+        /// `Expression` counterparts. JavaScript only syntax is rejected, such as single quoted
+        /// strings, identifiers, trailing commas or functions. Comments are ignored.
+        ///
+        /// # Errors
+        ///
+        /// * Syntax Error
+        /// * The expression is not a valid JSON value
+        pub fn parse_json_value(self) -> std::result::Result<Expression<'a>, Vec<OxcDiagnostic>> {
+            let unique = UniquePromise::new();
+            let parser = ParserImpl::new(
+                self.allocator,
+                self.source_text,
+                self.source_type,
+                self.options,
+                unique,
+            );
+            parser.parse_json_value()
+        }
     }
 }
 use parser_parse::UniquePromise;
@@ -285,6 +428,17 @@ struct ParserImpl<'a> {
     /// Emit `ParenthesizedExpression` in AST.
     /// Default: `true`
     preserve_parens: bool,
+
+    /// Stop parsing before the next statement once this many errors have been reported.
+    /// Default: `usize::MAX`
+    max_errors: usize,
+
+    /// Set when parsing stopped early because `max_errors` was reached
+    partial: bool,
+
+    /// Parse the Hack-style pipeline operator `|>` and its `%` topic reference.
+    /// Default: `false`
+    enable_pipeline_operator: bool,
 }
 
 impl<'a> ParserImpl<'a> {
@@ -300,8 +454,10 @@ impl<'a> ParserImpl<'a> {
         options: ParserOptions,
         unique: UniquePromise,
     ) -> Self {
+        let mut lexer = Lexer::new(allocator, source_text, source_type, unique);
+        lexer.pipeline_operator = options.enable_pipeline_operator;
         Self {
-            lexer: Lexer::new(allocator, source_text, source_type, unique),
+            lexer,
             source_type,
             source_text,
             errors: vec![],
@@ -311,6 +467,9 @@ impl<'a> ParserImpl<'a> {
             ctx: Self::default_context(source_type, options),
             ast: AstBuilder::new(allocator),
             preserve_parens: options.preserve_parens,
+            max_errors: options.max_errors,
+            partial: false,
+            enable_pipeline_operator: options.enable_pipeline_operator,
         }
     }
 
@@ -338,13 +497,23 @@ impl<'a> ParserImpl<'a> {
         };
         let errors = self.lexer.errors.into_iter().chain(self.errors).collect();
         let trivias = self.lexer.trivia_builder.build();
-        ParserReturn { program, errors, trivias, panicked }
+        ParserReturn {
+            program,
+            errors,
+            trivias,
+            panicked,
+            partial: self.partial,
+            source_map_remapper: None,
+        }
     }
 
     pub fn parse_expression(mut self) -> std::result::Result<Expression<'a>, Vec<OxcDiagnostic>> {
         // initialize cur_token and prev_token by moving onto the first token
         self.bump_any();
-        let expr = self.parse_expr().map_err(|diagnostic| vec![diagnostic])?;
+        let expr = self
+            .parse_expr()
+            .and_then(|expr| self.expect(Kind::Eof).map(|()| expr))
+            .map_err(|diagnostic| vec![diagnostic])?;
         let errors = self.lexer.errors.into_iter().chain(self.errors).collect::<Vec<_>>();
         if !errors.is_empty() {
             return Err(errors);
@@ -352,6 +521,20 @@ impl<'a> ParserImpl<'a> {
         Ok(expr)
     }
 
+    pub fn parse_statement(mut self) -> std::result::Result<Statement<'a>, Vec<OxcDiagnostic>> {
+        // initialize cur_token and prev_token by moving onto the first token
+        self.bump_any();
+        let stmt = self
+            .parse_statement_list_item(StatementContext::StatementList)
+            .and_then(|stmt| self.expect(Kind::Eof).map(|()| stmt))
+            .map_err(|diagnostic| vec![diagnostic])?;
+        let errors = self.lexer.errors.into_iter().chain(self.errors).collect::<Vec<_>>();
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        Ok(stmt)
+    }
+
     #[allow(clippy::cast_possible_truncation)]
     fn parse_program(&mut self) -> Result<Program<'a>> {
         // initialize cur_token and prev_token by moving onto the first token
@@ -415,12 +598,28 @@ impl<'a> ParserImpl<'a> {
     /// Push a Syntax Error
     fn error(&mut self, error: OxcDiagnostic) {
         self.errors.push(error);
+        self.check_max_errors();
     }
 
     fn errors_count(&self) -> usize {
         self.errors.len() + self.lexer.errors.len()
     }
 
+    /// Set `partial` once `max_errors` errors have been reported.
+    /// A program without any errors is always parsed completely, even with `max_errors = 0`.
+    fn check_max_errors(&mut self) {
+        let errors_count = self.errors_count();
+        if errors_count > 0 && errors_count >= self.max_errors {
+            self.partial = true;
+        }
+    }
+
+    /// Errors pushed by the lexer are only counted here, at the start of each statement.
+    fn reached_max_errors(&mut self) -> bool {
+        self.check_max_errors();
+        self.partial
+    }
+
     fn ts_enabled(&self) -> bool {
         self.source_type.is_typescript()
     }
@@ -430,7 +629,12 @@ impl<'a> ParserImpl<'a> {
 mod test {
     use std::path::Path;
 
-    use oxc_ast::{ast::Expression, CommentKind};
+    use oxc_ast::{
+        ast::{Expression, Statement},
+        CommentKind,
+    };
+    use oxc_span::GetSpan;
+    use oxc_syntax::operator::BinaryOperator;
 
     use super::*;
 
@@ -451,6 +655,255 @@ mod test {
         let source = "a";
         let expr = Parser::new(&allocator, source, source_type).parse_expression().unwrap();
         assert!(matches!(expr, Expression::Identifier(_)));
+
+        assert!(Parser::new(&allocator, "a b", source_type).parse_expression().is_err());
+        let source_type = SourceType::default().with_jsx(true);
+        let expr = Parser::new(&allocator, "<div />", source_type).parse_expression().unwrap();
+        assert!(matches!(expr, Expression::JSXElement(_)));
+    }
+
+    #[test]
+    fn parse_expression_trailing_input() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        for source in ["a b", "a;", "a, b;", "(a))"] {
+            let errors =
+                Parser::new(&allocator, source, source_type).parse_expression().unwrap_err();
+            assert_eq!(errors.len(), 1, "{source}");
+            assert!(errors[0].to_string().starts_with("Expected `EOF` but found"), "{source}");
+        }
+        for source in ["a ", "a // comment", "a /* comment */\n", "a, b"] {
+            assert!(
+                Parser::new(&allocator, source, source_type).parse_expression().is_ok(),
+                "{source}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_statement_smoke_test() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let stmt = Parser::new(&allocator, "let a = 1;", source_type).parse_statement().unwrap();
+        assert!(matches!(stmt, Statement::VariableDeclaration(_)));
+
+        assert!(Parser::new(&allocator, "a; b;", source_type).parse_statement().is_err());
+        let source_type = SourceType::default().with_typescript(true);
+        let stmt =
+            Parser::new(&allocator, "type A = string;", source_type).parse_statement().unwrap();
+        assert!(matches!(stmt, Statement::TSTypeAliasDeclaration(_)));
+    }
+
+    #[test]
+    fn max_errors() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "const a; const b; const c; d;";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(!ret.partial);
+        assert_eq!(ret.errors.len(), 3);
+        assert_eq!(ret.program.body.len(), 4);
+
+        let ret = Parser::new(&allocator, source, source_type).max_errors(2).parse();
+        assert!(ret.partial);
+        assert!(!ret.panicked);
+        assert_eq!(ret.errors.len(), 2);
+        assert_eq!(ret.program.body.len(), 2);
+
+        // Valid programs are parsed completely
+        let ret = Parser::new(&allocator, "a; b; c;", source_type).max_errors(0).parse();
+        assert!(!ret.partial);
+        assert!(ret.errors.is_empty());
+        assert_eq!(ret.program.body.len(), 3);
+
+        // Nested statements stop parsing too, the unfinished top level statement is dropped
+        let source = "a; function f() { const b; c; d; } e;";
+        let ret = Parser::new(&allocator, source, source_type).max_errors(1).parse();
+        assert!(ret.partial);
+        assert!(!ret.panicked);
+        assert_eq!(ret.errors.len(), 1);
+        assert_eq!(ret.program.body.len(), 1);
+    }
+
+    #[test]
+    fn parse_with_source_map() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        // Generated from `let a: number = 1;\nlet b = a;` in `input.ts`
+        let source_map = InputSourceMap::from_json_string(
+            r#"{"version":3,"sources":["input.ts"],"names":[],"mappings":"AAAA,IAAI,IAAY;AAChB,IAAI,IAAI"}"#,
+        )
+        .unwrap();
+        let source = "let a = 1;\nlet b = a;";
+        let ret = Parser::new(&allocator, source, source_type).parse_with_source_map(&source_map);
+        assert!(ret.errors.is_empty());
+
+        // `1` on the first line
+        let span = Span::new(8, 9);
+        let original = ret.remap_span(span).unwrap();
+        assert_eq!(original.source, Some("input.ts"));
+        assert_eq!(original.start, OriginalPosition { line: 0, column: 16 });
+
+        // `b` on the second line
+        let span = Span::new(15, 16);
+        let original = ret.remap_span(span).unwrap();
+        assert_eq!(original.start, OriginalPosition { line: 1, column: 4 });
+
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.remap_span(span).is_none());
+    }
+
+    #[test]
+    fn reparse() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "'use strict';\nlet a = 1;\nlet b = 2;\nfoo(a)\nlet c = 3;";
+        let previous = Parser::new(&allocator, source, source_type).parse();
+
+        // `foo(a)` -> `foo(a) + bar(b)`
+        let edit = TextEdit::new(42, 0, " + bar(b)");
+        let source = allocator.alloc_str(&edit.apply(source));
+        let ret = Parser::new(&allocator, source, source_type).reparse(previous, &edit);
+        let full = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.errors.is_empty());
+        assert_eq!(ret.program.directives.len(), 1);
+        assert_eq!(ret.program.body.len(), 4);
+        assert_eq!(ret.program.span, full.program.span);
+        for (stmt, expected) in ret.program.body.iter().zip(full.program.body.iter()) {
+            assert_eq!(stmt.span(), expected.span());
+        }
+
+        // Edits in the first statement fall back to a full parse
+        let edit = TextEdit::new(18, 1, "x");
+        let source = allocator.alloc_str(&edit.apply(source));
+        let ret = Parser::new(&allocator, source, source_type).reparse(full, &edit);
+        assert_eq!(ret.program.body.len(), 4);
+        assert_eq!(ret.program.directives.len(), 1);
+    }
+
+    #[test]
+    fn reparse_trivias_and_errors() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "// a\nconst a;\n/* b */ let b = 2;\nfoo(a) // c\nlet c = 3;";
+        let previous = Parser::new(&allocator, source, source_type).parse();
+        assert_eq!(previous.errors.len(), 1);
+
+        // `foo(a)` -> `foo(a, b)`, `const a;` is reused
+        let edit = TextEdit::new(38, 0, ", b");
+        let source = allocator.alloc_str(&edit.apply(source));
+        let ret = Parser::new(&allocator, source, source_type).reparse(previous, &edit);
+        let full = Parser::new(&allocator, source, source_type).parse();
+        assert_eq!(ret.program.body.len(), full.program.body.len());
+        assert_eq!(
+            ret.errors.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            full.errors.iter().map(ToString::to_string).collect::<Vec<_>>(),
+        );
+        assert_eq!(
+            ret.trivias.comments().collect::<Vec<_>>(),
+            full.trivias.comments().collect::<Vec<_>>(),
+        );
+        assert_eq!(ret.trivias.comments().count(), 3);
+    }
+
+    #[test]
+    fn pipeline_operator() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let parse = |source| {
+            Parser::new(&allocator, source, source_type).enable_pipeline_operator(true).parse()
+        };
+
+        let pipe_body_is = |source, is: fn(&Expression) -> bool| {
+            let ret = parse(source);
+            assert!(ret.errors.is_empty(), "{source}");
+            let Statement::ExpressionStatement(stmt) = &ret.program.body[0] else { unreachable!() };
+            let Expression::BinaryExpression(pipe) = &stmt.expression else { unreachable!() };
+            assert_eq!(pipe.operator, BinaryOperator::Pipeline);
+            is(&pipe.right)
+        };
+
+        // The body is an `AssignmentExpression`: `a |> (f(%) |> g(%))`
+        assert!(pipe_body_is("a |> f(%) |> g(%)", |body| matches!(
+            body,
+            Expression::BinaryExpression(body) if body.operator == BinaryOperator::Pipeline
+        )));
+        assert!(pipe_body_is("a |> % ? b : c", |body| matches!(
+            body,
+            Expression::ConditionalExpression(_)
+        )));
+        assert!(pipe_body_is("a |> x => x + %", |body| matches!(
+            body,
+            Expression::ArrowFunctionExpression(_)
+        )));
+        assert!(pipe_body_is("a |> (x) => %", |body| matches!(
+            body,
+            Expression::ArrowFunctionExpression(_)
+        )));
+        assert!(pipe_body_is("a |> x = %", |body| matches!(
+            body,
+            Expression::AssignmentExpression(_)
+        )));
+        assert!(parse("function* f() { a |> yield % }").errors.is_empty());
+        // The head is a `ShortCircuitExpression`
+        assert!(parse("a ? b : c |> %").errors.is_empty());
+        assert!(parse("a ?? b |> % || c").errors.is_empty());
+
+        // Nested pipeline, the topic of the inner pipeline shadows the outer one
+        assert!(parse("a |> f(% |> g(%, 1))").errors.is_empty());
+        assert!(parse("a |> % % 2").errors.is_empty());
+
+        // Missing topic reference, the pipeline is still parsed
+        let ret = parse("a |> f(b)");
+        assert_eq!(ret.errors.len(), 1);
+        assert!(!ret.panicked);
+        assert_eq!(ret.program.body.len(), 1);
+        assert!(!parse("a |> f(% |> g(b))").errors.is_empty());
+
+        // Topic reference outside of a pipe body
+        assert_eq!(parse("f(%)").errors.len(), 1);
+
+        // Disabled by default, `|>` is lexed as `|` followed by `>`
+        let ret = Parser::new(&allocator, "a |> f(%)", source_type).parse();
+        assert_eq!(ret.errors.len(), 1);
+        assert_eq!(ret.errors[0].to_string(), "Unexpected token");
+        let ret = Parser::new(&allocator, "a | > b", source_type).parse();
+        assert_eq!(ret.errors.len(), 1);
+        assert_eq!(ret.errors[0].to_string(), "Unexpected token");
+    }
+
+    #[test]
+    fn parse_json_value() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = r#"{ "name": "oxc", "version": 1.5e3, "private": true, "files": [null, -1, "\u00e9\n"], "a": {} }"#;
+        let expr = Parser::new(&allocator, source, source_type).parse_json_value().unwrap();
+        let Expression::ObjectExpression(object) = expr else { unreachable!() };
+        assert_eq!(object.properties.len(), 5);
+
+        let invalid = [
+            "{ name: 1 }",
+            "{ 'name': 1 }",
+            r#"{ "a": 1, }"#,
+            "[1, 2,]",
+            "[1, , 2]",
+            "[...a]",
+            r#"{ "a": function() {} }"#,
+            "`a`",
+            "undefined",
+            "01",
+            ".5",
+            "0x10",
+            "- 1",
+            "+1",
+            "1n",
+            r#""\x41""#,
+            "1, 2",
+        ];
+        for source in invalid {
+            let ret = Parser::new(&allocator, source, source_type).parse_json_value();
+            assert!(ret.is_err(), "{source}");
+        }
     }
 
     #[test]
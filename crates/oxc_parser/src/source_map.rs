@@ -0,0 +1,98 @@
+//! Remapping spans of transpiled source text back to the original source.
+
+use oxc_span::Span;
+use oxc_syntax::identifier::{LS, PS};
+
+/// A parsed v3 source map of the source text given to the parser.
+pub use oxc_sourcemap::SourceMap as InputSourceMap;
+
+/// Line and column in the original source, both 0-based.
+///
+/// Columns are counted in UTF-16 code units, the same as Mozilla's "source-map" library.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OriginalPosition {
+    pub line: u32,
+    pub column: u32,
+}
+
+/// A span mapped back to the original source by [`SourceMapRemapper::remap_span`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OriginalSpan<'a> {
+    /// Path of the original source, from the `sources` field of the source map
+    pub source: Option<&'a str>,
+    pub start: OriginalPosition,
+    pub end: OriginalPosition,
+}
+
+/// Maps spans in the parsed source text to spans in the original source,
+/// using an [`InputSourceMap`].
+pub struct SourceMapRemapper<'a> {
+    source_map: &'a InputSourceMap,
+    source_text: &'a str,
+    lookup_table: Vec<(u32, u32, u32)>,
+    /// Byte offset of the start of each line in `source_text`
+    line_starts: Vec<u32>,
+}
+
+impl<'a> SourceMapRemapper<'a> {
+    pub fn new(source_text: &'a str, source_map: &'a InputSourceMap) -> Self {
+        let lookup_table = source_map.generate_lookup_table();
+        let line_starts = Self::line_starts(source_text);
+        Self { source_map, source_text, lookup_table, line_starts }
+    }
+
+    /// Map `span` back to the original source.
+    ///
+    /// Returns `None` if the start of `span` is not covered by any mapping.
+    /// If the end of `span` is not covered by a mapping into the same source,
+    /// the original span is empty.
+    pub fn remap_span(&self, span: Span) -> Option<OriginalSpan<'a>> {
+        let (line, column) = self.line_and_column(span.start)?;
+        let token = self.source_map.lookup_token(&self.lookup_table, line, column)?;
+        let source_id = token.get_source_id();
+        let source = source_id.and_then(|id| self.source_map.get_source(id));
+        let start = OriginalPosition { line: token.get_src_line(), column: token.get_src_col() };
+        let end = self
+            .line_and_column(span.end)
+            .and_then(|(line, column)| {
+                self.source_map.lookup_token(&self.lookup_table, line, column)
+            })
+            .filter(|token| token.get_source_id() == source_id)
+            .map_or(start, |token| OriginalPosition {
+                line: token.get_src_line(),
+                column: token.get_src_col(),
+            });
+        Some(OriginalSpan { source, start, end })
+    }
+
+    /// Convert a byte offset into a 0-based line and UTF-16 column.
+    #[allow(clippy::cast_possible_truncation)]
+    fn line_and_column(&self, offset: u32) -> Option<(u32, u32)> {
+        let line = self.line_starts.partition_point(|&start| start <= offset) - 1;
+        let line_start = self.line_starts[line] as usize;
+        let column = self.source_text.get(line_start..offset as usize)?.encode_utf16().count();
+        Some((line as u32, column as u32))
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn line_starts(source_text: &str) -> Vec<u32> {
+        let mut line_starts = vec![0];
+        let mut chars = source_text.char_indices().peekable();
+        while let Some((offset, ch)) = chars.next() {
+            match ch {
+                '\r' => {
+                    // Handle Windows-specific "\r\n" newlines
+                    if chars.next_if(|&(_, ch)| ch == '\n').is_some() {
+                        line_starts.push(offset as u32 + 2);
+                    } else {
+                        line_starts.push(offset as u32 + 1);
+                    }
+                }
+                '\n' => line_starts.push(offset as u32 + 1),
+                LS | PS => line_starts.push(offset as u32 + 3),
+                _ => {}
+            }
+        }
+        line_starts
+    }
+}
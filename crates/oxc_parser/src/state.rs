@@ -6,4 +6,8 @@ pub struct ParserState<'a> {
     pub not_parenthesized_arrow: FxHashSet<u32>,
 
     pub decorators: Vec<Decorator<'a>>,
+
+    /// Number of `%` topic references in the pipeline body being parsed,
+    /// `None` outside of a pipeline body.
+    pub pipeline_topic_references: Option<u32>,
 }
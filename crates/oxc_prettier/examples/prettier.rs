@@ -17,6 +17,7 @@ fn main() -> std::io::Result<()> {
 
     let name = args.subcommand().ok().flatten().unwrap_or_else(|| String::from("test.js"));
     let semi = !args.contains("--no-semi");
+    let print_width = args.opt_value_from_str("--print-width").unwrap().unwrap_or(80);
 
     let path = Path::new(&name);
     let source_text = std::fs::read_to_string(path)?;
@@ -27,7 +28,12 @@ fn main() -> std::io::Result<()> {
         &allocator,
         &source_text,
         ret.trivias,
-        PrettierOptions { semi, trailing_comma: TrailingComma::All, ..PrettierOptions::default() },
+        PrettierOptions {
+            semi,
+            print_width,
+            trailing_comma: TrailingComma::All,
+            ..PrettierOptions::default()
+        },
     )
     .build(&ret.program);
     println!("{output}");
@@ -0,0 +1,30 @@
+use std::path::Path;
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::SourceType;
+use rayon::prelude::*;
+
+use crate::{format_source, PrettierOptions};
+
+// Options are shared by all formatting threads.
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<PrettierOptions>();
+};
+
+/// Format many files concurrently.
+///
+/// The source type of each file is inferred from its path, falling back to JavaScript.
+/// Each file is parsed with its own allocator, and results are returned in the order of `files`.
+pub fn format_files_parallel(
+    files: &[(&Path, &str)],
+    options: PrettierOptions,
+) -> Vec<Result<String, Vec<OxcDiagnostic>>> {
+    files
+        .par_iter()
+        .map(|(path, source_text)| {
+            let source_type = SourceType::from_path(path).unwrap_or_default();
+            format_source(source_text, source_type, options)
+        })
+        .collect()
+}
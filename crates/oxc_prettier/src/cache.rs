@@ -0,0 +1,128 @@
+use std::{
+    cell::Cell,
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    sync::{Arc, Mutex},
+};
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::SourceType;
+use rustc_hash::{FxHashMap, FxHasher};
+
+use crate::{format_source, PrettierOptions};
+
+/// Formatted outputs keyed by the hash of the source text, the source type and the options,
+/// so unchanged files are not formatted again.
+///
+/// Source texts are not kept, each entry is verified on a hit with a second, independent hash
+/// of its inputs, so a collision formats the source text again instead of returning the output
+/// of another file.
+/// The cache holds at most [`FormatterCache::DEFAULT_MAX_ENTRIES`] outputs by default,
+/// the least recently used output is evicted when it is full.
+/// Sources with syntax errors are not cached.
+#[derive(Debug)]
+pub struct FormatterCache {
+    entries: FxHashMap<u64, CacheEntry>,
+    max_entries: usize,
+    /// Incremented on every lookup, to find the least recently used entry
+    clock: u64,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    /// Hash of the inputs with a different hasher than the key
+    checksum: u64,
+    last_used: Cell<u64>,
+    output: String,
+}
+
+impl Default for FormatterCache {
+    fn default() -> Self {
+        Self::with_max_entries(Self::DEFAULT_MAX_ENTRIES)
+    }
+}
+
+impl FormatterCache {
+    pub const DEFAULT_MAX_ENTRIES: usize = 1024;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a cache which holds at most `max_entries` outputs, at least one.
+    pub fn with_max_entries(max_entries: usize) -> Self {
+        Self { entries: FxHashMap::default(), max_entries: max_entries.max(1), clock: 0 }
+    }
+
+    /// Return the cached output for `source_text`, or format it and cache the output.
+    ///
+    /// # Errors
+    ///
+    /// Returns the parser diagnostics if the source text has syntax errors.
+    pub fn format_or_cached(
+        &mut self,
+        source_text: &str,
+        source_type: SourceType,
+        options: &PrettierOptions,
+    ) -> Result<&str, Vec<OxcDiagnostic>> {
+        let key = Self::hash(FxHasher::default(), source_text, source_type, options);
+        let checksum = Self::hash(DefaultHasher::new(), source_text, source_type, options);
+        self.clock += 1;
+        let is_hit = self.entries.get(&key).is_some_and(|entry| entry.checksum == checksum);
+        if !is_hit {
+            let output = format_source(source_text, source_type, *options)?;
+            if !self.entries.contains_key(&key) && self.entries.len() >= self.max_entries {
+                self.evict_least_recently_used();
+            }
+            self.entries.insert(key, CacheEntry { checksum, last_used: Cell::new(0), output });
+        }
+        let entry = &self.entries[&key];
+        entry.last_used.set(self.clock);
+        Ok(entry.output.as_str())
+    }
+
+    /// Whether the output for `source_text` is cached, without marking it as recently used.
+    pub fn contains(
+        &self,
+        source_text: &str,
+        source_type: SourceType,
+        options: &PrettierOptions,
+    ) -> bool {
+        let key = Self::hash(FxHasher::default(), source_text, source_type, options);
+        let checksum = Self::hash(DefaultHasher::new(), source_text, source_type, options);
+        self.entries.get(&key).is_some_and(|entry| entry.checksum == checksum)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Share the cache between threads.
+    pub fn into_shared(self) -> Arc<Mutex<Self>> {
+        Arc::new(Mutex::new(self))
+    }
+
+    fn evict_least_recently_used(&mut self) {
+        let least_recently_used =
+            self.entries.iter().min_by_key(|(_, entry)| entry.last_used.get()).map(|(key, _)| *key);
+        if let Some(key) = least_recently_used {
+            self.entries.remove(&key);
+        }
+    }
+
+    fn hash(
+        mut hasher: impl Hasher,
+        source_text: &str,
+        source_type: SourceType,
+        options: &PrettierOptions,
+    ) -> u64 {
+        source_text.hash(&mut hasher);
+        source_type.hash(&mut hasher);
+        options.hash(&mut hasher);
+        hasher.finish()
+    }
+}
@@ -0,0 +1,119 @@
+//! JSDoc comment formatting
+//!
+//! References:
+//! * <https://github.com/prettier/prettier/blob/main/src/language-js/print/comment.js>
+//! * <https://github.com/hosseinmd/prettier-plugin-jsdoc>
+
+use crate::PrettierOptions;
+
+/// Whether the comment is a multi-line `/** ... */` comment where every line starts with `*`.
+pub fn is_jsdoc_comment(comment: &str) -> bool {
+    comment.starts_with("/**")
+        && comment.len() > "/**/".len()
+        && comment.contains('\n')
+        && comment.lines().skip(1).all(|line| line.trim_start().starts_with('*'))
+}
+
+/// Normalize a JSDoc comment.
+///
+/// * ` * ` prefixes are aligned.
+/// * Leading, trailing and consecutive blank lines are removed.
+/// * Whitespace in tags is normalized, e.g. `@param   { string }  a` becomes `@param {string} a`.
+/// * Tags longer than `print_width` are wrapped.
+/// * Code blocks fenced by ```` ``` ```` are kept as is.
+pub fn format_jsdoc_comment(comment: &str, options: &PrettierOptions) -> String {
+    let content = &comment["/**".len()..comment.len() - "*/".len()];
+    let width = options.print_width.saturating_sub(" * ".len());
+
+    let mut lines: Vec<String> = vec![];
+    let mut in_code_block = false;
+    for line in content.lines() {
+        let line = line.trim_start();
+        let line = line.strip_prefix('*').unwrap_or(line);
+        let line = line.strip_prefix(' ').unwrap_or(line).trim_end();
+
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            lines.push(line.trim_start().to_string());
+            continue;
+        }
+        if in_code_block {
+            lines.push(line.to_string());
+            continue;
+        }
+
+        let line = line.trim_start();
+        if line.is_empty() {
+            if lines.last().is_some_and(|last| !last.is_empty()) {
+                lines.push(String::new());
+            }
+        } else if line.starts_with('@') {
+            wrap_words(&tag_words(line), width, &mut lines);
+        } else {
+            lines.push(line.to_string());
+        }
+    }
+    while lines.last().is_some_and(String::is_empty) {
+        lines.pop();
+    }
+
+    let mut result = String::from("/**");
+    for line in &lines {
+        result.push('\n');
+        if line.is_empty() {
+            result.push_str(" *");
+        } else {
+            result.push_str(" * ");
+            result.push_str(line);
+        }
+    }
+    result.push_str("\n */");
+    result
+}
+
+/// Split a tag line into words, the tag name and its `{type}` are kept in a single word.
+fn tag_words(line: &str) -> Vec<String> {
+    let (tag, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+    let mut head = tag.to_string();
+    let mut rest = rest.trim_start();
+    if let Some(ty) = rest.strip_prefix('{') {
+        if let Some(end) = find_closing_brace(ty) {
+            head.push_str(" {");
+            head.push_str(&ty[..end].split_whitespace().collect::<Vec<_>>().join(" "));
+            head.push('}');
+            rest = &ty[end + 1..];
+        }
+    }
+    let mut words = vec![head];
+    words.extend(rest.split_whitespace().map(ToString::to_string));
+    words
+}
+
+fn find_closing_brace(text: &str) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in text.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' if depth == 0 => return Some(i),
+            '}' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+fn wrap_words(words: &[String], width: usize, lines: &mut Vec<String>) {
+    let mut current = String::new();
+    for word in words {
+        if !current.is_empty() && current.len() + 1 + word.len() > width {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+}
@@ -1,3 +1,4 @@
+mod jsdoc;
 mod print;
 
 use bitflags::bitflags;
@@ -1,7 +1,7 @@
 use oxc_allocator::Vec;
 use oxc_span::Span;
 
-use super::{Comment, CommentFlags, DanglingCommentsPrintOptions};
+use super::{jsdoc, Comment, CommentFlags, DanglingCommentsPrintOptions};
 use crate::{
     array,
     doc::{Doc, DocBuilder, Separator},
@@ -237,6 +237,18 @@ impl<'a> Prettier<'a> {
 
     #[must_use]
     fn print_comment(&self, comment: Comment) -> Doc<'a> {
-        Doc::Str(Span::new(comment.start, comment.end).source_text(self.source_text))
+        let text = Span::new(comment.start, comment.end).source_text(self.source_text);
+        if comment.is_block && jsdoc::is_jsdoc_comment(text) {
+            let formatted = jsdoc::format_jsdoc_comment(text, &self.options);
+            let mut parts = self.vec();
+            for (i, line) in formatted.lines().enumerate() {
+                if i > 0 {
+                    parts.extend(hardline!());
+                }
+                parts.push(self.str(line));
+            }
+            return Doc::Array(parts);
+        }
+        Doc::Str(text)
     }
 }
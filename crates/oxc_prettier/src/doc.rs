@@ -39,6 +39,8 @@ pub enum Doc<'a> {
     Fill(Fill<'a>),
     /// Include this anywhere to force all parent groups to break.
     BreakParent,
+    /// Placeholder for the cursor position, see [crate::Prettier::build_with_cursor].
+    Cursor,
 }
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
@@ -339,7 +341,120 @@ fn print_doc_to_debug(doc: &Doc<'_>) -> std::string::String {
         Doc::BreakParent => {
             string.push_str("BreakParent");
         }
+        Doc::Cursor => {
+            string.push_str("cursor");
+        }
     }
 
     string
 }
+
+/// Serialize a [Doc] to JSON, using the same `type` names as Prettier's doc builders.
+///
+/// Reference: <https://github.com/prettier/prettier/blob/main/src/document/constants.js>
+#[cfg(feature = "serialize")]
+pub fn doc_to_json(doc: &Doc<'_>) -> std::string::String {
+    let mut json = std::string::String::new();
+    print_doc_to_json(doc, &mut json);
+    json
+}
+
+#[cfg(feature = "serialize")]
+fn print_doc_to_json(doc: &Doc<'_>, json: &mut std::string::String) {
+    let print_docs = |docs: &[Doc<'_>], json: &mut std::string::String| {
+        json.push('[');
+        for (idx, doc) in docs.iter().enumerate() {
+            if idx != 0 {
+                json.push(',');
+            }
+            print_doc_to_json(doc, json);
+        }
+        json.push(']');
+    };
+    let print_group_id = |id: Option<GroupId>, json: &mut std::string::String| match id {
+        Some(id) => json.push_str(&id.to_string()),
+        None => json.push_str("null"),
+    };
+
+    match doc {
+        Doc::Str(s) => {
+            json.push_str(r#"{"type":"string","value":"#);
+            print_json_string(s, json);
+            json.push('}');
+        }
+        Doc::Array(docs) => {
+            json.push_str(r#"{"type":"array","parts":"#);
+            print_docs(docs, json);
+            json.push('}');
+        }
+        Doc::Indent(contents) => {
+            json.push_str(r#"{"type":"indent","contents":"#);
+            print_docs(contents, json);
+            json.push('}');
+        }
+        Doc::IndentIfBreak(indent_if_break) => {
+            json.push_str(r#"{"type":"indent-if-break","contents":"#);
+            print_docs(&indent_if_break.contents, json);
+            json.push_str(r#","groupId":"#);
+            print_group_id(indent_if_break.group_id, json);
+            json.push('}');
+        }
+        Doc::Group(group) => {
+            json.push_str(r#"{"type":"group","contents":"#);
+            print_docs(&group.contents, json);
+            json.push_str(r#","break":"#);
+            json.push_str(&group.should_break.to_string());
+            json.push_str(r#","id":"#);
+            print_group_id(group.id, json);
+            json.push_str(r#","expandedStates":"#);
+            match &group.expanded_states {
+                Some(expanded_states) => print_docs(expanded_states, json),
+                None => json.push_str("null"),
+            }
+            json.push('}');
+        }
+        Doc::Line(Line { hard, soft, literal }) => {
+            json.push_str(&format!(
+                r#"{{"type":"line","hard":{hard},"soft":{soft},"literal":{literal}}}"#
+            ));
+        }
+        Doc::LineSuffix(contents) => {
+            json.push_str(r#"{"type":"line-suffix","contents":"#);
+            print_docs(contents, json);
+            json.push('}');
+        }
+        Doc::IfBreak(if_break) => {
+            json.push_str(r#"{"type":"if-break","breakContents":"#);
+            print_doc_to_json(&if_break.break_contents, json);
+            json.push_str(r#","flatContents":"#);
+            print_doc_to_json(&if_break.flat_content, json);
+            json.push_str(r#","groupId":"#);
+            print_group_id(if_break.group_id, json);
+            json.push('}');
+        }
+        Doc::Fill(fill) => {
+            json.push_str(r#"{"type":"fill","parts":"#);
+            print_docs(fill.parts(), json);
+            json.push('}');
+        }
+        Doc::BreakParent => json.push_str(r#"{"type":"break-parent"}"#),
+        Doc::Cursor => json.push_str(r#"{"type":"cursor"}"#),
+    }
+}
+
+#[cfg(feature = "serialize")]
+fn print_json_string(s: &str, json: &mut std::string::String) {
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => json.push_str(&format!("\\u{:04x}", u32::from(c))),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
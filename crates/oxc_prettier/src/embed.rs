@@ -0,0 +1,106 @@
+//! Formatting of embedded languages in tagged template literals.
+//!
+//! See <https://github.com/prettier/prettier/tree/main/src/language-js/embed>
+
+use oxc_ast::ast::{Expression, TaggedTemplateExpression};
+
+use crate::{
+    doc::{Doc, DocBuilder},
+    hardline, ss, EmbeddedLanguageFormatting, Prettier, PrettierOptions,
+};
+
+/// Languages recognized by the tag of a template literal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EmbeddedLanguage {
+    /// `` css`...` ``
+    Css,
+    /// `` html`...` ``
+    Html,
+    /// `` graphql`...` `` and `` gql`...` ``
+    GraphQL,
+    /// `` markdown`...` `` and `` md`...` ``
+    Markdown,
+    /// `` sql`...` ``
+    Sql,
+}
+
+impl EmbeddedLanguage {
+    /// The language of a template literal tagged with `tag`, if any.
+    pub fn from_tag(tag: &Expression) -> Option<Self> {
+        let Expression::Identifier(ident) = tag else { return None };
+        match ident.name.as_str() {
+            "css" => Some(Self::Css),
+            "html" => Some(Self::Html),
+            "graphql" | "gql" => Some(Self::GraphQL),
+            "markdown" | "md" => Some(Self::Markdown),
+            "sql" => Some(Self::Sql),
+            _ => None,
+        }
+    }
+}
+
+/// Formats the contents of embedded templates, see [Prettier::with_embedded_formatter].
+pub trait EmbeddedFormatter {
+    /// Format `text` written in `language`.
+    ///
+    /// Return `None` to keep the template literal as is.
+    fn format(
+        &self,
+        language: EmbeddedLanguage,
+        text: &str,
+        options: &PrettierOptions,
+    ) -> Option<String>;
+}
+
+/// Print the quasi of a tagged template with the embedded formatter.
+///
+/// Only templates without substitutions are formatted,
+/// returns `None` when the template should be printed as usual.
+pub(crate) fn print_embedded_template<'a>(
+    p: &mut Prettier<'a>,
+    expr: &TaggedTemplateExpression<'a>,
+) -> Option<Doc<'a>> {
+    if p.options.embedded_language_formatting == EmbeddedLanguageFormatting::Off {
+        return None;
+    }
+    let embedded_formatter = p.embedded_formatter?;
+    let language = EmbeddedLanguage::from_tag(&expr.tag)?;
+    let [quasi] = expr.quasi.quasis.as_slice() else { return None };
+    let cooked = quasi.value.cooked.as_ref()?;
+    let output = embedded_formatter.format(language, cooked.as_str(), &p.options)?;
+    let formatted = escape_template_characters(output.trim());
+
+    if formatted.is_empty() {
+        return Some(ss!("``"));
+    }
+
+    let mut lines = p.vec();
+    for line in formatted.lines() {
+        lines.extend(hardline!());
+        lines.push(p.str(line));
+    }
+
+    let mut parts = p.vec();
+    parts.push(ss!("`"));
+    parts.push(Doc::Indent(lines));
+    parts.extend(hardline!());
+    parts.push(ss!("`"));
+    Some(Doc::Array(parts))
+}
+
+/// Reference: <https://github.com/prettier/prettier/blob/main/src/utils/escape-template-characters.js>
+fn escape_template_characters(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' | '`' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '$' if chars.peek() == Some(&'{') => escaped.push_str("\\$"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
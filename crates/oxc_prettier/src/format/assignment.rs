@@ -152,7 +152,7 @@ fn choose_layout<'a>(
         matches!(assignment_like_node, AssignmentLikeNode::AssignmentExpression(_))
             && matches!(
                 p.parent_kind(),
-                AstKind::AssignmentExpression(_) | AstKind::VariableDeclarator(_)
+                Some(AstKind::AssignmentExpression(_) | AstKind::VariableDeclarator(_))
             )
             && (!is_tail
                 || !matches!(
@@ -319,6 +319,11 @@ fn should_break_after_operator<'a>(
 
     match expr {
         Expression::SequenceExpression(_) => return true,
+        // Chains of experimental ternaries start on their own line, so that all cases line up.
+        Expression::ConditionalExpression(conditional_expr) if p.options.experimental_ternaries => {
+            return matches!(conditional_expr.consequent, Expression::ConditionalExpression(_))
+                || matches!(conditional_expr.alternate, Expression::ConditionalExpression(_));
+        }
         Expression::ConditionalExpression(conditional_expr) => {
             return matches!(
                 conditional_expr.test,
@@ -18,10 +18,12 @@ pub(super) fn print_binaryish_expression<'a>(
     let parent_kind = p.parent_kind();
     let is_inside_parenthesis = matches!(
         parent_kind,
-        AstKind::IfStatement(_)
-            | AstKind::WhileStatement(_)
-            | AstKind::SwitchStatement(_)
-            | AstKind::DoWhileStatement(_)
+        Some(
+            AstKind::IfStatement(_)
+                | AstKind::WhileStatement(_)
+                | AstKind::SwitchStatement(_)
+                | AstKind::DoWhileStatement(_)
+        )
     );
 
     let parts = print_binaryish_expressions(p, left, operator, right);
@@ -32,7 +34,7 @@ pub(super) fn print_binaryish_expression<'a>(
 
     // Avoid indenting sub-expressions in some cases where the first sub-expression is already
     // indented accordingly. We should indent sub-expressions where the first case isn't indented.
-    let should_not_indent = matches!(parent_kind, AstKind::ReturnStatement(_));
+    let should_not_indent = matches!(parent_kind, Some(AstKind::ReturnStatement(_)));
     if should_not_indent {
         return Doc::Group(Group::new(parts));
     }
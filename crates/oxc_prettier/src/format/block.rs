@@ -29,16 +29,18 @@ pub(super) fn print_block<'a>(
             || parent_parent.is_some_and(|p| !matches!(p, AstKind::ObjectProperty(_))))
             && !(matches!(
                 parent,
-                AstKind::FunctionBody(_)
-                    | AstKind::ArrowFunctionExpression(_)
-                    | AstKind::ObjectExpression(_)
-                    | AstKind::Function(_)
-                    | AstKind::ForStatement(_)
-                    | AstKind::WhileStatement(_)
-                    | AstKind::DoWhileStatement(_)
-                    | AstKind::MethodDefinition(_)
-                    | AstKind::PropertyDefinition(_)
-            ) || (matches!(parent, AstKind::CatchClause(_))
+                Some(
+                    AstKind::FunctionBody(_)
+                        | AstKind::ArrowFunctionExpression(_)
+                        | AstKind::ObjectExpression(_)
+                        | AstKind::Function(_)
+                        | AstKind::ForStatement(_)
+                        | AstKind::WhileStatement(_)
+                        | AstKind::DoWhileStatement(_)
+                        | AstKind::MethodDefinition(_)
+                        | AstKind::PropertyDefinition(_)
+                )
+            ) || (matches!(parent, Some(AstKind::CatchClause(_)))
                 && !matches!(p.parent_parent_kind(), Some(AstKind::TryStatement(stmt)) if stmt.finalizer.is_some()))
                 || matches!(p.current_kind(), AstKind::StaticBlock(_)))
         {
@@ -11,6 +11,9 @@ use crate::{
 
 pub(super) fn print_class<'a>(p: &mut Prettier<'a>, class: &Class<'a>) -> Doc<'a> {
     let mut parts = p.vec();
+    if class.declare {
+        parts.push(ss!("declare "));
+    }
     if class.r#abstract {
         parts.push(ss!("abstract "));
     }
@@ -13,6 +13,9 @@ pub(super) fn print_function<'a>(
     property_name: Option<&str>,
 ) -> Doc<'a> {
     let mut parts = p.vec();
+    if func.declare {
+        parts.push(ss!("declare "));
+    }
     if func.r#async {
         parts.push(ss!("async "));
     }
@@ -28,20 +31,23 @@ pub(super) fn print_function<'a>(
         parts.push(p.str(" "));
     }
 
-    if let Some(type_params) = &func.type_parameters {
-        parts.push(type_params.format(p));
-    }
     if let Some(id) = &func.id {
         parts.push(p.str(id.name.as_str()));
     }
+    if let Some(type_params) = &func.type_parameters {
+        parts.push(type_params.format(p));
+    }
     // Prettier has `returnTypeDoc` to group together, write this for keep same with prettier.
-    parts.push(group!(p, {
-        if should_group_function_parameters(func) {
-            group!(p, func.params.format(p))
-        } else {
-            func.params.format(p)
-        }
-    }));
+    let params_doc = if should_group_function_parameters(func) {
+        group!(p, func.params.format(p))
+    } else {
+        func.params.format(p)
+    };
+    let return_type_doc = match &func.return_type {
+        Some(return_type) => array![p, ss!(": "), return_type.type_annotation.format(p)],
+        None => ss!(""),
+    };
+    parts.push(group!(p, params_doc, return_type_doc));
     if let Some(body) = &func.body {
         parts.push(space!());
         parts.push(body.format(p));
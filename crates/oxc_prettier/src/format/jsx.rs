@@ -0,0 +1,122 @@
+use oxc_ast::ast::*;
+use oxc_span::GetSpan;
+
+use super::{string, Format};
+use crate::{
+    comments::CommentFlags,
+    doc::{Doc, DocBuilder, Group},
+    hardline, line, softline, space, ss, Prettier,
+};
+
+pub(super) fn print_jsx_element<'a>(p: &mut Prettier<'a>, element: &JSXElement<'a>) -> Doc<'a> {
+    let mut parts = p.vec();
+    parts.push(element.opening_element.format(p));
+    // TODO: port `printJsxChildren`, children are printed as they are for now.
+    parts.extend(element.children.iter().map(|child| child.format(p)));
+    if let Some(closing_element) = &element.closing_element {
+        parts.push(closing_element.format(p));
+    }
+    Doc::Array(parts)
+}
+
+pub(super) fn print_jsx_opening_element<'a>(
+    p: &mut Prettier<'a>,
+    element: &JSXOpeningElement<'a>,
+) -> Doc<'a> {
+    let mut parts = p.vec();
+    parts.push(ss!("<"));
+    parts.push(element.name.format(p));
+    if let Some(type_parameters) = &element.type_parameters {
+        parts.push(type_parameters.format(p));
+    }
+
+    // don't break if no attributes
+    if element.self_closing && element.attributes.is_empty() {
+        parts.push(ss!(" />"));
+        return Doc::Array(parts);
+    }
+
+    // don't break up opening elements with a single long text attribute
+    if let [attr] = element.attributes.as_slice() {
+        if get_string_value(attr).is_some_and(|value| !value.contains('\n')) {
+            parts.push(space!());
+            parts.push(attr.format(p));
+            parts.push(ss!(if element.self_closing { " />" } else { ">" }));
+            return Doc::Group(Group::new(parts));
+        }
+    }
+
+    // We should print the opening element expanded if any prop value is a
+    // string literal with a newline
+    let should_break = element
+        .attributes
+        .iter()
+        .any(|attr| get_string_value(attr).is_some_and(|value| value.contains('\n')));
+
+    let should_print_bracket_same_line = p.options.bracket_same_line
+        && !element
+            .attributes
+            .last()
+            .is_some_and(|attr| p.has_comment(attr.span(), CommentFlags::Trailing));
+
+    let should_break_attributes =
+        p.options.single_attribute_per_line && element.attributes.len() > 1;
+
+    parts.push(Doc::Indent({
+        let mut indent_parts = p.vec();
+        for attr in &element.attributes {
+            if should_break_attributes {
+                indent_parts.extend(hardline!());
+            } else {
+                indent_parts.push(line!());
+            }
+            indent_parts.push(attr.format(p));
+        }
+        indent_parts
+    }));
+
+    if element.self_closing {
+        parts.push(line!());
+        parts.push(ss!("/>"));
+    } else if should_print_bracket_same_line {
+        parts.push(ss!(">"));
+    } else {
+        parts.push(softline!());
+        parts.push(ss!(">"));
+    }
+
+    Doc::Group(Group::new(parts).with_break(should_break))
+}
+
+pub(super) fn print_jsx_attribute<'a>(p: &mut Prettier<'a>, attr: &JSXAttribute<'a>) -> Doc<'a> {
+    let mut parts = p.vec();
+    parts.push(attr.name.format(p));
+    if let Some(value) = &attr.value {
+        parts.push(ss!("="));
+        parts.push(value.format(p));
+    }
+    Doc::Array(parts)
+}
+
+/// JSX strings can't contain escapes, so quotes are replaced with HTML entities instead.
+pub(super) fn print_jsx_string_literal<'a>(p: &Prettier<'a>, lit: &StringLiteral<'a>) -> Doc<'a> {
+    let raw = &p.source_text[(lit.span.start + 1) as usize..(lit.span.end - 1) as usize];
+    let raw = raw.replace("&apos;", "'").replace("&quot;", "\"");
+    let quote = string::get_preferred_quote(&raw, p.options.jsx_single_quote);
+    let escaped = if quote == '"' { "&quot;" } else { "&apos;" };
+    let mut result = std::string::String::with_capacity(raw.len() + 2);
+    result.push(quote);
+    result.push_str(&raw.replace(quote, escaped));
+    result.push(quote);
+    p.str(&result)
+}
+
+fn get_string_value<'b>(attr: &'b JSXAttributeItem) -> Option<&'b str> {
+    match attr {
+        JSXAttributeItem::Attribute(attr) => match &attr.value {
+            Some(JSXAttributeValue::StringLiteral(lit)) => Some(lit.value.as_str()),
+            _ => None,
+        },
+        JSXAttributeItem::SpreadAttribute(_) => None,
+    }
+}
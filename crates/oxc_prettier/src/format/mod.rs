@@ -15,6 +15,7 @@ mod call_expression;
 mod class;
 mod function;
 mod function_parameters;
+mod jsx;
 mod misc;
 mod module;
 mod object;
@@ -31,11 +32,13 @@ use oxc_ast::{ast::*, AstKind};
 use oxc_span::GetSpan;
 use oxc_syntax::identifier::is_identifier_name;
 
+pub(crate) use self::statement::print_statement_sequence;
 use self::{array::Array, object::ObjectLike, template_literal::TemplateLiteralPrinter};
 use crate::{
     array,
     doc::{Doc, DocBuilder, Group, Separator},
-    format, group, hardline, indent, line, softline, space, ss, string, wrap, Prettier,
+    embed, format, group, hardline, if_break, indent, line, softline, space, ss, string, wrap,
+    Prettier,
 };
 
 pub trait Format<'a> {
@@ -576,19 +579,22 @@ impl<'a> Format<'a> for VariableDeclaration<'a> {
             // We generally want to terminate all variable declarations with a
             // semicolon, except when they in the () part of for loops.
             let parent_for_loop = match p.parent_kind() {
-                AstKind::ForStatement(stmt) => Some(stmt.body.span()),
-                AstKind::ForInStatement(stmt) => Some(stmt.body.span()),
-                AstKind::ForOfStatement(stmt) => Some(stmt.body.span()),
+                Some(AstKind::ForStatement(stmt)) => Some(stmt.body.span()),
+                Some(AstKind::ForInStatement(stmt)) => Some(stmt.body.span()),
+                Some(AstKind::ForOfStatement(stmt)) => Some(stmt.body.span()),
                 _ => None,
             };
 
             let kind = self.kind.as_str();
 
             let mut parts = p.vec();
+            if self.declare {
+                parts.push(ss!("declare "));
+            }
             parts.push(ss!(kind));
             parts.push(space!());
 
-            let is_hardline = !p.parent_kind().is_iteration_statement()
+            let is_hardline = !p.parent_kind().is_some_and(AstKind::is_iteration_statement)
                 && self.declarations.iter().all(|decl| decl.init.is_some());
             let decls_len = self.declarations.len();
             parts.extend(self.declarations.iter().enumerate().map(|(i, decl)| {
@@ -636,6 +642,9 @@ impl<'a> Format<'a> for TSTypeAliasDeclaration<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
         let mut parts = p.vec();
 
+        if self.declare {
+            parts.push(ss!("declare "));
+        }
         parts.push(ss!("type "));
         parts.push(format!(p, self.id));
         parts.push(ss!(" = "));
@@ -834,10 +843,26 @@ impl<'a> Format<'a> for TSInferType<'a> {
 
 impl<'a> Format<'a> for TSIntersectionType<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        print_type_list(p, &self.types, " & ")
     }
 }
 
+/// `A | B` and `A & B`, kept on one line.
+fn print_type_list<'a>(
+    p: &mut Prettier<'a>,
+    types: &[TSType<'a>],
+    separator: &'static str,
+) -> Doc<'a> {
+    let mut parts = p.vec();
+    for (i, ty) in types.iter().enumerate() {
+        if i > 0 {
+            parts.push(ss!(separator));
+        }
+        parts.push(ty.format(p));
+    }
+    Doc::Array(parts)
+}
+
 impl<'a> Format<'a> for TSLiteralType<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
         match &self.literal {
@@ -888,7 +913,32 @@ impl<'a> Format<'a> for TSTupleType<'a> {
 
 impl<'a> Format<'a> for TSTypeLiteral<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        if self.members.is_empty() {
+            return ss!("{}");
+        }
+
+        let semi = if p.options.semi { ";" } else { "" };
+        let mut parts = p.vec();
+        parts.push(ss!("{"));
+        parts.push(Doc::Indent({
+            let mut indent_parts = p.vec();
+            indent_parts.push(if p.options.bracket_spacing { line!() } else { softline!() });
+            for (i, member) in self.members.iter().enumerate() {
+                if i > 0 {
+                    indent_parts.push(if_break!(p, semi, ";", None));
+                    indent_parts.push(line!());
+                }
+                indent_parts.push(member.format(p));
+            }
+            indent_parts
+        }));
+        parts.push(if_break!(p, semi, "", None));
+        parts.push(if p.options.bracket_spacing { line!() } else { softline!() });
+        parts.push(ss!("}"));
+
+        let should_break =
+            misc::has_new_line_in_range(p.source_text, self.span.start, self.span.end);
+        Doc::Group(Group::new(parts).with_break(should_break))
     }
 }
 
@@ -929,7 +979,7 @@ impl<'a> Format<'a> for TSParenthesizedType<'a> {
 
 impl<'a> Format<'a> for TSUnionType<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        print_type_list(p, &self.types, " | ")
     }
 }
 
@@ -954,6 +1004,9 @@ impl<'a> Format<'a> for JSDocUnknownType {
 impl<'a> Format<'a> for TSInterfaceDeclaration<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
         let mut parts = p.vec();
+        if self.declare {
+            parts.push(ss!("declare "));
+        }
         parts.push(ss!("interface "));
         parts.push(format!(p, self.id));
         parts.push(space!());
@@ -963,6 +1016,9 @@ impl<'a> Format<'a> for TSInterfaceDeclaration<'a> {
             for sig in &self.body.body {
                 indent_parts.extend(hardline!());
                 indent_parts.push(format!(p, sig));
+                if let Some(semi) = p.semi() {
+                    indent_parts.push(semi);
+                }
             }
             parts.push(Doc::Indent(indent_parts));
             parts.extend(hardline!());
@@ -974,13 +1030,115 @@ impl<'a> Format<'a> for TSInterfaceDeclaration<'a> {
 
 impl<'a> Format<'a> for TSEnumDeclaration<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        wrap!(p, self, TSEnumDeclaration, {
+            let mut parts = p.vec();
+            if self.declare {
+                parts.push(ss!("declare "));
+            }
+            if self.r#const {
+                parts.push(ss!("const "));
+            }
+            parts.push(ss!("enum "));
+            parts.push(format!(p, self.id));
+            parts.push(space!());
+            parts.push(object::print_object_properties(p, ObjectLike::TSEnumDeclaration(self)));
+            Doc::Array(parts)
+        })
+    }
+}
+
+impl<'a> Format<'a> for TSEnumMember<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        wrap!(p, self, TSEnumMember, {
+            let mut parts = p.vec();
+            parts.push(format!(p, self.id));
+            if let Some(initializer) = &self.initializer {
+                parts.push(ss!(" = "));
+                parts.push(format!(p, initializer));
+            }
+            Doc::Array(parts)
+        })
+    }
+}
+
+impl<'a> Format<'a> for TSEnumMemberName<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        match self {
+            Self::StaticIdentifier(ident) => ident.format(p),
+            Self::StaticStringLiteral(literal) => literal.format(p),
+            Self::StaticNumericLiteral(literal) => literal.format(p),
+            match_expression!(Self) => {
+                array!(p, ss!("["), self.to_expression().format(p), ss!("]"))
+            }
+        }
     }
 }
 
 impl<'a> Format<'a> for TSModuleDeclaration<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        wrap!(p, self, TSModuleDeclaration, {
+            let mut parts = p.vec();
+            if self.declare {
+                parts.push(ss!("declare "));
+            }
+            match self.kind {
+                TSModuleDeclarationKind::Global => {}
+                TSModuleDeclarationKind::Module => parts.push(ss!("module ")),
+                TSModuleDeclarationKind::Namespace => parts.push(ss!("namespace ")),
+            }
+            parts.push(format!(p, self.id));
+
+            // `namespace A.B.C {}` is nested declarations sharing a single body.
+            let mut body = &self.body;
+            while let Some(TSModuleDeclarationBody::TSModuleDeclaration(decl)) = body {
+                parts.push(ss!("."));
+                parts.push(format!(p, decl.id));
+                body = &decl.body;
+            }
+
+            match body {
+                Some(TSModuleDeclarationBody::TSModuleBlock(block)) => {
+                    parts.push(space!());
+                    parts.push(format!(p, block));
+                }
+                Some(TSModuleDeclarationBody::TSModuleDeclaration(_)) => unreachable!(),
+                None => {
+                    if let Some(semi) = p.semi() {
+                        parts.push(semi);
+                    }
+                }
+            }
+            Doc::Array(parts)
+        })
+    }
+}
+
+impl<'a> Format<'a> for TSModuleDeclarationName<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        match self {
+            Self::Identifier(ident) => ident.format(p),
+            Self::StringLiteral(literal) => literal.format(p),
+        }
+    }
+}
+
+impl<'a> Format<'a> for TSModuleBlock<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        wrap!(p, self, TSModuleBlock, {
+            let mut parts = p.vec();
+            parts.push(ss!("{"));
+            if let Some(doc) =
+                block::print_block_body(p, &self.body, Some(&self.directives), true, false)
+            {
+                let mut indent_parts = p.vec();
+                indent_parts.extend(hardline!());
+                indent_parts.push(doc);
+                parts.push(Doc::Indent(indent_parts));
+                parts.extend(hardline!());
+            }
+            parts.push(ss!("}"));
+            Doc::Array(parts)
+        })
     }
 }
 
@@ -1032,22 +1190,51 @@ impl<'a> Format<'a> for TSExternalModuleReference<'a> {
 
 impl<'a> Format<'a> for TSTypeParameter<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        let mut parts = p.vec();
+        if self.r#const {
+            parts.push(ss!("const "));
+        }
+        if self.r#in {
+            parts.push(ss!("in "));
+        }
+        if self.out {
+            parts.push(ss!("out "));
+        }
+        parts.push(self.name.format(p));
+        if let Some(constraint) = &self.constraint {
+            parts.push(ss!(" extends "));
+            parts.push(constraint.format(p));
+        }
+        if let Some(default) = &self.default {
+            parts.push(ss!(" = "));
+            parts.push(default.format(p));
+        }
+        Doc::Array(parts)
     }
 }
 
 impl<'a> Format<'a> for TSTypeParameterDeclaration<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        let params = self.params.iter().map(|param| param.format(p)).collect();
+        print_type_parameters(p, params)
     }
 }
 
 impl<'a> Format<'a> for TSTypeParameterInstantiation<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        let params = self.params.iter().map(|param| param.format(p)).collect();
+        print_type_parameters(p, params)
     }
 }
 
+/// `<A, B>`
+///
+/// Reference: <https://github.com/prettier/prettier/blob/main/src/language-js/print/type-parameters.js>
+fn print_type_parameters<'a>(p: &mut Prettier<'a>, params: std::vec::Vec<Doc<'a>>) -> Doc<'a> {
+    let params = p.join(Separator::CommaLine, params);
+    group!(p, ss!("<"), indent!(p, softline!(), Doc::Array(params)), softline!(), ss!(">"))
+}
+
 impl<'a> Format<'a> for TSTupleElement<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
         line!()
@@ -1206,7 +1393,7 @@ impl<'a> Format<'a> for TSExportAssignment<'a> {
 
 impl<'a> Format<'a> for TSNamespaceExportDeclaration<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        array!(p, ss!(" as namespace "), self.id.format(p))
     }
 }
 
@@ -1280,6 +1467,7 @@ impl<'a> Format<'a> for Expression<'a> {
             Self::UnaryExpression(expr) => expr.format(p),
             Self::BinaryExpression(expr) => expr.format(p),
             Self::PrivateInExpression(expr) => expr.format(p),
+            Self::PipelinePlaceholder(expr) => expr.format(p),
             Self::LogicalExpression(expr) => expr.format(p),
             Self::ConditionalExpression(expr) => expr.format(p),
             Self::AssignmentExpression(expr) => expr.format(p),
@@ -1448,6 +1636,12 @@ impl<'a> Format<'a> for ThisExpression {
     }
 }
 
+impl<'a> Format<'a> for PipelinePlaceholder {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        Doc::Str("%")
+    }
+}
+
 impl<'a> Format<'a> for MemberExpression<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
         wrap!(p, self, MemberExpression, {
@@ -1751,7 +1945,9 @@ impl<'a> Format<'a> for BinaryExpression<'a> {
                 self.operator.into(),
                 &self.right,
             );
-            if misc::in_parentheses(p.parent_kind(), p.source_text, self.span) {
+            if p.parent_kind()
+                .is_some_and(|kind| misc::in_parentheses(kind, p.source_text, self.span))
+            {
                 group!(p, indent!(p, softline!(), doc), softline!())
             } else {
                 doc
@@ -1785,7 +1981,9 @@ impl<'a> Format<'a> for LogicalExpression<'a> {
                 &self.right,
             );
 
-            if misc::in_parentheses(p.parent_kind(), p.source_text, self.span) {
+            if p.parent_kind()
+                .is_some_and(|kind| misc::in_parentheses(kind, p.source_text, self.span))
+            {
                 group!(p, indent!(p, softline!(), doc), softline!())
             } else {
                 doc
@@ -1971,7 +2169,9 @@ impl<'a> Format<'a> for TaggedTemplateExpression<'a> {
                 parts.push(string!(p, ">"));
             }
 
-            parts.push(format!(p, self.quasi));
+            let quasi =
+                embed::print_embedded_template(p, self).unwrap_or_else(|| format!(p, self.quasi));
+            parts.push(quasi);
 
             Doc::Array(parts)
         })
@@ -2053,133 +2253,176 @@ impl<'a> Format<'a> for ClassElement<'a> {
 
 impl<'a> Format<'a> for JSXIdentifier<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        wrap!(p, self, JSXIdentifier, { p.str(self.name.as_str()) })
     }
 }
 
 impl<'a> Format<'a> for JSXMemberExpressionObject<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        match self {
+            Self::Identifier(ident) => ident.format(p),
+            Self::MemberExpression(expr) => expr.format(p),
+        }
     }
 }
 
 impl<'a> Format<'a> for JSXMemberExpression<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        wrap!(p, self, JSXMemberExpression, {
+            array![p, self.object.format(p), ss!("."), self.property.format(p)]
+        })
     }
 }
 
 impl<'a> Format<'a> for JSXElementName<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        match self {
+            Self::Identifier(ident) => ident.format(p),
+            Self::NamespacedName(name) => name.format(p),
+            Self::MemberExpression(expr) => expr.format(p),
+        }
     }
 }
 
 impl<'a> Format<'a> for JSXNamespacedName<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        wrap!(p, self, JSXNamespacedName, {
+            array![p, self.namespace.format(p), ss!(":"), self.property.format(p)]
+        })
     }
 }
 
 impl<'a> Format<'a> for JSXAttributeName<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        match self {
+            Self::Identifier(ident) => ident.format(p),
+            Self::NamespacedName(name) => name.format(p),
+        }
     }
 }
 
 impl<'a> Format<'a> for JSXAttribute<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        jsx::print_jsx_attribute(p, self)
     }
 }
 
 impl<'a> Format<'a> for JSXEmptyExpression {
     fn format(&self, _: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        ss!("")
     }
 }
 
 impl<'a> Format<'a> for JSXExpression<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        match self {
+            Self::EmptyExpression(expr) => expr.format(p),
+            match_expression!(Self) => self.to_expression().format(p),
+        }
     }
 }
 
 impl<'a> Format<'a> for JSXExpressionContainer<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        wrap!(p, self, JSXExpressionContainer, {
+            group![p, ss!("{"), self.expression.format(p), softline!(), ss!("}")]
+        })
     }
 }
 
 impl<'a> Format<'a> for JSXAttributeValue<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        match self {
+            Self::StringLiteral(lit) => jsx::print_jsx_string_literal(p, lit),
+            Self::ExpressionContainer(container) => container.format(p),
+            Self::Element(el) => el.format(p),
+            Self::Fragment(fragment) => fragment.format(p),
+        }
     }
 }
 
 impl<'a> Format<'a> for JSXSpreadAttribute<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        wrap!(p, self, JSXSpreadAttribute, {
+            array![p, ss!("{..."), self.argument.format(p), ss!("}")]
+        })
     }
 }
 
 impl<'a> Format<'a> for JSXAttributeItem<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        wrap!(p, self, JSXAttributeItem, {
+            match self {
+                Self::Attribute(attr) => attr.format(p),
+                Self::SpreadAttribute(attr) => attr.format(p),
+            }
+        })
     }
 }
 
 impl<'a> Format<'a> for JSXOpeningElement<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        wrap!(p, self, JSXOpeningElement, { jsx::print_jsx_opening_element(p, self) })
     }
 }
 
 impl<'a> Format<'a> for JSXClosingElement<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        wrap!(p, self, JSXClosingElement, { array![p, ss!("</"), self.name.format(p), ss!(">")] })
     }
 }
 
 impl<'a> Format<'a> for JSXElement<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        wrap!(p, self, JSXElement, { jsx::print_jsx_element(p, self) })
     }
 }
 
 impl<'a> Format<'a> for JSXOpeningFragment {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        ss!("<>")
     }
 }
 
 impl<'a> Format<'a> for JSXClosingFragment {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        ss!("</>")
     }
 }
 
 impl<'a> Format<'a> for JSXText<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        wrap!(p, self, JSXText, { p.str(self.value.as_str()) })
     }
 }
 
 impl<'a> Format<'a> for JSXSpreadChild<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        array![p, ss!("{..."), self.expression.format(p), ss!("}")]
     }
 }
 
 impl<'a> Format<'a> for JSXChild<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        match self {
+            Self::Text(text) => text.format(p),
+            Self::Element(el) => el.format(p),
+            Self::Fragment(fragment) => fragment.format(p),
+            Self::ExpressionContainer(container) => container.format(p),
+            Self::Spread(spread) => spread.format(p),
+        }
     }
 }
 
 impl<'a> Format<'a> for JSXFragment<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        wrap!(p, self, JSXFragment, {
+            let mut parts = p.vec();
+            parts.push(self.opening_fragment.format(p));
+            parts.extend(self.children.iter().map(|child| child.format(p)));
+            parts.push(self.closing_fragment.format(p));
+            Doc::Array(parts)
+        })
     }
 }
 
@@ -2307,7 +2550,22 @@ impl<'a> Format<'a> for RegExpFlags {
 
 impl<'a> Format<'a> for TSIndexSignature<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        let mut parts = p.vec();
+        if self.readonly {
+            parts.push(ss!("readonly "));
+        }
+        parts.push(ss!("["));
+        for (i, param) in self.parameters.iter().enumerate() {
+            if i > 0 {
+                parts.push(ss!(", "));
+            }
+            parts.push(p.str(param.name.as_str()));
+            parts.push(ss!(": "));
+            parts.push(param.type_annotation.type_annotation.format(p));
+        }
+        parts.push(ss!("]: "));
+        parts.push(self.type_annotation.type_annotation.format(p));
+        Doc::Array(parts)
     }
 }
 
@@ -2332,20 +2590,74 @@ impl<'a> Format<'a> for TSPropertySignature<'a> {
 
 impl<'a> Format<'a> for TSCallSignatureDeclaration<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        print_signature(
+            p,
+            self.type_parameters.as_deref(),
+            &self.params,
+            self.return_type.as_deref(),
+        )
     }
 }
 
 impl<'a> Format<'a> for TSConstructSignatureDeclaration<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        let signature = print_signature(
+            p,
+            self.type_parameters.as_deref(),
+            &self.params,
+            self.return_type.as_deref(),
+        );
+        array![p, ss!("new "), signature]
     }
 }
 
 impl<'a> Format<'a> for TSMethodSignature<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        wrap!(p, self, TSMethodSignature, {
+            let mut parts = p.vec();
+            match self.kind {
+                TSMethodSignatureKind::Method => {}
+                TSMethodSignatureKind::Get => parts.push(ss!("get ")),
+                TSMethodSignatureKind::Set => parts.push(ss!("set ")),
+            }
+            if self.computed {
+                parts.push(ss!("["));
+            }
+            parts.push(self.key.format(p));
+            if self.computed {
+                parts.push(ss!("]"));
+            }
+            if self.optional {
+                parts.push(ss!("?"));
+            }
+            parts.push(print_signature(
+                p,
+                self.type_parameters.as_deref(),
+                &self.params,
+                self.return_type.as_deref(),
+            ));
+            Doc::Array(parts)
+        })
+    }
+}
+
+/// `<T>(a: T): T` of call, construct and method signatures.
+fn print_signature<'a>(
+    p: &mut Prettier<'a>,
+    type_parameters: Option<&TSTypeParameterDeclaration<'a>>,
+    params: &FormalParameters<'a>,
+    return_type: Option<&TSTypeAnnotation<'a>>,
+) -> Doc<'a> {
+    let mut parts = p.vec();
+    if let Some(type_parameters) = type_parameters {
+        parts.push(type_parameters.format(p));
+    }
+    parts.push(group!(p, params.format(p)));
+    if let Some(return_type) = return_type {
+        parts.push(ss!(": "));
+        parts.push(return_type.type_annotation.format(p));
     }
+    Doc::Array(parts)
 }
 
 impl<'a> Format<'a> for TSSignature<'a> {
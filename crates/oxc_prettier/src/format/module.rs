@@ -62,10 +62,14 @@ fn print_semicolon_after_export_declaration<'a>(
             | ExportDefaultDeclarationKind::ClassDeclaration(_)
             | ExportDefaultDeclarationKind::TSInterfaceDeclaration(_) => None,
         },
+        // The declaration prints its own semicolon
+        ModuleDeclaration::ExportNamedDeclaration(decl) => {
+            decl.declaration.is_none().then(|| ss!(";"))
+        }
         ModuleDeclaration::ExportAllDeclaration(_)
-        | ModuleDeclaration::ExportNamedDeclaration(_)
-        | ModuleDeclaration::TSExportAssignment(_) => Some(ss!(";")),
-        _ => None,
+        | ModuleDeclaration::TSExportAssignment(_)
+        | ModuleDeclaration::TSNamespaceExportDeclaration(_) => Some(ss!(";")),
+        ModuleDeclaration::ImportDeclaration(_) => None,
     }
 }
 
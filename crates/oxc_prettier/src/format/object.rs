@@ -1,5 +1,5 @@
 use oxc_ast::{
-    ast::{ObjectAssignmentTarget, ObjectExpression, ObjectPattern, WithClause},
+    ast::{ObjectAssignmentTarget, ObjectExpression, ObjectPattern, TSEnumDeclaration, WithClause},
     AstKind,
 };
 use oxc_span::Span;
@@ -16,6 +16,7 @@ pub enum ObjectLike<'a, 'b> {
     AssignmentTarget(&'b ObjectAssignmentTarget<'a>),
     Pattern(&'b ObjectPattern<'a>),
     WithClause(&'b WithClause<'a>),
+    TSEnumDeclaration(&'b TSEnumDeclaration<'a>),
 }
 
 impl<'a, 'b> ObjectLike<'a, 'b> {
@@ -25,6 +26,7 @@ impl<'a, 'b> ObjectLike<'a, 'b> {
             Self::AssignmentTarget(target) => target.properties.len(),
             Self::Pattern(object) => object.properties.len(),
             Self::WithClause(attributes) => attributes.with_entries.len(),
+            Self::TSEnumDeclaration(decl) => decl.members.len(),
         }
     }
 
@@ -33,7 +35,7 @@ impl<'a, 'b> ObjectLike<'a, 'b> {
             Self::Expression(expr) => false,
             Self::AssignmentTarget(target) => target.rest.is_some(),
             Self::Pattern(object) => object.rest.is_some(),
-            Self::WithClause(_) => false,
+            Self::WithClause(_) | Self::TSEnumDeclaration(_) => false,
         }
     }
 
@@ -43,6 +45,7 @@ impl<'a, 'b> ObjectLike<'a, 'b> {
             Self::AssignmentTarget(object) => object.is_empty(),
             Self::Pattern(object) => object.is_empty(),
             Self::WithClause(attributes) => attributes.with_entries.is_empty(),
+            Self::TSEnumDeclaration(decl) => decl.members.is_empty(),
         }
     }
 
@@ -56,6 +59,7 @@ impl<'a, 'b> ObjectLike<'a, 'b> {
             Self::AssignmentTarget(object) => object.span,
             Self::Pattern(object) => object.span,
             Self::WithClause(attributes) => attributes.span,
+            Self::TSEnumDeclaration(decl) => decl.span,
         }
     }
 
@@ -71,6 +75,9 @@ impl<'a, 'b> ObjectLike<'a, 'b> {
             Self::WithClause(attributes) => {
                 Box::new(attributes.with_entries.iter().map(|entry| entry.format(p)))
             }
+            Self::TSEnumDeclaration(decl) => {
+                Box::new(decl.members.iter().map(|member| member.format(p)))
+            }
         }
     }
 }
@@ -103,7 +110,9 @@ pub(super) fn print_object_properties<'a>(
                 indent_parts.push(line!());
             }
             match object {
-                ObjectLike::Expression(_) | ObjectLike::WithClause(_) => {}
+                ObjectLike::Expression(_)
+                | ObjectLike::WithClause(_)
+                | ObjectLike::TSEnumDeclaration(_) => {}
                 ObjectLike::AssignmentTarget(target) => {
                     if let Some(rest) = &target.rest {
                         indent_parts.push(rest.format(p));
@@ -122,7 +131,7 @@ pub(super) fn print_object_properties<'a>(
                 ObjectLike::Expression(expr) => true,
                 ObjectLike::AssignmentTarget(target) => true,
                 ObjectLike::Pattern(pattern) => pattern.rest.is_none(),
-                ObjectLike::WithClause(_) => true,
+                ObjectLike::WithClause(_) | ObjectLike::TSEnumDeclaration(_) => true,
             }
         {
             parts.push(if_break!(p, ",", "", None));
@@ -131,12 +140,13 @@ pub(super) fn print_object_properties<'a>(
         parts.push(ss!("}"));
 
         let parent_kind = p.parent_kind();
-        if (object.is_object_pattern() && should_hug_the_only_parameter(p, parent_kind))
+        if (object.is_object_pattern()
+            && parent_kind.is_some_and(|kind| should_hug_the_only_parameter(p, kind)))
             || (!should_break
                 && object.is_object_pattern()
                 && matches!(
                     parent_kind,
-                    AstKind::AssignmentExpression(_) | AstKind::VariableDeclarator(_)
+                    Some(AstKind::AssignmentExpression(_) | AstKind::VariableDeclarator(_))
                 ))
         {
             Doc::Array(parts)
@@ -8,7 +8,7 @@ use crate::{
     hardline, Prettier,
 };
 
-pub(super) fn print_statement_sequence<'a>(
+pub(crate) fn print_statement_sequence<'a>(
     p: &mut Prettier<'a>,
     stmts: &[Statement<'a>],
     remove_last_statement_hardline: bool,
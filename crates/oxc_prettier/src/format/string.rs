@@ -2,7 +2,7 @@ use oxc_allocator::String;
 
 use crate::Prettier;
 
-fn get_preferred_quote(raw: &str, prefer_single_quote: bool) -> char {
+pub(super) fn get_preferred_quote(raw: &str, prefer_single_quote: bool) -> char {
     let (preferred_quote_char, alternate_quote_char) =
         if prefer_single_quote { ('\'', '"') } else { ('"', '\'') };
 
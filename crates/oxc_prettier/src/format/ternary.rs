@@ -1,8 +1,17 @@
-use oxc_ast::ast::*;
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstKind};
+use oxc_span::GetSpan;
 
-use crate::{doc::Doc, group, indent, line, ss, Format, Prettier};
+use crate::{
+    doc::{Doc, DocBuilder, Group},
+    group, indent, line, ss, wrap, Format, Prettier,
+};
 
 pub(super) fn print_ternary<'a>(p: &mut Prettier<'a>, expr: &ConditionalExpression<'a>) -> Doc<'a> {
+    if p.options.experimental_ternaries {
+        return print_experimental_ternary(p, expr);
+    }
+
     group![
         p,
         expr.test.format(p),
@@ -17,3 +26,45 @@ pub(super) fn print_ternary<'a>(p: &mut Prettier<'a>, expr: &ConditionalExpressi
         )
     ]
 }
+
+/// Print a chain of ternaries as `test ? consequent` cases, each alternate starting with `: `.
+///
+/// ```js
+/// const animal =
+///   isBird ? "bird"
+///   : isCat ? "cat"
+///   : "unknown";
+/// ```
+///
+/// Reference: <https://github.com/prettier/prettier/blob/main/src/language-js/print/ternary.js>
+fn print_experimental_ternary<'a>(
+    p: &mut Prettier<'a>,
+    expr: &ConditionalExpression<'a>,
+) -> Doc<'a> {
+    let parts = print_experimental_ternary_cases(p, expr);
+    Doc::Group(Group::new(parts))
+}
+
+/// Nested alternates are printed as further cases of the same group, and still go through `wrap!`
+/// for their comments and the cursor.
+fn print_experimental_ternary_cases<'a>(
+    p: &mut Prettier<'a>,
+    expr: &ConditionalExpression<'a>,
+) -> Vec<'a, Doc<'a>> {
+    let mut parts = p.vec();
+    let test = expr.test.format(p);
+    let consequent = expr.consequent.format(p);
+    parts.push(group![p, test, ss!(" ?"), indent!(p, line!(), consequent)]);
+    parts.push(line!());
+    parts.push(ss!(": "));
+    match &expr.alternate {
+        Expression::ConditionalExpression(alternate) => {
+            let alternate = &**alternate;
+            parts.push(wrap!(p, alternate, ConditionalExpression, {
+                Doc::Array(print_experimental_ternary_cases(p, alternate))
+            }));
+        }
+        alternate => parts.push(alternate.format(p)),
+    }
+    parts
+}
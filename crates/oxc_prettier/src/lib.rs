@@ -4,9 +4,12 @@
 
 #![allow(clippy::wildcard_imports)]
 
+mod batch;
 mod binaryish;
+mod cache;
 mod comments;
 mod doc;
+mod embed;
 mod format;
 mod macros;
 mod needs_parens;
@@ -17,17 +20,60 @@ mod utils;
 use std::{iter::Peekable, vec};
 
 use oxc_allocator::Allocator;
-use oxc_ast::{ast::Program, AstKind, CommentKind, Trivias};
-use oxc_span::Span;
+use oxc_ast::{
+    ast::{Expression, Program, Statement},
+    AstKind, CommentKind, Trivias,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType, Span};
 use oxc_syntax::identifier::is_line_terminator;
-
-pub use crate::options::{ArrowParens, EndOfLine, PrettierOptions, QuoteProps, TrailingComma};
+use similar::TextDiff;
+
+#[cfg(feature = "serialize")]
+pub use crate::doc::doc_to_json;
+pub use crate::{
+    batch::format_files_parallel,
+    cache::FormatterCache,
+    embed::{EmbeddedFormatter, EmbeddedLanguage},
+    options::{
+        ArrowParens, EmbeddedLanguageFormatting, EndOfLine, PrettierOptions,
+        PrettierOptionsBuilder, QuoteProps, TrailingComma,
+    },
+};
 use crate::{
+    comments::Comment,
     doc::{Doc, DocBuilder},
     format::Format,
     printer::Printer,
 };
 
+/// Parse and format a JavaScript module (with JSX) in one go.
+///
+/// # Errors
+///
+/// Returns the parser diagnostics if the source text has syntax errors.
+pub fn format_text(
+    source_text: &str,
+    options: PrettierOptions,
+) -> Result<String, Vec<OxcDiagnostic>> {
+    let source_type = SourceType::default().with_module(true).with_jsx(true);
+    format_source(source_text, source_type, options)
+}
+
+fn format_source(
+    source_text: &str,
+    source_type: SourceType,
+    options: PrettierOptions,
+) -> Result<String, Vec<OxcDiagnostic>> {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).preserve_parens(false).parse();
+    if !ret.errors.is_empty() {
+        return Err(ret.errors);
+    }
+    Ok(Prettier::new(&allocator, source_text, ret.trivias, options).build(&ret.program))
+}
+
 type GroupId = u32;
 #[derive(Default)]
 struct GroupIdBuilder {
@@ -63,6 +109,14 @@ pub struct Prettier<'a> {
 
     group_id_builder: GroupIdBuilder,
     args: PrettierArgs,
+
+    /// The cursor offset in the source text, see [Prettier::build_with_cursor]
+    cursor_offset: Option<u32>,
+    /// The span of the innermost node containing the cursor
+    cursor_node: Option<Span>,
+
+    /// Formatter for embedded languages, see [Prettier::with_embedded_formatter]
+    embedded_formatter: Option<&'a dyn EmbeddedFormatter>,
 }
 
 impl<'a> DocBuilder<'a> for Prettier<'a> {
@@ -88,11 +142,149 @@ impl<'a> Prettier<'a> {
             stack: vec![],
             group_id_builder: GroupIdBuilder::default(),
             args: PrettierArgs::default(),
+            cursor_offset: None,
+            cursor_node: None,
+            embedded_formatter: None,
         }
     }
 
+    /// Format template literals tagged with `css`, `html`, `graphql`, `gql` or `sql`
+    /// with `formatter` when [PrettierOptions::embedded_language_formatting] is `Auto`.
+    ///
+    /// Without an embedded formatter, these template literals are printed as is.
+    #[must_use]
+    pub fn with_embedded_formatter(mut self, formatter: &'a dyn EmbeddedFormatter) -> Self {
+        self.embedded_formatter = Some(formatter);
+        self
+    }
+
     pub fn build(mut self, program: &Program<'a>) -> String {
+        let has_pragma = self.has_pragma(program);
+        if self.options.require_pragma && !has_pragma {
+            return self.source_text.to_string();
+        }
         let doc = program.format(&mut self);
+        let output = Printer::new(doc, self.source_text, self.options, self.allocator).build();
+        if self.options.insert_pragma && !has_pragma {
+            return self.insert_pragma(program, &output);
+        }
+        output
+    }
+
+    /// Check whether the source text is already formatted.
+    ///
+    /// Printing stops at the first difference, the formatted text is never built in full
+    /// for a file which needs formatting.
+    pub fn check(mut self, program: &Program<'a>) -> bool {
+        let has_pragma = self.has_pragma(program);
+        if self.options.require_pragma && !has_pragma {
+            return true;
+        }
+        if self.options.insert_pragma && !has_pragma {
+            return false;
+        }
+        let doc = program.format(&mut self);
+        Printer::new(doc, self.source_text, self.options, self.allocator).check(self.source_text)
+    }
+
+    /// Check whether the source text is already formatted,
+    /// returns a unified diff between the source text and the formatted text if it is not.
+    pub fn check_diff(self, program: &Program<'a>) -> Option<String> {
+        let source_text = self.source_text;
+        let output = self.build(program);
+        (output != source_text).then(|| {
+            TextDiff::from_lines(source_text, &output)
+                .unified_diff()
+                .header("original", "formatted")
+                .to_string()
+        })
+    }
+
+    /// Format the program and return the new offset of `cursor_offset` in the formatted text.
+    ///
+    /// The innermost node containing the cursor is surrounded with [Doc::Cursor] placeholders,
+    /// the new offset is computed from where they are printed.
+    /// When only whitespace changed before the cursor in that node (or in the whole source text,
+    /// when no node contains the cursor), the cursor is kept right after the same non-whitespace
+    /// character, otherwise it keeps its distance from the node start.
+    ///
+    /// Reference: <https://github.com/prettier/prettier/blob/main/src/main/core.js>
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn build_with_cursor(mut self, program: &Program<'a>, cursor_offset: u32) -> (String, u32) {
+        self.cursor_offset = Some(cursor_offset);
+        let doc = program.format(&mut self);
+        let (output, cursor_positions) =
+            Printer::new(doc, self.source_text, self.options, self.allocator).build_with_cursor();
+        let mut offset = match (self.cursor_node, cursor_positions.as_slice()) {
+            (Some(span), [start, end, ..]) => {
+                let relative_offset = (cursor_offset - span.start) as usize;
+                align_cursor(
+                    span.source_text(self.source_text),
+                    &output[*start..*end],
+                    relative_offset,
+                )
+                .map_or_else(|| (start + relative_offset).min(*end), |offset| start + offset)
+            }
+            _ => align_cursor(self.source_text, &output, cursor_offset as usize)
+                .unwrap_or(cursor_offset as usize)
+                .min(output.len()),
+        };
+        while !output.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        (output, offset as u32)
+    }
+
+    /// Format the top-level statements overlapping `[start, end]` and splice them back
+    /// into the source text.
+    ///
+    /// The range is expanded to cover whole statements, everything outside of it
+    /// (including comments) is kept as is, and the full source text is returned.
+    ///
+    /// Reference: <https://prettier.io/docs/en/options#range>
+    pub fn build_range(mut self, program: &Program<'a>, start: u32, end: u32) -> String {
+        let overlaps = |stmt: &Statement<'a>| {
+            let span = stmt.span();
+            span.start < end && start < span.end
+        };
+        let Some(first) = program.body.iter().position(overlaps) else {
+            return self.source_text.to_string();
+        };
+        let last = program.body.iter().rposition(overlaps).unwrap_or(first);
+        let body: &[Statement<'a>] = &program.body;
+        let stmts = &body[first..=last];
+        let range = Span::new(stmts[0].span().start, stmts[stmts.len() - 1].span().end);
+
+        // Only comments inside of the range are printed, the others stay in the source text.
+        self.trivias = self
+            .trivias
+            .filter(|(_, span)| range.start <= span.start && span.end <= range.end)
+            .collect::<Vec<_>>()
+            .into_iter()
+            .peekable();
+
+        self.enter_node(AstKind::Program(self.alloc(program)));
+        let parts = format::print_statement_sequence(&mut self, stmts, false, false);
+        self.leave_node();
+
+        let formatted =
+            Printer::new(Doc::Array(parts), self.source_text, self.options, self.allocator).build();
+        let mut output = String::with_capacity(self.source_text.len());
+        output.push_str(&self.source_text[..range.start as usize]);
+        output.push_str(formatted.trim_end());
+        output.push_str(&self.source_text[range.end as usize..]);
+        output
+    }
+
+    /// Format a single expression in isolation, e.g. the contents of an embedded template.
+    ///
+    /// The expression is the root node, it has no parent to add parentheses or precedence
+    /// context for. The [Prettier] must be created with the source text the expression was
+    /// parsed from.
+    ///
+    /// Reference: <https://github.com/prettier/prettier/blob/main/src/language-js/embed/index.js>
+    pub fn format_expression(mut self, expr: &Expression<'a>) -> String {
+        let doc = expr.format(&mut self);
         Printer::new(doc, self.source_text, self.options, self.allocator).build()
     }
 
@@ -100,6 +292,59 @@ impl<'a> Prettier<'a> {
         program.format(&mut self)
     }
 
+    /// Whether one of the comments at the top of the file contains `@format` or `@prettier`.
+    ///
+    /// Reference: <https://github.com/prettier/prettier/blob/main/src/language-js/pragma.js>
+    fn has_pragma(&self, program: &Program<'a>) -> bool {
+        let mut last_end = program.hashbang.as_ref().map_or(0, |hashbang| hashbang.span.end);
+        for (kind, span) in self.trivias.clone() {
+            let comment = Comment::new(span.start, span.end, kind);
+            if comment.start < last_end
+                || !self.source_text[last_end as usize..comment.start as usize]
+                    .chars()
+                    .all(char::is_whitespace)
+            {
+                break;
+            }
+            let text = span.source_text(self.source_text);
+            if text.contains("@format") || text.contains("@prettier") {
+                return true;
+            }
+            last_end = comment.end;
+        }
+        false
+    }
+
+    /// Prepend a `/** @format */` comment to the formatted output, after the hashbang if any.
+    fn insert_pragma(&self, program: &Program<'a>, output: &str) -> String {
+        let pragma = std::format!("/** @format */{0}{0}", self.options.end_of_line.as_str());
+        let index = if program.hashbang.is_some() {
+            output
+                .find(self.options.end_of_line.as_str())
+                .map_or(output.len(), |i| i + self.options.end_of_line.as_str().len())
+        } else {
+            0
+        };
+        let mut result = String::with_capacity(output.len() + pragma.len());
+        result.push_str(&output[..index]);
+        result.push_str(&pragma);
+        result.push_str(&output[index..]);
+        result
+    }
+
+    /// Surround the innermost node containing the cursor with [Doc::Cursor] placeholders.
+    fn print_cursor(&mut self, doc: Doc<'a>, span: Span) -> Doc<'a> {
+        match self.cursor_offset {
+            Some(offset)
+                if self.cursor_node.is_none() && span.start <= offset && offset <= span.end =>
+            {
+                self.cursor_node = Some(span);
+                array![self, Doc::Cursor, doc, Doc::Cursor]
+            }
+            _ => doc,
+        }
+    }
+
     fn enter_node(&mut self, kind: AstKind<'a>) {
         self.stack.push(kind);
     }
@@ -112,8 +357,10 @@ impl<'a> Prettier<'a> {
         self.stack[self.stack.len() - 1]
     }
 
-    fn parent_kind(&self) -> AstKind<'a> {
-        self.stack[self.stack.len() - 2]
+    /// `None` for the root node, e.g. the expression of [Prettier::format_expression].
+    fn parent_kind(&self) -> Option<AstKind<'a>> {
+        let len = self.stack.len();
+        (len >= 2).then(|| self.stack[len - 2])
     }
 
     fn parent_parent_kind(&self) -> Option<AstKind<'a>> {
@@ -189,10 +436,21 @@ impl<'a> Prettier<'a> {
         self.skip_everything_but_new_line(Some(start_index), /* backwards */ false)
     }
 
-    #[allow(clippy::unused_self)]
+    /// Skip a `/* ... */` comment that doesn't span multiple lines.
+    #[allow(clippy::cast_possible_truncation)]
     fn skip_inline_comment(&self, start_index: Option<u32>) -> Option<u32> {
         let start_index = start_index?;
-        Some(start_index)
+        let text = &self.source_text[start_index as usize..];
+        if !text.starts_with("/*") {
+            return Some(start_index);
+        }
+        // Block comments don't nest, so the first `*/` ends the comment.
+        match text[2..].find("*/") {
+            Some(end) if !text[2..2 + end].chars().any(is_line_terminator) => {
+                Some(start_index + end as u32 + 4)
+            }
+            _ => Some(start_index),
+        }
     }
 
     fn skip_to_line_end(&self, start_index: Option<u32>) -> Option<u32> {
@@ -275,3 +533,18 @@ impl<'a> Prettier<'a> {
         self.group_id_builder.next_id()
     }
 }
+
+/// The offset in `new_text` of the cursor at `offset` in `old_text`,
+/// `None` if their non-whitespace characters before the cursor differ.
+fn align_cursor(old_text: &str, new_text: &str, offset: usize) -> Option<usize> {
+    let mut new_chars = new_text.char_indices().filter(|(_, c)| !c.is_whitespace());
+    let mut new_offset = 0;
+    for c in old_text.get(..offset)?.chars().filter(|c| !c.is_whitespace()) {
+        let (i, new_c) = new_chars.next()?;
+        if new_c != c {
+            return None;
+        }
+        new_offset = i + new_c.len_utf8();
+    }
+    Some(new_offset)
+}
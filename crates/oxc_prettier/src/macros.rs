@@ -173,6 +173,7 @@ macro_rules! wrap {
         $p.enter_node(kind);
         let leading = $p.print_leading_comments(kind.span());
         let doc = $block;
+        let doc = $p.print_cursor(doc, kind.span());
         let doc = $p.wrap_parens(doc, kind);
         let trailing = $p.print_trailing_comments(kind.span());
         let doc = $p.print_comments(leading, doc, trailing);
@@ -52,7 +52,7 @@ impl<'a> Prettier<'a> {
     }
 
     fn check_kind(&self, kind: AstKind<'a>) -> bool {
-        let parent_kind = self.parent_kind();
+        let Some(parent_kind) = self.parent_kind() else { return false };
         match kind {
             AstKind::NumericLiteral(literal) => {
                 matches!(parent_kind, AstKind::MemberExpression(e) if e.object().span() == literal.span)
@@ -189,7 +189,8 @@ impl<'a> Prettier<'a> {
     }
 
     fn check_parent_kind(&mut self, kind: AstKind<'a>) -> bool {
-        match self.parent_kind() {
+        let Some(parent_kind) = self.parent_kind() else { return false };
+        match parent_kind {
             AstKind::Class(class) => {
                 if let Some(h) = &class.super_class {
                     match kind {
@@ -240,7 +241,8 @@ impl<'a> Prettier<'a> {
     }
 
     fn check_sequence_expression(&self, span: Span) -> bool {
-        match self.parent_kind() {
+        let Some(parent_kind) = self.parent_kind() else { return false };
+        match parent_kind {
             AstKind::ReturnStatement(_) | AstKind::ForStatement(_) => false,
             AstKind::ExpressionStatement(expr) => expr.expression.span() != span,
             AstKind::ArrowFunctionExpression(expr) => expr.body.span != span,
@@ -274,7 +276,7 @@ impl<'a> Prettier<'a> {
     /// `for ((async) of []);` and `for ((let) of []);`
     fn check_for_of_stmt_head_starts_with_async_or_let(&self, kind: AstKind<'a>) -> bool {
         let AstKind::IdentifierReference(ident) = kind else { return false };
-        let AstKind::ForOfStatement(stmt) = self.parent_kind() else { return false };
+        let Some(AstKind::ForOfStatement(stmt)) = self.parent_kind() else { return false };
         if let ForStatementLeft::AssignmentTargetIdentifier(i) = &stmt.left {
             if (i.span == ident.span) && (i.name == "let" || (i.name == "async" && !stmt.r#await)) {
                 return true;
@@ -309,7 +311,7 @@ impl<'a> Prettier<'a> {
         if ident.name != "let" {
             return false;
         }
-        let AstKind::MemberExpression(MemberExpression::ComputedMemberExpression(expr)) =
+        let Some(AstKind::MemberExpression(MemberExpression::ComputedMemberExpression(expr))) =
             self.parent_kind()
         else {
             return false;
@@ -360,7 +362,8 @@ impl<'a> Prettier<'a> {
     }
 
     fn check_update_unary(&self, span: Span) -> bool {
-        match self.parent_kind() {
+        let Some(parent_kind) = self.parent_kind() else { return false };
+        match parent_kind {
             AstKind::MemberExpression(member_expr) => member_expr.object().span() == span,
             AstKind::TaggedTemplateExpression(_) => true,
             AstKind::CallExpression(call_expr) => call_expr.callee.span() == span,
@@ -374,7 +377,8 @@ impl<'a> Prettier<'a> {
     }
 
     fn check_yield_await(&self, span: Span) -> bool {
-        match self.parent_kind() {
+        let Some(parent_kind) = self.parent_kind() else { return false };
+        match parent_kind {
             AstKind::TaggedTemplateExpression(_)
             | AstKind::UnaryExpression(_)
             | AstKind::LogicalExpression(_)
@@ -393,7 +397,7 @@ impl<'a> Prettier<'a> {
 
     fn check_binarish(&self, span: Span) -> bool {
         let current_kind = self.current_kind();
-        let parent_kind = self.parent_kind();
+        let Some(parent_kind) = self.parent_kind() else { return false };
         match parent_kind {
             AstKind::TSAsExpression(_) => return !self.is_binary_cast_expression(span),
             AstKind::TSSatisfiesExpression(_) => return !self.is_binary_cast_expression(span),
@@ -473,7 +477,8 @@ impl<'a> Prettier<'a> {
     }
 
     fn check_member_call_tagged_template_ts_non_null(&self, span: Span) -> bool {
-        match self.parent_kind() {
+        let Some(parent_kind) = self.parent_kind() else { return false };
+        match parent_kind {
             AstKind::NewExpression(new_expr) if new_expr.callee.span() == span => {
                 let mut object = &new_expr.callee;
                 loop {
@@ -496,7 +501,7 @@ impl<'a> Prettier<'a> {
         let kind = self.current_kind();
         let b = matches!(
             self.parent_kind(),
-            AstKind::ModuleDeclaration(ModuleDeclaration::ExportDefaultDeclaration(_))
+            Some(AstKind::ModuleDeclaration(ModuleDeclaration::ExportDefaultDeclaration(_)))
         );
         if matches!(kind, AstKind::Function(f) if f.is_expression())
             || matches!(kind, AstKind::Class(c) if c.is_expression())
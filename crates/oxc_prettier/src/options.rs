@@ -6,7 +6,7 @@ use std::str::FromStr;
 /// * <https://prettier.io/docs/en/options>
 /// * <https://github.com/prettier/prettier/blob/main/src/main/core-options.evaluate.js>
 /// * <https://github.com/prettier/prettier/blob/main/src/language-js/options.js>
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct PrettierOptions {
     /* Global Options */
     /// Specify the line length that the printer will wrap on.
@@ -18,12 +18,21 @@ pub struct PrettierOptions {
     pub tab_width: usize,
 
     /// Indent lines with tabs instead of spaces.
+    /// Default: false
     pub use_tabs: bool,
 
     /// Which end of line characters to apply.
     /// Default: "lf"
     pub end_of_line: EndOfLine,
 
+    /// Only format files that contain a special comment (`@format` or `@prettier`) at the top of the file.
+    /// Default: false
+    pub require_pragma: bool,
+
+    /// Insert a special `@format` comment at the top of the file if it doesn't have one yet.
+    /// Default: false
+    pub insert_pragma: bool,
+
     /* JavaScript Options */
     /// Print semicolons at the ends of statements.
     /// Default: true
@@ -59,9 +68,21 @@ pub struct PrettierOptions {
     /// Default: false
     pub bracket_same_line: bool,
 
+    /// Enforce single attribute per line in HTML, Vue and JSX.
+    /// Default: false
+    pub single_attribute_per_line: bool,
+
     /// Include parentheses around a sole arrow function parameter.
     /// Default: [ArrowParens::Always]
     pub arrow_parens: ArrowParens,
+
+    /// Use curious ternaries, with the question mark after the condition.
+    /// Default: false
+    pub experimental_ternaries: bool,
+
+    /// Control whether Prettier formats quoted code embedded in the file.
+    /// Default: [EmbeddedLanguageFormatting::Auto]
+    pub embedded_language_formatting: EmbeddedLanguageFormatting,
 }
 
 impl Default for PrettierOptions {
@@ -71,6 +92,8 @@ impl Default for PrettierOptions {
             tab_width: 2,
             use_tabs: false,
             end_of_line: EndOfLine::default(),
+            require_pragma: false,
+            insert_pragma: false,
             semi: true,
             single_quote: false,
             quote_props: QuoteProps::default(),
@@ -78,12 +101,98 @@ impl Default for PrettierOptions {
             trailing_comma: TrailingComma::default(),
             bracket_spacing: true,
             bracket_same_line: false,
+            single_attribute_per_line: false,
             arrow_parens: ArrowParens::default(),
+            experimental_ternaries: false,
+            embedded_language_formatting: EmbeddedLanguageFormatting::default(),
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, Default)]
+impl PrettierOptions {
+    /// Build options by chaining setters on top of the defaults.
+    ///
+    /// ```
+    /// use oxc_prettier::{PrettierOptions, TrailingComma};
+    ///
+    /// let options = PrettierOptions::builder()
+    ///     .tab_width(4)
+    ///     .single_quote(true)
+    ///     .trailing_comma(TrailingComma::All)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(options.tab_width, 4);
+    /// ```
+    pub fn builder() -> PrettierOptionsBuilder {
+        PrettierOptionsBuilder::default()
+    }
+}
+
+macro_rules! builder_setters {
+    ($($(#[$attr:meta])* $field:ident: $ty:ty),* $(,)?) => {
+        $(
+            $(#[$attr])*
+            #[must_use]
+            pub fn $field(mut self, $field: $ty) -> Self {
+                self.options.$field = $field;
+                self
+            }
+        )*
+    };
+}
+
+/// Builder for [PrettierOptions], see [PrettierOptions::builder].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PrettierOptionsBuilder {
+    options: PrettierOptions,
+}
+
+impl PrettierOptionsBuilder {
+    builder_setters! {
+        /// See [PrettierOptions::print_width]. Must be greater than 0.
+        print_width: usize,
+        /// See [PrettierOptions::tab_width]. Must be between 1 and 8.
+        tab_width: usize,
+        use_tabs: bool,
+        end_of_line: EndOfLine,
+        require_pragma: bool,
+        insert_pragma: bool,
+        semi: bool,
+        single_quote: bool,
+        quote_props: QuoteProps,
+        jsx_single_quote: bool,
+        trailing_comma: TrailingComma,
+        bracket_spacing: bool,
+        bracket_same_line: bool,
+        single_attribute_per_line: bool,
+        arrow_parens: ArrowParens,
+        experimental_ternaries: bool,
+        embedded_language_formatting: EmbeddedLanguageFormatting,
+    }
+
+    /// Validate and return the options.
+    ///
+    /// # Errors
+    ///
+    /// * `print_width` is 0
+    /// * `tab_width` is not between 1 and 8
+    /// * both `require_pragma` and `insert_pragma` are set
+    pub fn build(self) -> Result<PrettierOptions, String> {
+        let options = self.options;
+        if options.print_width == 0 {
+            return Err("`print_width` must be greater than 0".to_string());
+        }
+        if !(1..=8).contains(&options.tab_width) {
+            return Err(format!("`tab_width` must be between 1 and 8, got {}", options.tab_width));
+        }
+        if options.require_pragma && options.insert_pragma {
+            return Err("`require_pragma` and `insert_pragma` cannot be used together".to_string());
+        }
+        Ok(options)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 pub enum EndOfLine {
     #[default]
     Lf,
@@ -116,7 +225,7 @@ impl FromStr for EndOfLine {
     }
 }
 
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 pub enum QuoteProps {
     /// Only add quotes around object properties where required.
     #[default]
@@ -154,7 +263,7 @@ impl FromStr for QuoteProps {
     }
 }
 
-#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum TrailingComma {
     /// Trailing commas wherever possible (including function parameters and calls).
     #[default]
@@ -192,7 +301,7 @@ impl FromStr for TrailingComma {
     }
 }
 
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 pub enum ArrowParens {
     /// Always include parens. `Example: (x) => x`
     #[default]
@@ -218,3 +327,24 @@ impl FromStr for ArrowParens {
         })
     }
 }
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum EmbeddedLanguageFormatting {
+    /// Format embedded code if Prettier can automatically identify it.
+    #[default]
+    Auto,
+    /// Never automatically format embedded code.
+    Off,
+}
+
+impl FromStr for EmbeddedLanguageFormatting {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "auto" => Self::Auto,
+            "off" => Self::Off,
+            _ => Self::default(),
+        })
+    }
+}
@@ -29,6 +29,9 @@ pub struct Printer<'a> {
     line_suffix: Vec<Command<'a>>,
     group_mode_map: HashMap<GroupId, Mode>,
 
+    /// Output positions of the printed [Doc::Cursor] placeholders
+    cursor_positions: Vec<usize>,
+
     // states
     new_line: &'static str,
 
@@ -60,6 +63,7 @@ impl<'a> Printer<'a> {
             cmds,
             line_suffix: vec![],
             group_mode_map: HashMap::new(),
+            cursor_positions: vec![],
             new_line: options.end_of_line.as_str(),
             allocator,
         }
@@ -74,29 +78,68 @@ impl<'a> Printer<'a> {
         }
     }
 
+    /// Same as [Printer::build], but also returns the output positions of the
+    /// [Doc::Cursor] placeholders in the order they were printed.
+    pub fn build_with_cursor(mut self) -> (String, Vec<usize>) {
+        self.print_doc_to_string();
+        let len = self.out.len();
+        let cursor_positions = self.cursor_positions.iter().map(|pos| (*pos).min(len)).collect();
+        // SAFETY: We should have constructed valid UTF8 strings
+        #[allow(unsafe_code)]
+        let output = unsafe { String::from_utf8_unchecked(self.out) };
+        (output, cursor_positions)
+    }
+
+    /// Whether the output is exactly `expected`.
+    ///
+    /// Printing stops at the first byte which differs from `expected`.
+    pub fn check(mut self, expected: &str) -> bool {
+        let expected = expected.as_bytes();
+        let mut checked = 0;
+        while let Some(cmd) = self.cmds.pop() {
+            self.print_command(cmd);
+            // Trailing spaces and tabs may still be trimmed by a following line break.
+            let stable = self.out.len()
+                - self.out.iter().rev().take_while(|b| matches!(b, b' ' | b'\t')).count();
+            if stable > checked {
+                if expected.get(checked..stable) != Some(&self.out[checked..stable]) {
+                    return false;
+                }
+                checked = stable;
+            }
+        }
+        self.out == expected
+    }
+
     /// Turn Doc into a string
     ///
     /// Reference:
     /// * <https://github.com/prettier/prettier/blob/0176a33db442e498fdb577784deaa77d7c9ae723/src/document/printer.js#L302>
     pub fn print_doc_to_string(&mut self) {
-        while let Some(Command { indent, mut doc, mode }) = self.cmds.pop() {
-            Self::propagate_breaks(&mut doc);
-            match doc {
-                Doc::Str(s) => self.handle_str(s),
-                Doc::Array(docs) => self.handle_array(indent, mode, docs),
-                Doc::Indent(docs) => self.handle_indent(indent, mode, docs),
-                Doc::Group(_) => self.handle_group(indent, mode, doc),
-                Doc::IndentIfBreak(docs) => self.handle_indent_if_break(indent, mode, docs),
-                Doc::Line(line) => self.handle_line(line, indent, mode, doc),
-                Doc::LineSuffix(docs) => self.handle_line_suffix(indent, mode, docs),
-                Doc::IfBreak(if_break) => self.handle_if_break(if_break, indent, mode),
-                Doc::Fill(fill) => self.handle_fill(indent, mode, fill),
-                Doc::BreakParent => { /* No op */ }
-            }
+        while let Some(cmd) = self.cmds.pop() {
+            self.print_command(cmd);
+        }
+    }
 
-            if self.cmds.is_empty() && !self.line_suffix.is_empty() {
-                self.cmds.extend(self.line_suffix.drain(..).rev());
-            }
+    fn print_command(&mut self, cmd: Command<'a>) {
+        let Command { indent, mut doc, mode } = cmd;
+        Self::propagate_breaks(&mut doc);
+        match doc {
+            Doc::Str(s) => self.handle_str(s),
+            Doc::Array(docs) => self.handle_array(indent, mode, docs),
+            Doc::Indent(docs) => self.handle_indent(indent, mode, docs),
+            Doc::Group(_) => self.handle_group(indent, mode, doc),
+            Doc::IndentIfBreak(docs) => self.handle_indent_if_break(indent, mode, docs),
+            Doc::Line(line) => self.handle_line(line, indent, mode, doc),
+            Doc::LineSuffix(docs) => self.handle_line_suffix(indent, mode, docs),
+            Doc::IfBreak(if_break) => self.handle_if_break(if_break, indent, mode),
+            Doc::Fill(fill) => self.handle_fill(indent, mode, fill),
+            Doc::BreakParent => { /* No op */ }
+            Doc::Cursor => self.cursor_positions.push(self.out.len()),
+        }
+
+        if self.cmds.is_empty() && !self.line_suffix.is_empty() {
+            self.cmds.extend(self.line_suffix.drain(..).rev());
         }
     }
 
@@ -334,15 +377,16 @@ impl<'a> Printer<'a> {
         };
     }
 
+    /// Print the indentation for `size` levels and return the resulting column width.
+    ///
+    /// A tab counts as `tab_width` columns so that `fits` measures the same width as Prettier.
     fn indent(&mut self, size: usize) -> usize {
         if self.options.use_tabs {
             self.out.extend("\t".repeat(size).as_bytes());
-            size
         } else {
-            let count = self.options.tab_width * size;
-            self.out.extend(" ".repeat(count).as_bytes());
-            count
+            self.out.extend(" ".repeat(self.options.tab_width * size).as_bytes());
         }
+        self.options.tab_width * size
     }
 
     fn trim(&mut self) {
@@ -423,7 +467,7 @@ impl<'a> Printer<'a> {
                 Doc::LineSuffix(_) => {
                     break;
                 }
-                Doc::BreakParent => {}
+                Doc::BreakParent | Doc::Cursor => {}
             }
 
             if remaining_width < 0 {
@@ -24,6 +24,6 @@ pub fn will_break(doc: &mut Doc<'_>) -> bool {
         | Doc::IndentIfBreak(IndentIfBreak { contents: arr, .. }) => check_array(arr),
         Doc::Fill(doc) => check_array(&mut doc.parts),
         Doc::Line(doc) => doc.hard,
-        Doc::Str(_) => false,
+        Doc::Str(_) | Doc::Cursor => false,
     }
 }
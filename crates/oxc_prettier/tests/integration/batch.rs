@@ -0,0 +1,35 @@
+use std::path::Path;
+
+use oxc_prettier::{format_files_parallel, PrettierOptions};
+
+#[test]
+fn preserves_order() {
+    let sources = (0..32).map(|i| format!("let  a{i}={i}")).collect::<Vec<_>>();
+    let paths = (0..32).map(|i| format!("file{i}.js")).collect::<Vec<_>>();
+    let files = paths
+        .iter()
+        .zip(&sources)
+        .map(|(path, source)| (Path::new(path.as_str()), source.as_str()))
+        .collect::<Vec<_>>();
+
+    let results = format_files_parallel(&files, PrettierOptions::default());
+    assert_eq!(results.len(), files.len());
+    for (i, result) in results.into_iter().enumerate() {
+        assert_eq!(result.unwrap(), format!("let a{i} = {i};"));
+    }
+}
+
+#[test]
+fn parse_error_keeps_other_files() {
+    let files = [
+        (Path::new("a.js"), "let  a=1"),
+        (Path::new("b.js"), "let = ;"),
+        (Path::new("c.ts"), "let  c:number=1"),
+    ];
+    let results = format_files_parallel(&files, PrettierOptions::default());
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].as_deref().ok(), Some("let a = 1;"));
+    assert!(results[1].as_ref().is_err_and(|errors| !errors.is_empty()));
+    // The source type is inferred from the path.
+    assert_eq!(results[2].as_deref().ok(), Some("let c: number = 1;"));
+}
@@ -0,0 +1,72 @@
+use oxc_prettier::{FormatterCache, PrettierOptions};
+use oxc_span::SourceType;
+
+#[test]
+fn reuses_output() {
+    let mut cache = FormatterCache::new();
+    let options = PrettierOptions::default();
+    let source_type = SourceType::default();
+    assert_eq!(cache.format_or_cached("let  a=1", source_type, &options).unwrap(), "let a = 1;");
+    assert_eq!(cache.format_or_cached("let  a=1", source_type, &options).unwrap(), "let a = 1;");
+    assert_eq!(cache.len(), 1);
+    assert_eq!(cache.format_or_cached("let  b=1", source_type, &options).unwrap(), "let b = 1;");
+    assert_eq!(cache.len(), 2);
+}
+
+#[test]
+fn keyed_by_options() {
+    let mut cache = FormatterCache::new();
+    let source_type = SourceType::default();
+    let options = PrettierOptions::default();
+    assert_eq!(cache.format_or_cached("let  a=1", source_type, &options).unwrap(), "let a = 1;");
+    let options = PrettierOptions { semi: false, ..options };
+    assert_eq!(cache.format_or_cached("let  a=1", source_type, &options).unwrap(), "let a = 1");
+    assert_eq!(cache.len(), 2);
+}
+
+#[test]
+fn keyed_by_source_type() {
+    let mut cache = FormatterCache::new();
+    let options = PrettierOptions::default();
+    let source_text = "let  a: number=1";
+    assert!(cache.format_or_cached(source_text, SourceType::default(), &options).is_err());
+    let source_type = SourceType::default().with_typescript(true);
+    assert_eq!(
+        cache.format_or_cached(source_text, source_type, &options).unwrap(),
+        "let a: number = 1;"
+    );
+    assert_eq!(cache.len(), 1);
+}
+
+#[test]
+fn evicts_least_recently_used() {
+    let mut cache = FormatterCache::with_max_entries(2);
+    let options = PrettierOptions::default();
+    let source_type = SourceType::default();
+    cache.format_or_cached("let  a=1", source_type, &options).unwrap();
+    cache.format_or_cached("let  b=1", source_type, &options).unwrap();
+    // `a` becomes the most recently used output, so `b` is evicted by `c`.
+    cache.format_or_cached("let  a=1", source_type, &options).unwrap();
+    assert_eq!(cache.format_or_cached("let  c=1", source_type, &options).unwrap(), "let c = 1;");
+    assert_eq!(cache.len(), 2);
+    assert!(cache.contains("let  a=1", source_type, &options));
+    assert!(!cache.contains("let  b=1", source_type, &options));
+    assert!(cache.contains("let  c=1", source_type, &options));
+
+    // `contains` does not mark `a` as recently used, so `a` is evicted by `b`.
+    cache.format_or_cached("let  b=1", source_type, &options).unwrap();
+    assert!(!cache.contains("let  a=1", source_type, &options));
+    assert!(cache.contains("let  b=1", source_type, &options));
+    assert!(cache.contains("let  c=1", source_type, &options));
+}
+
+#[test]
+fn default_max_entries() {
+    let mut cache = FormatterCache::new();
+    let options = PrettierOptions::default();
+    let source_type = SourceType::default();
+    for i in 0..=FormatterCache::DEFAULT_MAX_ENTRIES {
+        cache.format_or_cached(&format!("let  a{i}=1"), source_type, &options).unwrap();
+    }
+    assert_eq!(cache.len(), FormatterCache::DEFAULT_MAX_ENTRIES);
+}
@@ -0,0 +1,70 @@
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_prettier::{Prettier, PrettierOptions};
+use oxc_span::SourceType;
+
+use crate::format;
+
+fn check(source_text: &str, options: PrettierOptions) -> bool {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_module(true);
+    let ret = Parser::new(&allocator, source_text, source_type).preserve_parens(false).parse();
+    Prettier::new(&allocator, source_text, ret.trivias, options).check(&ret.program)
+}
+
+fn check_diff(source_text: &str) -> Option<String> {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_module(true);
+    let ret = Parser::new(&allocator, source_text, source_type).preserve_parens(false).parse();
+    Prettier::new(&allocator, source_text, ret.trivias, PrettierOptions::default())
+        .check_diff(&ret.program)
+}
+
+#[test]
+fn formatted() {
+    let options = PrettierOptions::default();
+    assert!(check("let a = 1;", options));
+    assert!(check("function foo() {\n  return 1;\n}", options));
+    assert!(check("foo(\n  aaaaaaaaaaaaaaaaaaaaaaaaaaaaaa,\n  bbbbbbbbbbbbbbbbbbbbbbbbbbbbbb,\n  cccccccccccccccccccc,\n);", options));
+}
+
+#[test]
+fn agrees_with_build() {
+    let options = PrettierOptions::default();
+    for source in
+        ["let  a=1", "class A { foo(){ return [1,2,3] } }", "// comment\nfoo( ) // trailing"]
+    {
+        let output = format(source, options);
+        assert!(!check(source, options), "{source}");
+        assert!(check(&output, options), "{output}");
+    }
+}
+
+#[test]
+fn unformatted() {
+    let options = PrettierOptions::default();
+    // First difference at the start, in the middle and at the end.
+    assert!(!check("let  a = 1;", options));
+    assert!(!check("function foo() {\n    return 1;\n}", options));
+    assert!(!check("let a = 1", options));
+    assert!(!check("let a = 1;\n", options));
+    // Trailing whitespace which the printer trims before a line break.
+    assert!(!check("let a = 1;  \nlet b = 2;", options));
+    assert!(!check("", PrettierOptions { insert_pragma: true, ..options }));
+}
+
+#[test]
+fn require_pragma() {
+    let options = PrettierOptions { require_pragma: true, ..PrettierOptions::default() };
+    assert!(check("let  a = 1;", options));
+    assert!(!check("/** @format */\nlet  a = 1;", options));
+}
+
+#[test]
+fn diff() {
+    assert_eq!(check_diff("let a = 1;"), None);
+    assert_eq!(
+        check_diff("let  a = 1;").unwrap(),
+        "--- original\n+++ formatted\n@@ -1 +1 @@\n-let  a = 1;\n\\ No newline at end of file\n+let a = 1;\n\\ No newline at end of file\n"
+    );
+}
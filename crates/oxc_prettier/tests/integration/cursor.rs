@@ -0,0 +1,44 @@
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_prettier::{Prettier, PrettierOptions};
+use oxc_span::SourceType;
+
+/// Format `source_text` with the cursor at `|`, and return the output with the new cursor at `|`.
+fn format_with_cursor(source_text: &str) -> String {
+    let cursor_offset = u32::try_from(source_text.find('|').unwrap()).unwrap();
+    let source_text = source_text.replacen('|', "", 1);
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_module(true);
+    let ret = Parser::new(&allocator, &source_text, source_type).preserve_parens(false).parse();
+    let (mut output, cursor_offset) =
+        Prettier::new(&allocator, &source_text, ret.trivias, PrettierOptions::default())
+            .build_with_cursor(&ret.program, cursor_offset);
+    output.insert(cursor_offset as usize, '|');
+    output
+}
+
+#[test]
+fn inside_identifier() {
+    assert_eq!(format_with_cursor("let  fo|o=1"), "let fo|o = 1;");
+    assert_eq!(format_with_cursor("foo(  a,  b|ar  )"), "foo(a, b|ar);");
+}
+
+#[test]
+fn reformatted_whitespace() {
+    assert_eq!(format_with_cursor("let a  | =  1"), "let a| = 1;");
+    assert_eq!(format_with_cursor("foo(  a,|  b  )"), "foo(a,| b);");
+    assert_eq!(format_with_cursor("if (a) {|\n\n\n  b()\n}"), "if (a) {|\n  b();\n}");
+}
+
+#[test]
+fn start_of_file() {
+    assert_eq!(format_with_cursor("|let  a=1"), "|let a = 1;");
+    assert_eq!(format_with_cursor("|\n\n  let  a=1"), "|let a = 1;");
+}
+
+#[test]
+fn end_of_file() {
+    assert_eq!(format_with_cursor("let  a=1|"), "let a = 1|;");
+    assert_eq!(format_with_cursor("let  a=1;\n\n\n|"), "let a = 1;|");
+    assert_eq!(format_with_cursor("let  a=1; // comment|"), "let a = 1; // comment|");
+}
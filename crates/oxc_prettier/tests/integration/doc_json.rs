@@ -0,0 +1,90 @@
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_prettier::{doc_to_json, Prettier, PrettierOptions};
+use oxc_span::SourceType;
+use serde_json::{json, Value};
+
+fn doc_json(source_text: &str) -> Value {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_module(true);
+    let ret = Parser::new(&allocator, source_text, source_type).preserve_parens(false).parse();
+    let doc = Prettier::new(&allocator, source_text, ret.trivias, PrettierOptions::default())
+        .doc(&ret.program);
+    serde_json::from_str(&doc_to_json(&doc)).unwrap()
+}
+
+/// All docs of `doc` and its descendants, in pre-order.
+fn descendants(doc: &Value) -> Vec<&Value> {
+    let mut docs = vec![doc];
+    for key in ["parts", "contents", "expandedStates"] {
+        if let Some(children) = doc[key].as_array() {
+            docs.extend(children.iter().flat_map(descendants));
+        }
+    }
+    for key in ["breakContents", "flatContents"] {
+        if doc[key].is_object() {
+            docs.extend(descendants(&doc[key]));
+        }
+    }
+    docs
+}
+
+fn strings(doc: &Value) -> Vec<&str> {
+    descendants(doc)
+        .into_iter()
+        .filter(|doc| doc["type"] == "string")
+        .map(|doc| doc["value"].as_str().unwrap())
+        .collect()
+}
+
+#[test]
+fn statement() {
+    let doc = doc_json("let a = 1;");
+    assert_eq!(doc["type"], "array");
+    assert_eq!(strings(&doc).concat(), "let a = 1;");
+    let types =
+        descendants(&doc).iter().map(|doc| doc["type"].as_str().unwrap()).collect::<Vec<_>>();
+    assert!(types.contains(&"group"));
+}
+
+#[test]
+fn every_doc_has_a_known_type() {
+    let doc = doc_json("function foo(a, b) {\n  return a ? [b, ...a] : `${b}`; // comment\n}");
+    for doc in descendants(&doc) {
+        let ty = doc["type"].as_str().unwrap();
+        let keys = doc.as_object().unwrap().keys().map(String::as_str).collect::<Vec<_>>();
+        let expected: &[&str] = match ty {
+            "string" => &["type", "value"],
+            "array" | "fill" => &["parts", "type"],
+            "indent" | "line-suffix" => &["contents", "type"],
+            "indent-if-break" => &["contents", "groupId", "type"],
+            "group" => &["break", "contents", "expandedStates", "id", "type"],
+            "line" => &["hard", "literal", "soft", "type"],
+            "if-break" => &["breakContents", "flatContents", "groupId", "type"],
+            "break-parent" | "cursor" => &["type"],
+            _ => panic!("unknown doc type {ty}"),
+        };
+        assert_eq!(keys, expected);
+    }
+}
+
+#[test]
+fn line_suffix_comment() {
+    let doc = doc_json("foo(); // comment");
+    let suffix = descendants(&doc).into_iter().find(|doc| doc["type"] == "line-suffix").unwrap();
+    assert!(strings(suffix).contains(&"// comment"));
+}
+
+#[test]
+fn escaped_strings() {
+    let doc = doc_json("let a = \"\\\"\\\\\\n\\t\";");
+    // Quotes and backslashes survive the JSON escaping.
+    assert!(strings(&doc).contains(&r#"'"\\\n\t'"#));
+}
+
+#[test]
+fn hardline() {
+    let doc = doc_json("a;\nb;");
+    assert!(descendants(&doc)
+        .contains(&&json!({ "type": "line", "hard": true, "soft": false, "literal": false })));
+}
@@ -0,0 +1,76 @@
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_prettier::{
+    EmbeddedFormatter, EmbeddedLanguage, EmbeddedLanguageFormatting, Prettier, PrettierOptions,
+};
+use oxc_span::SourceType;
+
+/// Collapses whitespace and prefixes the output with the language.
+struct TestFormatter;
+
+impl EmbeddedFormatter for TestFormatter {
+    fn format(
+        &self,
+        language: EmbeddedLanguage,
+        text: &str,
+        _options: &PrettierOptions,
+    ) -> Option<String> {
+        let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        Some(format!("{language:?}: {text}"))
+    }
+}
+
+fn format_embedded(source_text: &str, options: PrettierOptions) -> String {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_module(true);
+    let ret = Parser::new(&allocator, source_text, source_type).preserve_parens(false).parse();
+    Prettier::new(&allocator, source_text, ret.trivias, options)
+        .with_embedded_formatter(&TestFormatter)
+        .build(&ret.program)
+}
+
+fn test(source_text: &str, expected: &str) {
+    assert_eq!(format_embedded(source_text, PrettierOptions::default()), expected);
+}
+
+#[test]
+fn css() {
+    test("css`a {  color: red; }`;", "css`\n  Css: a { color: red; }\n`;");
+}
+
+#[test]
+fn graphql() {
+    test("graphql`query {  user }`;", "graphql`\n  GraphQL: query { user }\n`;");
+    test("gql`query {  user }`;", "gql`\n  GraphQL: query { user }\n`;");
+}
+
+#[test]
+fn html() {
+    test("html`<div>  hi</div>`;", "html`\n  Html: <div> hi</div>\n`;");
+}
+
+#[test]
+fn markdown() {
+    test("markdown`#  Title`;", "markdown`\n  Markdown: # Title\n`;");
+    test("md`#  Title`;", "md`\n  Markdown: # Title\n`;");
+}
+
+#[test]
+fn unhandled_tag() {
+    test("yaml`a:  1`;", "yaml`a:  1`;");
+    test("foo.css`a {  color: red; }`;", "foo.css`a {  color: red; }`;");
+}
+
+#[test]
+fn substitutions_are_kept() {
+    test("css`a { color: ${color}; }`;", "css`a { color: ${color}; }`;");
+}
+
+#[test]
+fn off() {
+    let options = PrettierOptions {
+        embedded_language_formatting: EmbeddedLanguageFormatting::Off,
+        ..PrettierOptions::default()
+    };
+    assert_eq!(format_embedded("css`a {  color: red; }`;", options), "css`a {  color: red; }`;");
+}
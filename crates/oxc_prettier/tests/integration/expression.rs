@@ -0,0 +1,35 @@
+use oxc_allocator::Allocator;
+use oxc_ast::Trivias;
+use oxc_parser::Parser;
+use oxc_prettier::{Prettier, PrettierOptions};
+use oxc_span::SourceType;
+
+fn format_expression(source_text: &str) -> String {
+    let allocator = Allocator::default();
+    let expr = Parser::new(&allocator, source_text, SourceType::default())
+        .preserve_parens(false)
+        .parse_expression()
+        .unwrap();
+    Prettier::new(&allocator, source_text, Trivias::default(), PrettierOptions::default())
+        .format_expression(&expr)
+}
+
+#[test]
+fn root_expressions() {
+    assert_eq!(format_expression("a+b*c"), "a + b * c");
+    assert_eq!(format_expression("(a+b)*c"), "(a + b) * c");
+    assert_eq!(format_expression("a=b=c"), "a = b = c");
+    assert_eq!(format_expression("x=>x"), "(x) => x");
+    assert_eq!(format_expression("{a:1}"), "{ a: 1 }");
+    assert_eq!(format_expression("function(){}"), "function () {}");
+    assert_eq!(format_expression("a,b"), "a, b");
+    assert_eq!(format_expression("a?b:c"), "a ? b : c");
+}
+
+#[test]
+fn no_parentheses_at_the_root() {
+    // These would be wrapped at the start of a statement.
+    assert_eq!(format_expression("({}).toString()"), "{}.toString()");
+    assert_eq!(format_expression("(function(){})()"), "(function () {})()");
+    assert_eq!(format_expression("(let)"), "let");
+}
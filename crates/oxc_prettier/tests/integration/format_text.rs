@@ -0,0 +1,24 @@
+use oxc_prettier::{format_text, PrettierOptions};
+
+#[test]
+fn formats_modules_with_jsx() {
+    let options = PrettierOptions::default();
+    assert_eq!(
+        format_text("import a from 'a'\nexport default a", options).unwrap(),
+        "import a from \"a\";\nexport default a;"
+    );
+    assert_eq!(
+        format_text("const el = <div  a='1' />", options).unwrap(),
+        "const el = <div a=\"1\" />;"
+    );
+    let options = PrettierOptions { semi: false, ..PrettierOptions::default() };
+    assert_eq!(format_text("let  a=1;", options).unwrap(), "let a = 1");
+}
+
+#[test]
+fn syntax_errors() {
+    let errors = format_text("let = ;", PrettierOptions::default()).unwrap_err();
+    assert!(!errors.is_empty());
+    // TypeScript is not enabled.
+    assert!(format_text("let a: number = 1", PrettierOptions::default()).is_err());
+}
@@ -0,0 +1,19 @@
+use oxc_prettier::PrettierOptions;
+
+use crate::format;
+
+#[test]
+fn blank_line_after_inline_comment() {
+    let options = PrettierOptions::default();
+    // The blank line after a statement is kept when a single-line block comment follows it.
+    assert_eq!(format("a(); /* c */\n\nb();", options), "a(); /* c */\n\nb();");
+    assert_eq!(format("a(); /* c */\nb();", options), "a(); /* c */\nb();");
+}
+
+#[test]
+fn multiline_block_comment() {
+    let options = PrettierOptions::default();
+    // Only single-line block comments are skipped when looking for the blank line.
+    assert_eq!(format("a(); /* c\n */\n\nb();", options), "a(); /* c\n */\nb();");
+    assert_eq!(format("a(); /* c\n */\nb();", options), "a(); /* c\n */\nb();");
+}
@@ -0,0 +1,60 @@
+use oxc_prettier::PrettierOptions;
+
+use crate::format;
+
+fn test(source_text: &str, expected: &str) {
+    assert_eq!(format(source_text, PrettierOptions::default()), expected);
+}
+
+#[test]
+fn aligns_prefixes() {
+    test("/**\n   * Foo\n      *   bar\n*/\nfoo();", "/**\n * Foo\n * bar\n */\nfoo();");
+}
+
+#[test]
+fn removes_blank_lines() {
+    test(
+        "/**\n *\n * Foo\n *\n *\n * Bar\n *\n */\nfoo();",
+        "/**\n * Foo\n *\n * Bar\n */\nfoo();",
+    );
+}
+
+#[test]
+fn normalizes_tags() {
+    test(
+        "/**\n * @param   {  string  }   a   The  name\n * @returns {Promise<{ a: number }>} x\n */\nfoo();",
+        "/**\n * @param {string} a The name\n * @returns {Promise<{ a: number }>} x\n */\nfoo();",
+    );
+}
+
+#[test]
+fn wraps_long_tags() {
+    let source = "/**\n * @param {string} name The name of the thing which is described by this rather long description\n */\nfoo();";
+    test(
+        source,
+        "/**\n * @param {string} name The name of the thing which is described by this rather\n * long description\n */\nfoo();",
+    );
+}
+
+#[test]
+fn keeps_code_blocks() {
+    test(
+        "/**\n * Example:\n * ```js\n *   foo(  1  );\n *\n *\n * ```\n */\nfoo();",
+        "/**\n * Example:\n * ```js\n *   foo(  1  );\n *\n *\n * ```\n */\nfoo();",
+    );
+}
+
+#[test]
+fn indented() {
+    test(
+        "class A {\n/**\n* Method\n  * @param {string}  a\n*/\nfoo(a) {}\n}",
+        "class A {\n  /**\n   * Method\n   * @param {string} a\n   */\n  foo(a) {}\n}",
+    );
+}
+
+#[test]
+fn other_comments_are_kept() {
+    test("/** single line */\nfoo();", "/** single line */\nfoo();");
+    test("/*\n   * Not JSDoc\n */\nfoo();", "/*\n   * Not JSDoc\n */\nfoo();");
+    test("/**\n   Missing stars\n */\nfoo();", "/**\n   Missing stars\n */\nfoo();");
+}
@@ -0,0 +1,71 @@
+use oxc_prettier::PrettierOptions;
+use oxc_span::SourceType;
+
+use crate::format_with_source_type;
+
+fn format_jsx(source_text: &str, options: PrettierOptions) -> String {
+    format_with_source_type(
+        source_text,
+        SourceType::default().with_module(true).with_jsx(true),
+        options,
+    )
+}
+
+#[test]
+fn opening_element() {
+    let options = PrettierOptions::default();
+    assert_eq!(format_jsx("<div   a='1'   b={2}>x</div>", options), "<div a=\"1\" b={2}>x</div>;");
+    assert_eq!(format_jsx("<Foo   />", options), "<Foo />;");
+    assert_eq!(format_jsx("<div {...props}  key={1} />", options), "<div {...props} key={1} />;");
+    // A single string attribute is never broken.
+    assert_eq!(
+        format_jsx("<div className='aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa'>x</div>", options),
+        "<div className=\"aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\">x</div>;"
+    );
+}
+
+#[test]
+fn long_attributes() {
+    let source = "<div aaaaaaaaaaaaaaaaaaaa='1' bbbbbbbbbbbbbbbbbbbbbbbbb='2' cccccccccccccccccccccccccc='3'>x</div>";
+    assert_eq!(
+        format_jsx(source, PrettierOptions::default()),
+        "<div\n  aaaaaaaaaaaaaaaaaaaa=\"1\"\n  bbbbbbbbbbbbbbbbbbbbbbbbb=\"2\"\n  cccccccccccccccccccccccccc=\"3\"\n>x</div>;"
+    );
+    let options = PrettierOptions { bracket_same_line: true, ..PrettierOptions::default() };
+    assert_eq!(
+        format_jsx(source, options),
+        "<div\n  aaaaaaaaaaaaaaaaaaaa=\"1\"\n  bbbbbbbbbbbbbbbbbbbbbbbbb=\"2\"\n  cccccccccccccccccccccccccc=\"3\">x</div>;"
+    );
+    // Self-closing elements always put `/>` on its own line.
+    let source = "<div aaaaaaaaaaaaaaaaaaaa='1' bbbbbbbbbbbbbbbbbbbbbbbbb='2' cccccccccccccccccccccccccc='3' />";
+    assert_eq!(
+        format_jsx(source, options),
+        "<div\n  aaaaaaaaaaaaaaaaaaaa=\"1\"\n  bbbbbbbbbbbbbbbbbbbbbbbbb=\"2\"\n  cccccccccccccccccccccccccc=\"3\"\n/>;"
+    );
+}
+
+#[test]
+fn multiline_string_attribute() {
+    assert_eq!(
+        format_jsx("<div a='x\ny' b='1'>x</div>", PrettierOptions::default()),
+        "<div\n  a=\"x\ny\"\n  b=\"1\"\n>x</div>;"
+    );
+}
+
+#[test]
+fn single_attribute_per_line() {
+    let options = PrettierOptions { single_attribute_per_line: true, ..PrettierOptions::default() };
+    assert_eq!(
+        format_jsx("<div a='1' b={2}>x</div>", options),
+        "<div\n  a=\"1\"\n  b={2}\n>x</div>;"
+    );
+    assert_eq!(format_jsx("<div a='1' b={2} />", options), "<div\n  a=\"1\"\n  b={2}\n/>;");
+    // A single attribute stays on the line of the element.
+    assert_eq!(format_jsx("<div a='1'>x</div>", options), "<div a=\"1\">x</div>;");
+    assert_eq!(format_jsx("<div a={1}>x</div>", options), "<div a={1}>x</div>;");
+    let options = PrettierOptions { bracket_same_line: true, ..options };
+    assert_eq!(
+        format_jsx("<div a='1' b={2}>x</div>", options),
+        "<div\n  a=\"1\"\n  b={2}>x</div>;"
+    );
+}
@@ -0,0 +1,51 @@
+#![allow(clippy::missing_panics_doc)]
+
+mod batch;
+mod cache;
+mod check;
+mod cursor;
+#[cfg(feature = "serialize")]
+mod doc_json;
+mod embed;
+mod expression;
+mod format_text;
+mod inline_comments;
+mod jsdoc;
+mod jsx;
+mod options;
+mod print_width;
+mod range;
+mod ternary;
+mod type_literal;
+mod typescript_declarations;
+
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_prettier::{Prettier, PrettierOptions};
+use oxc_span::SourceType;
+
+pub fn format(source_text: &str, options: PrettierOptions) -> String {
+    format_with_source_type(source_text, SourceType::default().with_module(true), options)
+}
+
+pub fn format_with_source_type(
+    source_text: &str,
+    source_type: SourceType,
+    options: PrettierOptions,
+) -> String {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).preserve_parens(false).parse();
+    assert!(ret.errors.is_empty(), "{source_text}");
+    Prettier::new(&allocator, source_text, ret.trivias, options).build(&ret.program)
+}
+
+pub fn format_range(source_text: &str, start: u32, end: u32) -> String {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_module(true);
+    let ret = Parser::new(&allocator, source_text, source_type).preserve_parens(false).parse();
+    Prettier::new(&allocator, source_text, ret.trivias, PrettierOptions::default()).build_range(
+        &ret.program,
+        start,
+        end,
+    )
+}
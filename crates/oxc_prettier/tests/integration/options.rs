@@ -0,0 +1,74 @@
+use oxc_prettier::{ArrowParens, PrettierOptions, TrailingComma};
+
+#[test]
+fn defaults() {
+    assert_eq!(PrettierOptions::builder().build(), Ok(PrettierOptions::default()));
+}
+
+#[test]
+fn setters() {
+    let options = PrettierOptions::builder()
+        .print_width(100)
+        .tab_width(4)
+        .single_quote(true)
+        .trailing_comma(TrailingComma::None)
+        .arrow_parens(ArrowParens::Avoid)
+        .build()
+        .unwrap();
+    let expected = PrettierOptions {
+        print_width: 100,
+        tab_width: 4,
+        single_quote: true,
+        trailing_comma: TrailingComma::None,
+        arrow_parens: ArrowParens::Avoid,
+        ..PrettierOptions::default()
+    };
+    assert_eq!(options, expected);
+}
+
+#[test]
+fn print_width() {
+    assert_eq!(
+        PrettierOptions::builder().print_width(0).build(),
+        Err("`print_width` must be greater than 0".to_string())
+    );
+    assert_eq!(PrettierOptions::builder().print_width(1).build().unwrap().print_width, 1);
+    assert_eq!(
+        PrettierOptions::builder().print_width(usize::MAX).build().unwrap().print_width,
+        usize::MAX
+    );
+}
+
+#[test]
+fn tab_width() {
+    for tab_width in [0, 9, usize::MAX] {
+        assert_eq!(
+            PrettierOptions::builder().tab_width(tab_width).build(),
+            Err(format!("`tab_width` must be between 1 and 8, got {tab_width}"))
+        );
+    }
+    for tab_width in 1..=8 {
+        assert_eq!(
+            PrettierOptions::builder().tab_width(tab_width).build().unwrap().tab_width,
+            tab_width
+        );
+    }
+}
+
+#[test]
+fn pragma() {
+    assert_eq!(
+        PrettierOptions::builder().require_pragma(true).insert_pragma(true).build(),
+        Err("`require_pragma` and `insert_pragma` cannot be used together".to_string())
+    );
+    assert!(PrettierOptions::builder().require_pragma(true).build().is_ok());
+    assert!(PrettierOptions::builder().insert_pragma(true).build().is_ok());
+}
+
+#[test]
+fn first_error_wins() {
+    assert_eq!(
+        PrettierOptions::builder().print_width(0).tab_width(0).build(),
+        Err("`print_width` must be greater than 0".to_string())
+    );
+}
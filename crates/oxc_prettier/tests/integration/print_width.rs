@@ -0,0 +1,39 @@
+use oxc_prettier::PrettierOptions;
+
+use crate::format;
+
+fn format_with_width(source_text: &str, print_width: usize) -> String {
+    format(source_text, PrettierOptions { print_width, ..PrettierOptions::default() })
+}
+
+#[test]
+fn narrow_width_breaks_member_chain() {
+    let source = "promise.then(result => result.value).catch(error => log(error));";
+    assert_eq!(
+        format_with_width(source, 80),
+        "promise.then((result) => result.value).catch((error) => log(error));"
+    );
+    // Member chains are not broken per call yet, the first call which does not fit breaks.
+    assert_eq!(
+        format_with_width(source, 40),
+        "promise.then(\n  (result) => result.value,\n).catch((error) => log(error));"
+    );
+}
+
+#[test]
+fn wide_width_keeps_import_inline() {
+    let source = "import { aaaaaaaaaaaa, bbbbbbbbbbbb, cccccccccccc, dddddddddddd, eeeeeeeeeeee } from \"module\";";
+    assert_eq!(format_with_width(source, 120), source.trim_end());
+    assert_eq!(
+        format_with_width(source, 80),
+        "import {\n  aaaaaaaaaaaa,\n  bbbbbbbbbbbb,\n  cccccccccccc,\n  dddddddddddd,\n  eeeeeeeeeeee,\n} from \"module\";"
+    );
+}
+
+#[test]
+fn fits_exactly() {
+    // 21 columns including the semicolon
+    let source = "call(aaaaaa, bbbbbb);";
+    assert_eq!(format_with_width(source, 21), source.trim_end());
+    assert_eq!(format_with_width(source, 20), "call(\n  aaaaaa,\n  bbbbbb,\n);");
+}
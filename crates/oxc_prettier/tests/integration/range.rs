@@ -0,0 +1,58 @@
+use crate::format_range;
+
+/// The offset of the first occurrence of `needle` in `haystack`.
+#[allow(clippy::cast_possible_truncation)]
+fn offset(haystack: &str, needle: &str) -> u32 {
+    haystack.find(needle).unwrap() as u32
+}
+
+#[test]
+fn whole_statement() {
+    let source = "let a  =  1;\nlet b  =  2;\nlet c  =  3;\n";
+    let start = offset(source, "let b");
+    let end = offset(source, "\nlet c");
+    assert_eq!(format_range(source, start, end), "let a  =  1;\nlet b = 2;\nlet c  =  3;\n");
+}
+
+#[test]
+fn starts_mid_statement() {
+    let source = "let a  =  1;\nlet b  =  2;\nlet c  =  3;\n";
+    let start = offset(source, "2;");
+    let end = offset(source, "let c") + 3;
+    assert_eq!(format_range(source, start, end), "let a  =  1;\nlet b = 2;\nlet c = 3;\n");
+}
+
+#[test]
+fn ends_mid_statement() {
+    let source = "foo(  a,b  );\nbar(  c,d  );\nbaz(  e,f  );\n";
+    let start = offset(source, "foo");
+    let end = offset(source, "c,d");
+    assert_eq!(format_range(source, start, end), "foo(a, b);\nbar(c, d);\nbaz(  e,f  );\n");
+}
+
+#[test]
+fn inside_nested_node() {
+    // The range is expanded to the enclosing top level statement.
+    let source = "let x  =  1;\nfunction f(  ) {\n  return   1+2;\n}\nlet y  =  2;\n";
+    let start = offset(source, "1+2");
+    let end = start + 1;
+    assert_eq!(
+        format_range(source, start, end),
+        "let x  =  1;\nfunction f() {\n  return 1 + 2;\n}\nlet y  =  2;\n"
+    );
+}
+
+#[test]
+fn between_statements() {
+    let source = "let a  =  1;\n\n\nlet b  =  2;\n";
+    let start = offset(source, "\n\n") + 1;
+    assert_eq!(format_range(source, start, start + 1), source);
+}
+
+#[test]
+fn keeps_comments_outside_of_range() {
+    let source = "// a\nlet a  =  1;\n/* b */ let b  =  2;\n";
+    let start = offset(source, "let a");
+    let end = start + 1;
+    assert_eq!(format_range(source, start, end), "// a\nlet a = 1;\n/* b */ let b  =  2;\n");
+}
@@ -0,0 +1,48 @@
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_prettier::{Prettier, PrettierOptions};
+use oxc_span::SourceType;
+
+use crate::format;
+
+fn format_experimental(source_text: &str) -> String {
+    format(
+        source_text,
+        PrettierOptions { experimental_ternaries: true, ..PrettierOptions::default() },
+    )
+}
+
+#[test]
+fn chained_ternaries() {
+    let source = "const animal = isBird ? 'bird' : isCat ? 'cat' : isDog ? 'dog' : 'probably a fish or something';";
+    assert_eq!(format(source, PrettierOptions::default()), "const animal = isBird\n  ? \"bird\"\n  : isCat ? \"cat\" : isDog ? \"dog\" : \"probably a fish or something\";");
+    assert_eq!(format_experimental(source), "const animal =\n  isBird ? \"bird\"\n  : isCat ? \"cat\"\n  : isDog ? \"dog\"\n  : \"probably a fish or something\";");
+}
+
+#[test]
+fn short_chained_ternaries() {
+    let source = "x = a ? b : c ? d : e;";
+    assert_eq!(format(source, PrettierOptions::default()), "x = a ? b : c ? d : e;");
+    assert_eq!(format_experimental(source), "x = a ? b : c ? d : e;");
+}
+
+#[test]
+fn comments_in_chained_ternaries() {
+    let source = "const animal = isBird ? 'bird' : /* cat */ isCat ? 'cat' : isDog ? 'dog' : 'probably a fish or something';";
+    assert_eq!(format(source, PrettierOptions::default()), "const animal = isBird\n  ? \"bird\"\n  : /* cat */ isCat ? \"cat\" : isDog ? \"dog\" : \"probably a fish or something\";");
+    assert_eq!(format_experimental(source), "const animal =\n  isBird ? \"bird\"\n  : /* cat */ isCat ? \"cat\"\n  : isDog ? \"dog\"\n  : \"probably a fish or something\";");
+}
+
+#[test]
+fn cursor_in_chained_ternaries() {
+    // The cursor is after the `?` of the nested alternate `c  ?  d : e`,
+    // the quotes of the outer ternary change.
+    let source = "x = a ? 'b' : c  ?  d : e;";
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source, SourceType::default()).parse();
+    let options = PrettierOptions { experimental_ternaries: true, ..PrettierOptions::default() };
+    let (output, cursor) =
+        Prettier::new(&allocator, source, ret.trivias, options).build_with_cursor(&ret.program, 18);
+    assert_eq!(output, "x = a ? \"b\" : c ? d : e;");
+    assert_eq!(&output[..cursor as usize], "x = a ? \"b\" : c ?");
+}
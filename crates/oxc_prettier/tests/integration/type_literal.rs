@@ -0,0 +1,52 @@
+use oxc_prettier::PrettierOptions;
+use oxc_span::SourceType;
+
+use crate::format_with_source_type;
+
+fn format_ts(source_text: &str, options: PrettierOptions) -> String {
+    format_with_source_type(source_text, SourceType::default().with_typescript(true), options)
+}
+
+#[test]
+fn type_literal() {
+    let options = PrettierOptions::default();
+    assert_eq!(
+        format_ts("type A = {a:string,b?:number}", options),
+        "type A = { a: string; b?: number };"
+    );
+    assert_eq!(format_ts("type A = {}", options), "type A = {};");
+    assert_eq!(format_ts("let a: {a:string}", options), "let a: { a: string };");
+    assert_eq!(
+        format_ts("function f(a: {a:string;b():void}) {}", options),
+        "function f(a: { a: string; b(): void }) {}"
+    );
+}
+
+#[test]
+fn bracket_spacing() {
+    let options = PrettierOptions { bracket_spacing: false, ..PrettierOptions::default() };
+    assert_eq!(
+        format_ts("type A = {a:string,b?:number}", options),
+        "type A = {a: string; b?: number};"
+    );
+    assert_eq!(format_ts("type A = {}", options), "type A = {};");
+}
+
+#[test]
+fn breaks() {
+    let options = PrettierOptions::default();
+    // A newline in the original type literal keeps it expanded.
+    assert_eq!(
+        format_ts("type A = {\na:string,b:number}", options),
+        "type A = {\n  a: string;\n  b: number;\n};"
+    );
+    assert_eq!(
+        format_ts("type A = {aaaaaaaaaaaaaaaaaaaa:string,bbbbbbbbbbbbbbbbbbbbbb:number,cccccccccccccccccccc:boolean}", options),
+        "type A = {\n  aaaaaaaaaaaaaaaaaaaa: string;\n  bbbbbbbbbbbbbbbbbbbbbb: number;\n  cccccccccccccccccccc: boolean;\n};"
+    );
+    let options = PrettierOptions { semi: false, ..PrettierOptions::default() };
+    assert_eq!(
+        format_ts("type A = {\na:string,b:number}", options),
+        "type A = {\n  a: string\n  b: number\n}"
+    );
+}
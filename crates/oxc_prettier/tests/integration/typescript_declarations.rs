@@ -0,0 +1,83 @@
+use oxc_prettier::PrettierOptions;
+use oxc_span::SourceType;
+
+use crate::format_with_source_type;
+
+/// Declaration files which are already formatted stay the same.
+fn test_same(source_text: &str) {
+    let source_type = SourceType::from_path("index.d.ts").unwrap();
+    let output = format_with_source_type(source_text, source_type, PrettierOptions::default());
+    assert_eq!(output, source_text);
+}
+
+#[test]
+fn declare_module() {
+    test_same(
+        "declare module \"foo\" {\n  export function f(a: string): void;\n  export default f;\n}",
+    );
+    test_same("declare module \"foo\";");
+}
+
+#[test]
+fn declare_global() {
+    test_same("declare global {\n  interface Window {\n    foo: string;\n  }\n}");
+}
+
+#[test]
+fn namespace() {
+    test_same("declare namespace A.B.C {\n  const x: number;\n}");
+    test_same("namespace N {}");
+    test_same("export declare namespace M {\n  type T = string;\n}");
+}
+
+#[test]
+fn enums() {
+    test_same("declare enum E {\n  A,\n  B = 1,\n}");
+    test_same("declare const enum F {\n  A = \"a\",\n}");
+}
+
+#[test]
+fn export_assignment() {
+    test_same("export = foo;");
+}
+
+#[test]
+fn namespace_export() {
+    test_same("export as namespace MyLib;");
+}
+
+#[test]
+fn declarations() {
+    test_same("declare function g(): void;");
+    test_same("export declare function h<T>(a: T): T;");
+    test_same("declare class C {\n  m(): void;\n}");
+    test_same("declare const c: number;");
+    test_same("declare let d: string, e: number;");
+    test_same("declare function f<T extends string = \"a\">(a: T | null): A<T> & B;");
+}
+
+#[test]
+fn formats() {
+    let source_type = SourceType::from_path("index.d.ts").unwrap();
+    let source_text = "declare module 'foo'{export function f( ):void}\nexport   =  foo";
+    let output = format_with_source_type(source_text, source_type, PrettierOptions::default());
+    assert_eq!(output, "declare module \"foo\" {\n  export function f(): void;\n}\nexport = foo;");
+}
+
+#[test]
+fn interface_signatures() {
+    test_same(
+        "interface I {
+  a: string;
+  readonly b?: number;
+  [key: string]: unknown;
+  (): void;
+  new (a: string): I;
+  m<T>(a: T): T;
+  n?(): void;
+  get g(): string;
+  set s(value: string);
+  [Symbol.iterator](): Iterator<string>;
+}",
+    );
+}
@@ -68,12 +68,12 @@ fn main() -> std::io::Result<()> {
     let mut ast_nodes_by_block = HashMap::<_, Vec<_>>::new();
     for node in semantic.semantic.nodes().iter() {
         let block = node.cfg_id();
-        let block_ix = cfg.graph.node_weight(block).unwrap();
+        let block_ix = cfg.graph().node_weight(block).unwrap();
         ast_nodes_by_block.entry(*block_ix).or_default().push(node);
     }
 
     let basic_blocks_printed = cfg
-        .basic_blocks
+        .basic_blocks()
         .iter()
         .map(DisplayDot::display_dot)
         .enumerate()
@@ -128,7 +128,7 @@ fn main() -> std::io::Result<()> {
                     node.1,
                     nodes,
                     node.1,
-                    cfg.basic_blocks[*node.1].debug_dot(semantic.semantic.nodes().into()).trim()
+                    cfg.basic_blocks()[*node.1].debug_dot(semantic.semantic.nodes().into()).trim()
                 )
             }
         )
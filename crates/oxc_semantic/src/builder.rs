@@ -5,7 +5,7 @@ use std::{cell::RefCell, path::PathBuf, sync::Arc};
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::{ast::*, AstKind, Trivias, Visit};
 use oxc_cfg::{
-    ControlFlowGraphBuilder, CtxCursor, CtxFlags, EdgeType, ErrorEdgeKind,
+    BasicBlockId, ControlFlowGraphBuilder, CtxCursor, CtxFlags, EdgeType, ErrorEdgeKind,
     IterationInstructionKind, ReturnInstructionKind,
 };
 use oxc_diagnostics::OxcDiagnostic;
@@ -24,6 +24,7 @@ use crate::{
     reference::{Reference, ReferenceFlag, ReferenceId},
     scope::{ScopeFlags, ScopeId, ScopeTree},
     symbol::{SymbolFlags, SymbolId, SymbolTable},
+    type_inference::{TypeInference, TypeMap},
     JSDocFinder, Semantic,
 };
 
@@ -52,6 +53,8 @@ pub struct SemanticBuilder<'a> {
     pub current_node_flags: NodeFlags,
     pub current_symbol_flags: SymbolFlags,
     pub current_scope_id: ScopeId,
+    /// The current scope was entered before the node creating it
+    current_scope_node_pending: bool,
     /// Stores current `AstKind::Function` and `AstKind::ArrowFunctionExpression` during AST visit
     pub function_stack: Vec<AstNodeId>,
     // To make a namespace/module value like
@@ -75,6 +78,8 @@ pub struct SemanticBuilder<'a> {
 
     check_syntax_error: bool,
 
+    type_inference: bool,
+
     pub cfg: Option<ControlFlowGraphBuilder<'a>>,
 
     pub class_table_builder: ClassTableBuilder,
@@ -103,6 +108,7 @@ impl<'a> SemanticBuilder<'a> {
             current_symbol_flags: SymbolFlags::empty(),
             current_reference_flag: ReferenceFlag::empty(),
             current_scope_id,
+            current_scope_node_pending: false,
             function_stack: vec![],
             namespace_stack: vec![],
             nodes: AstNodes::default(),
@@ -113,6 +119,7 @@ impl<'a> SemanticBuilder<'a> {
             build_jsdoc: false,
             jsdoc: JSDocBuilder::new(source_text, trivias),
             check_syntax_error: false,
+            type_inference: false,
             cfg: None,
             class_table_builder: ClassTableBuilder::new(),
             ast_nodes_records: Vec::new(),
@@ -138,12 +145,25 @@ impl<'a> SemanticBuilder<'a> {
         self
     }
 
+    /// Build the control flow graph, `false` by default.
+    ///
+    /// Without the control flow graph, [`Semantic::cfg`] and
+    /// [`AstNode::checked_cfg_id`](crate::AstNode::checked_cfg_id) are `None`.
     #[must_use]
     pub fn with_cfg(mut self, cfg: bool) -> Self {
         self.cfg = if cfg { Some(ControlFlowGraphBuilder::default()) } else { None };
         self
     }
 
+    /// Infer the types of symbols and narrowed references, `false` by default.
+    ///
+    /// See [`Semantic::types`].
+    #[must_use]
+    pub fn with_type_inference(mut self, yes: bool) -> Self {
+        self.type_inference = yes;
+        self
+    }
+
     /// Get the built module record from `build_module_record`
     pub fn module_record(&self) -> Arc<ModuleRecord> {
         Arc::clone(&self.module_record)
@@ -177,6 +197,12 @@ impl<'a> SemanticBuilder<'a> {
 
         let jsdoc = if self.build_jsdoc { self.jsdoc.build() } else { JSDocFinder::default() };
 
+        let types = if self.type_inference && !self.source_type.is_typescript_definition() {
+            TypeInference::new(&self.symbols).build(program)
+        } else {
+            TypeMap::default()
+        };
+
         let semantic = Semantic {
             source_text: self.source_text,
             source_type: self.source_type,
@@ -189,6 +215,7 @@ impl<'a> SemanticBuilder<'a> {
             jsdoc,
             unused_labels: self.label_builder.unused_node_ids,
             cfg: self.cfg.map(ControlFlowGraphBuilder::build),
+            types,
         };
         SemanticBuilderReturn { semantic, errors: self.errors.into_inner() }
     }
@@ -208,7 +235,7 @@ impl<'a> SemanticBuilder<'a> {
         let ast_node = AstNode::new(
             kind,
             self.current_scope_id,
-            control_flow!(|self, cfg| cfg.current_node_ix),
+            self.cfg.as_ref().map_or(BasicBlockId::end(), |cfg| cfg.current_node_ix),
             flags,
         );
         self.current_node_id = if matches!(kind, AstKind::Program(_)) {
@@ -405,6 +432,7 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         }
 
         self.current_scope_id = self.scope.add_scope(parent_scope_id, flags);
+        self.current_scope_node_pending = true;
     }
 
     fn leave_scope(&mut self) {
@@ -418,6 +446,9 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
     // The order is important here.
     fn enter_node(&mut self, kind: AstKind<'a>) {
         self.create_ast_node(kind);
+        if self.current_scope_node_pending {
+            self.add_current_node_id_to_current_scope();
+        }
         self.enter_kind(kind);
     }
 
@@ -1074,6 +1105,7 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         self.visit_expression(&stmt.discriminant);
         self.enter_scope(ScopeFlags::empty());
         stmt.scope_id.set(Some(self.current_scope_id));
+        self.add_current_node_id_to_current_scope();
 
         /* cfg */
         let discriminant_graph_ix = control_flow!(|self, cfg| {
@@ -1583,6 +1615,7 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         self.visit_binding_identifier(&decl.id);
         self.enter_scope(ScopeFlags::empty());
         decl.scope_id.set(Some(self.current_scope_id));
+        self.add_current_node_id_to_current_scope();
         for member in &decl.members {
             self.visit_enum_member(member);
         }
@@ -1599,6 +1632,7 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         }
         self.enter_scope(ScopeFlags::TsModuleBlock);
         decl.scope_id.set(Some(self.current_scope_id));
+        self.add_current_node_id_to_current_scope();
         match &decl.body {
             Some(TSModuleDeclarationBody::TSModuleDeclaration(decl)) => {
                 self.visit_ts_module_declaration(decl);
@@ -1647,6 +1681,10 @@ impl<'a> SemanticBuilder<'a> {
         });
         /* cfg */
 
+        if is_ambient_declaration(kind) {
+            self.current_symbol_flags |= SymbolFlags::Ambient;
+        }
+
         match kind {
             AstKind::ExportDefaultDeclaration(_) => {
                 self.current_symbol_flags |= SymbolFlags::Export;
@@ -1681,12 +1719,10 @@ impl<'a> SemanticBuilder<'a> {
                 self.function_stack.push(self.current_node_id);
                 func.bind(self);
                 self.label_builder.enter_function_or_static_block();
-                self.add_current_node_id_to_current_scope();
                 self.make_all_namespaces_valuelike();
             }
             AstKind::ArrowFunctionExpression(_) => {
                 self.function_stack.push(self.current_node_id);
-                self.add_current_node_id_to_current_scope();
                 self.make_all_namespaces_valuelike();
             }
             AstKind::Class(class) => {
@@ -1795,6 +1831,10 @@ impl<'a> SemanticBuilder<'a> {
 
     #[allow(clippy::single_match)]
     fn leave_kind(&mut self, kind: AstKind<'a>) {
+        if is_ambient_declaration(kind) {
+            self.current_symbol_flags -= SymbolFlags::Ambient;
+        }
+
         match kind {
             AstKind::Program(program) => {
                 self.add_export_flag_to_export_identifiers(program);
@@ -1868,6 +1908,7 @@ impl<'a> SemanticBuilder<'a> {
 
     fn add_current_node_id_to_current_scope(&mut self) {
         self.scope.add_node_id(self.current_scope_id, self.current_node_id);
+        self.current_scope_node_pending = false;
     }
 
     fn make_all_namespaces_valuelike(&mut self) {
@@ -1927,3 +1968,17 @@ impl<'a> SemanticBuilder<'a> {
         false
     }
 }
+
+/// Symbols declared within a `declare` declaration are ambient.
+fn is_ambient_declaration(kind: AstKind) -> bool {
+    match kind {
+        AstKind::VariableDeclaration(decl) => decl.declare,
+        AstKind::Function(func) => func.declare,
+        AstKind::Class(class) => class.declare,
+        AstKind::TSEnumDeclaration(decl) => decl.declare,
+        AstKind::TSModuleDeclaration(decl) => decl.declare,
+        AstKind::TSTypeAliasDeclaration(decl) => decl.declare,
+        AstKind::TSInterfaceDeclaration(decl) => decl.declare,
+        _ => false,
+    }
+}
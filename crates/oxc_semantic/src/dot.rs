@@ -3,11 +3,7 @@ use oxc_ast::{
     AstKind,
 };
 use oxc_cfg::{
-    graph::{
-        dot::{Config, Dot},
-        visit::EdgeRef,
-    },
-    BasicBlock, ControlFlowGraph, EdgeType, Instruction, InstructionKind, IterationInstructionKind,
+    BasicBlock, ControlFlowGraph, Instruction, InstructionKind, IterationInstructionKind,
     LabeledInstruction, ReturnInstructionKind,
 };
 use oxc_syntax::node::AstNodeId;
@@ -48,30 +44,35 @@ impl<'a, 'b> From<&'b AstNodes<'a>> for DebugDotContext<'a, 'b> {
     }
 }
 
+impl<'a> AstNodes<'a> {
+    /// Print the AST as a Graphviz graph, nodes which create a scope are filled.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph {\n");
+        for node in self.iter() {
+            let id = node.id().index();
+            let label = format!("{} ({id})", node.kind().debug_name());
+            let creates_scope = self
+                .parent_node(node.id())
+                .map_or(true, |parent| parent.scope_id() != node.scope_id());
+            if creates_scope {
+                dot.push_str(&format!(
+                    "    {id} [ label = {label:?} style = filled fillcolor = lightblue ]\n"
+                ));
+            } else {
+                dot.push_str(&format!("    {id} [ label = {label:?} ]\n"));
+            }
+            if let Some(parent_id) = self.parent_id(node.id()) {
+                dot.push_str(&format!("    {} -> {id}\n", parent_id.index()));
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
 impl DebugDot for ControlFlowGraph {
     fn debug_dot(&self, ctx: DebugDotContext) -> String {
-        format!(
-            "{:?}",
-            Dot::with_attr_getters(
-                &self.graph,
-                &[Config::EdgeNoLabel, Config::NodeNoLabel],
-                &|_graph, edge| {
-                    let weight = edge.weight();
-                    let label = format!("label = \"{weight:?}\" ");
-                    if matches!(weight, EdgeType::Unreachable)
-                        || self.basic_block(edge.source()).unreachable
-                    {
-                        format!("{label}, style = \"dotted\" ")
-                    } else {
-                        label
-                    }
-                },
-                &|_graph, node| format!(
-                    "label = {:?} ",
-                    self.basic_blocks[*node.1].debug_dot(ctx).trim()
-                ),
-            )
-        )
+        self.dot_with_labels(|basic_block| basic_block.debug_dot(ctx))
     }
 }
 
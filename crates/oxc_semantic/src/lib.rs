@@ -10,6 +10,7 @@ mod node;
 mod reference;
 mod scope;
 mod symbol;
+mod type_inference;
 
 pub mod dot;
 
@@ -18,7 +19,7 @@ use std::sync::Arc;
 pub use builder::{SemanticBuilder, SemanticBuilderReturn};
 use class::ClassTable;
 pub use jsdoc::{JSDoc, JSDocFinder, JSDocTag};
-pub use node::{AstNode, AstNodeId, AstNodes};
+pub use node::{AstNode, AstNodeId, AstNodes, TraversalOrder};
 use oxc_ast::{ast::IdentifierReference, AstKind, Trivias};
 use oxc_cfg::ControlFlowGraph;
 use oxc_span::SourceType;
@@ -32,7 +33,8 @@ use rustc_hash::FxHashSet;
 pub use crate::{
     reference::{Reference, ReferenceFlag, ReferenceId},
     scope::ScopeTree,
-    symbol::SymbolTable,
+    symbol::{SymbolTable, UnusedSymbolsOptions},
+    type_inference::{InferredType, TypeKeyword, TypeMap},
 };
 
 pub struct Semantic<'a> {
@@ -57,6 +59,8 @@ pub struct Semantic<'a> {
     unused_labels: FxHashSet<AstNodeId>,
 
     cfg: Option<ControlFlowGraph>,
+
+    types: TypeMap,
 }
 
 impl<'a> Semantic<'a> {
@@ -112,6 +116,19 @@ impl<'a> Semantic<'a> {
         self.cfg.as_ref()
     }
 
+    /// Inferred types, empty unless built with [`SemanticBuilder::with_type_inference`].
+    pub fn types(&self) -> &TypeMap {
+        &self.types
+    }
+
+    /// The inferred type of a reference, narrowed by the guards around it.
+    pub fn reference_type(&self, reference_id: ReferenceId) -> Option<&InferredType> {
+        self.types.narrowed_type(reference_id).or_else(|| {
+            let symbol_id = self.symbols.get_reference(reference_id).symbol_id()?;
+            self.types.symbol_type(symbol_id)
+        })
+    }
+
     pub fn is_unresolved_reference(&self, node_id: AstNodeId) -> bool {
         let reference_node = self.nodes.get_node(node_id);
         let AstKind::IdentifierReference(id) = reference_node.kind() else {
@@ -143,6 +160,7 @@ impl<'a> Semantic<'a> {
 mod tests {
     use oxc_allocator::Allocator;
     use oxc_ast::{ast::VariableDeclarationKind, AstKind};
+    use oxc_cfg::BasicBlockId;
     use oxc_span::{Atom, SourceType};
 
     use super::*;
@@ -229,6 +247,24 @@ mod tests {
         assert!(semantic.symbols().references.len() == 1);
     }
 
+    #[test]
+    fn without_cfg() {
+        let source = "if (a) { b } else { c }";
+        let allocator = Allocator::default();
+        let semantic = get_semantic(&allocator, source, SourceType::default());
+        assert!(semantic.cfg().is_none());
+    }
+
+    #[test]
+    fn cfg_id_without_cfg() {
+        let source = "a";
+        let allocator = Allocator::default();
+        let semantic = get_semantic(&allocator, source, SourceType::default());
+        let node = semantic.nodes().root_node().unwrap();
+        assert_eq!(node.checked_cfg_id(), None);
+        assert_eq!(node.cfg_id(), BasicBlockId::default());
+    }
+
     #[test]
     fn test_reference_resolutions_simple_read_write() {
         let alloc = Allocator::default();
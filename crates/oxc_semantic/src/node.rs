@@ -1,7 +1,9 @@
 use oxc_ast::AstKind;
 use oxc_cfg::BasicBlockId;
 use oxc_index::IndexVec;
+use oxc_span::{GetSpan, Span};
 pub use oxc_syntax::node::{AstNodeId, NodeFlags};
+use smallvec::SmallVec;
 
 use crate::scope::ScopeId;
 
@@ -16,9 +18,14 @@ pub struct AstNode<'a> {
     scope_id: ScopeId,
 
     /// Associated `BasicBlockId` in CFG (initialized by control_flow)
+    ///
+    /// `BasicBlockId::end()` when the CFG is not built
     cfg_id: BasicBlockId,
 
     flags: NodeFlags,
+
+    /// Number of ancestors of this node, `0` for the root `Program` (initialized by `AstNodes::add_node`)
+    depth: u32,
 }
 
 impl<'a> AstNode<'a> {
@@ -28,15 +35,23 @@ impl<'a> AstNode<'a> {
         cfg_id: BasicBlockId,
         flags: NodeFlags,
     ) -> Self {
-        Self { id: AstNodeId::new(0), kind, cfg_id, scope_id, flags }
+        Self { id: AstNodeId::new(0), kind, cfg_id, scope_id, flags, depth: 0 }
     }
 
     pub fn id(&self) -> AstNodeId {
         self.id
     }
 
+    /// Returns the default `BasicBlockId` if the semantic was built without the control flow graph,
+    /// use [`AstNode::checked_cfg_id`] to tell these apart.
     pub fn cfg_id(&self) -> BasicBlockId {
-        self.cfg_id
+        self.checked_cfg_id().unwrap_or_default()
+    }
+
+    /// Returns `None` if the semantic was built without the control flow graph
+    /// (`SemanticBuilder::with_cfg`).
+    pub fn checked_cfg_id(&self) -> Option<BasicBlockId> {
+        (self.cfg_id != BasicBlockId::end()).then_some(self.cfg_id)
     }
 
     pub fn kind(&self) -> AstKind<'a> {
@@ -47,6 +62,10 @@ impl<'a> AstNode<'a> {
         self.scope_id
     }
 
+    pub fn depth(&self) -> u32 {
+        self.depth
+    }
+
     pub fn flags(&self) -> NodeFlags {
         self.flags
     }
@@ -62,6 +81,8 @@ pub struct AstNodes<'a> {
     root: Option<AstNodeId>,
     nodes: IndexVec<AstNodeId, AstNode<'a>>,
     parent_ids: IndexVec<AstNodeId, Option<AstNodeId>>,
+    /// Direct children of each node, in the order they were visited.
+    children: IndexVec<AstNodeId, SmallVec<[AstNodeId; 4]>>,
 }
 
 impl<'a> AstNodes<'a> {
@@ -69,6 +90,21 @@ impl<'a> AstNodes<'a> {
         self.nodes.iter()
     }
 
+    /// Iterate over the nodes narrowed down by `f`, e.g. all call expressions:
+    ///
+    /// ```ignore
+    /// nodes.iter_by_kind(|kind| match kind {
+    ///     AstKind::CallExpression(call_expr) => Some(call_expr),
+    ///     _ => None,
+    /// })
+    /// ```
+    pub fn iter_by_kind<'s, T: 'a, F>(&'s self, f: F) -> impl Iterator<Item = &'a T> + 's
+    where
+        F: Fn(AstKind<'a>) -> Option<&'a T> + 's,
+    {
+        self.nodes.iter().filter_map(move |node| f(node.kind()))
+    }
+
     pub fn len(&self) -> usize {
         self.nodes.len()
     }
@@ -86,6 +122,48 @@ impl<'a> AstNodes<'a> {
         AstNodeParentIter { curr, nodes: self }
     }
 
+    /// Same as [`AstNodes::iter_parents`], also producing the scope of each node.
+    pub fn iter_parents_with_scope(
+        &self,
+        node_id: AstNodeId,
+    ) -> impl Iterator<Item = (&AstNode<'a>, ScopeId)> + '_ {
+        self.iter_parents(node_id).map(|node| (node, node.scope_id()))
+    }
+
+    /// Walk up the AST, iterating over the nodes at scope boundaries,
+    /// i.e. the nodes whose scope differs from their parent's scope (e.g. functions and blocks).
+    ///
+    /// The root node is always produced last.
+    pub fn iter_parents_scopes(
+        &self,
+        node_id: AstNodeId,
+    ) -> impl Iterator<Item = &AstNode<'a>> + '_ {
+        self.iter_parents(node_id).filter(|node| {
+            self.parent_node(node.id()).map_or(true, |parent| parent.scope_id() != node.scope_id())
+        })
+    }
+
+    /// Iterate over the direct children of the node pointed to by `node_id`.
+    ///
+    /// Children are produced in the order they were visited, which is not always source order,
+    /// e.g. the arguments of a call are visited before its callee.
+    pub fn iter_children(&self, node_id: AstNodeId) -> impl Iterator<Item = &AstNode<'a>> + '_ {
+        self.children[node_id].iter().map(|child_id| self.get_node(*child_id))
+    }
+
+    /// Walk down the AST, iterating over the subtree of the node pointed to by `root_id`
+    /// depth-first in the given `order`.
+    ///
+    /// The node pointed to by `root_id` itself is produced first in [`TraversalOrder::PreOrder`]
+    /// and last in [`TraversalOrder::PostOrder`].
+    pub fn subtree_iter(
+        &self,
+        root_id: AstNodeId,
+        order: TraversalOrder,
+    ) -> AstNodeSubtreeIter<'_, 'a> {
+        AstNodeSubtreeIter { stack: vec![(root_id, 0)], order, nodes: self }
+    }
+
     pub fn kind(&self, ast_node_id: AstNodeId) -> AstKind<'a> {
         self.nodes[ast_node_id].kind
     }
@@ -152,16 +230,109 @@ impl<'a> AstNodes<'a> {
         std::iter::successors(Some(ast_node_id), |node_id| parent_ids[*node_id])
     }
 
+    /// Find the closest node that is an ancestor of both `a` and `b`.
+    ///
+    /// Like [`AstNodes::ancestors`], a node is considered to be its own ancestor,
+    /// so if `a` is an ancestor of `b` then `a` is returned.
+    pub fn common_ancestor(&self, a: AstNodeId, b: AstNodeId) -> Option<AstNodeId> {
+        // Nodes are added in pre-order, so a parent always has a smaller id than its children.
+        let (mut a, mut b) = (a, b);
+        while a != b {
+            if a > b {
+                a = self.parent_id(a)?;
+            } else {
+                b = self.parent_id(b)?;
+            }
+        }
+        Some(a)
+    }
+
+    /// Get the deepest node whose span is exactly `span`,
+    /// falling back to the smallest node enclosing `span`.
+    pub fn get_node_by_span(&self, span: Span) -> Option<&AstNode<'a>> {
+        self.enclosing_node_id(span.start, span.end).map(|node_id| self.get_node(node_id))
+    }
+
+    /// Get the deepest node whose span contains `offset`.
+    pub fn get_node_at_offset(&self, offset: u32) -> Option<&AstNode<'a>> {
+        self.enclosing_node_id(offset, offset).map(|node_id| self.get_node(node_id))
+    }
+
+    /// Walk down from the root, so no index has to be maintained while building semantic.
+    fn enclosing_node_id(&self, start: u32, end: u32) -> Option<AstNodeId> {
+        let contains = |node_id: AstNodeId| {
+            let span = self.kind(node_id).span();
+            span.start <= start && end <= span.end
+        };
+        let mut node_id = self.root.filter(|root_id| contains(*root_id))?;
+        // Children are in visit order, not source order, so every child is checked.
+        while let Some(child_id) =
+            self.children[node_id].iter().copied().find(|child_id| contains(*child_id))
+        {
+            node_id = child_id;
+        }
+        Some(node_id)
+    }
+
     /// Adds an `AstNode` to the `AstNodes` tree and returns its `AstNodeId`.
     pub fn add_node(&mut self, node: AstNode<'a>, parent_id: Option<AstNodeId>) -> AstNodeId {
         let mut node = node;
         let ast_node_id = self.parent_ids.push(parent_id);
+        self.children.push(SmallVec::new());
+        if let Some(parent_id) = parent_id {
+            self.children[parent_id].push(ast_node_id);
+        }
         node.id = ast_node_id;
+        node.depth = parent_id.map_or(0, |parent_id| self.nodes[parent_id].depth + 1);
         self.nodes.push(node);
         ast_node_id
     }
 }
 
+/// The order in which [`AstNodes::subtree_iter`] produces nodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraversalOrder {
+    /// A node is produced before its children.
+    PreOrder,
+    /// A node is produced after its children, e.g. to process inner scopes before outer ones.
+    PostOrder,
+}
+
+#[derive(Debug)]
+pub struct AstNodeSubtreeIter<'s, 'a> {
+    /// The path from the root to the current node, with the index of the next child to visit.
+    stack: Vec<(AstNodeId, usize)>,
+    order: TraversalOrder,
+    nodes: &'s AstNodes<'a>,
+}
+
+impl<'s, 'a> Iterator for AstNodeSubtreeIter<'s, 'a> {
+    type Item = &'s AstNode<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (node_id, next_child) = self.stack.last_mut()?;
+            let node_id = *node_id;
+            let first_visit = *next_child == 0;
+            let descended = if let Some(child_id) = self.nodes.children[node_id].get(*next_child) {
+                *next_child += 1;
+                self.stack.push((*child_id, 0));
+                true
+            } else {
+                self.stack.pop();
+                false
+            };
+            let produce = match self.order {
+                TraversalOrder::PreOrder => first_visit,
+                TraversalOrder::PostOrder => !descended,
+            };
+            if produce {
+                return Some(self.nodes.get_node(node_id));
+            }
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct AstNodeParentIter<'s, 'a> {
     curr: Option<&'s AstNode<'a>>,
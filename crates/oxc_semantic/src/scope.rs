@@ -1,12 +1,13 @@
 use std::hash::BuildHasherDefault;
 
 use indexmap::IndexMap;
+use oxc_ast::AstKind;
 use oxc_index::IndexVec;
 use oxc_span::CompactStr;
 pub use oxc_syntax::scope::{ScopeFlags, ScopeId};
 use rustc_hash::{FxHashMap, FxHasher};
 
-use crate::{reference::ReferenceId, symbol::SymbolId, AstNodeId};
+use crate::{reference::ReferenceId, symbol::SymbolId, AstNodeId, AstNodes};
 
 type FxIndexMap<K, V> = IndexMap<K, V, BuildHasherDefault<FxHasher>>;
 
@@ -125,6 +126,46 @@ impl ScopeTree {
         flags
     }
 
+    /// The top level scope of the program,
+    /// the module scope in modules and the global scope in scripts.
+    pub fn is_module_scope(&self, scope_id: ScopeId) -> bool {
+        self.get_flags(scope_id).is_top()
+    }
+
+    /// The scope of a function, arrow function, method or accessor.
+    pub fn is_function_scope(&self, scope_id: ScopeId) -> bool {
+        self.get_flags(scope_id).is_function()
+    }
+
+    /// The scope of a block statement, a `for` or `switch` statement,
+    /// or a `catch` or `finally` clause.
+    pub fn is_block_scope(&self, scope_id: ScopeId, nodes: &AstNodes) -> bool {
+        matches!(
+            nodes.kind(self.get_node_id(scope_id)),
+            AstKind::BlockStatement(_)
+                | AstKind::ForStatement(_)
+                | AstKind::ForInStatement(_)
+                | AstKind::ForOfStatement(_)
+                | AstKind::SwitchStatement(_)
+                | AstKind::CatchClause(_)
+                | AstKind::FinallyClause(_)
+        )
+    }
+
+    /// The scope of a class expression, which contains the class name.
+    pub fn is_class_scope(&self, scope_id: ScopeId, nodes: &AstNodes) -> bool {
+        matches!(nodes.kind(self.get_node_id(scope_id)), AstKind::Class(_))
+    }
+
+    pub fn is_strict_mode_scope(&self, scope_id: ScopeId) -> bool {
+        self.get_flags(scope_id).is_strict_mode()
+    }
+
+    /// The scope of a `catch` clause, which contains the catch parameter.
+    pub fn is_catch_scope(&self, scope_id: ScopeId, nodes: &AstNodes) -> bool {
+        matches!(nodes.kind(self.get_node_id(scope_id)), AstKind::CatchClause(_))
+    }
+
     pub fn get_parent_id(&self, scope_id: ScopeId) -> Option<ScopeId> {
         self.parent_ids[scope_id]
     }
@@ -149,10 +190,13 @@ impl ScopeTree {
         self.bindings[scope_id].get(name).is_some()
     }
 
+    /// Get a symbol by name that was declared in exactly this scope, in constant time.
     pub fn get_binding(&self, scope_id: ScopeId, name: &str) -> Option<SymbolId> {
         self.bindings[scope_id].get(name).copied()
     }
 
+    /// Get a symbol by name that is visible from this scope,
+    /// i.e. declared in this scope or the closest ancestor scope declaring it.
     pub fn find_binding(&self, scope_id: ScopeId, name: &str) -> Option<SymbolId> {
         for scope_id in self.ancestors(scope_id) {
             if let Some(symbol_id) = self.bindings[scope_id].get(name) {
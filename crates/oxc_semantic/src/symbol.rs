@@ -23,6 +23,16 @@ const TS_APPEND_CONTENT: &'static str = r#"
 export type IndexVec<I, T> = Array<T>;
 "#;
 
+/// Options for [`SymbolTable::unused_symbols`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct UnusedSymbolsOptions {
+    /// Include TypeScript type-only declarations,
+    /// such as interfaces, type aliases and type parameters.
+    ///
+    /// Default `false`
+    pub include_types: bool,
+}
+
 /// Symbol Table
 ///
 /// `SoA` (Struct of Arrays) for memory efficiency.
@@ -169,6 +179,29 @@ impl SymbolTable {
             .map(|reference_id| &self.references[*reference_id])
     }
 
+    /// Symbols without any resolved references.
+    ///
+    /// Exported symbols and symbols declared in a `declare` context are excluded.
+    /// References in type positions only count for symbols which declare a type, such as classes.
+    pub fn unused_symbols(
+        &self,
+        options: UnusedSymbolsOptions,
+    ) -> impl Iterator<Item = SymbolId> + '_ {
+        self.iter().filter(move |&symbol_id| {
+            let flags = self.get_flag(symbol_id);
+            if flags.intersects(SymbolFlags::Export | SymbolFlags::Ambient) {
+                return false;
+            }
+            let is_type = flags.intersects(SymbolFlags::Type);
+            if is_type && !flags.intersects(SymbolFlags::Value) && !options.include_types {
+                return false;
+            }
+            !self.get_resolved_references(symbol_id).any(|reference| {
+                is_type || reference.is_read() || reference.is_write() || !reference.is_type()
+            })
+        })
+    }
+
     /// Determine whether evaluating the specific input `node` is a consequenceless reference. ie.
     /// evaluating it won't result in potentially arbitrary code from being ran. The following are
     /// allowed and determined not to cause side effects:
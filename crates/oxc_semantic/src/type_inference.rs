@@ -0,0 +1,561 @@
+//! Lightweight type inference
+//!
+//! Infers the types of symbols from their initializers and assignments, and the narrowed types
+//! of references guarded by `typeof`, `instanceof` and `null` / `undefined` checks.
+//! This is not a type checker,
+//! types which cannot be inferred locally are [`InferredType::Unknown`].
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, syntax_directed_operations::BoundNames, visit::walk, Visit};
+use oxc_index::IndexVec;
+use oxc_span::CompactStr;
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    reference::ReferenceId,
+    symbol::{SymbolFlags, SymbolId, SymbolTable},
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InferredType {
+    /// The type cannot be inferred
+    Unknown,
+    Null,
+    Undefined,
+    BooleanLiteral(bool),
+    NumberLiteral(f64),
+    StringLiteral(CompactStr),
+    /// `string`, `number`, `object`, ...
+    Keyword(TypeKeyword),
+    /// Narrowed by `x instanceof Foo`
+    Instance(CompactStr),
+    /// Union of two or more types, never nested and never containing [`InferredType::Unknown`]
+    Union(Vec<InferredType>),
+}
+
+/// Types that can be checked with `typeof`, excluding `null` and `undefined`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypeKeyword {
+    String,
+    Number,
+    Boolean,
+    BigInt,
+    Symbol,
+    Object,
+    Function,
+}
+
+impl InferredType {
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Self::Unknown)
+    }
+
+    /// Whether the type includes `null` or `undefined`.
+    pub fn is_nullable(&self) -> bool {
+        match self {
+            Self::Unknown | Self::Null | Self::Undefined => true,
+            Self::Union(types) => types.iter().any(Self::is_nullable),
+            _ => false,
+        }
+    }
+
+    /// `A | B`
+    #[must_use]
+    pub fn union(self, other: Self) -> Self {
+        let (mut types, others) = match (self, other) {
+            (Self::Unknown, _) | (_, Self::Unknown) => return Self::Unknown,
+            (Self::Union(types), Self::Union(others)) => (types, others),
+            (Self::Union(types), other) => (types, vec![other]),
+            (ty, Self::Union(others)) => (vec![ty], others),
+            (ty, other) => (vec![ty], vec![other]),
+        };
+        for ty in others {
+            if !types.contains(&ty) {
+                types.push(ty);
+            }
+        }
+        match types.len() {
+            1 => types.swap_remove(0),
+            _ => Self::Union(types),
+        }
+    }
+
+    /// The type of a mutable binding, `1` -> `number`.
+    #[must_use]
+    pub fn widen(self) -> Self {
+        match self {
+            Self::BooleanLiteral(_) => Self::Keyword(TypeKeyword::Boolean),
+            Self::NumberLiteral(_) => Self::Keyword(TypeKeyword::Number),
+            Self::StringLiteral(_) => Self::Keyword(TypeKeyword::String),
+            Self::Union(types) => {
+                types.into_iter().map(Self::widen).fold(Self::Union(vec![]), Self::union)
+            }
+            ty => ty,
+        }
+    }
+
+    /// Remove `excluded` from a union, `string | null` without `null` is `string`.
+    ///
+    /// Returns `None` if nothing can be excluded.
+    fn exclude(&self, excluded: &Self) -> Option<Self> {
+        let Self::Union(types) = self else {
+            return None;
+        };
+        let excluded = |ty: &Self| match excluded {
+            Self::Union(excluded) => excluded.iter().any(|excluded| ty.is_subtype_of(excluded)),
+            excluded => ty.is_subtype_of(excluded),
+        };
+        let types = types.iter().filter(|ty| !excluded(ty)).cloned().collect::<Vec<_>>();
+        if types.is_empty() {
+            return None;
+        }
+        Some(types.into_iter().fold(Self::Union(vec![]), Self::union))
+    }
+
+    fn is_subtype_of(&self, other: &Self) -> bool {
+        self == other || (matches!(other, Self::Keyword(_)) && self.clone().widen() == *other)
+    }
+
+    fn from_typeof(name: &str) -> Option<Self> {
+        let keyword = match name {
+            "string" => TypeKeyword::String,
+            "number" => TypeKeyword::Number,
+            "boolean" => TypeKeyword::Boolean,
+            "bigint" => TypeKeyword::BigInt,
+            "symbol" => TypeKeyword::Symbol,
+            "function" => TypeKeyword::Function,
+            "undefined" => return Some(Self::Undefined),
+            "object" => return Some(Self::Keyword(TypeKeyword::Object).union(Self::Null)),
+            _ => return None,
+        };
+        Some(Self::Keyword(keyword))
+    }
+
+    /// The type of a type annotation, `Unknown` for anything but keywords, literals and unions.
+    fn from_ts_type(ts_type: &TSType) -> Self {
+        match ts_type {
+            TSType::TSStringKeyword(_) => Self::Keyword(TypeKeyword::String),
+            TSType::TSNumberKeyword(_) => Self::Keyword(TypeKeyword::Number),
+            TSType::TSBooleanKeyword(_) => Self::Keyword(TypeKeyword::Boolean),
+            TSType::TSBigIntKeyword(_) => Self::Keyword(TypeKeyword::BigInt),
+            TSType::TSSymbolKeyword(_) => Self::Keyword(TypeKeyword::Symbol),
+            TSType::TSObjectKeyword(_) => Self::Keyword(TypeKeyword::Object),
+            TSType::TSNullKeyword(_) => Self::Null,
+            TSType::TSUndefinedKeyword(_) | TSType::TSVoidKeyword(_) => Self::Undefined,
+            TSType::TSLiteralType(ty) => match &ty.literal {
+                TSLiteral::BooleanLiteral(lit) => Self::BooleanLiteral(lit.value),
+                TSLiteral::NullLiteral(_) => Self::Null,
+                TSLiteral::NumericLiteral(lit) => Self::NumberLiteral(lit.value),
+                TSLiteral::StringLiteral(lit) => Self::StringLiteral(lit.value.to_compact_str()),
+                TSLiteral::BigIntLiteral(_) => Self::Keyword(TypeKeyword::BigInt),
+                _ => Self::Unknown,
+            },
+            TSType::TSUnionType(ty) => {
+                ty.types.iter().map(Self::from_ts_type).fold(Self::Union(vec![]), Self::union)
+            }
+            TSType::TSParenthesizedType(ty) => Self::from_ts_type(&ty.type_annotation),
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// Inferred types of symbols and narrowed references.
+///
+/// Built by [`SemanticBuilder::with_type_inference`](crate::SemanticBuilder::with_type_inference).
+#[derive(Debug, Default)]
+pub struct TypeMap {
+    /// Types of symbols, `None` when the declaration was not visited
+    symbols: IndexVec<SymbolId, Option<InferredType>>,
+    /// Types of references narrowed by a guard
+    narrowed: FxHashMap<ReferenceId, InferredType>,
+}
+
+impl TypeMap {
+    /// The type of a symbol, including all values assigned to it.
+    pub fn symbol_type(&self, symbol_id: SymbolId) -> Option<&InferredType> {
+        self.symbols.get(symbol_id).and_then(Option::as_ref)
+    }
+
+    /// The narrowed type of a reference, `None` if the reference is not guarded.
+    pub fn narrowed_type(&self, reference_id: ReferenceId) -> Option<&InferredType> {
+        self.narrowed.get(&reference_id)
+    }
+}
+
+/// Narrowed types of a symbol when a condition is truthy or falsy.
+struct Narrowing {
+    symbol_id: SymbolId,
+    when_true: Option<InferredType>,
+    when_false: Option<InferredType>,
+}
+
+impl Narrowing {
+    fn negate(self) -> Self {
+        Self { symbol_id: self.symbol_id, when_true: self.when_false, when_false: self.when_true }
+    }
+}
+
+pub(crate) struct TypeInference<'s> {
+    symbols: &'s SymbolTable,
+    types: TypeMap,
+    /// Narrowed types of the guards around the current node, innermost last
+    narrowings: Vec<(SymbolId, InferredType)>,
+    /// Write references to the targets of `x = value`, these are typed by the assigned value
+    typed_writes: FxHashSet<ReferenceId>,
+}
+
+impl<'s> TypeInference<'s> {
+    pub fn new(symbols: &'s SymbolTable) -> Self {
+        let types = TypeMap {
+            symbols: IndexVec::from_vec(vec![None; symbols.len()]),
+            narrowed: FxHashMap::default(),
+        };
+        Self { symbols, types, narrowings: vec![], typed_writes: FxHashSet::default() }
+    }
+
+    pub fn build(mut self, program: &Program<'_>) -> TypeMap {
+        self.visit_program(program);
+        self.types
+    }
+
+    /// Add a possible type to a symbol.
+    fn assign(&mut self, symbol_id: SymbolId, ty: InferredType) {
+        let slot = &mut self.types.symbols[symbol_id];
+        *slot = Some(match slot.take() {
+            Some(existing) => existing.union(ty),
+            None => ty,
+        });
+    }
+
+    /// Whether a symbol keeps the value of its declaration, so its type holds at every reference.
+    fn is_never_written(&self, symbol_id: SymbolId) -> bool {
+        self.symbols
+            .get_resolved_reference_ids(symbol_id)
+            .iter()
+            .all(|&reference_id| !self.symbols.get_reference(reference_id).is_write())
+    }
+
+    fn reference_symbol_id(&self, ident: &IdentifierReference) -> Option<SymbolId> {
+        let reference_id = ident.reference_id.get()?;
+        self.symbols.get_reference(reference_id).symbol_id()
+    }
+
+    fn infer_expression(&self, expr: &Expression) -> InferredType {
+        match expr {
+            Expression::BooleanLiteral(lit) => InferredType::BooleanLiteral(lit.value),
+            Expression::NullLiteral(_) => InferredType::Null,
+            Expression::NumericLiteral(lit) => InferredType::NumberLiteral(lit.value),
+            Expression::StringLiteral(lit) => {
+                InferredType::StringLiteral(lit.value.to_compact_str())
+            }
+            Expression::BigIntLiteral(_) => InferredType::Keyword(TypeKeyword::BigInt),
+            Expression::TemplateLiteral(_) => InferredType::Keyword(TypeKeyword::String),
+            Expression::RegExpLiteral(_)
+            | Expression::ObjectExpression(_)
+            | Expression::ArrayExpression(_) => InferredType::Keyword(TypeKeyword::Object),
+            Expression::FunctionExpression(_)
+            | Expression::ArrowFunctionExpression(_)
+            | Expression::ClassExpression(_) => InferredType::Keyword(TypeKeyword::Function),
+            Expression::Identifier(ident) => match self.reference_symbol_id(ident) {
+                Some(symbol_id) if self.is_never_written(symbol_id) => {
+                    self.types.symbol_type(symbol_id).cloned().unwrap_or(InferredType::Unknown)
+                }
+                None if ident.name.as_str() == "undefined" => InferredType::Undefined,
+                Some(_) | None => InferredType::Unknown,
+            },
+            Expression::UnaryExpression(expr) => match expr.operator {
+                UnaryOperator::Void => InferredType::Undefined,
+                UnaryOperator::Typeof => InferredType::Keyword(TypeKeyword::String),
+                UnaryOperator::LogicalNot | UnaryOperator::Delete => {
+                    InferredType::Keyword(TypeKeyword::Boolean)
+                }
+                UnaryOperator::UnaryNegation => match &expr.argument {
+                    Expression::NumericLiteral(lit) => InferredType::NumberLiteral(-lit.value),
+                    _ => InferredType::Unknown,
+                },
+                UnaryOperator::UnaryPlus => InferredType::Keyword(TypeKeyword::Number),
+                UnaryOperator::BitwiseNot => InferredType::Unknown,
+            },
+            Expression::BinaryExpression(expr)
+                if expr.operator.is_equality()
+                    || expr.operator.is_compare()
+                    || matches!(expr.operator, BinaryOperator::In | BinaryOperator::Instanceof) =>
+            {
+                InferredType::Keyword(TypeKeyword::Boolean)
+            }
+            // `a || b`, `a && b` and `a ?? b` evaluate to either operand
+            Expression::LogicalExpression(expr) => {
+                self.infer_expression(&expr.left).union(self.infer_expression(&expr.right))
+            }
+            Expression::ConditionalExpression(expr) => self
+                .infer_expression(&expr.consequent)
+                .union(self.infer_expression(&expr.alternate)),
+            Expression::SequenceExpression(expr) => expr
+                .expressions
+                .last()
+                .map_or(InferredType::Unknown, |expr| self.infer_expression(expr)),
+            Expression::ParenthesizedExpression(expr) => self.infer_expression(&expr.expression),
+            _ => InferredType::Unknown,
+        }
+    }
+
+    /// The narrowed types from a condition.
+    fn narrowing(&self, test: &Expression) -> Option<Narrowing> {
+        match test.without_parenthesized() {
+            Expression::UnaryExpression(expr) if expr.operator == UnaryOperator::LogicalNot => {
+                self.narrowing(&expr.argument).map(Narrowing::negate)
+            }
+            // `x instanceof Foo`
+            Expression::BinaryExpression(expr) if expr.operator == BinaryOperator::Instanceof => {
+                let symbol_id = self.narrowable_symbol_id(&expr.left)?;
+                let Expression::Identifier(class) = &expr.right else {
+                    return None;
+                };
+                let ty = InferredType::Instance(class.name.to_compact_str());
+                Some(Narrowing { symbol_id, when_true: Some(ty), when_false: None })
+            }
+            Expression::BinaryExpression(expr) if expr.operator.is_equality() => {
+                let strict = matches!(
+                    expr.operator,
+                    BinaryOperator::StrictEquality | BinaryOperator::StrictInequality
+                );
+                let narrowing = self
+                    .equality_narrowing(&expr.left, &expr.right, strict)
+                    .or_else(|| self.equality_narrowing(&expr.right, &expr.left, strict))?;
+                if matches!(
+                    expr.operator,
+                    BinaryOperator::Inequality | BinaryOperator::StrictInequality
+                ) {
+                    Some(narrowing.negate())
+                } else {
+                    Some(narrowing)
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// `typeof x === "string"`, `x === null`, `x == undefined`
+    fn equality_narrowing(
+        &self,
+        left: &Expression,
+        right: &Expression,
+        strict: bool,
+    ) -> Option<Narrowing> {
+        let (symbol_id, ty) = match left.without_parenthesized() {
+            Expression::UnaryExpression(expr) if expr.operator == UnaryOperator::Typeof => {
+                let Expression::StringLiteral(lit) = right.without_parenthesized() else {
+                    return None;
+                };
+                (
+                    self.narrowable_symbol_id(&expr.argument)?,
+                    InferredType::from_typeof(lit.value.as_str())?,
+                )
+            }
+            left => {
+                let symbol_id = self.narrowable_symbol_id(left)?;
+                let ty = match self.infer_expression(right) {
+                    ty @ (InferredType::Null | InferredType::Undefined) if strict => ty,
+                    InferredType::Null | InferredType::Undefined => {
+                        InferredType::Null.union(InferredType::Undefined)
+                    }
+                    _ => return None,
+                };
+                (symbol_id, ty)
+            }
+        };
+        let when_false =
+            self.types.symbol_type(symbol_id).and_then(|declared| declared.exclude(&ty));
+        Some(Narrowing { symbol_id, when_true: Some(ty), when_false })
+    }
+
+    /// Only symbols which are never written can be narrowed,
+    /// an assignment would invalidate the narrowed type.
+    fn narrowable_symbol_id(&self, expr: &Expression) -> Option<SymbolId> {
+        let Expression::Identifier(ident) = expr.without_parenthesized() else {
+            return None;
+        };
+        self.reference_symbol_id(ident).filter(|&symbol_id| self.is_never_written(symbol_id))
+    }
+
+    /// The declaration in `for (const x in object)` and `for (const x of iterable)`
+    fn visit_for_declaration(&mut self, decl: &VariableDeclaration, ty: &InferredType) {
+        decl.bound_names(&mut |ident| {
+            if let Some(symbol_id) = ident.symbol_id.get() {
+                self.assign(symbol_id, ty.clone());
+            }
+        });
+    }
+
+    /// Visit `f` with the narrowed type of a symbol.
+    fn with_narrowing<F: FnOnce(&mut Self)>(&mut self, narrowing: SymbolNarrowing, f: F) {
+        let pushed = narrowing.is_some();
+        self.narrowings.extend(narrowing);
+        f(self);
+        if pushed {
+            self.narrowings.pop();
+        }
+    }
+}
+
+impl<'a, 's> Visit<'a> for TypeInference<'s> {
+    fn visit_variable_declarator(&mut self, declarator: &VariableDeclarator<'a>) {
+        let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else {
+            walk::walk_variable_declarator(self, declarator);
+            return;
+        };
+        if let Some(init) = &declarator.init {
+            self.visit_expression(init);
+        }
+        let Some(symbol_id) = ident.symbol_id.get() else {
+            return;
+        };
+        let ty = if let Some(annotation) = &declarator.id.type_annotation {
+            InferredType::from_ts_type(&annotation.type_annotation)
+        } else {
+            let ty = declarator
+                .init
+                .as_ref()
+                .map_or(InferredType::Undefined, |init| self.infer_expression(init));
+            if declarator.kind.is_const() {
+                ty
+            } else {
+                ty.widen()
+            }
+        };
+        self.assign(symbol_id, ty);
+    }
+
+    fn visit_formal_parameter(&mut self, param: &FormalParameter<'a>) {
+        let (BindingPatternKind::BindingIdentifier(ident), Some(annotation)) =
+            (&param.pattern.kind, &param.pattern.type_annotation)
+        else {
+            walk::walk_formal_parameter(self, param);
+            return;
+        };
+        for decorator in &param.decorators {
+            self.visit_decorator(decorator);
+        }
+        if let Some(symbol_id) = ident.symbol_id.get() {
+            let mut ty = InferredType::from_ts_type(&annotation.type_annotation);
+            if param.pattern.optional {
+                ty = ty.union(InferredType::Undefined);
+            }
+            self.assign(symbol_id, ty);
+        }
+    }
+
+    /// Bindings which are not handled by a declarator or a parameter.
+    fn visit_binding_identifier(&mut self, ident: &BindingIdentifier<'a>) {
+        let Some(symbol_id) = ident.symbol_id.get() else {
+            return;
+        };
+        let flags = self.symbols.get_flag(symbol_id);
+        let ty = if flags.intersects(SymbolFlags::Function | SymbolFlags::Class) {
+            InferredType::Keyword(TypeKeyword::Function)
+        } else {
+            InferredType::Unknown
+        };
+        self.assign(symbol_id, ty);
+    }
+
+    /// `for (const key in object)`, keys are strings
+    fn visit_for_in_statement(&mut self, stmt: &ForInStatement<'a>) {
+        let ForStatementLeft::VariableDeclaration(decl) = &stmt.left else {
+            walk::walk_for_in_statement(self, stmt);
+            return;
+        };
+        let ty = InferredType::Keyword(TypeKeyword::String);
+        self.visit_for_declaration(decl, &ty);
+        self.visit_expression(&stmt.right);
+        self.visit_statement(&stmt.body);
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &ForOfStatement<'a>) {
+        let ForStatementLeft::VariableDeclaration(decl) = &stmt.left else {
+            walk::walk_for_of_statement(self, stmt);
+            return;
+        };
+        self.visit_for_declaration(decl, &InferredType::Unknown);
+        self.visit_expression(&stmt.right);
+        self.visit_statement(&stmt.body);
+    }
+
+    fn visit_assignment_expression(&mut self, expr: &AssignmentExpression<'a>) {
+        if let AssignmentTarget::AssignmentTargetIdentifier(ident) = &expr.left {
+            let typed = matches!(
+                expr.operator,
+                AssignmentOperator::Assign
+                    | AssignmentOperator::LogicalOr
+                    | AssignmentOperator::LogicalAnd
+                    | AssignmentOperator::LogicalNullish
+            );
+            if let (true, Some(reference_id)) = (typed, ident.reference_id.get()) {
+                if let Some(symbol_id) = self.symbols.get_reference(reference_id).symbol_id() {
+                    let ty = self.infer_expression(&expr.right).widen();
+                    self.assign(symbol_id, ty);
+                    self.typed_writes.insert(reference_id);
+                }
+            }
+        }
+        walk::walk_assignment_expression(self, expr);
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let Some(reference_id) = ident.reference_id.get() else {
+            return;
+        };
+        let reference = self.symbols.get_reference(reference_id);
+        let Some(symbol_id) = reference.symbol_id() else {
+            return;
+        };
+        if reference.is_write() {
+            // `x++`, `x += 1`, `[x] = array`
+            if !self.typed_writes.contains(&reference_id) {
+                self.assign(symbol_id, InferredType::Unknown);
+            }
+            return;
+        }
+        if let Some((_, ty)) = self.narrowings.iter().rev().find(|(id, _)| *id == symbol_id) {
+            self.types.narrowed.insert(reference_id, ty.clone());
+        }
+    }
+
+    fn visit_if_statement(&mut self, stmt: &IfStatement<'a>) {
+        self.visit_expression(&stmt.test);
+        let narrowing = self.narrowing(&stmt.test);
+        let (when_true, when_false) = split(narrowing);
+        self.with_narrowing(when_true, |visitor| visitor.visit_statement(&stmt.consequent));
+        if let Some(alternate) = &stmt.alternate {
+            self.with_narrowing(when_false, |visitor| visitor.visit_statement(alternate));
+        }
+    }
+
+    fn visit_conditional_expression(&mut self, expr: &ConditionalExpression<'a>) {
+        self.visit_expression(&expr.test);
+        let (when_true, when_false) = split(self.narrowing(&expr.test));
+        self.with_narrowing(when_true, |visitor| visitor.visit_expression(&expr.consequent));
+        self.with_narrowing(when_false, |visitor| visitor.visit_expression(&expr.alternate));
+    }
+
+    fn visit_logical_expression(&mut self, expr: &LogicalExpression<'a>) {
+        self.visit_expression(&expr.left);
+        let (when_true, when_false) = split(self.narrowing(&expr.left));
+        let narrowing = match expr.operator {
+            LogicalOperator::And => when_true,
+            LogicalOperator::Or => when_false,
+            LogicalOperator::Coalesce => None,
+        };
+        self.with_narrowing(narrowing, |visitor| visitor.visit_expression(&expr.right));
+    }
+}
+
+type SymbolNarrowing = Option<(SymbolId, InferredType)>;
+
+fn split(narrowing: Option<Narrowing>) -> (SymbolNarrowing, SymbolNarrowing) {
+    let Some(Narrowing { symbol_id, when_true, when_false }) = narrowing else {
+        return (None, None);
+    };
+    (when_true.map(|ty| (symbol_id, ty)), when_false.map(|ty| (symbol_id, ty)))
+}
@@ -0,0 +1,4 @@
+let i = 0;
+while (i < 10) {
+  i++;
+}
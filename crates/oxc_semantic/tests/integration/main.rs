@@ -3,6 +3,8 @@
 pub mod cfg;
 pub mod classes;
 pub mod modules;
+pub mod nodes;
 pub mod scopes;
 pub mod symbols;
+pub mod types;
 pub mod util;
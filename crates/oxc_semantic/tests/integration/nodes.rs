@@ -0,0 +1,253 @@
+use oxc_ast::AstKind;
+use oxc_semantic::{AstNode, TraversalOrder};
+use oxc_span::{GetSpan, Span};
+
+use crate::util::SemanticTester;
+
+#[test]
+fn test_iter_children() {
+    let tester = SemanticTester::js("let a = 1; function foo() {} a;");
+    let semantic = tester.build();
+    let nodes = semantic.nodes();
+    let root = nodes.root().unwrap();
+
+    let children = nodes.iter_children(root).map(AstNode::kind).collect::<Vec<_>>();
+    assert_eq!(children.len(), 3);
+    assert!(matches!(children[0], AstKind::VariableDeclaration(_)));
+    assert!(matches!(children[1], AstKind::Function(_)));
+    assert!(matches!(children[2], AstKind::ExpressionStatement(_)));
+
+    let declarator =
+        nodes.iter().find(|node| matches!(node.kind(), AstKind::VariableDeclarator(_))).unwrap();
+    assert!(nodes
+        .iter_children(declarator.id())
+        .all(|child| nodes.parent_id(child.id()) == Some(declarator.id())));
+}
+
+#[test]
+fn test_iter_by_kind() {
+    let tester = SemanticTester::js("foo(); bar.baz(1); new Qux();");
+    let semantic = tester.build();
+    let nodes = semantic.nodes();
+
+    let callees = nodes
+        .iter_by_kind(|kind| match kind {
+            AstKind::CallExpression(call_expr) => Some(call_expr),
+            _ => None,
+        })
+        .map(|call_expr| call_expr.callee.span())
+        .collect::<Vec<_>>();
+    assert_eq!(callees, [Span::new(0, 3), Span::new(7, 14)]);
+}
+
+#[test]
+fn test_iter_parents_scopes() {
+    let tester = SemanticTester::js("function foo() { if (a) { let b = () => c; } }");
+    let semantic = tester.build();
+    let nodes = semantic.nodes();
+    let reference = nodes
+        .iter()
+        .find(
+            |node| matches!(node.kind(), AstKind::IdentifierReference(ident) if ident.name == "c"),
+        )
+        .unwrap();
+
+    let scopes = nodes.iter_parents_with_scope(reference.id()).map(|(_, scope_id)| scope_id);
+    assert!(scopes
+        .zip(nodes.iter_parents(reference.id()))
+        .all(|(scope_id, node)| scope_id == node.scope_id()));
+
+    let boundaries =
+        nodes.iter_parents_scopes(reference.id()).map(AstNode::kind).collect::<Vec<_>>();
+    assert_eq!(boundaries.len(), 4);
+    assert!(matches!(boundaries[0], AstKind::ArrowFunctionExpression(_)));
+    assert!(matches!(boundaries[1], AstKind::BlockStatement(_)));
+    assert!(matches!(boundaries[2], AstKind::Function(_)));
+    assert!(matches!(boundaries[3], AstKind::Program(_)));
+}
+
+#[test]
+fn test_common_ancestor() {
+    let tester = SemanticTester::js("function foo() { let a = b + c; }");
+    let semantic = tester.build();
+    let nodes = semantic.nodes();
+
+    let find = |name: &str| {
+        nodes
+            .iter()
+            .find(|node| {
+                matches!(node.kind(), AstKind::IdentifierReference(ident) if ident.name == name)
+            })
+            .unwrap()
+            .id()
+    };
+    let (b, c) = (find("b"), find("c"));
+
+    let ancestor = nodes.common_ancestor(b, c).unwrap();
+    assert!(matches!(nodes.kind(ancestor), AstKind::BinaryExpression(_)));
+    assert_eq!(nodes.common_ancestor(b, b), Some(b));
+    assert_eq!(nodes.common_ancestor(ancestor, c), Some(ancestor));
+    assert_eq!(nodes.common_ancestor(b, nodes.root().unwrap()), nodes.root());
+}
+
+#[test]
+fn test_subtree_iter() {
+    let tester = SemanticTester::js("function foo() { return a + b; } let c = d;");
+    let semantic = tester.build();
+    let nodes = semantic.nodes();
+
+    let function =
+        nodes.iter().find(|node| matches!(node.kind(), AstKind::Function(_))).unwrap().id();
+    let subtree = nodes.subtree_iter(function, TraversalOrder::PreOrder).collect::<Vec<_>>();
+    assert_eq!(subtree[0].id(), function);
+    assert!(subtree.iter().any(|node| matches!(node.kind(), AstKind::ReturnStatement(_))));
+    assert!(!subtree.iter().any(|node| matches!(node.kind(), AstKind::VariableDeclaration(_))));
+    assert!(subtree.iter().skip(1).all(|node| nodes.ancestors(node.id()).any(|id| id == function)));
+
+    let root = nodes.root().unwrap();
+    assert_eq!(nodes.subtree_iter(root, TraversalOrder::PreOrder).count(), nodes.len());
+    // Nodes are added in pre-order.
+    assert!(nodes
+        .subtree_iter(root, TraversalOrder::PreOrder)
+        .map(AstNode::id)
+        .eq(nodes.iter().map(AstNode::id)));
+}
+
+#[test]
+fn test_subtree_iter_post_order() {
+    let tester = SemanticTester::js("function foo() { return a + b; } let c = d;");
+    let semantic = tester.build();
+    let nodes = semantic.nodes();
+
+    let function =
+        nodes.iter().find(|node| matches!(node.kind(), AstKind::Function(_))).unwrap().id();
+    let pre_order = nodes.subtree_iter(function, TraversalOrder::PreOrder).collect::<Vec<_>>();
+    let post_order = nodes.subtree_iter(function, TraversalOrder::PostOrder).collect::<Vec<_>>();
+    assert_eq!(post_order.len(), pre_order.len());
+    assert_eq!(post_order.last().unwrap().id(), function);
+    // Every node comes after all of its descendants.
+    for (i, node) in post_order.iter().enumerate() {
+        assert!(post_order[i + 1..]
+            .iter()
+            .all(|later| !nodes.ancestors(later.id()).skip(1).any(|id| id == node.id())));
+    }
+    let kinds =
+        post_order.iter().map(|node| node.kind().debug_name().into_owned()).collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        [
+            "BindingIdentifier(foo)",
+            "FormalParameters",
+            "IdentifierReference(a)",
+            "IdentifierReference(b)",
+            "BinaryExpression+",
+            "ReturnStatement",
+            "FunctionBody",
+            "Function(foo)",
+        ]
+    );
+}
+
+#[test]
+fn test_depth() {
+    let tester = SemanticTester::js("let a = b;");
+    let semantic = tester.build();
+    let nodes = semantic.nodes();
+
+    assert_eq!(nodes.root_node().unwrap().depth(), 0);
+    for node in nodes.iter() {
+        assert_eq!(node.depth() as usize, nodes.ancestors(node.id()).count() - 1);
+    }
+}
+
+#[test]
+fn test_get_node_by_span() {
+    let source = "let foo = bar + baz;";
+    let tester = SemanticTester::js(source);
+    let semantic = tester.build();
+    let nodes = semantic.nodes();
+
+    let bar = Span::new(10, 13);
+    assert_eq!(bar.source_text(source), "bar");
+    let node = nodes.get_node_by_span(bar).unwrap();
+    assert!(matches!(node.kind(), AstKind::IdentifierReference(ident) if ident.name == "bar"));
+
+    // `bar +` is enclosed by `bar + baz`
+    let node = nodes.get_node_by_span(Span::new(10, 15)).unwrap();
+    assert!(matches!(node.kind(), AstKind::BinaryExpression(_)));
+
+    let node = nodes.get_node_at_offset(17).unwrap();
+    assert!(matches!(node.kind(), AstKind::IdentifierReference(ident) if ident.name == "baz"));
+
+    // Between the operands of `bar + baz`
+    let node = nodes.get_node_at_offset(14).unwrap();
+    assert!(matches!(node.kind(), AstKind::BinaryExpression(_)));
+}
+
+#[test]
+fn test_get_node_by_span_in_call() {
+    // The arguments of a call are visited before its callee
+    let source = "foo.bar(baz, qux);";
+    let tester = SemanticTester::js(source);
+    let semantic = tester.build();
+    let nodes = semantic.nodes();
+
+    let object = Span::new(0, 3);
+    assert_eq!(object.source_text(source), "foo");
+    let node = nodes.get_node_by_span(object).unwrap();
+    assert!(matches!(node.kind(), AstKind::IdentifierReference(ident) if ident.name == "foo"));
+
+    let node = nodes.get_node_at_offset(5).unwrap();
+    assert!(matches!(node.kind(), AstKind::IdentifierName(ident) if ident.name == "bar"));
+
+    let qux = Span::new(13, 16);
+    assert_eq!(qux.source_text(source), "qux");
+    let node = nodes.get_node_by_span(qux).unwrap();
+    assert!(matches!(node.kind(), AstKind::IdentifierReference(ident) if ident.name == "qux"));
+
+    let node = nodes.get_node_at_offset(9).unwrap();
+    assert!(matches!(node.kind(), AstKind::IdentifierReference(ident) if ident.name == "baz"));
+}
+
+#[test]
+fn test_to_dot() {
+    let tester = SemanticTester::js("function foo() { return 1; }");
+    let semantic = tester.build();
+    let nodes = semantic.nodes();
+
+    let dot = nodes.to_dot();
+    assert!(dot.starts_with("digraph {\n"));
+    assert!(dot.ends_with("}\n"));
+    assert!(dot.contains("0 [ label = \"Program (0)\" style = filled fillcolor = lightblue ]"));
+    assert_eq!(dot.matches(" -> ").count(), nodes.len() - 1);
+    assert_eq!(dot.matches("fillcolor").count(), 2);
+}
+
+#[test]
+fn test_kind_categories() {
+    let tester =
+        SemanticTester::js("function foo() {} (function () {}); class A {} (class {}); a;");
+    let semantic = tester.build();
+    let nodes = semantic.nodes();
+
+    let categories = nodes
+        .iter()
+        .map(AstNode::kind)
+        .filter(|kind| matches!(kind, AstKind::Function(_) | AstKind::Class(_)))
+        .map(|kind| {
+            (kind.is_statement_variant(), kind.is_declaration_variant(), kind.is_expression())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        categories,
+        [(true, true, false), (false, false, true), (true, true, false), (false, false, true)]
+    );
+
+    assert!(!nodes.root_node().unwrap().kind().is_statement_variant());
+    let reference = nodes
+        .iter()
+        .find(|node| matches!(node.kind(), AstKind::IdentifierReference(_)))
+        .unwrap()
+        .kind();
+    assert!(reference.is_expression() && !reference.is_statement_variant());
+}
@@ -1,4 +1,4 @@
-use oxc_semantic::ScopeFlags;
+use oxc_semantic::{ScopeFlags, ScopeId};
 
 use crate::util::{Expect, SemanticTester};
 
@@ -138,3 +138,61 @@ fn test_catch_clause_parameters() {
     .has_number_of_references(1)
     .test();
 }
+
+#[test]
+fn test_scope_predicates() {
+    let tester = SemanticTester::js(
+        "
+        function f() {
+            try {} catch (e) {} finally {}
+            for (let i of a) {}
+            switch (a) {}
+        }
+        const c = class {};
+    ",
+    );
+    let semantic = tester.build();
+    let scopes = semantic.scopes();
+    let nodes = semantic.nodes();
+    let count = |predicate: &dyn Fn(ScopeId) -> bool| {
+        scopes.descendants_from_root().filter(|&scope_id| predicate(scope_id)).count()
+    };
+    assert_eq!(count(&|scope_id| scopes.is_module_scope(scope_id)), 1);
+    assert_eq!(count(&|scope_id| scopes.is_function_scope(scope_id)), 1);
+    // try, catch, finally, for-of and its body, switch
+    assert_eq!(count(&|scope_id| scopes.is_block_scope(scope_id, nodes)), 6);
+    assert_eq!(count(&|scope_id| scopes.is_catch_scope(scope_id, nodes)), 1);
+    assert_eq!(count(&|scope_id| scopes.is_class_scope(scope_id, nodes)), 1);
+    assert_eq!(count(&|scope_id| scopes.is_strict_mode_scope(scope_id)), scopes.len());
+
+    let tester = SemanticTester::js("function f() { 'use strict'; {} } {}").with_module(false);
+    let semantic = tester.build();
+    let scopes = semantic.scopes();
+    let strict = scopes
+        .descendants_from_root()
+        .filter(|&scope_id| scopes.is_strict_mode_scope(scope_id))
+        .count();
+    assert_eq!(strict, 2);
+}
+
+#[test]
+fn test_scope_node_ids() {
+    // Switch statements, enums and namespaces enter their scope after their node
+    let tester = SemanticTester::ts("switch (a) {} enum E { A } namespace N {} { }");
+    let semantic = tester.build();
+    let scopes = semantic.scopes();
+    let types = scopes
+        .descendants_from_root()
+        .map(|scope_id| semantic.nodes().kind(scopes.get_node_id(scope_id)).ty().name())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        types,
+        [
+            "Program",
+            "SwitchStatement",
+            "TSEnumDeclaration",
+            "TSModuleDeclaration",
+            "BlockStatement"
+        ]
+    );
+}
@@ -1,4 +1,4 @@
-use oxc_semantic::SymbolFlags;
+use oxc_semantic::{ScopeFlags, SymbolFlags, UnusedSymbolsOptions};
 
 use crate::util::SemanticTester;
 
@@ -46,6 +46,21 @@ fn test_var_simple() {
         .test();
 }
 
+#[test]
+fn test_using_declaration() {
+    SemanticTester::js("{ using x = foo(); }")
+        .has_some_symbol("x")
+        .contains_flags(SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable)
+        .is_not_in_scope(ScopeFlags::Top)
+        .test();
+
+    SemanticTester::js("async function f() { for (await using x of y) {} }")
+        .has_some_symbol("x")
+        .contains_flags(SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable)
+        .is_not_in_scope(ScopeFlags::Function)
+        .test();
+}
+
 #[test]
 fn test_var_read_write() {
     SemanticTester::js("let x; x += 1")
@@ -114,3 +129,46 @@ fn test_export_flag() {
     tester.has_root_symbol("b").contains_flags(SymbolFlags::Export).test();
     tester.has_root_symbol("c").contains_flags(SymbolFlags::Export).test();
 }
+
+fn unused_symbol_names(tester: &SemanticTester, options: UnusedSymbolsOptions) -> Vec<String> {
+    let semantic = tester.build();
+    let mut names = semantic
+        .symbols()
+        .unused_symbols(options)
+        .map(|symbol_id| semantic.symbols().get_name(symbol_id).to_string())
+        .collect::<Vec<_>>();
+    names.sort();
+    names
+}
+
+#[test]
+fn test_unused_symbols() {
+    let tester = SemanticTester::js(
+        "
+        let a = 1;
+        let b = a;
+        export let c;
+        function f(x) {}
+        export { b };
+    ",
+    );
+    assert_eq!(unused_symbol_names(&tester, UnusedSymbolsOptions::default()), ["f", "x"]);
+
+    let tester = SemanticTester::ts(
+        "
+        interface I {}
+        type T = string;
+        let x: T;
+        declare const d: number;
+        declare namespace N { const n: number; }
+        class C {}
+        let y: C;
+    ",
+    );
+    tester.has_root_symbol("d").contains_flags(SymbolFlags::Ambient).test();
+    assert_eq!(unused_symbol_names(&tester, UnusedSymbolsOptions::default()), ["x", "y"]);
+    assert_eq!(
+        unused_symbol_names(&tester, UnusedSymbolsOptions { include_types: true }),
+        ["I", "x", "y"]
+    );
+}
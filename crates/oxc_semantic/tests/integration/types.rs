@@ -0,0 +1,192 @@
+use oxc_semantic::{InferredType, Semantic, TypeKeyword};
+
+use crate::util::SemanticTester;
+
+/// The inferred type of a top level symbol.
+fn symbol_type(semantic: &Semantic, name: &str) -> Option<InferredType> {
+    let symbol_id = semantic.scopes().get_binding(semantic.scopes().root_scope_id(), name)?;
+    semantic.types().symbol_type(symbol_id).cloned()
+}
+
+/// The types of all read references to `name`, in source order.
+fn reference_types(semantic: &Semantic, name: &str) -> Vec<Option<InferredType>> {
+    semantic
+        .symbols()
+        .references
+        .iter_enumerated()
+        .filter(|(_, reference)| reference.name().as_str() == name && !reference.is_write())
+        .map(|(reference_id, _)| semantic.reference_type(reference_id).cloned())
+        .collect()
+}
+
+fn keyword(keyword: TypeKeyword) -> InferredType {
+    InferredType::Keyword(keyword)
+}
+
+#[test]
+fn test_disabled_by_default() {
+    let tester = SemanticTester::js("const x = 1;");
+    let semantic = tester.build();
+    assert_eq!(symbol_type(&semantic, "x"), None);
+}
+
+#[test]
+fn test_literal_initializers() {
+    let tester = SemanticTester::js(
+        "const a = 1; const b = 'b'; const c = true; const d = null; const e = -1;
+        let f = 1; var g; const h = {}; const i = () => {}; function j() {} const k = a;",
+    )
+    .with_type_inference(true);
+    let semantic = tester.build();
+    assert_eq!(symbol_type(&semantic, "a"), Some(InferredType::NumberLiteral(1.0)));
+    assert_eq!(symbol_type(&semantic, "b"), Some(InferredType::StringLiteral("b".into())));
+    assert_eq!(symbol_type(&semantic, "c"), Some(InferredType::BooleanLiteral(true)));
+    assert_eq!(symbol_type(&semantic, "d"), Some(InferredType::Null));
+    assert_eq!(symbol_type(&semantic, "e"), Some(InferredType::NumberLiteral(-1.0)));
+    assert_eq!(symbol_type(&semantic, "f"), Some(keyword(TypeKeyword::Number)));
+    assert_eq!(symbol_type(&semantic, "g"), Some(InferredType::Undefined));
+    assert_eq!(symbol_type(&semantic, "h"), Some(keyword(TypeKeyword::Object)));
+    assert_eq!(symbol_type(&semantic, "i"), Some(keyword(TypeKeyword::Function)));
+    assert_eq!(symbol_type(&semantic, "j"), Some(keyword(TypeKeyword::Function)));
+    assert_eq!(symbol_type(&semantic, "k"), Some(InferredType::NumberLiteral(1.0)));
+}
+
+#[test]
+fn test_unions() {
+    let tester = SemanticTester::js(
+        "const a = b || 'default'; const c = a ?? null; const d = x ? 1 : 'one';
+        let e = 1; e = 'e'; let f = 1; f++; const [g] = array; let h = null; h ||= 1;",
+    )
+    .with_type_inference(true);
+    let semantic = tester.build();
+    assert_eq!(symbol_type(&semantic, "a"), Some(InferredType::Unknown));
+    assert_eq!(
+        symbol_type(&semantic, "d"),
+        Some(InferredType::Union(vec![
+            InferredType::NumberLiteral(1.0),
+            InferredType::StringLiteral("one".into())
+        ]))
+    );
+    assert_eq!(
+        symbol_type(&semantic, "e"),
+        Some(InferredType::Union(vec![keyword(TypeKeyword::Number), keyword(TypeKeyword::String)]))
+    );
+    assert_eq!(symbol_type(&semantic, "f"), Some(InferredType::Unknown));
+    assert_eq!(symbol_type(&semantic, "g"), Some(InferredType::Unknown));
+    assert_eq!(
+        symbol_type(&semantic, "h"),
+        Some(InferredType::Union(vec![InferredType::Null, keyword(TypeKeyword::Number)]))
+    );
+
+    let tester =
+        SemanticTester::js("const a = 0; const b = a || 'default';").with_type_inference(true);
+    let semantic = tester.build();
+    assert_eq!(
+        symbol_type(&semantic, "b"),
+        Some(InferredType::Union(vec![
+            InferredType::NumberLiteral(0.0),
+            InferredType::StringLiteral("default".into())
+        ]))
+    );
+}
+
+#[test]
+fn test_type_annotations() {
+    let tester = SemanticTester::ts(
+        "let a: string | null = null; const b: 'b' = 'b'; function f(c, d?: number) {}",
+    )
+    .with_type_inference(true);
+    let semantic = tester.build();
+    assert_eq!(
+        symbol_type(&semantic, "a"),
+        Some(InferredType::Union(vec![keyword(TypeKeyword::String), InferredType::Null]))
+    );
+    assert_eq!(symbol_type(&semantic, "b"), Some(InferredType::StringLiteral("b".into())));
+
+    let params = semantic
+        .symbols()
+        .names
+        .iter_enumerated()
+        .filter(|(_, name)| matches!(name.as_str(), "c" | "d"))
+        .map(|(symbol_id, _)| semantic.types().symbol_type(symbol_id).cloned())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        params,
+        [
+            Some(InferredType::Unknown),
+            Some(InferredType::Union(vec![keyword(TypeKeyword::Number), InferredType::Undefined]))
+        ]
+    );
+}
+
+#[test]
+fn test_typeof_narrowing() {
+    let tester = SemanticTester::js(
+        "function f(x) {
+            if (typeof x === 'string') { x } else { x }
+            typeof x !== 'number' ? x : x;
+            x
+        }",
+    )
+    .with_type_inference(true);
+    let semantic = tester.build();
+    assert_eq!(
+        reference_types(&semantic, "x"),
+        [
+            Some(InferredType::Unknown),
+            Some(keyword(TypeKeyword::String)),
+            Some(InferredType::Unknown),
+            Some(InferredType::Unknown),
+            Some(InferredType::Unknown),
+            Some(keyword(TypeKeyword::Number)),
+            Some(InferredType::Unknown),
+        ]
+    );
+}
+
+#[test]
+fn test_null_narrowing() {
+    let tester = SemanticTester::ts(
+        "function f(x: string | null) {
+            if (x !== null) { x } else { x }
+            x === undefined && x;
+            x
+        }",
+    )
+    .with_type_inference(true);
+    let semantic = tester.build();
+    let declared = InferredType::Union(vec![keyword(TypeKeyword::String), InferredType::Null]);
+    assert_eq!(
+        reference_types(&semantic, "x"),
+        [
+            Some(declared.clone()),
+            Some(keyword(TypeKeyword::String)),
+            Some(InferredType::Null),
+            Some(declared.clone()),
+            Some(InferredType::Undefined),
+            Some(declared),
+        ]
+    );
+}
+
+#[test]
+fn test_instanceof_narrowing() {
+    let tester = SemanticTester::js("const x = f(); if (!(x instanceof Foo)) {} else { x }")
+        .with_type_inference(true);
+    let semantic = tester.build();
+    assert_eq!(
+        reference_types(&semantic, "x"),
+        [Some(InferredType::Unknown), Some(InferredType::Instance("Foo".into()))]
+    );
+}
+
+#[test]
+fn test_no_narrowing_when_written() {
+    let tester = SemanticTester::js("let x = f(); if (typeof x === 'string') { x; x = 1; }")
+        .with_type_inference(true);
+    let semantic = tester.build();
+    assert_eq!(
+        reference_types(&semantic, "x"),
+        [Some(InferredType::Unknown), Some(InferredType::Unknown)]
+    );
+}
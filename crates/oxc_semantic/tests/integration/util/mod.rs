@@ -18,6 +18,7 @@ pub struct SemanticTester<'a> {
     source_type: SourceType,
     source_text: &'a str,
     cfg: bool,
+    type_inference: bool,
     /// Expect semantic analysis to produce errors.
     ///
     /// Default is `false`.
@@ -52,6 +53,7 @@ impl<'a> SemanticTester<'a> {
             source_type,
             source_text,
             cfg: false,
+            type_inference: false,
             expect_errors: false,
         }
     }
@@ -81,6 +83,13 @@ impl<'a> SemanticTester<'a> {
         self.cfg = yes;
         self
     }
+
+    #[must_use]
+    pub fn with_type_inference(mut self, yes: bool) -> Self {
+        self.type_inference = yes;
+        self
+    }
+
     /// The program being tested is expected to produce errors during semantic analysis.
     ///
     /// By default, programs are expected to be error-free.
@@ -150,18 +159,19 @@ impl<'a> SemanticTester<'a> {
             .with_check_syntax_error(true)
             .with_trivias(parse.trivias)
             .with_cfg(self.cfg)
+            .with_type_inference(self.type_inference)
             .build(program)
     }
 
     pub fn basic_blocks_count(&self) -> usize {
         let built = self.build();
-        built.cfg().map_or(0, |cfg| cfg.basic_blocks.len())
+        built.cfg().map_or(0, |cfg| cfg.basic_blocks().len())
     }
 
     pub fn basic_blocks_printed(&self) -> String {
         let built = self.build();
         built.cfg().map_or_else(String::default, |cfg| {
-            cfg.basic_blocks
+            cfg.basic_blocks()
                 .iter()
                 .map(DisplayDot::display_dot)
                 .enumerate()
@@ -5,6 +5,7 @@ use std::{
 };
 
 use compact_str::CompactString;
+use oxc_allocator::{Allocator, CloneIn};
 #[cfg(feature = "serialize")]
 use serde::{Serialize, Serializer};
 
@@ -58,6 +59,14 @@ impl<'a> Atom<'a> {
     }
 }
 
+impl<'old_alloc, 'new_alloc> CloneIn<'new_alloc> for Atom<'old_alloc> {
+    type Cloned = Atom<'new_alloc>;
+
+    fn clone_in(&self, alloc: &'new_alloc Allocator) -> Self::Cloned {
+        Atom::from(self.as_str().clone_in(alloc))
+    }
+}
+
 impl<'a> From<&'a str> for Atom<'a> {
     fn from(s: &'a str) -> Self {
         Self(s)
@@ -0,0 +1,60 @@
+use crate::Span;
+
+/// Line start offsets of a source text, for converting many offsets to line/column.
+///
+/// Lines and columns are zero-based, columns are counted in bytes.
+/// Lines are terminated by `\n`, so `\r\n` counts as a single line ending.
+///
+/// ```
+/// use oxc_span::{LineIndex, Span};
+///
+/// let line_index = LineIndex::new("let a;\r\nlet b;");
+/// assert_eq!(line_index.line_col(9), (1, 1));
+/// assert_eq!(line_index.span_line_col(Span::new(8, 14)), ((1, 0), (1, 6)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LineIndex {
+    /// Offset of the first byte of each line, the first line starts at 0
+    line_starts: Vec<u32>,
+}
+
+impl LineIndex {
+    /// Scan `source_text` once for line endings.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `source_text` is larger than 4 GiB.
+    pub fn new(source_text: &str) -> Self {
+        let mut line_starts = vec![0];
+        line_starts.extend(
+            source_text
+                .bytes()
+                .enumerate()
+                .filter(|(_, b)| *b == b'\n')
+                .map(|(i, _)| u32::try_from(i + 1).unwrap()),
+        );
+        Self { line_starts }
+    }
+
+    /// Number of lines in the source text.
+    pub fn len(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Always `false`, an empty source text has one empty line.
+    pub fn is_empty(&self) -> bool {
+        self.line_starts.is_empty()
+    }
+
+    /// Zero-based line and column of a byte `offset`, in `O(log n)`.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn line_col(&self, offset: u32) -> (u32, u32) {
+        let line = self.line_starts.partition_point(|&start| start <= offset) - 1;
+        (line as u32, offset - self.line_starts[line])
+    }
+
+    /// Line and column of the start and end of `span`.
+    pub fn span_line_col(&self, span: Span) -> ((u32, u32), (u32, u32)) {
+        (self.line_col(span.start), self.line_col(span.end))
+    }
+}
@@ -3,6 +3,7 @@
 
 use std::path::Path;
 
+use oxc_allocator::{Allocator, CloneIn};
 #[cfg(feature = "serialize")]
 use serde::Serialize;
 #[cfg(feature = "serialize")]
@@ -59,6 +60,15 @@ pub enum LanguageVariant {
 #[derive(Debug)]
 pub struct UnknownExtension(pub String);
 
+impl<'alloc> CloneIn<'alloc> for SourceType {
+    type Cloned = Self;
+
+    #[inline]
+    fn clone_in(&self, _: &'alloc Allocator) -> Self {
+        *self
+    }
+}
+
 impl Default for SourceType {
     fn default() -> Self {
         Self {
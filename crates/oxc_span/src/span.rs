@@ -7,6 +7,7 @@ use std::{
 };
 
 use miette::{LabeledSpan, SourceOffset, SourceSpan};
+use oxc_allocator::{Allocator, CloneIn};
 #[cfg(feature = "serialize")]
 use serde::Serialize;
 #[cfg(feature = "serialize")]
@@ -148,6 +149,53 @@ impl Span {
         Self::new(self.start.min(other.start), self.end.max(other.end))
     }
 
+    /// Create a [`Span`] covering all of the given [`Span`]s, or `None` if there are none.
+    ///
+    /// # Example
+    /// ```
+    /// use oxc_span::Span;
+    ///
+    /// let spans = [Span::new(10, 12), Span::new(0, 1), Span::new(4, 6)];
+    /// assert_eq!(Span::merge_all(spans), Some(Span::new(0, 12)));
+    /// assert_eq!(Span::merge_all([]), None);
+    /// ```
+    pub fn merge_all<I: IntoIterator<Item = Self>>(spans: I) -> Option<Self> {
+        spans.into_iter().reduce(|merged, span| merged.merge(&span))
+    }
+
+    /// Returns `true` if `other` lies entirely within `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use oxc_span::Span;
+    ///
+    /// let span = Span::new(5, 10);
+    /// assert!(span.contains_span(Span::new(5, 10)));
+    /// assert!(span.contains_span(Span::new(6, 8)));
+    /// assert!(!span.contains_span(Span::new(4, 8)));
+    /// ```
+    pub const fn contains_span(self, other: Self) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Returns `true` if `self` and `other` share at least one byte.
+    ///
+    /// # Example
+    /// ```
+    /// use oxc_span::Span;
+    ///
+    /// let span = Span::new(5, 10);
+    /// assert!(span.overlaps(Span::new(8, 12)));
+    /// assert!(!span.overlaps(Span::new(10, 12)));
+    /// ```
+    pub const fn overlaps(self, other: Self) -> bool {
+        // Empty spans have no bytes to share
+        self.start < self.end
+            && other.start < other.end
+            && self.start < other.end
+            && other.start < self.end
+    }
+
     /// Create a [`Span`] that has its start position moved to the left by
     /// `offset` bytes.
     ///
@@ -272,10 +320,54 @@ impl Span {
     /// let name = name_span.source_text(source);
     /// assert_eq!(name_span.size(), name.len() as u32);
     /// ```
+    ///
+    /// # Panics
+    /// Panics if the [`Span`] is out of bounds of `source_text` or does not lie on UTF-8
+    /// character boundaries, see [`Span::source_text_safe`] for a non-panicking version.
     pub fn source_text<'a>(&self, source_text: &'a str) -> &'a str {
         &source_text[self.start as usize..self.end as usize]
     }
 
+    /// Get a snippet of text from a source string that the [`Span`] covers,
+    /// or `None` if the [`Span`] is out of bounds or not on UTF-8 character boundaries.
+    ///
+    /// # Example
+    /// ```
+    /// use oxc_span::Span;
+    ///
+    /// let source = "let ö = 1;";
+    /// assert_eq!(Span::new(4, 6).source_text_safe(source), Some("ö"));
+    /// assert_eq!(Span::new(4, 5).source_text_safe(source), None);
+    /// assert_eq!(Span::new(4, 20).source_text_safe(source), None);
+    /// ```
+    pub fn source_text_safe<'a>(&self, source_text: &'a str) -> Option<&'a str> {
+        source_text.get(self.start as usize..self.end as usize)
+    }
+
+    /// Zero-based line and column of the start of this [`Span`] in `source_text`.
+    ///
+    /// Columns are counted in bytes and `\r\n` is a single line ending.
+    /// This scans the source text up to the offset, use [`LineIndex`](crate::LineIndex)
+    /// when converting many spans of the same source text.
+    ///
+    /// # Example
+    /// ```
+    /// use oxc_span::Span;
+    ///
+    /// let source = "let a;\r\nlet b;";
+    /// assert_eq!(Span::new(12, 13).start_line_col(source), (1, 4));
+    /// ```
+    pub fn start_line_col(&self, source_text: &str) -> (u32, u32) {
+        line_col(source_text, self.start)
+    }
+
+    /// Zero-based line and column of the end of this [`Span`] in `source_text`.
+    ///
+    /// See [`Span::start_line_col`].
+    pub fn end_line_col(&self, source_text: &str) -> (u32, u32) {
+        line_col(source_text, self.end)
+    }
+
     /// Create a [`LabeledSpan`] covering this [`Span`] with the given label.
     #[must_use]
     pub fn label<S: Into<String>>(self, label: S) -> LabeledSpan {
@@ -283,6 +375,14 @@ impl Span {
     }
 }
 
+#[allow(clippy::cast_possible_truncation)]
+fn line_col(source_text: &str, offset: u32) -> (u32, u32) {
+    let before = &source_text.as_bytes()[..offset as usize];
+    let mut lines = before.split(|b| *b == b'\n');
+    let column = lines.next_back().map_or(0, <[u8]>::len);
+    (lines.count() as u32, column as u32)
+}
+
 impl Index<Span> for str {
     type Output = str;
 
@@ -299,6 +399,15 @@ impl IndexMut<Span> for str {
     }
 }
 
+impl<'alloc> CloneIn<'alloc> for Span {
+    type Cloned = Self;
+
+    #[inline]
+    fn clone_in(&self, _: &'alloc Allocator) -> Self {
+        *self
+    }
+}
+
 impl From<Range<u32>> for Span {
     #[inline]
     fn from(range: Range<u32>) -> Self {
@@ -354,6 +463,55 @@ mod test {
         assert_ne!(Span::new(0, 0), Span::new(0, 1));
     }
 
+    #[test]
+    fn test_line_col() {
+        use crate::LineIndex;
+        let source = "a\nbc\r\n\nd";
+        let line_index = LineIndex::new(source);
+        assert_eq!(line_index.len(), 4);
+        for (offset, expected) in [
+            (0, (0, 0)),
+            (1, (0, 1)),
+            (2, (1, 0)),
+            (4, (1, 2)),
+            (6, (2, 0)),
+            (7, (3, 0)),
+            (8, (3, 1)),
+        ] {
+            assert_eq!(line_index.line_col(offset), expected);
+            assert_eq!(Span::empty(offset).start_line_col(source), expected);
+        }
+        assert_eq!(Span::new(2, 8).end_line_col(source), (3, 1));
+    }
+
+    #[test]
+    fn test_source_text_safe() {
+        let source = "let ö = 1;";
+        assert_eq!(Span::new(4, 6).source_text_safe(source), Some("ö"));
+        assert_eq!(Span::new(4, 5).source_text_safe(source), None);
+        assert_eq!(Span::new(10, 12).source_text_safe(source), None);
+        assert_eq!(Span::new(11, 11).source_text_safe(source), Some(""));
+    }
+
+    #[test]
+    fn test_merge_all() {
+        assert_eq!(Span::merge_all([Span::new(3, 4)]), Some(Span::new(3, 4)));
+        assert_eq!(Span::merge_all([Span::new(3, 4), Span::new(1, 2)]), Some(Span::new(1, 4)));
+        assert_eq!(Span::merge_all(std::iter::empty()), None);
+    }
+
+    #[test]
+    fn test_contains_and_overlaps() {
+        let span = Span::new(5, 10);
+        assert!(span.contains_span(span));
+        assert!(span.contains_span(Span::empty(10)));
+        assert!(!span.contains_span(Span::new(9, 11)));
+        assert!(span.overlaps(span));
+        assert!(span.overlaps(Span::new(0, 6)));
+        assert!(!span.overlaps(Span::new(0, 5)));
+        assert!(!span.overlaps(Span::empty(7)));
+    }
+
     #[test]
     fn test_ordering_less() {
         assert!(Span::new(0, 0) < Span::new(0, 1));
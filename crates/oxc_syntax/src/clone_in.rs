@@ -0,0 +1,31 @@
+//! [CloneIn] for the `Copy` types of this crate used in the AST.
+
+use oxc_allocator::{Allocator, CloneIn};
+
+use crate::{
+    number::{BigintBase, NumberBase},
+    operator::{
+        AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator, UpdateOperator,
+    },
+    reference::ReferenceFlag,
+};
+
+macro_rules! impl_clone_in {
+    ($($t:ty)*) => {
+        $(
+            impl<'alloc> CloneIn<'alloc> for $t {
+                type Cloned = Self;
+                #[inline]
+                fn clone_in(&self, _: &'alloc Allocator) -> Self {
+                    *self
+                }
+            }
+        )*
+    }
+}
+
+impl_clone_in! {
+    AssignmentOperator BinaryOperator LogicalOperator UnaryOperator UpdateOperator
+    NumberBase BigintBase
+    ReferenceFlag
+}
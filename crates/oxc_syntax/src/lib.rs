@@ -1,6 +1,7 @@
 //! Common code for JavaScript Syntax
 
 pub mod class;
+mod clone_in;
 pub mod identifier;
 pub mod keyword;
 pub mod module_graph_visitor;
@@ -133,6 +133,9 @@ pub enum BinaryOperator {
     Instanceof,
     #[cfg_attr(feature = "serialize", serde(rename = "**"))]
     Exponential,
+    /// Hack-style pipeline operator, see <https://github.com/tc39/proposal-pipeline-operator>
+    #[cfg_attr(feature = "serialize", serde(rename = "|>"))]
+    Pipeline,
 }
 
 impl BinaryOperator {
@@ -225,6 +228,7 @@ impl BinaryOperator {
             Self::In => "in",
             Self::Instanceof => "instanceof",
             Self::Exponential => "**",
+            Self::Pipeline => "|>",
         }
     }
 
@@ -246,6 +250,7 @@ impl BinaryOperator {
             Self::Addition | Self::Subtraction => Precedence::Shift,
             Self::Multiplication | Self::Remainder | Self::Division => Precedence::Add,
             Self::Exponential => Precedence::Multiply,
+            Self::Pipeline => Precedence::Assign,
         }
     }
 }
@@ -269,6 +274,8 @@ impl GetPrecedence for BinaryOperator {
             Self::Subtraction | Self::Addition => Precedence::Add,
             Self::Multiplication | Self::Remainder | Self::Division => Precedence::Multiply,
             Self::Exponential => Precedence::Exponential,
+            // `ShortCircuitExpression |> AssignmentExpression` is an `AssignmentExpression`
+            Self::Pipeline => Precedence::Assign,
         }
     }
 }
@@ -0,0 +1,53 @@
+mod optional_chaining;
+mod options;
+
+use std::rc::Rc;
+
+pub(crate) use optional_chaining::{into_chain_element_expression, transform_chain};
+pub use optional_chaining::{OptionalChaining, OptionalChainingOptions};
+pub use options::ES2020Options;
+use oxc_allocator::Vec;
+use oxc_ast::ast::*;
+use oxc_traverse::TraverseCtx;
+
+use crate::context::Ctx;
+
+#[allow(dead_code)]
+pub struct ES2020<'a> {
+    ctx: Ctx<'a>,
+    options: ES2020Options,
+
+    // Plugins
+    optional_chaining: OptionalChaining<'a>,
+}
+
+impl<'a> ES2020<'a> {
+    pub fn new(options: ES2020Options, ctx: Ctx<'a>) -> Self {
+        Self {
+            optional_chaining: OptionalChaining::new(
+                options.optional_chaining.clone().unwrap_or_default(),
+                Rc::clone(&ctx),
+            ),
+            ctx,
+            options,
+        }
+    }
+
+    pub fn enter_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &TraverseCtx<'a>) {
+        if self.options.optional_chaining.is_some() {
+            self.optional_chaining.transform_statements(stmts, ctx);
+        }
+    }
+
+    pub fn exit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &TraverseCtx<'a>) {
+        if self.options.optional_chaining.is_some() {
+            self.optional_chaining.transform_statements_on_exit(stmts, ctx);
+        }
+    }
+
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.optional_chaining.is_some() {
+            self.optional_chaining.transform_expression(expr, ctx);
+        }
+    }
+}
@@ -0,0 +1,347 @@
+use std::rc::Rc;
+
+use oxc_allocator::Vec;
+use oxc_ast::ast::*;
+use oxc_span::SPAN;
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator};
+use oxc_traverse::TraverseCtx;
+use serde::Deserialize;
+
+use crate::{
+    context::Ctx,
+    helpers::{bindings::BoundIdentifier, var_declarations::VarDeclarations},
+};
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct OptionalChainingOptions {
+    /// When `true`, this transform will pretend `document.all` does not exist,
+    /// and perform loose equality checks with `null` instead of strict equality checks
+    /// against both `null` and `undefined`.
+    pub loose: bool,
+}
+
+/// [plugin-transform-optional-chaining](https://babel.dev/docs/babel-plugin-transform-optional-chaining)
+///
+/// This plugin transforms optional chains to conditional expressions.
+///
+/// This plugin is included in `preset-env`, in ES2020
+///
+/// ## Example
+///
+/// Input:
+/// ```js
+/// a?.b.c;
+/// foo.bar?.();
+/// ```
+///
+/// Output:
+/// ```js
+/// var _foo$bar;
+/// a === null || a === void 0 ? void 0 : a.b.c;
+/// (_foo$bar = foo.bar) === null || _foo$bar === void 0 ? void 0 : _foo$bar.call(foo);
+/// ```
+///
+/// References:
+///
+/// * <https://babeljs.io/docs/babel-plugin-transform-optional-chaining>
+/// * <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-optional-chaining>
+pub struct OptionalChaining<'a> {
+    ctx: Ctx<'a>,
+    options: OptionalChainingOptions,
+    var_declarations: VarDeclarations<'a>,
+}
+
+/// A value which can be read again without evaluating its expression twice
+enum Memo<'a> {
+    This,
+    Binding(BoundIdentifier<'a>),
+}
+
+impl<'a> Memo<'a> {
+    fn read(&self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        match self {
+            Self::This => ctx.ast.this_expression(SPAN),
+            Self::Binding(binding) => {
+                ctx.ast.identifier_reference_expression(binding.create_read_reference(ctx))
+            }
+        }
+    }
+}
+
+impl<'a> OptionalChaining<'a> {
+    pub fn new(options: OptionalChainingOptions, ctx: Ctx<'a>) -> Self {
+        let var_declarations = VarDeclarations::new(Rc::clone(&ctx));
+        Self { ctx, options, var_declarations }
+    }
+
+    pub fn transform_statements(
+        &mut self,
+        _stmts: &mut Vec<'a, Statement<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        self.var_declarations.enter_statements(ctx);
+    }
+
+    pub fn transform_statements_on_exit(
+        &mut self,
+        stmts: &mut Vec<'a, Statement<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        self.var_declarations.exit_statements(stmts, ctx);
+    }
+
+    /// Transforms `a?.b` and `delete a?.b`
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let (chain, is_delete) = match expr {
+            Expression::ChainExpression(_) => (self.ctx.ast.move_expression(expr), false),
+            Expression::UnaryExpression(unary)
+                if unary.operator == UnaryOperator::Delete
+                    && matches!(unary.argument, Expression::ChainExpression(_)) =>
+            {
+                (self.ctx.ast.move_expression(&mut unary.argument), true)
+            }
+            _ => return,
+        };
+        let mut lowering = ChainLowering {
+            loose: self.options.loose,
+            var_declarations: &mut self.var_declarations,
+        };
+        *expr = lowering.transform_chain(into_chain_element_expression(chain), is_delete, ctx);
+    }
+}
+
+/// Transforms an optional chain, which has been converted with
+/// [`into_chain_element_expression`], to conditional expressions.
+///
+/// Also used by other plugins which need to rewrite a segment of the chain,
+/// e.g. `a?.#x` in `transform-class-properties`.
+pub(crate) fn transform_chain<'a>(
+    expr: Expression<'a>,
+    loose: bool,
+    var_declarations: &mut VarDeclarations<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    ChainLowering { loose, var_declarations }.transform_chain(expr, false, ctx)
+}
+
+struct ChainLowering<'a, 'v> {
+    loose: bool,
+    var_declarations: &'v mut VarDeclarations<'a>,
+}
+
+impl<'a, 'v> ChainLowering<'a, 'v> {
+    /// Short-circuits at the innermost optional segment, which is evaluated first.
+    /// The rest of the chain, including later optional segments, becomes the alternate.
+    ///
+    /// `a?.b.c?.d` -> `a == null ? void 0 : (_a$b$c = a.b.c) == null ? void 0 : _a$b$c.d`
+    fn transform_chain(
+        &mut self,
+        mut expr: Expression<'a>,
+        is_delete: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let Some(depth) = innermost_optional_depth(&expr) else {
+            return if is_delete {
+                ctx.ast.unary_expression(SPAN, UnaryOperator::Delete, expr)
+            } else {
+                expr
+            };
+        };
+
+        let mut segment = &mut expr;
+        for _ in 0..depth {
+            segment = chain_object_mut(segment).unwrap();
+        }
+
+        let test = match segment {
+            // `a.b?.()` -> `(_a$b = a.b) == null ? void 0 : _a$b.call(a)`
+            Expression::CallExpression(call) if call.callee.is_member_expression() => {
+                call.optional = false;
+                let mut callee = ctx.ast.move_expression(&mut call.callee);
+                let member = callee.as_member_expression_mut().unwrap();
+                let object = ctx.ast.move_expression(member.object_mut());
+                let (object, context) = self.memoize_context(object, ctx);
+                *member.object_mut() = object;
+
+                let (callee, function) = self.memoize_temp(callee, ctx);
+                let property = IdentifierName::new(SPAN, "call".into());
+                call.callee =
+                    ctx.ast.static_member_expression(SPAN, function.read(ctx), property, false);
+                call.arguments.insert(0, Argument::from(context.read(ctx)));
+                self.null_check(callee, &function, ctx)
+            }
+            Expression::CallExpression(call) => {
+                call.optional = false;
+                let callee = ctx.ast.move_expression(&mut call.callee);
+                let (callee, memo) = self.memoize(callee, ctx);
+                call.callee = memo.read(ctx);
+                self.null_check(callee, &memo, ctx)
+            }
+            match_member_expression!(Expression) => {
+                let member = segment.to_member_expression_mut();
+                set_optional(member, false);
+                let object = ctx.ast.move_expression(member.object_mut());
+                let (object, memo) = self.memoize(object, ctx);
+                *member.object_mut() = memo.read(ctx);
+                self.null_check(object, &memo, ctx)
+            }
+            _ => unreachable!(),
+        };
+
+        let short_circuit = if is_delete {
+            ctx.ast.literal_boolean_expression(BooleanLiteral::new(SPAN, true))
+        } else {
+            ctx.ast.void_0()
+        };
+        let rest = self.transform_chain(expr, is_delete, ctx);
+        ctx.ast.conditional_expression(SPAN, test, short_circuit, rest)
+    }
+
+    /// `first == null` or `first === null || memo === void 0`
+    fn null_check(
+        &self,
+        first: Expression<'a>,
+        memo: &Memo<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let ast = ctx.ast;
+        let null = ast.literal_null_expression(NullLiteral::new(SPAN));
+        if self.loose {
+            return ast.binary_expression(SPAN, first, BinaryOperator::Equality, null);
+        }
+        let left = ast.binary_expression(SPAN, first, BinaryOperator::StrictEquality, null);
+        let right = ast.binary_expression(
+            SPAN,
+            memo.read(ctx),
+            BinaryOperator::StrictEquality,
+            ast.void_0(),
+        );
+        ast.logical_expression(SPAN, left, LogicalOperator::Or, right)
+    }
+
+    /// Returns the expression to evaluate first, and a value to read it again.
+    /// `this` and bound identifiers are read again as is, other expressions are
+    /// assigned to a temporary variable.
+    fn memoize(
+        &mut self,
+        expr: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Expression<'a>, Memo<'a>) {
+        match &expr {
+            Expression::ThisExpression(_) => (expr, Memo::This),
+            Expression::Identifier(ident) => {
+                let symbol_id = ident
+                    .reference_id
+                    .get()
+                    .and_then(|reference_id| ctx.symbols().get_reference(reference_id).symbol_id());
+                match symbol_id {
+                    Some(symbol_id) => {
+                        let binding = BoundIdentifier { name: ident.name.clone(), symbol_id };
+                        (expr, Memo::Binding(binding))
+                    }
+                    // Globals can be getters
+                    None => self.memoize_temp(expr, ctx),
+                }
+            }
+            _ => self.memoize_temp(expr, ctx),
+        }
+    }
+
+    /// Same as `memoize`, but `super` is read again as `this`, for calling `super.a?.()`.
+    fn memoize_context(
+        &mut self,
+        expr: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Expression<'a>, Memo<'a>) {
+        if matches!(expr, Expression::Super(_)) {
+            (expr, Memo::This)
+        } else {
+            self.memoize(expr, ctx)
+        }
+    }
+
+    /// `_a = expr`, with `var _a` declared in the enclosing statement list
+    fn memoize_temp(
+        &mut self,
+        expr: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Expression<'a>, Memo<'a>) {
+        let binding = self.var_declarations.create_var(&temp_name(&expr), None, ctx);
+        let target =
+            ctx.ast.simple_assignment_target_identifier(binding.create_write_reference(ctx));
+        let assignment =
+            ctx.ast.assignment_expression(SPAN, AssignmentOperator::Assign, target, expr);
+        (assignment, Memo::Binding(binding))
+    }
+}
+
+pub(crate) fn into_chain_element_expression(expr: Expression) -> Expression {
+    let Expression::ChainExpression(chain) = expr else { return expr };
+    match chain.unbox().expression {
+        ChainElement::CallExpression(call) => Expression::CallExpression(call),
+        ChainElement::ComputedMemberExpression(member) => {
+            Expression::ComputedMemberExpression(member)
+        }
+        ChainElement::StaticMemberExpression(member) => Expression::StaticMemberExpression(member),
+        ChainElement::PrivateFieldExpression(member) => Expression::PrivateFieldExpression(member),
+    }
+}
+
+/// The number of segments between `expr` and the innermost optional segment of the chain
+fn innermost_optional_depth(expr: &Expression) -> Option<usize> {
+    let mut innermost = None;
+    let mut depth = 0;
+    let mut segment = expr;
+    loop {
+        let (object, optional) = match segment {
+            Expression::CallExpression(call) => (&call.callee, call.optional),
+            Expression::ComputedMemberExpression(member) => (&member.object, member.optional),
+            Expression::StaticMemberExpression(member) => (&member.object, member.optional),
+            Expression::PrivateFieldExpression(member) => (&member.object, member.optional),
+            // `a?.b!.c`
+            Expression::TSNonNullExpression(expr) => (&expr.expression, false),
+            _ => return innermost,
+        };
+        if optional {
+            innermost = Some(depth);
+        }
+        segment = object;
+        depth += 1;
+    }
+}
+
+fn chain_object_mut<'a, 'b>(expr: &'b mut Expression<'a>) -> Option<&'b mut Expression<'a>> {
+    match expr {
+        Expression::CallExpression(call) => Some(&mut call.callee),
+        Expression::ComputedMemberExpression(member) => Some(&mut member.object),
+        Expression::StaticMemberExpression(member) => Some(&mut member.object),
+        Expression::PrivateFieldExpression(member) => Some(&mut member.object),
+        Expression::TSNonNullExpression(expr) => Some(&mut expr.expression),
+        _ => None,
+    }
+}
+
+fn set_optional(member: &mut MemberExpression, optional: bool) {
+    match member {
+        MemberExpression::ComputedMemberExpression(member) => member.optional = optional,
+        MemberExpression::StaticMemberExpression(member) => member.optional = optional,
+        MemberExpression::PrivateFieldExpression(member) => member.optional = optional,
+    }
+}
+
+/// `a.b.c` -> `a$b$c`, like babel's `generateUidIdentifierBasedOnNode`
+fn temp_name(expr: &Expression) -> String {
+    match expr {
+        Expression::Identifier(ident) => ident.name.to_string(),
+        Expression::ThisExpression(_) => "this".to_string(),
+        Expression::Super(_) => "super".to_string(),
+        // An already memoized object, `(_a = a).b`
+        Expression::AssignmentExpression(assignment) => temp_name(&assignment.right),
+        Expression::StaticMemberExpression(member) => {
+            format!("{}${}", temp_name(&member.object), member.property.name)
+        }
+        Expression::CallExpression(call) => temp_name(&call.callee),
+        _ => "ref".to_string(),
+    }
+}
@@ -0,0 +1,21 @@
+use serde::Deserialize;
+
+use super::OptionalChainingOptions;
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct ES2020Options {
+    #[serde(skip)]
+    pub optional_chaining: Option<OptionalChainingOptions>,
+}
+
+impl ES2020Options {
+    #[must_use]
+    pub fn with_optional_chaining(
+        mut self,
+        optional_chaining: Option<OptionalChainingOptions>,
+    ) -> Self {
+        self.optional_chaining = optional_chaining;
+        self
+    }
+}
@@ -0,0 +1,1127 @@
+use std::rc::Rc;
+
+use oxc_allocator::{Box, Vec};
+use oxc_ast::{ast::*, visit::walk_mut, Visit, VisitMut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{Atom, CompactStr, Span, SPAN};
+use oxc_syntax::{
+    number::NumberBase,
+    operator::{
+        AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator, UpdateOperator,
+    },
+    reference::ReferenceFlag,
+    scope::{ScopeFlags, ScopeId},
+    symbol::{SymbolFlags, SymbolId},
+};
+use oxc_traverse::TraverseCtx;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Deserialize;
+
+use crate::{
+    context::Ctx,
+    es2020::{into_chain_element_expression, transform_chain},
+    helpers::{bindings::BoundIdentifier, var_declarations::VarDeclarations},
+};
+
+fn nested_super_call(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(
+        "Class fields can't be initialized, `super()` is not called at the top level of the constructor.",
+    )
+    .with_label(span)
+}
+
+fn super_assignment(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Assigning to a `super` property in a static field is not supported.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct ClassPropertiesOptions {
+    /// When `true`, public fields are initialized with assignments
+    /// instead of `Object.defineProperty`.
+    pub loose: bool,
+}
+
+/// [plugin-transform-class-properties](https://babel.dev/docs/babel-plugin-transform-class-properties)
+///
+/// This plugin transforms class fields for environments without native support:
+///
+/// * Public instance fields are initialized in the constructor, after `super()`.
+/// * Static fields are initialized after the class, with `this` replaced by the class and
+///   `super.x` by `Reflect.get(Object.getPrototypeOf(Class), "x", Class)`.
+/// * Each private field is stored in a `WeakMap`, keyed by the instance (or the class for
+///   static fields), and accesses to it are rewritten to the `WeakMap` methods.
+///
+/// Classes with decorators are not transformed, as the decorators need the fields.
+///
+/// This plugin is included in `preset-env`, in ES2022
+///
+/// ## Example
+///
+/// Input:
+/// ```js
+/// class Foo extends Bar {
+///   x = 1;
+///   #y = 2;
+///   static z = this.name;
+/// }
+/// ```
+///
+/// Output (with `loose`):
+/// ```js
+/// var _y = new WeakMap();
+/// class Foo extends Bar {
+///   constructor(..._args) {
+///     super(..._args);
+///     this.x = 1;
+///     _y.set(this, 2);
+///   }
+/// }
+/// Foo.z = Foo.name;
+/// ```
+///
+/// References:
+///
+/// * <https://babeljs.io/docs/babel-plugin-transform-class-properties>
+/// * <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-class-properties>
+pub struct ClassProperties<'a> {
+    ctx: Ctx<'a>,
+    options: ClassPropertiesOptions,
+    var_declarations: VarDeclarations<'a>,
+}
+
+/// Expressions to evaluate before and after the class definition
+#[derive(Default)]
+struct ClassInitializers<'a> {
+    before: std::vec::Vec<Expression<'a>>,
+    after: std::vec::Vec<Expression<'a>>,
+    /// Reference to the class, set when there are static fields
+    class_ref: Option<BoundIdentifier<'a>>,
+}
+
+enum FieldKey<'a> {
+    Identifier(Atom<'a>),
+    Expression(Expression<'a>),
+}
+
+impl<'a> ClassProperties<'a> {
+    pub fn new(options: ClassPropertiesOptions, ctx: Ctx<'a>) -> Self {
+        let var_declarations = VarDeclarations::new(Rc::clone(&ctx));
+        Self { ctx, options, var_declarations }
+    }
+
+    /// Transforms class declarations, static fields become statements after the class.
+    pub fn transform_statements(
+        &mut self,
+        stmts: &mut Vec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.var_declarations.enter_statements(ctx);
+
+        if !stmts.iter_mut().any(|stmt| class_declaration_mut(stmt).is_some_and(|c| has_fields(c)))
+        {
+            return;
+        }
+
+        let ast = self.ctx.ast;
+        let mut new_stmts = ast.new_vec_with_capacity(stmts.len());
+        for mut stmt in stmts.drain(..) {
+            let initializers = match class_declaration_mut(&mut stmt) {
+                Some(class) if has_fields(class) => self.transform_class(class, ctx),
+                _ => {
+                    new_stmts.push(stmt);
+                    continue;
+                }
+            };
+            let to_statement = |expr| ast.expression_statement(SPAN, expr);
+            new_stmts.extend(initializers.before.into_iter().map(to_statement));
+            new_stmts.push(stmt);
+            new_stmts.extend(initializers.after.into_iter().map(to_statement));
+        }
+        *stmts = new_stmts;
+    }
+
+    pub fn transform_statements_on_exit(
+        &mut self,
+        stmts: &mut Vec<'a, Statement<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        self.var_declarations.exit_statements(stmts, ctx);
+    }
+
+    /// Transforms class expressions, static fields are initialized in a sequence expression.
+    ///
+    /// `class { static x = 1 }` -> `(_Class = class {}, _Class.x = 1, _Class)`
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::ClassExpression(class) = expr else { return };
+        if !has_fields(class) {
+            return;
+        }
+        let ClassInitializers { before, after, class_ref } = self.transform_class(class, ctx);
+        if before.is_empty() && after.is_empty() {
+            return;
+        }
+
+        let ast = self.ctx.ast;
+        let class = ast.move_expression(expr);
+        let mut expressions = ast.new_vec_from_iter(before);
+        if let Some(class_ref) = class_ref {
+            let target =
+                ast.simple_assignment_target_identifier(class_ref.create_write_reference(ctx));
+            expressions.push(ast.assignment_expression(
+                SPAN,
+                AssignmentOperator::Assign,
+                target,
+                class,
+            ));
+            expressions.extend(after);
+            expressions
+                .push(ast.identifier_reference_expression(class_ref.create_read_reference(ctx)));
+        } else {
+            expressions.push(class);
+        }
+        *expr = ast.sequence_expression(SPAN, expressions);
+    }
+
+    fn transform_class(
+        &mut self,
+        class: &mut Class<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ClassInitializers<'a> {
+        let ast = self.ctx.ast;
+        let mut initializers = ClassInitializers::default();
+
+        // `#x` -> `var _x = new WeakMap();`
+        let mut private_names = FxHashMap::default();
+        for element in &class.body.body {
+            let ClassElement::PropertyDefinition(def) = element else { continue };
+            let PropertyKey::PrivateIdentifier(ident) = &def.key else { continue };
+            if is_transformable(def) {
+                let weak_map =
+                    ctx.create_unbound_reference_id(SPAN, "WeakMap".into(), ReferenceFlag::Read);
+                let callee = ast.identifier_reference_expression(weak_map);
+                let init = ast.new_expression(SPAN, callee, ast.new_vec(), None);
+                let binding = self.var_declarations.create_var(&ident.name, Some(init), ctx);
+                private_names.insert(ident.name.clone(), binding);
+            }
+        }
+
+        let has_static_fields = class.body.body.iter().any(
+            |element| matches!(element, ClassElement::PropertyDefinition(def) if def.r#static && is_transformable(def)),
+        );
+        let class_ref = has_static_fields.then(|| self.create_class_ref(class, ctx));
+
+        let mut instance_initializers = ast.new_vec();
+        let mut elements = ast.new_vec_with_capacity(class.body.body.len());
+        for element in class.body.body.drain(..) {
+            let def = match element {
+                ClassElement::PropertyDefinition(def) if is_transformable(&def) => def.unbox(),
+                element => {
+                    elements.push(element);
+                    continue;
+                }
+            };
+
+            let mut value = def.value.unwrap_or_else(|| ast.void_0());
+            let object = if let Some(class_ref) = class_ref.as_ref().filter(|_| def.r#static) {
+                ThisSuperReplacer { class_ref, transform_ctx: &self.ctx, ctx }
+                    .visit_expression(&mut value);
+                ast.identifier_reference_expression(class_ref.create_read_reference(ctx))
+            } else {
+                ast.this_expression(SPAN)
+            };
+
+            let init = if let PropertyKey::PrivateIdentifier(ident) = &def.key {
+                // `_x.set(this, value)`
+                let map = &private_names[&ident.name];
+                let map = ast.identifier_reference_expression(map.create_read_reference(ctx));
+                let property = IdentifierName::new(SPAN, "set".into());
+                let callee = ast.static_member_expression(SPAN, map, property, false);
+                let arguments =
+                    ast.new_vec_from_iter([Argument::from(object), Argument::from(value)]);
+                ast.call_expression(SPAN, callee, arguments, false, None)
+            } else {
+                let key = self.transform_key(def.key, def.computed, &mut initializers.before, ctx);
+                self.define_property(object, key, value, ctx)
+            };
+
+            if def.r#static {
+                initializers.after.push(init);
+            } else {
+                instance_initializers.push(ast.expression_statement(SPAN, init));
+            }
+        }
+        class.body.body = elements;
+
+        if !instance_initializers.is_empty() {
+            self.insert_instance_initializers(class, instance_initializers, ctx);
+        }
+
+        // `this.#x` -> `_x.get(this)`, in the class and in the static initializers
+        if !private_names.is_empty() {
+            let mut rewriter = PrivateFieldRewriter {
+                names: &private_names,
+                var_declarations: &mut self.var_declarations,
+                ctx,
+                scope_id: class.scope_id.get(),
+                shadowed: vec![],
+            };
+            rewriter.visit_class_body(&mut class.body);
+            for expr in &mut initializers.after {
+                rewriter.visit_expression(expr);
+            }
+        }
+
+        initializers.class_ref = class_ref;
+        initializers
+    }
+
+    fn create_class_ref(
+        &mut self,
+        class: &mut Class<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        if class.r#type == ClassType::ClassExpression {
+            let name = class.id.as_ref().map_or("Class", |id| id.name.as_str());
+            return self.var_declarations.create_var(name, None, ctx);
+        }
+        if let Some(id) = &class.id {
+            if let Some(symbol_id) = id.symbol_id.get() {
+                return BoundIdentifier { name: id.name.clone(), symbol_id };
+            }
+        }
+        // `export default class {}`
+        let binding =
+            BoundIdentifier::new_uid("Class", ctx.current_scope_id(), SymbolFlags::Class, ctx);
+        class.id = Some(binding.create_binding_identifier());
+        binding
+    }
+
+    /// Computed keys are evaluated once, when the class is defined.
+    fn transform_key(
+        &mut self,
+        key: PropertyKey<'a>,
+        computed: bool,
+        before: &mut std::vec::Vec<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> FieldKey<'a> {
+        let ast = self.ctx.ast;
+        let mut key = match key {
+            PropertyKey::StaticIdentifier(ident) => {
+                return FieldKey::Identifier(ident.name.clone())
+            }
+            PropertyKey::PrivateIdentifier(_) => unreachable!(),
+            key => key,
+        };
+        let expr = ast.move_expression(key.to_expression_mut());
+        if !computed || matches!(expr, Expression::StringLiteral(_) | Expression::NumericLiteral(_))
+        {
+            return FieldKey::Expression(expr);
+        }
+
+        // `[foo()] = 1` -> `_ref = foo()` before the class
+        let name = match &expr {
+            Expression::Identifier(ident) => ident.name.as_str(),
+            _ => "ref",
+        };
+        let binding = self.var_declarations.create_var(name, None, ctx);
+        let target = ast.simple_assignment_target_identifier(binding.create_write_reference(ctx));
+        before.push(ast.assignment_expression(SPAN, AssignmentOperator::Assign, target, expr));
+        FieldKey::Expression(
+            ast.identifier_reference_expression(binding.create_read_reference(ctx)),
+        )
+    }
+
+    /// `object.key = value` with `loose`, otherwise
+    /// `Object.defineProperty(object, "key", { value, enumerable: true, configurable: true, writable: true })`
+    fn define_property(
+        &self,
+        object: Expression<'a>,
+        key: FieldKey<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let ast = self.ctx.ast;
+
+        if self.options.loose {
+            let member = match key {
+                FieldKey::Identifier(name) => {
+                    ast.static_member(SPAN, object, IdentifierName::new(SPAN, name), false)
+                }
+                FieldKey::Expression(expr) => ast.computed_member(SPAN, object, expr, false),
+            };
+            let target = ast.simple_assignment_target_member_expression(member);
+            return ast.assignment_expression(SPAN, AssignmentOperator::Assign, target, value);
+        }
+
+        let key = match key {
+            FieldKey::Identifier(name) => {
+                ast.literal_string_expression(StringLiteral::new(SPAN, name))
+            }
+            FieldKey::Expression(expr) => expr,
+        };
+        let property = |name: &'static str, value: Expression<'a>| {
+            let key = ast.property_key_identifier(IdentifierName::new(SPAN, name.into()));
+            ObjectPropertyKind::ObjectProperty(ast.object_property(
+                SPAN,
+                PropertyKind::Init,
+                key,
+                value,
+                None,
+                false,
+                false,
+                false,
+            ))
+        };
+        let enabled = || ast.literal_boolean_expression(BooleanLiteral::new(SPAN, true));
+        let descriptor = ast.object_expression(
+            SPAN,
+            ast.new_vec_from_iter([
+                property("value", value),
+                property("enumerable", enabled()),
+                property("configurable", enabled()),
+                property("writable", enabled()),
+            ]),
+            None,
+        );
+
+        let object_ident =
+            ctx.create_unbound_reference_id(SPAN, "Object".into(), ReferenceFlag::Read);
+        let callee = ast.static_member_expression(
+            SPAN,
+            ast.identifier_reference_expression(object_ident),
+            IdentifierName::new(SPAN, "defineProperty".into()),
+            false,
+        );
+        let arguments = ast.new_vec_from_iter([
+            Argument::from(object),
+            Argument::from(key),
+            Argument::from(descriptor),
+        ]);
+        ast.call_expression(SPAN, callee, arguments, false, None)
+    }
+
+    /// Insert the initializers at the start of the constructor, or after `super()` in a
+    /// derived class. A constructor is created if the class doesn't have one.
+    fn insert_instance_initializers(
+        &self,
+        class: &mut Class<'a>,
+        initializers: Vec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let ast = self.ctx.ast;
+        let is_derived = class.super_class.is_some();
+
+        let constructor = class.body.body.iter_mut().find_map(|element| match element {
+            ClassElement::MethodDefinition(method)
+                if method.kind == MethodDefinitionKind::Constructor
+                    && method.value.body.is_some() =>
+            {
+                Some(&mut method.value)
+            }
+            _ => None,
+        });
+
+        if let Some(function) = constructor {
+            rename_shadowing_bindings(function, &initializers, ctx);
+            let body = function.body.as_mut().unwrap();
+            let index = if is_derived {
+                let super_call = body.statements.iter().position(|stmt| {
+                    matches!(stmt, Statement::ExpressionStatement(stmt) if stmt.expression.is_super_call_expression())
+                });
+                let Some(index) = super_call else {
+                    self.ctx.error(nested_super_call(body.span));
+                    return;
+                };
+                index + 1
+            } else {
+                0
+            };
+            for (i, stmt) in initializers.into_iter().enumerate() {
+                body.statements.insert(index + i, stmt);
+            }
+            return;
+        }
+
+        let scope_id = ctx.scopes_mut().add_scope(
+            class.scope_id.get(),
+            ScopeFlags::Function | ScopeFlags::Constructor | ScopeFlags::StrictMode,
+        );
+        let mut statements = ast.new_vec();
+        let rest = if is_derived {
+            // `constructor(..._args) { super(..._args); }`
+            let args = BoundIdentifier::new_uid(
+                "args",
+                scope_id,
+                SymbolFlags::FunctionScopedVariable,
+                ctx,
+            );
+            let spread = ast.spread_element(
+                SPAN,
+                ast.identifier_reference_expression(args.create_read_reference(ctx)),
+            );
+            let super_call = ast.call_expression(
+                SPAN,
+                ast.super_(SPAN),
+                ast.new_vec_single(Argument::SpreadElement(spread)),
+                false,
+                None,
+            );
+            statements.push(ast.expression_statement(SPAN, super_call));
+            let pattern = ast.binding_pattern(
+                ast.binding_pattern_identifier(args.create_binding_identifier()),
+                None,
+                false,
+            );
+            Some(ast.rest_element(SPAN, pattern))
+        } else {
+            None
+        };
+        statements.extend(initializers);
+
+        let params =
+            ast.formal_parameters(SPAN, FormalParameterKind::FormalParameter, ast.new_vec(), rest);
+        let body = ast.function_body(SPAN, ast.new_vec(), statements);
+        let function =
+            ast.plain_function(FunctionType::FunctionExpression, SPAN, None, params, Some(body));
+        function.scope_id.set(Some(scope_id));
+        class.body.body.insert(0, ast.class_constructor(SPAN, function));
+    }
+}
+
+/// Renames the constructor's own bindings which shadow a name used by the initializers, as the
+/// initializers are moved into the constructor.
+///
+/// `class { x = y; constructor(y) {} }` -> `class { constructor(_y) { this.x = y; } }`
+fn rename_shadowing_bindings<'a>(
+    function: &mut Function<'a>,
+    initializers: &[Statement<'a>],
+    ctx: &mut TraverseCtx<'a>,
+) {
+    let Some(scope_id) = function.scope_id.get() else { return };
+    let mut references = ReferenceNames::default();
+    for stmt in initializers {
+        references.visit_statement(stmt);
+    }
+
+    let mut renames = FxHashMap::default();
+    for name in references.names {
+        let Some(symbol_id) = ctx.scopes().get_binding(scope_id, &name) else { continue };
+        // The new symbol only reserves the name, the binding keeps its symbol and references.
+        let uid = ctx.generate_uid(&name, scope_id, ctx.symbols().get_flag(symbol_id));
+        let new_name = ctx.symbols().names[uid].clone();
+        ctx.scopes_mut().remove_binding(scope_id, &CompactStr::new(&name));
+        ctx.scopes_mut().add_binding(scope_id, new_name.clone(), symbol_id);
+        ctx.symbols_mut().set_name(symbol_id, new_name.clone());
+        renames.insert(symbol_id, ctx.ast.new_atom(&new_name));
+    }
+
+    if !renames.is_empty() {
+        BindingRenamer { renames: &renames, ctx }.visit_function(function, None);
+    }
+}
+
+fn class_declaration_mut<'b, 'a>(
+    stmt: &'b mut Statement<'a>,
+) -> Option<&'b mut Box<'a, Class<'a>>> {
+    match stmt {
+        Statement::ClassDeclaration(class) => Some(class),
+        Statement::ExportNamedDeclaration(decl) => match &mut decl.declaration {
+            Some(Declaration::ClassDeclaration(class)) => Some(class),
+            _ => None,
+        },
+        Statement::ExportDefaultDeclaration(decl) => match &mut decl.declaration {
+            ExportDefaultDeclarationKind::ClassDeclaration(class) => Some(class),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Whether the class has fields to transform, and no decorators.
+fn has_fields(class: &Class) -> bool {
+    if class.declare || !class.decorators.is_empty() {
+        return false;
+    }
+    let has_decorators = class.body.body.iter().any(|element| match element {
+        ClassElement::MethodDefinition(method) => !method.decorators.is_empty(),
+        ClassElement::PropertyDefinition(def) => !def.decorators.is_empty(),
+        ClassElement::AccessorProperty(accessor) => !accessor.decorators.is_empty(),
+        _ => false,
+    });
+    !has_decorators
+        && class.body.body.iter().any(
+            |element| matches!(element, ClassElement::PropertyDefinition(def) if is_transformable(def)),
+        )
+}
+
+/// `declare` and `abstract` fields are removed by the TypeScript transform.
+fn is_transformable(def: &PropertyDefinition) -> bool {
+    def.r#type == PropertyDefinitionType::PropertyDefinition && !def.declare
+}
+
+/// Names of the identifiers referenced by the instance initializers.
+#[derive(Default)]
+struct ReferenceNames<'a> {
+    names: FxHashSet<Atom<'a>>,
+}
+
+impl<'a> Visit<'a> for ReferenceNames<'a> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        self.names.insert(ident.name.clone());
+    }
+}
+
+/// Renames the bindings of the given symbols and their references.
+struct BindingRenamer<'a, 'c> {
+    renames: &'c FxHashMap<SymbolId, Atom<'a>>,
+    ctx: &'c TraverseCtx<'a>,
+}
+
+impl<'a, 'c> VisitMut<'a> for BindingRenamer<'a, 'c> {
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier<'a>) {
+        if let Some(name) = ident.symbol_id.get().and_then(|id| self.renames.get(&id)) {
+            ident.name = name.clone();
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let symbol_id = ident
+            .reference_id
+            .get()
+            .and_then(|id| self.ctx.symbols().get_reference(id).symbol_id());
+        if let Some(name) = symbol_id.and_then(|id| self.renames.get(&id)) {
+            ident.name = name.clone();
+        }
+    }
+}
+
+/// Replaces `this` and `super` in static field initializers, which are moved out of the class.
+struct ThisSuperReplacer<'a, 'c> {
+    class_ref: &'c BoundIdentifier<'a>,
+    transform_ctx: &'c Ctx<'a>,
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a, 'c> VisitMut<'a> for ThisSuperReplacer<'a, 'c> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::ThisExpression(this) => {
+                let ident = self.class_ref.create_spanned_read_reference(this.span, self.ctx);
+                *expr = self.ctx.ast.identifier_reference_expression(ident);
+                return;
+            }
+            // `super.x` -> `Reflect.get(Object.getPrototypeOf(_A), "x", _A)`
+            Expression::StaticMemberExpression(_) | Expression::ComputedMemberExpression(_)
+                if is_super_property(expr) =>
+            {
+                *expr = self.super_property(expr);
+                return;
+            }
+            // `super.x()` -> `Reflect.get(Object.getPrototypeOf(_A), "x", _A).call(_A)`
+            Expression::CallExpression(call) if is_super_property(&call.callee) => {
+                for argument in call.arguments.iter_mut() {
+                    self.visit_argument(argument);
+                }
+                let function = self.super_property(&mut call.callee);
+                let property = IdentifierName::new(SPAN, "call".into());
+                call.callee =
+                    self.ctx.ast.static_member_expression(SPAN, function, property, false);
+                let this = self.read_class_ref();
+                call.arguments.insert(0, Argument::from(this));
+                return;
+            }
+            // Only reached by assignments, `super.x = 1`
+            Expression::Super(super_) => {
+                self.transform_ctx.error(super_assignment(super_.span));
+                return;
+            }
+            _ => {}
+        }
+        walk_mut::walk_expression_mut(self, expr);
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: Option<ScopeFlags>) {}
+
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+}
+
+impl<'a, 'c> ThisSuperReplacer<'a, 'c> {
+    fn super_property(&mut self, expr: &mut Expression<'a>) -> Expression<'a> {
+        let ast = self.ctx.ast;
+        let key = match expr {
+            Expression::StaticMemberExpression(member) => ast
+                .literal_string_expression(StringLiteral::new(SPAN, member.property.name.clone())),
+            Expression::ComputedMemberExpression(member) => {
+                self.visit_expression(&mut member.expression);
+                ast.move_expression(&mut member.expression)
+            }
+            _ => unreachable!(),
+        };
+
+        let global = |name: &'static str, ctx: &mut TraverseCtx<'a>| {
+            let ident = ctx.create_unbound_reference_id(SPAN, name.into(), ReferenceFlag::Read);
+            ast.identifier_reference_expression(ident)
+        };
+        let get_prototype_of = ast.static_member_expression(
+            SPAN,
+            global("Object", self.ctx),
+            IdentifierName::new(SPAN, "getPrototypeOf".into()),
+            false,
+        );
+        let class_ref = self.read_class_ref();
+        let prototype = ast.call_expression(
+            SPAN,
+            get_prototype_of,
+            ast.new_vec_single(Argument::from(class_ref)),
+            false,
+            None,
+        );
+        let reflect_get = ast.static_member_expression(
+            SPAN,
+            global("Reflect", self.ctx),
+            IdentifierName::new(SPAN, "get".into()),
+            false,
+        );
+        let receiver = self.read_class_ref();
+        let arguments = ast.new_vec_from_iter([
+            Argument::from(prototype),
+            Argument::from(key),
+            Argument::from(receiver),
+        ]);
+        ast.call_expression(SPAN, reflect_get, arguments, false, None)
+    }
+
+    fn read_class_ref(&mut self) -> Expression<'a> {
+        let ident = self.class_ref.create_read_reference(self.ctx);
+        self.ctx.ast.identifier_reference_expression(ident)
+    }
+}
+
+fn is_super_property(expr: &Expression) -> bool {
+    match expr {
+        Expression::StaticMemberExpression(member) => matches!(member.object, Expression::Super(_)),
+        Expression::ComputedMemberExpression(member) => {
+            matches!(member.object, Expression::Super(_))
+        }
+        _ => false,
+    }
+}
+
+/// Rewrites accesses to private fields with their `WeakMap`.
+struct PrivateFieldRewriter<'a, 'c> {
+    names: &'c FxHashMap<Atom<'a>, BoundIdentifier<'a>>,
+    var_declarations: &'c mut VarDeclarations<'a>,
+    ctx: &'c mut TraverseCtx<'a>,
+    /// Parent scope of the setters created for destructuring targets
+    scope_id: Option<ScopeId>,
+    /// Private names declared by nested classes, which shadow `names`
+    shadowed: std::vec::Vec<FxHashSet<Atom<'a>>>,
+}
+
+impl<'a, 'c> VisitMut<'a> for PrivateFieldRewriter<'a, 'c> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        self.lower_chain(expr);
+        walk_mut::walk_expression_mut(self, expr);
+        if let Some(new_expr) = self.transform(expr, false) {
+            *expr = new_expr;
+        }
+    }
+
+    fn visit_expression_statement(&mut self, stmt: &mut ExpressionStatement<'a>) {
+        self.lower_chain(&mut stmt.expression);
+        walk_mut::walk_expression_mut(self, &mut stmt.expression);
+        if let Some(new_expr) = self.transform(&mut stmt.expression, true) {
+            stmt.expression = new_expr;
+        }
+    }
+
+    /// `obj.#m(...args)` -> `_m.get(_obj = obj).call(_obj, ...args)`
+    fn visit_call_expression(&mut self, call: &mut CallExpression<'a>) {
+        let Expression::PrivateFieldExpression(field) = &mut call.callee else {
+            walk_mut::walk_call_expression_mut(self, call);
+            return;
+        };
+        let Some(map) = self.lookup(&field.field.name) else {
+            walk_mut::walk_call_expression_mut(self, call);
+            return;
+        };
+        self.visit_expression(&mut field.object);
+        for argument in call.arguments.iter_mut() {
+            self.visit_argument(argument);
+        }
+
+        let ast = self.ctx.ast;
+        let (object, memo) = self.memoize(ast.move_expression(&mut field.object));
+        let function = self.call(&map, "get", [object]);
+        let property = IdentifierName::new(SPAN, "call".into());
+        call.callee = ast.static_member_expression(SPAN, function, property, false);
+        let this = self.read(memo.as_ref());
+        call.arguments.insert(0, Argument::from(this));
+    }
+
+    fn visit_assignment_expression(&mut self, expr: &mut AssignmentExpression<'a>) {
+        // Assigning to a private field is rewritten with the whole assignment, in `transform`
+        if let AssignmentTarget::PrivateFieldExpression(field) = &mut expr.left {
+            if self.lookup(&field.field.name).is_some() {
+                self.visit_expression(&mut field.object);
+                self.visit_expression(&mut expr.right);
+                return;
+            }
+        }
+        walk_mut::walk_assignment_expression_mut(self, expr);
+    }
+
+    /// Private fields in destructuring and `for-in` / `for-of` targets are assigned with a setter.
+    ///
+    /// `[obj.#x] = arr` -> `[(_obj = obj, { set value(_value) { _x.set(_obj, _value); } }).value] = arr`
+    fn visit_assignment_target(&mut self, target: &mut AssignmentTarget<'a>) {
+        walk_mut::walk_assignment_target_mut(self, target);
+        let AssignmentTarget::PrivateFieldExpression(field) = target else { return };
+        let Some(map) = self.lookup(&field.field.name) else { return };
+        let object = self.ctx.ast.move_expression(&mut field.object);
+        *target = self.setter_target(&map, object);
+    }
+
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        let names = class
+            .body
+            .body
+            .iter()
+            .filter_map(|element| match element {
+                ClassElement::MethodDefinition(method) => method.key.private_name(),
+                ClassElement::PropertyDefinition(def) => def.key.private_name(),
+                ClassElement::AccessorProperty(accessor) => accessor.key.private_name(),
+                _ => None,
+            })
+            .collect();
+        self.shadowed.push(names);
+        walk_mut::walk_class_mut(self, class);
+        self.shadowed.pop();
+    }
+}
+
+impl<'a, 'c> PrivateFieldRewriter<'a, 'c> {
+    /// `is_statement` is `true` when the value of `expr` is unused.
+    fn transform(
+        &mut self,
+        expr: &mut Expression<'a>,
+        is_statement: bool,
+    ) -> Option<Expression<'a>> {
+        let ast = self.ctx.ast;
+        match expr {
+            // `obj.#x` -> `_x.get(obj)`
+            Expression::PrivateFieldExpression(field) => {
+                let map = self.lookup(&field.field.name)?;
+                let object = ast.move_expression(&mut field.object);
+                Some(self.call(&map, "get", [object]))
+            }
+            // `#x in obj` -> `_x.has(obj)`
+            Expression::PrivateInExpression(expr) => {
+                let map = self.lookup(&expr.left.name)?;
+                let object = ast.move_expression(&mut expr.right);
+                Some(self.call(&map, "has", [object]))
+            }
+            // `obj.#x = value` -> `_x.set(obj, value)`
+            // `obj.#x += value` -> `_x.set(_obj = obj, _x.get(_obj) + value)`
+            // `obj.#x ||= value` -> `_x.get(_obj = obj) || _x.set(_obj, value)`
+            Expression::AssignmentExpression(assign) => {
+                let operator = assign.operator;
+                let AssignmentTarget::PrivateFieldExpression(field) = &mut assign.left else {
+                    return None;
+                };
+                let map = self.lookup(&field.field.name)?;
+                let object = ast.move_expression(&mut field.object);
+                let mut value = ast.move_expression(&mut assign.right);
+
+                if operator == AssignmentOperator::Assign && is_statement {
+                    return Some(self.call(&map, "set", [object, value]));
+                }
+                let (object, memo) = self.memoize(object);
+                if let Some(operator) = logical_operator(operator) {
+                    let current = self.call(&map, "get", [object]);
+                    let object = self.read(memo.as_ref());
+                    let mut set = self.call(&map, "set", [object, value]);
+                    if !is_statement {
+                        let object = self.read(memo.as_ref());
+                        set = self.method_call(set, "get", [object]);
+                    }
+                    return Some(ast.logical_expression(SPAN, current, operator, set));
+                }
+                if let Some(operator) = compound_operator(operator) {
+                    let object = self.read(memo.as_ref());
+                    let current = self.call(&map, "get", [object]);
+                    value = ast.binary_expression(SPAN, current, operator, value);
+                }
+                let set = self.call(&map, "set", [object, value]);
+                if is_statement {
+                    return Some(set);
+                }
+                // `WeakMap.prototype.set` returns the map, read the value back
+                let object = self.read(memo.as_ref());
+                Some(self.method_call(set, "get", [object]))
+            }
+            // `obj.#x++` -> `_x.set(_obj = obj, +_x.get(_obj) + 1)`
+            Expression::UpdateExpression(update) => {
+                let operator = match update.operator {
+                    UpdateOperator::Increment => BinaryOperator::Addition,
+                    UpdateOperator::Decrement => BinaryOperator::Subtraction,
+                };
+                let prefix = update.prefix;
+                let SimpleAssignmentTarget::PrivateFieldExpression(field) = &mut update.argument
+                else {
+                    return None;
+                };
+                let map = self.lookup(&field.field.name)?;
+                let object = ast.move_expression(&mut field.object);
+
+                let (object, memo) = self.memoize(object);
+                let current = {
+                    let object = self.read(memo.as_ref());
+                    let current = self.call(&map, "get", [object]);
+                    ast.unary_expression(SPAN, UnaryOperator::UnaryPlus, current)
+                };
+                let one = ast.literal_number_expression(ast.number_literal(
+                    SPAN,
+                    1.0,
+                    "1",
+                    NumberBase::Decimal,
+                ));
+
+                if is_statement || prefix {
+                    let value = ast.binary_expression(SPAN, current, operator, one);
+                    let set = self.call(&map, "set", [object, value]);
+                    if is_statement {
+                        return Some(set);
+                    }
+                    let object = self.read(memo.as_ref());
+                    return Some(self.method_call(set, "get", [object]));
+                }
+
+                // `(_x.set(_obj = obj, (_old = +_x.get(_obj)) + 1), _old)`
+                let old = self.var_declarations.create_var("old", None, self.ctx);
+                let target =
+                    ast.simple_assignment_target_identifier(old.create_write_reference(self.ctx));
+                let current =
+                    ast.assignment_expression(SPAN, AssignmentOperator::Assign, target, current);
+                let value = ast.binary_expression(SPAN, current, operator, one);
+                let set = self.call(&map, "set", [object, value]);
+                let old = ast.identifier_reference_expression(old.create_read_reference(self.ctx));
+                Some(ast.sequence_expression(SPAN, ast.new_vec_from_iter([set, old])))
+            }
+            _ => None,
+        }
+    }
+
+    /// Lowers an optional chain with a private field, as `obj?.#x` can't be rewritten in place.
+    ///
+    /// `obj?.#x` -> `obj === null || obj === void 0 ? void 0 : obj.#x`, then `_x.get(obj)`
+    fn lower_chain(&mut self, expr: &mut Expression<'a>) {
+        let Expression::ChainExpression(chain) = expr else { return };
+        if !self.has_private_field(&chain.expression) {
+            return;
+        }
+        let chain = self.ctx.ast.move_expression(expr);
+        *expr = transform_chain(
+            into_chain_element_expression(chain),
+            false,
+            self.var_declarations,
+            self.ctx,
+        );
+    }
+
+    fn has_private_field(&self, element: &ChainElement<'a>) -> bool {
+        let mut expr = match element {
+            ChainElement::PrivateFieldExpression(field) => {
+                if self.lookup(&field.field.name).is_some() {
+                    return true;
+                }
+                &field.object
+            }
+            ChainElement::CallExpression(call) => &call.callee,
+            ChainElement::ComputedMemberExpression(member) => &member.object,
+            ChainElement::StaticMemberExpression(member) => &member.object,
+        };
+        loop {
+            expr = match expr {
+                Expression::PrivateFieldExpression(field) => {
+                    if self.lookup(&field.field.name).is_some() {
+                        return true;
+                    }
+                    &field.object
+                }
+                Expression::CallExpression(call) => &call.callee,
+                Expression::ComputedMemberExpression(member) => &member.object,
+                Expression::StaticMemberExpression(member) => &member.object,
+                Expression::TSNonNullExpression(expr) => &expr.expression,
+                _ => return false,
+            };
+        }
+    }
+
+    /// `(_obj = obj, { set value(_value) { _x.set(_obj, _value); } }).value`
+    ///
+    /// The object is always memoized, `this` in the setter is the object literal.
+    fn setter_target(
+        &mut self,
+        map: &BoundIdentifier<'a>,
+        object: Expression<'a>,
+    ) -> AssignmentTarget<'a> {
+        let ast = self.ctx.ast;
+        let binding = self.var_declarations.create_var("obj", None, self.ctx);
+        let target =
+            ast.simple_assignment_target_identifier(binding.create_write_reference(self.ctx));
+        let object = ast.assignment_expression(SPAN, AssignmentOperator::Assign, target, object);
+
+        let scope_id = self.ctx.scopes_mut().add_scope(
+            self.scope_id,
+            ScopeFlags::Function | ScopeFlags::SetAccessor | ScopeFlags::StrictMode,
+        );
+        let value = BoundIdentifier::new_uid(
+            "value",
+            scope_id,
+            SymbolFlags::FunctionScopedVariable,
+            self.ctx,
+        );
+        let set = {
+            let object = self.read(Some(&binding));
+            let value = ast.identifier_reference_expression(value.create_read_reference(self.ctx));
+            self.call(map, "set", [object, value])
+        };
+        let pattern = ast.binding_pattern(
+            ast.binding_pattern_identifier(value.create_binding_identifier()),
+            None,
+            false,
+        );
+        let params = ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ast.new_vec_single(ast.plain_formal_parameter(SPAN, pattern)),
+            None,
+        );
+        let body = ast.function_body(
+            SPAN,
+            ast.new_vec(),
+            ast.new_vec_single(ast.expression_statement(SPAN, set)),
+        );
+        let setter =
+            ast.plain_function(FunctionType::FunctionExpression, SPAN, None, params, Some(body));
+        setter.scope_id.set(Some(scope_id));
+
+        let key = ast.property_key_identifier(IdentifierName::new(SPAN, "value".into()));
+        let property = ast.object_property(
+            SPAN,
+            PropertyKind::Set,
+            key,
+            ast.function_expression(setter),
+            None,
+            false,
+            false,
+            false,
+        );
+        let setter_object = ast.object_expression(
+            SPAN,
+            ast.new_vec_single(ObjectPropertyKind::ObjectProperty(property)),
+            None,
+        );
+        let sequence =
+            ast.sequence_expression(SPAN, ast.new_vec_from_iter([object, setter_object]));
+        let member = ast.static_member(
+            SPAN,
+            ast.parenthesized_expression(SPAN, sequence),
+            IdentifierName::new(SPAN, "value".into()),
+            false,
+        );
+        ast.simple_assignment_target_member_expression(member)
+    }
+
+    fn lookup(&self, name: &Atom<'a>) -> Option<BoundIdentifier<'a>> {
+        if self.shadowed.iter().any(|names| names.contains(name)) {
+            return None;
+        }
+        self.names.get(name).cloned()
+    }
+
+    /// `_x.method(...arguments)`
+    fn call<const N: usize>(
+        &mut self,
+        map: &BoundIdentifier<'a>,
+        method: &'static str,
+        arguments: [Expression<'a>; N],
+    ) -> Expression<'a> {
+        let map = self.ctx.ast.identifier_reference_expression(map.create_read_reference(self.ctx));
+        self.method_call(map, method, arguments)
+    }
+
+    fn method_call<const N: usize>(
+        &self,
+        object: Expression<'a>,
+        method: &'static str,
+        arguments: [Expression<'a>; N],
+    ) -> Expression<'a> {
+        let ast = self.ctx.ast;
+        let property = IdentifierName::new(SPAN, method.into());
+        let callee = ast.static_member_expression(SPAN, object, property, false);
+        let arguments = ast.new_vec_from_iter(arguments.into_iter().map(Argument::from));
+        ast.call_expression(SPAN, callee, arguments, false, None)
+    }
+
+    /// Returns the object to evaluate first, and the binding to read it again.
+    /// `this` is read again as is, and has no binding.
+    fn memoize(&mut self, object: Expression<'a>) -> (Expression<'a>, Option<BoundIdentifier<'a>>) {
+        if matches!(object, Expression::ThisExpression(_)) {
+            return (object, None);
+        }
+        let ast = self.ctx.ast;
+        let binding = self.var_declarations.create_var("obj", None, self.ctx);
+        let target =
+            ast.simple_assignment_target_identifier(binding.create_write_reference(self.ctx));
+        let assignment =
+            ast.assignment_expression(SPAN, AssignmentOperator::Assign, target, object);
+        (assignment, Some(binding))
+    }
+
+    fn read(&mut self, memo: Option<&BoundIdentifier<'a>>) -> Expression<'a> {
+        match memo {
+            Some(binding) => self
+                .ctx
+                .ast
+                .identifier_reference_expression(binding.create_read_reference(self.ctx)),
+            None => self.ctx.ast.this_expression(SPAN),
+        }
+    }
+}
+
+/// `||=` -> `||`
+fn logical_operator(operator: AssignmentOperator) -> Option<LogicalOperator> {
+    match operator {
+        AssignmentOperator::LogicalOr => Some(LogicalOperator::Or),
+        AssignmentOperator::LogicalAnd => Some(LogicalOperator::And),
+        AssignmentOperator::LogicalNullish => Some(LogicalOperator::Coalesce),
+        _ => None,
+    }
+}
+
+/// `+=` -> `+`
+fn compound_operator(operator: AssignmentOperator) -> Option<BinaryOperator> {
+    let operator = match operator {
+        AssignmentOperator::Addition => BinaryOperator::Addition,
+        AssignmentOperator::Subtraction => BinaryOperator::Subtraction,
+        AssignmentOperator::Multiplication => BinaryOperator::Multiplication,
+        AssignmentOperator::Division => BinaryOperator::Division,
+        AssignmentOperator::Remainder => BinaryOperator::Remainder,
+        AssignmentOperator::Exponential => BinaryOperator::Exponential,
+        AssignmentOperator::ShiftLeft => BinaryOperator::ShiftLeft,
+        AssignmentOperator::ShiftRight => BinaryOperator::ShiftRight,
+        AssignmentOperator::ShiftRightZeroFill => BinaryOperator::ShiftRightZeroFill,
+        AssignmentOperator::BitwiseOR => BinaryOperator::BitwiseOR,
+        AssignmentOperator::BitwiseXOR => BinaryOperator::BitwiseXOR,
+        AssignmentOperator::BitwiseAnd => BinaryOperator::BitwiseAnd,
+        AssignmentOperator::Assign
+        | AssignmentOperator::LogicalAnd
+        | AssignmentOperator::LogicalOr
+        | AssignmentOperator::LogicalNullish => return None,
+    };
+    Some(operator)
+}
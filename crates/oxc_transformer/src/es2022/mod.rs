@@ -0,0 +1,56 @@
+mod class_properties;
+mod options;
+
+use std::rc::Rc;
+
+pub use class_properties::{ClassProperties, ClassPropertiesOptions};
+pub use options::ES2022Options;
+use oxc_allocator::Vec;
+use oxc_ast::ast::*;
+use oxc_traverse::TraverseCtx;
+
+use crate::context::Ctx;
+
+#[allow(dead_code)]
+pub struct ES2022<'a> {
+    ctx: Ctx<'a>,
+    options: ES2022Options,
+
+    // Plugins
+    class_properties: ClassProperties<'a>,
+}
+
+impl<'a> ES2022<'a> {
+    pub fn new(options: ES2022Options, ctx: Ctx<'a>) -> Self {
+        Self {
+            class_properties: ClassProperties::new(
+                options.class_properties.clone().unwrap_or_default(),
+                Rc::clone(&ctx),
+            ),
+            ctx,
+            options,
+        }
+    }
+
+    pub fn enter_statements(
+        &mut self,
+        stmts: &mut Vec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.class_properties.is_some() {
+            self.class_properties.transform_statements(stmts, ctx);
+        }
+    }
+
+    pub fn exit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &TraverseCtx<'a>) {
+        if self.options.class_properties.is_some() {
+            self.class_properties.transform_statements_on_exit(stmts, ctx);
+        }
+    }
+
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.class_properties.is_some() {
+            self.class_properties.transform_expression(expr, ctx);
+        }
+    }
+}
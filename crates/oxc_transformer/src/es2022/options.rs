@@ -0,0 +1,21 @@
+use serde::Deserialize;
+
+use super::ClassPropertiesOptions;
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct ES2022Options {
+    #[serde(skip)]
+    pub class_properties: Option<ClassPropertiesOptions>,
+}
+
+impl ES2022Options {
+    #[must_use]
+    pub fn with_class_properties(
+        mut self,
+        class_properties: Option<ClassPropertiesOptions>,
+    ) -> Self {
+        self.class_properties = class_properties;
+        self
+    }
+}
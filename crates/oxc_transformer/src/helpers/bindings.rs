@@ -51,6 +51,12 @@ impl<'a> BoundIdentifier<'a> {
         ctx.create_bound_reference_id(span, self.name.clone(), self.symbol_id, ReferenceFlag::Read)
     }
 
+    /// Create `IdentifierReference` referencing this binding which is written to
+    /// in current scope
+    pub fn create_write_reference(&self, ctx: &mut TraverseCtx<'a>) -> IdentifierReference<'a> {
+        ctx.create_bound_reference_id(SPAN, self.name.clone(), self.symbol_id, ReferenceFlag::Write)
+    }
+
     /// Create `BindingIdentifier` for this binding
     pub fn create_binding_identifier(&self) -> BindingIdentifier<'a> {
         BindingIdentifier {
@@ -0,0 +1,71 @@
+use oxc_allocator::Vec;
+use oxc_ast::ast::*;
+use oxc_span::SPAN;
+use oxc_syntax::symbol::SymbolFlags;
+use oxc_traverse::{Ancestor, TraverseCtx};
+
+use crate::{context::Ctx, helpers::bindings::BoundIdentifier};
+
+/// Store for temporary variables, which are declared with `var` at the top of
+/// the statement list enclosing the expression that created them.
+pub struct VarDeclarations<'a> {
+    ctx: Ctx<'a>,
+    stack: std::vec::Vec<Vec<'a, VariableDeclarator<'a>>>,
+}
+
+impl<'a> VarDeclarations<'a> {
+    pub fn new(ctx: Ctx<'a>) -> Self {
+        Self { ctx, stack: vec![] }
+    }
+
+    pub fn enter_statements(&mut self, ctx: &TraverseCtx<'a>) {
+        // The body of `() => expr` can't hold a declaration, use the enclosing statement list
+        if !is_arrow_function_expression_body(ctx) {
+            self.stack.push(self.ctx.ast.new_vec());
+        }
+    }
+
+    /// Insert `var _a, _b = init;`
+    pub fn exit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &TraverseCtx<'a>) {
+        if is_arrow_function_expression_body(ctx) {
+            return;
+        }
+        let Some(declarations) = self.stack.pop() else { return };
+        if declarations.is_empty() {
+            return;
+        }
+        let kind = VariableDeclarationKind::Var;
+        let decl = self.ctx.ast.variable_declaration(SPAN, kind, declarations, false);
+        stmts.insert(0, Statement::VariableDeclaration(decl));
+    }
+
+    /// Create a new binding in the current scope, and declare it.
+    pub fn create_var(
+        &mut self,
+        name: &str,
+        init: Option<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        let binding = BoundIdentifier::new_uid(
+            name,
+            ctx.current_scope_id(),
+            SymbolFlags::FunctionScopedVariable,
+            ctx,
+        );
+        let ast = self.ctx.ast;
+        let id = ast.binding_pattern(
+            ast.binding_pattern_identifier(binding.create_binding_identifier()),
+            None,
+            false,
+        );
+        let declarator =
+            ast.variable_declarator(SPAN, VariableDeclarationKind::Var, id, init, false);
+        self.stack.last_mut().unwrap().push(declarator);
+        binding
+    }
+}
+
+fn is_arrow_function_expression_body(ctx: &TraverseCtx) -> bool {
+    matches!(ctx.parent(), Ancestor::FunctionBodyStatements(_))
+        && matches!(ctx.ancestor(2), Some(Ancestor::ArrowFunctionExpressionBody(arrow)) if *arrow.expression())
+}
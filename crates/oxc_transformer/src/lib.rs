@@ -15,12 +15,17 @@ mod options;
 // Presets: <https://babel.dev/docs/presets>
 mod env;
 mod es2015;
+mod es2020;
+mod es2022;
+// Proposals: <https://babel.dev/docs/plugins-list#proposals>
+mod proposals;
 mod react;
 mod typescript;
 
 mod helpers {
     pub mod bindings;
     pub mod module_imports;
+    pub mod var_declarations;
 }
 
 use std::{path::Path, rc::Rc};
@@ -36,13 +41,19 @@ pub use crate::{
     compiler_assumptions::CompilerAssumptions,
     env::EnvOptions,
     es2015::{ArrowFunctionsOptions, ES2015Options},
+    es2020::{ES2020Options, OptionalChainingOptions},
+    es2022::{ClassPropertiesOptions, ES2022Options},
     options::{BabelOptions, TransformOptions},
-    react::{ReactJsxRuntime, ReactOptions},
+    proposals::{PipelineOperatorOptions, PipelineProposal, PipelineTopicToken, ProposalOptions},
+    react::{ReactJsxRuntime, ReactOptions, ReactRefreshOptions},
     typescript::TypeScriptOptions,
 };
 use crate::{
     context::{Ctx, TransformCtx},
     es2015::ES2015,
+    es2020::ES2020,
+    es2022::ES2022,
+    proposals::Proposals,
     react::React,
     typescript::TypeScript,
 };
@@ -58,6 +69,9 @@ pub struct Transformer<'a> {
     // NOTE: all callbacks must run in order.
     x0_typescript: TypeScript<'a>,
     x1_react: React<'a>,
+    x2_proposals: Proposals<'a>,
+    x2_es2022: ES2022<'a>,
+    x2_es2020: ES2020<'a>,
     x3_es2015: ES2015<'a>,
 }
 
@@ -82,6 +96,9 @@ impl<'a> Transformer<'a> {
             ctx: Rc::clone(&ctx),
             x0_typescript: TypeScript::new(options.typescript, Rc::clone(&ctx)),
             x1_react: React::new(options.react, Rc::clone(&ctx)),
+            x2_proposals: Proposals::new(options.proposals, Rc::clone(&ctx)),
+            x2_es2022: ES2022::new(options.es2022, Rc::clone(&ctx)),
+            x2_es2020: ES2020::new(options.es2020, Rc::clone(&ctx)),
             x3_es2015: ES2015::new(options.es2015, ctx),
         }
     }
@@ -126,6 +143,15 @@ impl<'a> Traverse<'a> for Transformer<'a> {
         _ctx: &mut TraverseCtx<'a>,
     ) {
         self.x0_typescript.transform_arrow_expression(expr);
+        self.x1_react.transform_arrow_expression();
+    }
+
+    fn exit_arrow_function_expression(
+        &mut self,
+        expr: &mut ArrowFunctionExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x1_react.transform_arrow_expression_on_exit(expr);
     }
 
     fn enter_binding_pattern(&mut self, pat: &mut BindingPattern<'a>, _ctx: &mut TraverseCtx<'a>) {
@@ -175,12 +201,16 @@ impl<'a> Traverse<'a> for Transformer<'a> {
     }
 
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
-        self.x0_typescript.transform_expression(expr);
+        self.x0_typescript.transform_expression(expr, ctx);
         self.x1_react.transform_expression(expr, ctx);
+        self.x2_es2022.transform_expression(expr, ctx);
+        self.x2_es2020.transform_expression(expr, ctx);
         self.x3_es2015.transform_expression(expr);
     }
 
     fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x1_react.transform_expression_on_exit(expr, ctx);
+        self.x2_proposals.transform_expression_on_exit(expr, ctx);
         self.x3_es2015.transform_expression_on_exit(expr, ctx);
     }
 
@@ -210,6 +240,11 @@ impl<'a> Traverse<'a> for Transformer<'a> {
 
     fn enter_function(&mut self, func: &mut Function<'a>, _ctx: &mut TraverseCtx<'a>) {
         self.x0_typescript.transform_function(func);
+        self.x1_react.transform_function();
+    }
+
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x1_react.transform_function_on_exit(func, ctx);
     }
 
     fn enter_jsx_element(&mut self, node: &mut JSXElement<'a>, _ctx: &mut TraverseCtx<'a>) {
@@ -261,13 +296,21 @@ impl<'a> Traverse<'a> for Transformer<'a> {
         self.x0_typescript.transform_property_definition(def);
     }
 
-    fn enter_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, _ctx: &mut TraverseCtx<'a>) {
+    fn enter_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
         self.x0_typescript.transform_statements(stmts);
+        self.x1_react.transform_statements(stmts, ctx);
+        self.x2_proposals.enter_statements(stmts, ctx);
+        self.x2_es2022.enter_statements(stmts, ctx);
+        self.x2_es2020.enter_statements(stmts, ctx);
         self.x3_es2015.enter_statements(stmts);
     }
 
     fn exit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
         self.x0_typescript.transform_statements_on_exit(stmts, ctx);
+        self.x1_react.transform_statements_on_exit(stmts, ctx);
+        self.x2_proposals.exit_statements(stmts, ctx);
+        self.x2_es2022.exit_statements(stmts, ctx);
+        self.x2_es2020.exit_statements(stmts, ctx);
         self.x3_es2015.exit_statements(stmts);
     }
 
@@ -7,8 +7,11 @@ use crate::{
     compiler_assumptions::CompilerAssumptions,
     env::{can_enable_plugin, EnvOptions, Versions},
     es2015::{ArrowFunctionsOptions, ES2015Options},
+    es2020::{ES2020Options, OptionalChainingOptions},
+    es2022::{ClassPropertiesOptions, ES2022Options},
     options::babel::BabelOptions,
-    react::ReactOptions,
+    proposals::{PipelineOperatorOptions, ProposalOptions},
+    react::{ReactOptions, ReactRefreshOptions},
     typescript::TypeScriptOptions,
 };
 
@@ -34,6 +37,13 @@ pub struct TransformOptions {
     pub react: ReactOptions,
 
     pub es2015: ES2015Options,
+
+    pub es2020: ES2020Options,
+
+    pub es2022: ES2022Options,
+
+    /// Proposals, which are only enabled by adding their plugin
+    pub proposals: ProposalOptions,
 }
 
 impl TransformOptions {
@@ -60,7 +70,7 @@ impl TransformOptions {
         };
 
         let preset_name = "react";
-        let react = if options.has_preset(preset_name) {
+        let mut react = if options.has_preset(preset_name) {
             from_value::<ReactOptions>(get_preset_options(preset_name, options)).unwrap_or_else(
                 |err| {
                     report_error(preset_name, &err, true, &mut errors);
@@ -93,6 +103,16 @@ impl TransformOptions {
             react_options.jsx_source_plugin = options.has_plugin("transform-react-jsx-source");
             react_options
         };
+        react.refresh = {
+            let plugin_name = "react-refresh";
+            options.has_plugin(plugin_name).then(|| {
+                from_value::<ReactRefreshOptions>(get_plugin_options(plugin_name, options))
+                    .unwrap_or_else(|err| {
+                        report_error(plugin_name, &err, false, &mut errors);
+                        ReactRefreshOptions::default()
+                    })
+            })
+        };
 
         let es2015 = ES2015Options::default().with_arrow_function({
             let plugin_name = "transform-arrow-functions";
@@ -104,6 +124,37 @@ impl TransformOptions {
             })
         });
 
+        let es2020 = ES2020Options::default().with_optional_chaining({
+            let plugin_name = "transform-optional-chaining";
+            enable_plugin(plugin_name, options, &env_options, &targets).map(|options| {
+                from_value::<OptionalChainingOptions>(options).unwrap_or_else(|err| {
+                    report_error(plugin_name, &err, false, &mut errors);
+                    OptionalChainingOptions::default()
+                })
+            })
+        });
+
+        let es2022 = ES2022Options::default().with_class_properties({
+            let plugin_name = "transform-class-properties";
+            enable_plugin(plugin_name, options, &env_options, &targets).map(|options| {
+                from_value::<ClassPropertiesOptions>(options).unwrap_or_else(|err| {
+                    report_error(plugin_name, &err, false, &mut errors);
+                    ClassPropertiesOptions::default()
+                })
+            })
+        });
+
+        let proposals = ProposalOptions::default().with_pipeline_operator({
+            let plugin_name = "proposal-pipeline-operator";
+            options.has_plugin(plugin_name).then(|| {
+                from_value::<PipelineOperatorOptions>(get_plugin_options(plugin_name, options))
+                    .unwrap_or_else(|err| {
+                        report_error(plugin_name, &err, false, &mut errors);
+                        PipelineOperatorOptions::default()
+                    })
+            })
+        });
+
         let typescript = {
             let plugin_name = "transform-typescript";
             from_value::<TypeScriptOptions>(get_plugin_options(plugin_name, options))
@@ -135,6 +186,9 @@ impl TransformOptions {
             typescript,
             react,
             es2015,
+            es2020,
+            es2022,
+            proposals,
         })
     }
 }
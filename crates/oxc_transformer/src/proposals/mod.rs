@@ -0,0 +1,59 @@
+mod options;
+mod pipeline_operator;
+
+use std::rc::Rc;
+
+pub use options::ProposalOptions;
+use oxc_allocator::Vec;
+use oxc_ast::ast::*;
+use oxc_traverse::TraverseCtx;
+pub use pipeline_operator::{
+    PipelineOperator, PipelineOperatorOptions, PipelineProposal, PipelineTopicToken,
+};
+
+use crate::context::Ctx;
+
+/// Stage 2 and 3 proposals, which are only transformed when their plugin is enabled explicitly.
+#[allow(dead_code)]
+pub struct Proposals<'a> {
+    ctx: Ctx<'a>,
+    options: ProposalOptions,
+
+    // Plugins
+    pipeline_operator: PipelineOperator<'a>,
+}
+
+impl<'a> Proposals<'a> {
+    pub fn new(options: ProposalOptions, ctx: Ctx<'a>) -> Self {
+        Self {
+            pipeline_operator: PipelineOperator::new(
+                options.pipeline_operator.clone().unwrap_or_default(),
+                Rc::clone(&ctx),
+            ),
+            ctx,
+            options,
+        }
+    }
+
+    pub fn enter_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &TraverseCtx<'a>) {
+        if self.options.pipeline_operator.is_some() {
+            self.pipeline_operator.transform_statements(stmts, ctx);
+        }
+    }
+
+    pub fn exit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &TraverseCtx<'a>) {
+        if self.options.pipeline_operator.is_some() {
+            self.pipeline_operator.transform_statements_on_exit(stmts, ctx);
+        }
+    }
+
+    pub fn transform_expression_on_exit(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.pipeline_operator.is_some() {
+            self.pipeline_operator.transform_expression_on_exit(expr, ctx);
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use serde::Deserialize;
+
+use super::PipelineOperatorOptions;
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct ProposalOptions {
+    #[serde(skip)]
+    pub pipeline_operator: Option<PipelineOperatorOptions>,
+}
+
+impl ProposalOptions {
+    #[must_use]
+    pub fn with_pipeline_operator(
+        mut self,
+        pipeline_operator: Option<PipelineOperatorOptions>,
+    ) -> Self {
+        self.pipeline_operator = pipeline_operator;
+        self
+    }
+}
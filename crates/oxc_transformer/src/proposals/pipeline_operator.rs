@@ -0,0 +1,246 @@
+use std::rc::Rc;
+
+use oxc_allocator::Vec;
+use oxc_ast::{
+    ast::*,
+    visit::{walk, walk_mut},
+    Visit, VisitMut,
+};
+use oxc_span::SPAN;
+use oxc_syntax::{
+    operator::{AssignmentOperator, BinaryOperator},
+    scope::ScopeFlags,
+};
+use oxc_traverse::TraverseCtx;
+use serde::Deserialize;
+
+use crate::{
+    context::Ctx,
+    helpers::{bindings::BoundIdentifier, var_declarations::VarDeclarations},
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PipelineProposal {
+    /// <https://github.com/tc39/proposal-pipeline-operator>
+    #[default]
+    Hack,
+}
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+pub enum PipelineTopicToken {
+    #[default]
+    #[serde(rename = "%")]
+    Percent,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct PipelineOperatorOptions {
+    /// Only the Hack-style proposal is supported.
+    pub proposal: PipelineProposal,
+    /// Only `%` is supported.
+    pub topic_token: PipelineTopicToken,
+}
+
+/// [plugin-proposal-pipeline-operator](https://babel.dev/docs/babel-plugin-proposal-pipeline-operator)
+///
+/// This plugin transforms Hack-style pipelines, parsed with `Parser::enable_pipeline_operator`.
+///
+/// The left hand side is substituted for the topic reference `%` when it is referenced once,
+/// and either can't be changed by the pipe body, or is evaluated before anything else in it.
+/// Otherwise it is stored in a temporary variable.
+///
+/// ## Example
+///
+/// Input:
+/// ```js
+/// const a = 1;
+/// a |> f(%);
+/// b |> % + %;
+/// ```
+///
+/// Output:
+/// ```js
+/// var _ref;
+/// const a = 1;
+/// f(a);
+/// _ref = b, _ref + _ref;
+/// ```
+///
+/// References:
+///
+/// * <https://babeljs.io/docs/babel-plugin-proposal-pipeline-operator>
+/// * <https://github.com/babel/babel/tree/main/packages/babel-plugin-proposal-pipeline-operator>
+pub struct PipelineOperator<'a> {
+    ctx: Ctx<'a>,
+    _options: PipelineOperatorOptions,
+    var_declarations: VarDeclarations<'a>,
+}
+
+impl<'a> PipelineOperator<'a> {
+    pub fn new(options: PipelineOperatorOptions, ctx: Ctx<'a>) -> Self {
+        let var_declarations = VarDeclarations::new(Rc::clone(&ctx));
+        Self { ctx, _options: options, var_declarations }
+    }
+
+    pub fn transform_statements(
+        &mut self,
+        _stmts: &mut Vec<'a, Statement<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        self.var_declarations.enter_statements(ctx);
+    }
+
+    pub fn transform_statements_on_exit(
+        &mut self,
+        stmts: &mut Vec<'a, Statement<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        self.var_declarations.exit_statements(stmts, ctx);
+    }
+
+    /// Transforms `a |> f(%)`.
+    ///
+    /// Pipelines are transformed on exit, so nested pipelines in the body have already been
+    /// transformed, and all remaining topic references in the body refer to this pipeline.
+    pub fn transform_expression_on_exit(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::BinaryExpression(binary) = expr else { return };
+        if binary.operator != BinaryOperator::Pipeline {
+            return;
+        }
+        let ast = self.ctx.ast;
+        let topic = ast.move_expression(&mut binary.left);
+        let mut body = ast.move_expression(&mut binary.right);
+
+        let mut counter = TopicCounter::default();
+        counter.visit_expression(&body);
+        if counter.count == 1
+            && !counter.deferred
+            && (is_constant_expression(&topic, ctx) || is_topic_evaluated_first(&body, ctx))
+        {
+            TopicReplacer { topic: Topic::Expression(Some(topic)), ctx }
+                .visit_expression(&mut body);
+            *expr = body;
+            return;
+        }
+
+        let binding = self.var_declarations.create_var("ref", None, ctx);
+        let target = ast.simple_assignment_target_identifier(binding.create_write_reference(ctx));
+        let assignment = ast.assignment_expression(SPAN, AssignmentOperator::Assign, target, topic);
+        TopicReplacer { topic: Topic::Binding(binding), ctx }.visit_expression(&mut body);
+        *expr = ast.sequence_expression(SPAN, ast.new_vec_from_iter([assignment, body]));
+    }
+}
+
+/// Literals, `this` and identifiers which are never assigned to can be moved into the pipe body,
+/// without reordering any side effects. `a |> (a = 1, f(%))` must read `a` before assigning it.
+fn is_constant_expression(expr: &Expression, ctx: &TraverseCtx) -> bool {
+    match expr {
+        Expression::ThisExpression(_) => true,
+        Expression::Identifier(ident) => {
+            let symbols = ctx.symbols();
+            ident
+                .reference_id
+                .get()
+                .and_then(|reference_id| symbols.get_reference(reference_id).symbol_id())
+                .is_some_and(|symbol_id| {
+                    symbols
+                        .get_resolved_references(symbol_id)
+                        .all(|reference| !reference.is_write())
+                })
+        }
+        _ => expr.is_literal(),
+    }
+}
+
+/// `f(%)`, `f(x, %)` or `new C(%)`, where everything evaluated before the topic is constant.
+/// `g(f(%))` from the chained pipeline `a |> f(%) |> g(%)` is also accepted.
+fn is_topic_evaluated_first(body: &Expression, ctx: &TraverseCtx) -> bool {
+    let (callee, arguments) = match body {
+        Expression::CallExpression(call) if !call.optional => (&call.callee, &call.arguments),
+        Expression::NewExpression(new) => (&new.callee, &new.arguments),
+        _ => return false,
+    };
+    if !is_constant_expression(callee, ctx) {
+        return false;
+    }
+    for argument in arguments {
+        let Some(expr) = argument.as_expression() else { return false };
+        if matches!(expr, Expression::PipelinePlaceholder(_)) {
+            return true;
+        }
+        if !is_constant_expression(expr, ctx) {
+            return is_topic_evaluated_first(expr, ctx);
+        }
+    }
+    false
+}
+
+/// Counts topic references in a pipe body.
+#[derive(Default)]
+struct TopicCounter {
+    count: usize,
+    /// A topic reference is inside a function or class, and may be evaluated later or repeatedly
+    deferred: bool,
+}
+
+impl TopicCounter {
+    fn visit_deferred(&mut self, visit: impl FnOnce(&mut Self)) {
+        let count = self.count;
+        visit(self);
+        if self.count != count {
+            self.deferred = true;
+        }
+    }
+}
+
+impl<'a> Visit<'a> for TopicCounter {
+    fn visit_pipeline_placeholder(&mut self, _expr: &PipelinePlaceholder) {
+        self.count += 1;
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, flags: Option<ScopeFlags>) {
+        self.visit_deferred(|counter| walk::walk_function(counter, func, flags));
+    }
+
+    fn visit_arrow_expression(&mut self, expr: &ArrowFunctionExpression<'a>) {
+        self.visit_deferred(|counter| walk::walk_arrow_expression(counter, expr));
+    }
+
+    fn visit_class(&mut self, class: &Class<'a>) {
+        self.visit_deferred(|counter| walk::walk_class(counter, class));
+    }
+}
+
+enum Topic<'a> {
+    /// Substituted for the only topic reference
+    Expression(Option<Expression<'a>>),
+    Binding(BoundIdentifier<'a>),
+}
+
+/// Replaces topic references in a pipe body.
+struct TopicReplacer<'a, 'c> {
+    topic: Topic<'a>,
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a, 'c> VisitMut<'a> for TopicReplacer<'a, 'c> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::PipelinePlaceholder(placeholder) = expr {
+            *expr = match &mut self.topic {
+                Topic::Expression(topic) => topic.take().unwrap(),
+                Topic::Binding(binding) => {
+                    let ident = binding.create_spanned_read_reference(placeholder.span, self.ctx);
+                    self.ctx.ast.identifier_reference_expression(ident)
+                }
+            };
+            return;
+        }
+        walk_mut::walk_expression_mut(self, expr);
+    }
+}
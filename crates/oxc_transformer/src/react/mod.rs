@@ -4,10 +4,12 @@ mod jsx;
 mod jsx_self;
 mod jsx_source;
 mod options;
+mod refresh;
 mod utils;
 
 use std::rc::Rc;
 
+use oxc_allocator::Vec;
 use oxc_ast::ast::*;
 use oxc_traverse::TraverseCtx;
 
@@ -15,6 +17,7 @@ pub use self::{
     display_name::ReactDisplayName,
     jsx::ReactJsx,
     options::{ReactJsxRuntime, ReactOptions},
+    refresh::{ReactRefresh, ReactRefreshOptions},
 };
 use crate::context::Ctx;
 
@@ -26,13 +29,17 @@ use crate::context::Ctx;
 /// * [plugin-transform-react-jsx-self](https://babeljs.io/docs/babel-plugin-transform-react-jsx-self)
 /// * [plugin-transform-react-jsx-source](https://babel.dev/docs/babel-plugin-transform-react-jsx-source)
 /// * [plugin-transform-react-display-name](https://babeljs.io/docs/babel-plugin-transform-react-display-name)
+///
+/// and [react-refresh/babel](https://github.com/facebook/react/tree/main/packages/react-refresh) for Fast Refresh.
 pub struct React<'a> {
     jsx: ReactJsx<'a>,
     display_name: ReactDisplayName<'a>,
+    refresh: ReactRefresh<'a>,
     jsx_plugin: bool,
     display_name_plugin: bool,
     jsx_self_plugin: bool,
     jsx_source_plugin: bool,
+    refresh_plugin: bool,
 }
 
 // Constructors
@@ -49,13 +56,17 @@ impl<'a> React<'a> {
             jsx_source_plugin,
             ..
         } = options;
+        let refresh_plugin = options.refresh.is_some();
+        let refresh_options = options.refresh.clone().unwrap_or_default();
         Self {
             jsx: ReactJsx::new(options, Rc::clone(&ctx)),
-            display_name: ReactDisplayName::new(ctx),
+            display_name: ReactDisplayName::new(Rc::clone(&ctx)),
+            refresh: ReactRefresh::new(refresh_options, ctx),
             jsx_plugin,
             display_name_plugin,
             jsx_self_plugin,
             jsx_source_plugin,
+            refresh_plugin,
         }
     }
 }
@@ -82,14 +93,71 @@ impl<'a> React<'a> {
         }
     }
 
+    pub fn transform_expression_on_exit(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.refresh_plugin {
+            self.refresh.transform_expression_on_exit(expr, ctx);
+        }
+    }
+
+    pub fn transform_statements(
+        &mut self,
+        stmts: &mut Vec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.refresh_plugin {
+            self.refresh.transform_statements(stmts, ctx);
+        }
+    }
+
+    pub fn transform_statements_on_exit(
+        &mut self,
+        stmts: &mut Vec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.refresh_plugin {
+            self.refresh.transform_statements_on_exit(stmts, ctx);
+        }
+    }
+
+    pub fn transform_function(&mut self) {
+        if self.refresh_plugin {
+            self.refresh.transform_function();
+        }
+    }
+
+    pub fn transform_function_on_exit(&mut self, func: &Function<'a>, ctx: &TraverseCtx<'a>) {
+        if self.refresh_plugin {
+            self.refresh.transform_function_on_exit(func, ctx);
+        }
+    }
+
+    pub fn transform_arrow_expression(&mut self) {
+        if self.refresh_plugin {
+            self.refresh.transform_arrow_expression();
+        }
+    }
+
+    pub fn transform_arrow_expression_on_exit(&mut self, arrow: &ArrowFunctionExpression<'a>) {
+        if self.refresh_plugin {
+            self.refresh.transform_arrow_expression_on_exit(arrow);
+        }
+    }
+
     pub fn transform_call_expression(
-        &self,
+        &mut self,
         call_expr: &mut CallExpression<'a>,
-        ctx: &TraverseCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
     ) {
         if self.display_name_plugin {
             self.display_name.transform_call_expression(call_expr, ctx);
         }
+        if self.refresh_plugin {
+            self.refresh.transform_call_expression(call_expr, ctx);
+        }
     }
 
     pub fn transform_jsx_opening_element(
@@ -1,5 +1,6 @@
 use serde::Deserialize;
 
+use super::ReactRefreshOptions;
 use crate::TransformCtx;
 
 #[inline]
@@ -45,6 +46,11 @@ pub struct ReactOptions {
     #[serde(skip)]
     pub jsx_source_plugin: bool,
 
+    /// Enables [react-refresh/babel](https://github.com/facebook/react/tree/main/packages/react-refresh),
+    /// instrumenting components for Fast Refresh.
+    #[serde(skip)]
+    pub refresh: Option<ReactRefreshOptions>,
+
     // Both Runtimes
     //
     /// Decides which runtime to use.
@@ -113,6 +119,7 @@ impl Default for ReactOptions {
             display_name_plugin: true,
             jsx_self_plugin: false,
             jsx_source_plugin: false,
+            refresh: None,
             runtime: ReactJsxRuntime::default(),
             development: false,
             throw_if_namespace: default_as_true(),
@@ -0,0 +1,587 @@
+use std::rc::Rc;
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, visit::walk, Visit};
+use oxc_span::{Atom, GetSpan, SPAN};
+use oxc_syntax::{
+    operator::AssignmentOperator,
+    reference::ReferenceFlag,
+    scope::{ScopeFlags, ScopeId},
+    symbol::SymbolFlags,
+};
+use oxc_traverse::{Ancestor, TraverseCtx};
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+
+use crate::{
+    context::Ctx,
+    helpers::{bindings::BoundIdentifier, var_declarations::VarDeclarations},
+};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct ReactRefreshOptions {
+    /// Specify the identifier of the refresh registration variable.
+    ///
+    /// Defaults to `$RefreshReg$`.
+    pub refresh_reg: String,
+
+    /// Specify the identifier of the refresh signature variable.
+    ///
+    /// Defaults to `$RefreshSig$`.
+    pub refresh_sig: String,
+}
+
+impl Default for ReactRefreshOptions {
+    fn default() -> Self {
+        Self { refresh_reg: "$RefreshReg$".to_string(), refresh_sig: "$RefreshSig$".to_string() }
+    }
+}
+
+/// [react-refresh/babel](https://github.com/facebook/react/blob/main/packages/react-refresh/src/ReactFreshBabelPlugin.js)
+///
+/// Instruments components and hooks for React Fast Refresh.
+///
+/// * Top-level functions with a capitalized name which return JSX are registered as components,
+///   with `$RefreshReg$(component, "Name")`.
+/// * Functions calling hooks get a signature from `$RefreshSig$()`, which is called on each
+///   render and records the hooks called, so the component state is reset when they change.
+///
+/// Signatures are emitted in full, they are not hashed.
+///
+/// ## Example
+///
+/// Input:
+/// ```js
+/// export default function App() {
+///   const [foo, setFoo] = useState(0);
+///   return <h1>{foo}</h1>;
+/// }
+/// ```
+///
+/// Output:
+/// ```js
+/// var _s = $RefreshSig$();
+/// export default function App() {
+///   _s();
+///   const [foo, setFoo] = useState(0);
+///   return <h1>{foo}</h1>;
+/// }
+/// _s(App, "useState{[foo, setFoo](0)}");
+/// _c = App;
+/// var _c;
+/// $RefreshReg$(_c, "App");
+/// ```
+pub struct ReactRefresh<'a> {
+    ctx: Ctx<'a>,
+    options: ReactRefreshOptions,
+    var_declarations: VarDeclarations<'a>,
+    /// Hook calls of each function being traversed
+    hook_calls: std::vec::Vec<std::vec::Vec<HookCall<'a>>>,
+    /// Hook calls of exited functions, by function scope, until their signature is inserted
+    signatures: FxHashMap<ScopeId, std::vec::Vec<HookCall<'a>>>,
+}
+
+struct HookCall<'a> {
+    /// `useState` in `useState()` and `React.useState()`
+    name: Atom<'a>,
+    /// The source text of the bound pattern, and of the argument resetting the state on edit:
+    /// `[foo, setFoo](0)` in `const [foo, setFoo] = useState(0)`
+    key: String,
+    /// The callee of a custom hook, which is returned by the signature
+    custom_callee: Option<Expression<'a>>,
+}
+
+impl<'a> ReactRefresh<'a> {
+    pub fn new(options: ReactRefreshOptions, ctx: Ctx<'a>) -> Self {
+        let var_declarations = VarDeclarations::new(Rc::clone(&ctx));
+        Self {
+            ctx,
+            options,
+            var_declarations,
+            hook_calls: vec![],
+            signatures: FxHashMap::default(),
+        }
+    }
+}
+
+// Transforms
+impl<'a> ReactRefresh<'a> {
+    /// Registers the components declared at the top level.
+    ///
+    /// `function Foo() {}` -> `function Foo() {} _c = Foo; var _c; $RefreshReg$(_c, "Foo");`
+    pub fn transform_statements(
+        &mut self,
+        stmts: &mut Vec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.var_declarations.enter_statements(ctx);
+
+        if !matches!(ctx.parent(), Ancestor::ProgramBody(_)) {
+            return;
+        }
+
+        let ast = self.ctx.ast;
+        let mut registrations = vec![];
+        let mut new_stmts = ast.new_vec_with_capacity(stmts.len());
+        for stmt in stmts.drain(..) {
+            let component = component_binding(&stmt);
+            new_stmts.push(stmt);
+            let Some(component) = component else { continue };
+            // `_c = Foo`
+            let binding =
+                BoundIdentifier::new_root_uid("c", SymbolFlags::FunctionScopedVariable, ctx);
+            let target =
+                ast.simple_assignment_target_identifier(binding.create_write_reference(ctx));
+            let value = ast.identifier_reference_expression(component.create_read_reference(ctx));
+            let assignment =
+                ast.assignment_expression(SPAN, AssignmentOperator::Assign, target, value);
+            new_stmts.push(ast.expression_statement(SPAN, assignment));
+            registrations.push((binding, component.name));
+        }
+
+        if !registrations.is_empty() {
+            // `var _c, _c2;`
+            let declarations = ast.new_vec_from_iter(registrations.iter().map(|(binding, _)| {
+                let id = ast.binding_pattern(
+                    ast.binding_pattern_identifier(binding.create_binding_identifier()),
+                    None,
+                    false,
+                );
+                ast.variable_declarator(SPAN, VariableDeclarationKind::Var, id, None, false)
+            }));
+            let kind = VariableDeclarationKind::Var;
+            let decl = ast.variable_declaration(SPAN, kind, declarations, false);
+            new_stmts.push(Statement::VariableDeclaration(decl));
+
+            // `$RefreshReg$(_c, "Foo");`
+            for (binding, name) in registrations {
+                let callee = self.refresh_identifier(&self.options.refresh_reg, ctx);
+                let binding =
+                    ast.identifier_reference_expression(binding.create_read_reference(ctx));
+                let name = ast.literal_string_expression(StringLiteral::new(SPAN, name));
+                let arguments =
+                    ast.new_vec_from_iter([Argument::from(binding), Argument::from(name)]);
+                let call = ast.call_expression(SPAN, callee, arguments, false, None);
+                new_stmts.push(ast.expression_statement(SPAN, call));
+            }
+        }
+
+        *stmts = new_stmts;
+    }
+
+    /// Inserts the signatures of function declarations.
+    ///
+    /// `function Foo() { useState() }` -> `function Foo() { _s(); useState() } _s(Foo, "useState{}");`
+    pub fn transform_statements_on_exit(
+        &mut self,
+        stmts: &mut Vec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !self.signatures.is_empty() {
+            let ast = self.ctx.ast;
+            let mut new_stmts = ast.new_vec_with_capacity(stmts.len());
+            for mut stmt in stmts.drain(..) {
+                let signature = self.transform_function_declaration(&mut stmt, ctx);
+                new_stmts.push(stmt);
+                if let Some(signature) = signature {
+                    new_stmts.push(ast.expression_statement(SPAN, signature));
+                }
+            }
+            *stmts = new_stmts;
+        }
+
+        self.var_declarations.exit_statements(stmts, ctx);
+    }
+
+    pub fn transform_function(&mut self) {
+        self.hook_calls.push(vec![]);
+    }
+
+    pub fn transform_function_on_exit(&mut self, func: &Function<'a>, ctx: &TraverseCtx<'a>) {
+        let hook_calls = self.hook_calls.pop().unwrap_or_default();
+        // Methods can't be wrapped
+        if hook_calls.is_empty() || matches!(ctx.parent(), Ancestor::MethodDefinitionValue(_)) {
+            return;
+        }
+        if let Some(scope_id) = func.scope_id.get() {
+            self.signatures.insert(scope_id, hook_calls);
+        }
+    }
+
+    pub fn transform_arrow_expression(&mut self) {
+        self.hook_calls.push(vec![]);
+    }
+
+    pub fn transform_arrow_expression_on_exit(&mut self, arrow: &ArrowFunctionExpression<'a>) {
+        let hook_calls = self.hook_calls.pop().unwrap_or_default();
+        if hook_calls.is_empty() {
+            return;
+        }
+        if let Some(scope_id) = arrow.scope_id.get() {
+            self.signatures.insert(scope_id, hook_calls);
+        }
+    }
+
+    /// Records the hook calls of the enclosing function.
+    pub fn transform_call_expression(
+        &mut self,
+        call: &CallExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.hook_calls.is_empty() {
+            return;
+        }
+        let name = match &call.callee {
+            Expression::Identifier(ident) => &ident.name,
+            Expression::StaticMemberExpression(member) => &member.property.name,
+            _ => return,
+        };
+        if !is_hook_name(name) {
+            return;
+        }
+
+        let source_text = self.ctx.source_text;
+        let mut key = match ctx.parent() {
+            // `const [foo, setFoo] = useState(0)`
+            Ancestor::VariableDeclaratorInit(decl) => decl.id().span().source_text(source_text),
+            _ => "",
+        }
+        .to_string();
+        // State is reset when its initial value is edited
+        let reset_argument = match name.as_str() {
+            "useState" => call.arguments.first(),
+            "useReducer" => call.arguments.get(1),
+            _ => None,
+        };
+        if let Some(argument) = reset_argument {
+            key.push('(');
+            key.push_str(argument.span().source_text(source_text));
+            key.push(')');
+        }
+
+        let custom_callee =
+            if is_builtin_hook(name) { None } else { self.clone_callee(&call.callee, ctx) };
+        let hook_call = HookCall { name: name.clone(), key, custom_callee };
+        if let Some(hook_calls) = self.hook_calls.last_mut() {
+            hook_calls.push(hook_call);
+        }
+    }
+
+    /// Wraps function expressions calling hooks with their signature.
+    ///
+    /// `() => useState()` -> `_s(() => { _s(); return useState(); }, "useState{}")`
+    pub fn transform_expression_on_exit(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let scope_id = match expr {
+            Expression::FunctionExpression(func) => func.scope_id.get(),
+            Expression::ArrowFunctionExpression(arrow) => arrow.scope_id.get(),
+            _ => return,
+        };
+        let Some(hook_calls) = scope_id.and_then(|scope_id| self.signatures.remove(&scope_id))
+        else {
+            return;
+        };
+        // `{ foo() {} }` and accessors can't be wrapped
+        if let Ancestor::ObjectPropertyValue(prop) = ctx.parent() {
+            if *prop.method() || *prop.kind() != PropertyKind::Init {
+                return;
+            }
+        }
+
+        let ast = self.ctx.ast;
+        let body = match expr {
+            Expression::FunctionExpression(func) => {
+                let Some(body) = func.body.as_mut() else { return };
+                body
+            }
+            Expression::ArrowFunctionExpression(arrow) => {
+                // `() => expr` -> `() => { return expr; }`
+                if arrow.expression {
+                    arrow.expression = false;
+                    if let Some(Statement::ExpressionStatement(stmt)) = arrow.body.statements.pop()
+                    {
+                        let stmt = stmt.unbox();
+                        arrow
+                            .body
+                            .statements
+                            .push(ast.return_statement(stmt.span, Some(stmt.expression)));
+                    }
+                }
+                &mut arrow.body
+            }
+            _ => unreachable!(),
+        };
+        let signature = self.create_signature(body, ctx);
+        let func = ast.move_expression(expr);
+        *expr = self.signature_call(&signature, func, hook_calls, ctx);
+    }
+}
+
+// Utils
+impl<'a> ReactRefresh<'a> {
+    /// Returns `_s(Foo, "...")` for a function declaration calling hooks.
+    fn transform_function_declaration(
+        &mut self,
+        stmt: &mut Statement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let func = match stmt {
+            Statement::FunctionDeclaration(func) => func,
+            Statement::ExportNamedDeclaration(decl) => match &mut decl.declaration {
+                Some(Declaration::FunctionDeclaration(func)) => func,
+                _ => return None,
+            },
+            Statement::ExportDefaultDeclaration(decl) => match &mut decl.declaration {
+                ExportDefaultDeclarationKind::FunctionDeclaration(func) => func,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        let hook_calls = self.signatures.remove(&func.scope_id.get()?)?;
+        // `export default function () {}` can't be referenced
+        let id = func.id.as_ref()?;
+        let function = BoundIdentifier { name: id.name.clone(), symbol_id: id.symbol_id.get()? };
+        let signature = self.create_signature(func.body.as_mut()?, ctx);
+        let function =
+            self.ctx.ast.identifier_reference_expression(function.create_read_reference(ctx));
+        Some(self.signature_call(&signature, function, hook_calls, ctx))
+    }
+
+    /// Declares `var _s = $RefreshSig$();`, and inserts `_s();` at the start of `body`.
+    fn create_signature(
+        &mut self,
+        body: &mut FunctionBody<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        let ast = self.ctx.ast;
+        let callee = self.refresh_identifier(&self.options.refresh_sig, ctx);
+        let init = ast.call_expression(SPAN, callee, ast.new_vec(), false, None);
+        let signature = self.var_declarations.create_var("s", Some(init), ctx);
+
+        let callee = ast.identifier_reference_expression(signature.create_read_reference(ctx));
+        let call = ast.call_expression(SPAN, callee, ast.new_vec(), false, None);
+        body.statements.insert(0, ast.expression_statement(SPAN, call));
+        signature
+    }
+
+    /// `_s(func, "useState{[foo, setFoo](0)}\nuseFoo{}", false, function () { return [useFoo]; })`
+    fn signature_call(
+        &self,
+        signature: &BoundIdentifier<'a>,
+        func: Expression<'a>,
+        hook_calls: std::vec::Vec<HookCall<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let ast = self.ctx.ast;
+        let key = hook_calls
+            .iter()
+            .map(|hook_call| format!("{}{{{}}}", hook_call.name, hook_call.key))
+            .collect::<std::vec::Vec<_>>()
+            .join("\n");
+        let key = ast.literal_string_expression(StringLiteral::new(SPAN, ast.new_atom(&key)));
+
+        let mut arguments = ast.new_vec_from_iter([Argument::from(func), Argument::from(key)]);
+        let custom_hooks = hook_calls
+            .into_iter()
+            .filter_map(|hook_call| hook_call.custom_callee)
+            .map(ArrayExpressionElement::from)
+            .collect::<std::vec::Vec<_>>();
+        if !custom_hooks.is_empty() {
+            // `false`, the state is not force reset
+            let force_reset = ast.literal_boolean_expression(BooleanLiteral::new(SPAN, false));
+            arguments.push(Argument::from(force_reset));
+
+            let custom_hooks =
+                ast.array_expression(SPAN, ast.new_vec_from_iter(custom_hooks), None);
+            let body = ast.function_body(
+                SPAN,
+                ast.new_vec(),
+                ast.new_vec_single(ast.return_statement(SPAN, Some(custom_hooks))),
+            );
+            let params = ast.formal_parameters(
+                SPAN,
+                FormalParameterKind::FormalParameter,
+                ast.new_vec(),
+                None,
+            );
+            let function = ast.plain_function(
+                FunctionType::FunctionExpression,
+                SPAN,
+                None,
+                params,
+                Some(body),
+            );
+            let parent_scope_id = ctx.current_scope_id();
+            let scope_id = ctx.scopes_mut().add_scope(Some(parent_scope_id), ScopeFlags::Function);
+            function.scope_id.set(Some(scope_id));
+            arguments.push(Argument::from(ast.function_expression(function)));
+        }
+
+        let callee = ast.identifier_reference_expression(signature.create_read_reference(ctx));
+        ast.call_expression(SPAN, callee, arguments, false, None)
+    }
+
+    /// `useFoo` or `Foo.useFoo`, referenced from the signature of a custom hook
+    fn clone_callee(
+        &self,
+        callee: &Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let ast = self.ctx.ast;
+        let clone_reference = |ident: &IdentifierReference<'a>, ctx: &mut TraverseCtx<'a>| {
+            let symbol_id = ident
+                .reference_id
+                .get()
+                .and_then(|reference_id| ctx.symbols().get_reference(reference_id).symbol_id());
+            let ident =
+                ctx.create_reference_id(SPAN, ident.name.clone(), symbol_id, ReferenceFlag::Read);
+            ast.identifier_reference_expression(ident)
+        };
+        match callee {
+            Expression::Identifier(ident) => Some(clone_reference(ident, ctx)),
+            Expression::StaticMemberExpression(member) => {
+                let Expression::Identifier(object) = &member.object else { return None };
+                let object = clone_reference(object, ctx);
+                let property = IdentifierName::new(SPAN, member.property.name.clone());
+                Some(ast.static_member_expression(SPAN, object, property, false))
+            }
+            _ => None,
+        }
+    }
+
+    /// `$RefreshReg$` or `window.$RefreshReg$`
+    fn refresh_identifier(&self, name: &str, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let ast = self.ctx.ast;
+        let mut parts = name.split('.');
+        let object = parts.next().unwrap_or_default();
+        let ident =
+            ctx.create_unbound_reference_id(SPAN, ast.new_atom(object), ReferenceFlag::Read);
+        parts.fold(ast.identifier_reference_expression(ident), |object, property| {
+            let property = IdentifierName::new(SPAN, ast.new_atom(property));
+            ast.static_member_expression(SPAN, object, property, false)
+        })
+    }
+}
+
+/// The binding of a top-level component declaration.
+///
+/// Components are functions with a capitalized name returning JSX:
+/// `function Foo() { return <div />; }`, `const Foo = () => <div />` and their exports.
+fn component_binding<'a>(stmt: &Statement<'a>) -> Option<BoundIdentifier<'a>> {
+    let id = match stmt {
+        Statement::FunctionDeclaration(func) => function_component_id(func),
+        Statement::VariableDeclaration(decl) => variable_component_id(decl),
+        Statement::ExportNamedDeclaration(decl) => match &decl.declaration {
+            Some(Declaration::FunctionDeclaration(func)) => function_component_id(func),
+            Some(Declaration::VariableDeclaration(decl)) => variable_component_id(decl),
+            _ => None,
+        },
+        Statement::ExportDefaultDeclaration(decl) => match &decl.declaration {
+            ExportDefaultDeclarationKind::FunctionDeclaration(func) => function_component_id(func),
+            _ => None,
+        },
+        _ => None,
+    }?;
+    if !id.name.starts_with(|c: char| c.is_ascii_uppercase()) {
+        return None;
+    }
+    Some(BoundIdentifier { name: id.name.clone(), symbol_id: id.symbol_id.get()? })
+}
+
+/// `function Foo() { return <div />; }`
+fn function_component_id<'b, 'a>(func: &'b Function<'a>) -> Option<&'b BindingIdentifier<'a>> {
+    func.body.as_ref().is_some_and(|body| returns_jsx(body)).then_some(func.id.as_ref()?)
+}
+
+/// `const Foo = () => <div />` or `const Foo = function () { return <div />; }`
+fn variable_component_id<'b, 'a>(
+    decl: &'b VariableDeclaration<'a>,
+) -> Option<&'b BindingIdentifier<'a>> {
+    let [declarator] = decl.declarations.as_slice() else { return None };
+    let BindingPatternKind::BindingIdentifier(id) = &declarator.id.kind else { return None };
+    let is_component = match &declarator.init {
+        Some(Expression::ArrowFunctionExpression(arrow)) => match arrow.get_expression() {
+            Some(expr) => is_jsx(expr),
+            None => returns_jsx(&arrow.body),
+        },
+        Some(Expression::FunctionExpression(func)) => {
+            func.body.as_ref().is_some_and(|body| returns_jsx(body))
+        }
+        _ => false,
+    };
+    is_component.then_some(&**id)
+}
+
+/// Whether a `return` of `body`, outside of nested functions and classes, returns JSX.
+fn returns_jsx(body: &FunctionBody) -> bool {
+    let mut finder = JsxReturnFinder { found: false };
+    finder.visit_function_body(body);
+    finder.found
+}
+
+/// `<div />`, `<></>`, and either of them in a conditional or logical expression
+fn is_jsx(expr: &Expression) -> bool {
+    match expr.without_parenthesized() {
+        Expression::JSXElement(_) | Expression::JSXFragment(_) => true,
+        Expression::ConditionalExpression(expr) => {
+            is_jsx(&expr.consequent) || is_jsx(&expr.alternate)
+        }
+        Expression::LogicalExpression(expr) => is_jsx(&expr.left) || is_jsx(&expr.right),
+        Expression::SequenceExpression(expr) => expr.expressions.last().is_some_and(is_jsx),
+        _ => false,
+    }
+}
+
+struct JsxReturnFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for JsxReturnFinder {
+    fn visit_return_statement(&mut self, stmt: &ReturnStatement<'a>) {
+        if stmt.argument.as_ref().is_some_and(is_jsx) {
+            self.found = true;
+        }
+        walk::walk_return_statement(self, stmt);
+    }
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: Option<ScopeFlags>) {}
+
+    fn visit_arrow_expression(&mut self, _expr: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_class(&mut self, _class: &Class<'a>) {}
+}
+
+/// `useFoo`, but not `user`
+fn is_hook_name(name: &str) -> bool {
+    name.strip_prefix("use").is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_uppercase()))
+}
+
+/// Built-in hooks need not be returned by the signature, they can't change on edit.
+fn is_builtin_hook(name: &str) -> bool {
+    matches!(
+        name,
+        "useState"
+            | "useReducer"
+            | "useEffect"
+            | "useLayoutEffect"
+            | "useMemo"
+            | "useCallback"
+            | "useRef"
+            | "useContext"
+            | "useImperativeHandle"
+            | "useDebugValue"
+            | "useId"
+            | "useDeferredValue"
+            | "useTransition"
+            | "useInsertionEffect"
+            | "useSyncExternalStore"
+            | "useFormStatus"
+            | "useFormState"
+            | "useActionState"
+            | "useOptimistic"
+    )
+}
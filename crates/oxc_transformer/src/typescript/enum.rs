@@ -1,4 +1,4 @@
-use std::cell::Cell;
+use std::{cell::Cell, rc::Rc};
 
 use oxc_allocator::Vec;
 use oxc_ast::{ast::*, visit::walk_mut, VisitMut};
@@ -7,21 +7,154 @@ use oxc_syntax::{
     number::{NumberBase, ToJsInt32, ToJsString},
     operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator},
     reference::ReferenceFlag,
-    symbol::SymbolFlags,
+    symbol::{SymbolFlags, SymbolId},
 };
 use oxc_traverse::TraverseCtx;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
+use super::TypeScriptOptions;
 use crate::context::Ctx;
 
 pub struct TypeScriptEnum<'a> {
+    options: Rc<TypeScriptOptions>,
     ctx: Ctx<'a>,
     enums: FxHashMap<Atom<'a>, FxHashMap<Atom<'a>, ConstantValue>>,
+    /// Members of `const enum`s, only collected with `optimize_const_enums`.
+    const_enums: FxHashMap<SymbolId, FxHashMap<Atom<'a>, ConstantValue>>,
+    /// Exported `const enum`s whose members are all constant, which are emitted as objects.
+    exported_const_enums: FxHashSet<SymbolId>,
 }
 
 impl<'a> TypeScriptEnum<'a> {
-    pub fn new(ctx: Ctx<'a>) -> Self {
-        Self { ctx, enums: FxHashMap::default() }
+    pub fn new(options: Rc<TypeScriptOptions>, ctx: Ctx<'a>) -> Self {
+        Self {
+            options,
+            ctx,
+            enums: FxHashMap::default(),
+            const_enums: FxHashMap::default(),
+            exported_const_enums: FxHashSet::default(),
+        }
+    }
+
+    /// Collect the members of `const enum`s declared in `stmts`, and remove the
+    /// declarations which are not exported and whose members are all constant.
+    /// Exported ones are emitted as plain objects by `transform_statement`.
+    ///
+    /// ```TypeScript
+    /// const enum Foo { X = 1, Y }
+    /// Foo.Y;
+    /// export const enum Bar { Z }
+    /// ```
+    /// ```JavaScript
+    /// 2;
+    /// export var Bar = { Z: 0 };
+    /// ```
+    pub fn transform_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        if !self.options.optimize_const_enums {
+            return;
+        }
+
+        // Const enums which must be kept, because they are exported or a member is not constant
+        let mut kept = FxHashSet::default();
+        for stmt in stmts.iter() {
+            match stmt {
+                Statement::TSEnumDeclaration(decl) if decl.r#const => {
+                    if let Some(symbol_id) = decl.id.symbol_id.get() {
+                        if !self.collect_const_enum(decl, symbol_id) {
+                            kept.insert(symbol_id);
+                        }
+                    }
+                }
+                Statement::ExportNamedDeclaration(export_decl) => {
+                    if let Some(Declaration::TSEnumDeclaration(decl)) = &export_decl.declaration {
+                        if let Some(symbol_id) = decl.id.symbol_id.get() {
+                            if decl.r#const {
+                                if self.collect_const_enum(decl, symbol_id) {
+                                    self.exported_const_enums.insert(symbol_id);
+                                }
+                                kept.insert(symbol_id);
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        stmts.retain(|stmt| match stmt {
+            Statement::TSEnumDeclaration(decl) if decl.r#const => {
+                decl.id.symbol_id.get().map_or(true, |symbol_id| kept.contains(&symbol_id))
+            }
+            _ => true,
+        });
+    }
+
+    /// Replace references to `const enum` members with their values.
+    pub fn transform_expression(&self, expr: &mut Expression<'a>, ctx: &TraverseCtx<'a>) {
+        if self.const_enums.is_empty() {
+            return;
+        }
+
+        let Some(member_expr) = expr.as_member_expression() else { return };
+        let Expression::Identifier(ident) = member_expr.object() else { return };
+        let Some(symbol_id) = ident
+            .reference_id
+            .get()
+            .and_then(|reference_id| ctx.symbols().get_reference(reference_id).symbol_id())
+        else {
+            return;
+        };
+        let Some(value) = member_expr
+            .static_property_name()
+            .and_then(|name| self.const_enums.get(&symbol_id)?.get(name))
+        else {
+            return;
+        };
+
+        *expr = match value {
+            ConstantValue::Number(v) => self.get_initializer_expr(*v),
+            ConstantValue::String(str) => self
+                .ctx
+                .ast
+                .literal_string_expression(StringLiteral::new(SPAN, self.ctx.ast.new_atom(str))),
+        };
+    }
+
+    /// Returns `false` if a member is not a constant.
+    fn collect_const_enum(&mut self, decl: &TSEnumDeclaration<'a>, symbol_id: SymbolId) -> bool {
+        // Merged declarations can refer to the members of previous declarations
+        let mut members = self.const_enums.remove(&symbol_id).unwrap_or_default();
+        let mut prev_constant_value = Some(ConstantValue::Number(-1.0));
+        let mut all_constant = true;
+
+        for member in &decl.members {
+            let member_name = match &member.id {
+                TSEnumMemberName::StaticIdentifier(id) => &id.name,
+                TSEnumMemberName::StaticStringLiteral(str) => &str.value,
+                #[allow(clippy::unnested_or_patterns)] // Clippy is wrong
+                TSEnumMemberName::StaticNumericLiteral(_) | match_expression!(TSEnumMemberName) => {
+                    unreachable!()
+                }
+            };
+
+            let constant_value = match &member.initializer {
+                Some(initializer) => self.computed_constant_value(initializer, &members),
+                None => match prev_constant_value {
+                    Some(ConstantValue::Number(v)) => Some(ConstantValue::Number(v + 1.0)),
+                    _ => None,
+                },
+            };
+
+            if let Some(value) = &constant_value {
+                members.insert(member_name.clone(), value.clone());
+            } else {
+                all_constant = false;
+            }
+            prev_constant_value = constant_value;
+        }
+
+        self.const_enums.insert(symbol_id, members);
+        all_constant
     }
 
     pub fn transform_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -31,7 +164,15 @@ impl<'a> TypeScriptEnum<'a> {
             }
             Statement::ExportNamedDeclaration(decl) => {
                 if let Some(Declaration::TSEnumDeclaration(ts_enum_decl)) = &decl.declaration {
-                    self.transform_ts_enum(ts_enum_decl, Some(decl.span), ctx)
+                    let is_constant =
+                        ts_enum_decl.id.symbol_id.get().is_some_and(|symbol_id| {
+                            self.exported_const_enums.contains(&symbol_id)
+                        });
+                    if is_constant && !ts_enum_decl.declare {
+                        Some(self.transform_exported_const_enum(ts_enum_decl, decl.span, ctx))
+                    } else {
+                        self.transform_ts_enum(ts_enum_decl, Some(decl.span), ctx)
+                    }
                 } else {
                     None
                 }
@@ -174,6 +315,68 @@ impl<'a> TypeScriptEnum<'a> {
         Some(stmt)
     }
 
+    /// ```TypeScript
+    /// export const enum Foo { X = 1, Y }
+    /// ```
+    /// ```JavaScript
+    /// export var Foo = { X: 1, Y: 2 };
+    /// ```
+    fn transform_exported_const_enum(
+        &self,
+        decl: &TSEnumDeclaration<'a>,
+        export_span: Span,
+        ctx: &TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let ast = ctx.ast;
+        let members =
+            decl.id.symbol_id.get().and_then(|symbol_id| self.const_enums.get(&symbol_id));
+        let properties = ast.new_vec_from_iter(decl.members.iter().filter_map(|member| {
+            let (key, name) = match &member.id {
+                TSEnumMemberName::StaticIdentifier(id) => (
+                    ast.property_key_identifier(IdentifierName::new(SPAN, id.name.clone())),
+                    &id.name,
+                ),
+                TSEnumMemberName::StaticStringLiteral(str) => (
+                    ast.property_key_expression(
+                        ast.literal_string_expression(StringLiteral::new(SPAN, str.value.clone())),
+                    ),
+                    &str.value,
+                ),
+                _ => return None,
+            };
+            let value = match members?.get(name)? {
+                ConstantValue::Number(v) => self.get_number_literal_expression(*v),
+                ConstantValue::String(str) => {
+                    ast.literal_string_expression(StringLiteral::new(SPAN, ast.new_atom(str)))
+                }
+            };
+            let property = ast.object_property(
+                SPAN,
+                PropertyKind::Init,
+                key,
+                value,
+                None,
+                false,
+                false,
+                false,
+            );
+            Some(ObjectPropertyKind::ObjectProperty(property))
+        }));
+        let init = ast.object_expression(SPAN, properties, None);
+
+        let kind = VariableDeclarationKind::Var;
+        let binding =
+            ast.binding_pattern(ast.binding_pattern_identifier(decl.id.clone()), None, false);
+        let declarator = ast.variable_declarator(SPAN, kind, binding, Some(init), false);
+        let declaration =
+            ast.variable_declaration(decl.span, kind, ast.new_vec_single(declarator), false);
+        let declaration = ast.plain_export_named_declaration_declaration(
+            export_span,
+            Declaration::VariableDeclaration(declaration),
+        );
+        Statement::ExportNamedDeclaration(declaration)
+    }
+
     fn transform_ts_enum_members(
         &mut self,
         members: &Vec<'a, TSEnumMember<'a>>,
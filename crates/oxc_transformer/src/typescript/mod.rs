@@ -51,7 +51,7 @@ impl<'a> TypeScript<'a> {
 
         Self {
             annotations: TypeScriptAnnotations::new(Rc::clone(&options), Rc::clone(&ctx)),
-            r#enum: TypeScriptEnum::new(Rc::clone(&ctx)),
+            r#enum: TypeScriptEnum::new(Rc::clone(&options), Rc::clone(&ctx)),
             options,
             ctx,
         }
@@ -111,8 +111,9 @@ impl<'a> TypeScript<'a> {
         self.annotations.transform_ts_module_declaration(decl);
     }
 
-    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         self.annotations.transform_expression(expr);
+        self.r#enum.transform_expression(expr, ctx);
     }
 
     pub fn transform_simple_assignment_target(&mut self, target: &mut SimpleAssignmentTarget<'a>) {
@@ -156,6 +157,8 @@ impl<'a> TypeScript<'a> {
     }
 
     pub fn transform_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        // Before `declare` declarations are removed, `declare const enum`s are inlined too
+        self.r#enum.transform_statements(stmts);
         self.annotations.transform_statements(stmts);
     }
 
@@ -194,8 +194,18 @@ impl<'a> TypeScript<'a> {
                             continue;
                         }
                         match decl {
-                            Declaration::TSEnumDeclaration(_)
-                            | Declaration::FunctionDeclaration(_)
+                            Declaration::TSEnumDeclaration(mut enum_decl) => {
+                                // `_N.G = G` refers to the enum, so an exported `const enum` is
+                                // not removed by `optimize_const_enums`
+                                enum_decl.r#const = false;
+                                self.add_declaration(
+                                    Declaration::TSEnumDeclaration(enum_decl),
+                                    name.clone(),
+                                    &mut names,
+                                    &mut new_stmts,
+                                );
+                            }
+                            Declaration::FunctionDeclaration(_)
                             | Declaration::ClassDeclaration(_) => {
                                 self.add_declaration(
                                     decl,
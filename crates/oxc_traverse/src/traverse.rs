@@ -318,6 +318,21 @@ pub trait Traverse<'a> {
     ) {
     }
 
+    #[inline]
+    fn enter_pipeline_placeholder(
+        &mut self,
+        node: &mut PipelinePlaceholder,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    #[inline]
+    fn exit_pipeline_placeholder(
+        &mut self,
+        node: &mut PipelinePlaceholder,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
     #[inline]
     fn enter_logical_expression(
         &mut self,
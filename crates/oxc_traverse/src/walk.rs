@@ -177,6 +177,9 @@ pub(crate) unsafe fn walk_expression<'a, Tr: Traverse<'a>>(
         Expression::TSInstantiationExpression(node) => {
             walk_ts_instantiation_expression(traverser, (&mut **node) as *mut _, ctx)
         }
+        Expression::PipelinePlaceholder(node) => {
+            walk_pipeline_placeholder(traverser, (&mut **node) as *mut _, ctx)
+        }
         Expression::ComputedMemberExpression(_)
         | Expression::StaticMemberExpression(_)
         | Expression::PrivateFieldExpression(_) => {
@@ -300,6 +303,7 @@ pub(crate) unsafe fn walk_array_expression_element<'a, Tr: Traverse<'a>>(
         | ArrayExpressionElement::TSTypeAssertion(_)
         | ArrayExpressionElement::TSNonNullExpression(_)
         | ArrayExpressionElement::TSInstantiationExpression(_)
+        | ArrayExpressionElement::PipelinePlaceholder(_)
         | ArrayExpressionElement::ComputedMemberExpression(_)
         | ArrayExpressionElement::StaticMemberExpression(_)
         | ArrayExpressionElement::PrivateFieldExpression(_) => {
@@ -434,6 +438,7 @@ pub(crate) unsafe fn walk_property_key<'a, Tr: Traverse<'a>>(
         | PropertyKey::TSTypeAssertion(_)
         | PropertyKey::TSNonNullExpression(_)
         | PropertyKey::TSInstantiationExpression(_)
+        | PropertyKey::PipelinePlaceholder(_)
         | PropertyKey::ComputedMemberExpression(_)
         | PropertyKey::StaticMemberExpression(_)
         | PropertyKey::PrivateFieldExpression(_) => walk_expression(traverser, node as *mut _, ctx),
@@ -751,6 +756,7 @@ pub(crate) unsafe fn walk_argument<'a, Tr: Traverse<'a>>(
         | Argument::TSTypeAssertion(_)
         | Argument::TSNonNullExpression(_)
         | Argument::TSInstantiationExpression(_)
+        | Argument::PipelinePlaceholder(_)
         | Argument::ComputedMemberExpression(_)
         | Argument::StaticMemberExpression(_)
         | Argument::PrivateFieldExpression(_) => walk_expression(traverser, node as *mut _, ctx),
@@ -842,6 +848,15 @@ pub(crate) unsafe fn walk_private_in_expression<'a, Tr: Traverse<'a>>(
     traverser.exit_private_in_expression(&mut *node, ctx);
 }
 
+pub(crate) unsafe fn walk_pipeline_placeholder<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: *mut PipelinePlaceholder,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_pipeline_placeholder(&mut *node, ctx);
+    traverser.exit_pipeline_placeholder(&mut *node, ctx);
+}
+
 pub(crate) unsafe fn walk_logical_expression<'a, Tr: Traverse<'a>>(
     traverser: &mut Tr,
     node: *mut LogicalExpression<'a>,
@@ -1717,6 +1732,7 @@ pub(crate) unsafe fn walk_for_statement_init<'a, Tr: Traverse<'a>>(
         | ForStatementInit::TSTypeAssertion(_)
         | ForStatementInit::TSNonNullExpression(_)
         | ForStatementInit::TSInstantiationExpression(_)
+        | ForStatementInit::PipelinePlaceholder(_)
         | ForStatementInit::ComputedMemberExpression(_)
         | ForStatementInit::StaticMemberExpression(_)
         | ForStatementInit::PrivateFieldExpression(_) => {
@@ -3097,6 +3113,7 @@ pub(crate) unsafe fn walk_export_default_declaration_kind<'a, Tr: Traverse<'a>>(
         | ExportDefaultDeclarationKind::TSTypeAssertion(_)
         | ExportDefaultDeclarationKind::TSNonNullExpression(_)
         | ExportDefaultDeclarationKind::TSInstantiationExpression(_)
+        | ExportDefaultDeclarationKind::PipelinePlaceholder(_)
         | ExportDefaultDeclarationKind::ComputedMemberExpression(_)
         | ExportDefaultDeclarationKind::StaticMemberExpression(_)
         | ExportDefaultDeclarationKind::PrivateFieldExpression(_) => {
@@ -3376,6 +3393,7 @@ pub(crate) unsafe fn walk_jsx_expression<'a, Tr: Traverse<'a>>(
         | JSXExpression::TSTypeAssertion(_)
         | JSXExpression::TSNonNullExpression(_)
         | JSXExpression::TSInstantiationExpression(_)
+        | JSXExpression::PipelinePlaceholder(_)
         | JSXExpression::ComputedMemberExpression(_)
         | JSXExpression::StaticMemberExpression(_)
         | JSXExpression::PrivateFieldExpression(_) => {
@@ -3733,6 +3751,7 @@ pub(crate) unsafe fn walk_ts_enum_member_name<'a, Tr: Traverse<'a>>(
         | TSEnumMemberName::TSTypeAssertion(_)
         | TSEnumMemberName::TSNonNullExpression(_)
         | TSEnumMemberName::TSInstantiationExpression(_)
+        | TSEnumMemberName::PipelinePlaceholder(_)
         | TSEnumMemberName::ComputedMemberExpression(_)
         | TSEnumMemberName::StaticMemberExpression(_)
         | TSEnumMemberName::PrivateFieldExpression(_) => {
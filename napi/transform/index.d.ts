@@ -9,6 +9,7 @@ export interface TypeScriptBindingOptions {
   onlyRemoveTypeImports?: boolean
   allowNamespaces?: boolean
   allowDeclareFields?: boolean
+  optimizeConstEnums?: boolean
 }
 export interface ReactBindingOptions {
   runtime?: 'classic' | 'automatic'
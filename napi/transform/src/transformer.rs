@@ -17,6 +17,7 @@ pub struct TypeScriptBindingOptions {
     pub only_remove_type_imports: Option<bool>,
     pub allow_namespaces: Option<bool>,
     pub allow_declare_fields: Option<bool>,
+    pub optimize_const_enums: Option<bool>,
 }
 
 impl From<TypeScriptBindingOptions> for TypeScriptOptions {
@@ -30,6 +31,7 @@ impl From<TypeScriptBindingOptions> for TypeScriptOptions {
                 .unwrap_or(ops.only_remove_type_imports),
             allow_namespaces: options.allow_namespaces.unwrap_or(ops.allow_namespaces),
             allow_declare_fields: options.allow_declare_fields.unwrap_or(ops.allow_declare_fields),
+            optimize_const_enums: options.optimize_const_enums.unwrap_or(ops.optimize_const_enums),
         }
     }
 }
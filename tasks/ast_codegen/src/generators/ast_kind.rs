@@ -1,88 +1,54 @@
 use itertools::Itertools;
-use quote::quote;
-use syn::{parse_quote, Arm, Ident, Type, Variant};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse_quote, Arm, Fields, GenericArgument, Ident, PathArguments, Type, TypePath, Variant,
+};
 
-use crate::{schema::RType, CodegenCtx, Generator, GeneratorOutput};
+use crate::{
+    schema::{Inherit, RType},
+    CodegenCtx, Generator, GeneratorOutput,
+};
 
 use super::generated_header;
 
 pub struct AstKindGenerator;
 
-const BLACK_LIST: [&str; 69] = [
-    "Expression",
-    "ObjectPropertyKind",
-    "TemplateElement",
-    "ComputedMemberExpression",
-    "StaticMemberExpression",
-    "PrivateFieldExpression",
-    "AssignmentTargetPattern",
-    "ArrayAssignmentTarget",
-    "ObjectAssignmentTarget",
-    "AssignmentTargetRest",
-    "AssignmentTargetMaybeDefault",
-    "AssignmentTargetProperty",
-    "AssignmentTargetPropertyIdentifier",
-    "AssignmentTargetPropertyProperty",
-    "ChainElement",
-    "Statement",
-    "Declaration",
-    "ForStatementLeft",
-    "BindingPattern",
-    "BindingPatternKind",
-    "BindingProperty",
-    "ClassElement",
-    "AccessorProperty",
-    "ImportDeclarationSpecifier",
-    "WithClause",
-    "ImportAttribute",
-    "ImportAttributeKey",
-    "ExportDefaultDeclarationKind",
-    "ModuleExportName",
-    "TSEnumMemberName",
-    "TSLiteral",
-    "TSType",
-    "TSConditionalType",
-    "TSTypeOperator",
-    "TSArrayType",
-    "TSTupleType",
-    "TSOptionalType",
-    "TSRestType",
-    "TSTupleElement",
-    "TSInterfaceBody",
-    "TSSignature",
-    "TSIndexSignature",
-    "TSCallSignatureDeclaration",
-    "TSConstructSignatureDeclaration",
-    "TSIndexSignatureName",
-    "TSTypePredicate",
-    "TSTypePredicateName",
-    "TSModuleDeclarationName",
-    "TSModuleDeclarationBody",
-    "TSTypeQueryExprName",
-    "TSImportAttribute",
-    "TSImportAttributes",
-    "TSImportAttributeName",
-    "TSFunctionType",
-    "TSConstructorType",
-    "TSMappedType",
-    "TSModuleReference",
-    "TSExportAssignment",
-    "TSNamespaceExportDeclaration",
-    "JSDocNullableType",
-    "JSDocNonNullableType",
-    "JSDocUnknownType",
-    "JSXExpression",
-    "JSXEmptyExpression",
-    "JSXAttribute",
-    "JSXAttributeName",
-    "JSXAttributeValue",
-    "JSXChild",
-    "JSXSpreadChild",
+/// `AstType` categories with the enums defining them and their doc comments.
+/// A type belongs to a category if one of the enums has a variant wrapping it, or inherits from it.
+///
+/// `Function` and `Class` are both expressions and declarations, `AstKind::is_expression`,
+/// `AstKind::is_statement_variant` and `AstKind::is_declaration_variant` tell them apart and
+/// delegate to these for every other type.
+const CATEGORIES: [(&str, &[&str], &str); 4] = [
+    (
+        "is_expression",
+        &["Expression"],
+        " Types which can be an [`Expression`], see `AstKind::is_expression` for `Function` and `Class`.",
+    ),
+    (
+        "is_statement",
+        &["Statement"],
+        " Types which can be a [`Statement`], see `AstKind::is_statement_variant` for `Function` and `Class`.",
+    ),
+    (
+        "is_declaration",
+        &["Declaration"],
+        " Types which can be a [`Declaration`], see `AstKind::is_declaration_variant` for `Function` and `Class`.",
+    ),
+    (
+        "is_pattern",
+        &["BindingPatternKind", "AssignmentTargetPattern"],
+        " Types which can be a [`BindingPatternKind`] or an [`AssignmentTargetPattern`].",
+    ),
 ];
 
-pub fn blacklist((ident, _): &(Ident, Type)) -> bool {
-    !BLACK_LIST.contains(&ident.to_string().as_str())
-}
+/// Modules of the language extensions, types defined in them belong to the extension.
+const JSX_MODULE: &str = "jsx";
+const TYPESCRIPT_MODULE: &str = "ts";
+
+/// Function like types, whose bodies are separate from the surrounding code.
+const FUNCTION_LIKE: [&str; 2] = ["Function", "ArrowFunctionExpression"];
 
 pub fn aliased_nodes() -> [(Ident, Type); 3] {
     use syn::parse_quote as pq;
@@ -99,18 +65,24 @@ impl Generator for AstKindGenerator {
     }
 
     fn generate(&mut self, ctx: &CodegenCtx) -> GeneratorOutput {
+        let scope_creating = ctx
+            .ty_table
+            .iter()
+            .filter(|it| it.borrow().has_scope())
+            .filter_map(|it| it.borrow().ident().cloned())
+            .collect_vec();
+
         let have_kinds: Vec<(Ident, Type)> = ctx
             .ty_table
             .iter()
             .filter_map(|maybe_kind| match &*maybe_kind.borrow() {
-                kind @ (RType::Enum(_) | RType::Struct(_)) if kind.visitable() => {
+                kind @ (RType::Enum(_) | RType::Struct(_)) if kind.has_kind() => {
                     let ident = kind.ident().unwrap().clone();
                     let typ = kind.as_type().unwrap();
                     Some((ident, typ))
                 }
                 _ => None,
             })
-            .filter(blacklist)
             .chain(aliased_nodes())
             .collect();
 
@@ -125,6 +97,63 @@ impl Generator for AstKindGenerator {
             .map(|(ident, _)| parse_quote!(Self :: #ident(it) => it.span()))
             .collect_vec();
 
+        let ty_matches: Vec<Arm> = have_kinds
+            .iter()
+            .map(|(ident, _)| parse_quote!(Self :: #ident(_) => AstType :: #ident))
+            .collect_vec();
+
+        let name_matches: Vec<Arm> = have_kinds
+            .iter()
+            .map(|(ident, _)| {
+                let name = ident.to_string();
+                parse_quote!(Self :: #ident => #name)
+            })
+            .collect_vec();
+
+        let from_name_matches: Vec<Arm> = have_kinds
+            .iter()
+            .map(|(ident, _)| {
+                let name = ident.to_string();
+                parse_quote!(#name => Some(Self :: #ident))
+            })
+            .collect_vec();
+
+        let categories: Vec<TokenStream> = CATEGORIES
+            .iter()
+            .map(|(name, enums, doc)| {
+                let members = category_members(ctx, enums);
+                let types = have_kinds
+                    .iter()
+                    .map(|(ident, _)| ident)
+                    .filter(|ident| members.contains(ident))
+                    .collect_vec();
+                let name = format_ident!("{name}");
+                quote! {
+                    #[doc = #doc]
+                    pub fn #name(self) -> bool {
+                        matches!(self, #(Self :: #types)|*)
+                    }
+
+                    endl!();
+                }
+            })
+            .collect_vec();
+
+        let function_like = have_kinds
+            .iter()
+            .map(|(ident, _)| ident)
+            .filter(|ident| FUNCTION_LIKE.iter().any(|it| ident == it))
+            .collect_vec();
+
+        let scope_creating = have_kinds
+            .iter()
+            .map(|(ident, _)| ident)
+            .filter(|ident| scope_creating.contains(ident))
+            .collect_vec();
+
+        let jsx = module_members(ctx, &have_kinds, JSX_MODULE);
+        let typescript = module_members(ctx, &have_kinds, TYPESCRIPT_MODULE);
+
         let header = generated_header!();
 
         GeneratorOutput::One(quote! {
@@ -142,6 +171,61 @@ impl Generator for AstKindGenerator {
 
             endl!();
 
+            impl AstType {
+                pub fn name(self) -> &'static str {
+                    match self {
+                        #(#name_matches),*,
+                    }
+                }
+
+                endl!();
+
+                pub fn from_name(name: &str) -> Option<Self> {
+                    match name {
+                        #(#from_name_matches),*,
+                        _ => None,
+                    }
+                }
+
+                endl!();
+
+                #(#categories)*
+
+                pub fn is_function_like(self) -> bool {
+                    matches!(self, #(Self :: #function_like)|*)
+                }
+
+                endl!();
+
+                /// Types which may create a scope, conditional scopes (e.g. `for` statements
+                /// with lexical declarations) depend on the node.
+                pub fn is_scope_creating(self) -> bool {
+                    matches!(self, #(Self :: #scope_creating)|*)
+                }
+
+                endl!();
+
+                pub fn is_jsx(self) -> bool {
+                    matches!(self, #(Self :: #jsx)|*)
+                }
+
+                endl!();
+
+                pub fn is_typescript(self) -> bool {
+                    matches!(self, #(Self :: #typescript)|*)
+                }
+            }
+
+            endl!();
+
+            impl std::fmt::Display for AstType {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.write_str(self.name())
+                }
+            }
+
+            endl!();
+
             /// Untyped AST Node Kind
             #[derive(Debug, Clone, Copy)]
             pub enum AstKind<'a> {
@@ -150,6 +234,24 @@ impl Generator for AstKindGenerator {
 
             endl!();
 
+            impl<'a> AstKind<'a> {
+                pub fn ty(&self) -> AstType {
+                    match self {
+                        #(#ty_matches),*,
+                    }
+                }
+            }
+
+            endl!();
+
+            impl<'a> std::fmt::Display for AstKind<'a> {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    std::fmt::Display::fmt(&self.ty(), f)
+                }
+            }
+
+            endl!();
+
             impl<'a> GetSpan for AstKind<'a> {
                 #[allow(clippy::match_same_arms)]
                 fn span(&self) -> Span {
@@ -161,3 +263,51 @@ impl Generator for AstKindGenerator {
         })
     }
 }
+
+/// Types with kinds defined in the given module.
+fn module_members<'k>(
+    ctx: &CodegenCtx,
+    have_kinds: &'k [(Ident, Type)],
+    module: &str,
+) -> Vec<&'k Ident> {
+    let Some(module) = ctx.modules.iter().find(|it| it.module == module) else {
+        return Vec::new();
+    };
+    let idents = module.items.iter().filter_map(|it| it.borrow().ident().cloned()).collect_vec();
+    have_kinds.iter().map(|(ident, _)| ident).filter(|ident| idents.contains(ident)).collect()
+}
+
+/// Types belonging to the category defined by `enums`.
+fn category_members(ctx: &CodegenCtx, enums: &[&str]) -> Vec<Ident> {
+    enums
+        .iter()
+        .filter_map(|name| ctx.find(&(*name).to_string()))
+        .flat_map(|ty| {
+            let RType::Enum(it) = &*ty.borrow() else { return Vec::new() };
+            let inherited = it.meta.inherits.iter().filter_map(|inherit| match inherit {
+                Inherit::Linked { super_, .. } => Some(format_ident!("{super_}")),
+                Inherit::Unlinked(_) => None,
+            });
+            it.item.variants.iter().filter_map(variant_type_ident).chain(inherited).collect_vec()
+        })
+        .unique()
+        .collect()
+}
+
+/// The ident of the type wrapped by an enum variant,
+/// e.g. `Function` for `FunctionDeclaration(Box<'a, Function<'a>>)`.
+fn variant_type_ident(variant: &Variant) -> Option<Ident> {
+    let Fields::Unnamed(fields) = &variant.fields else { return None };
+    let Type::Path(TypePath { path, .. }) = &fields.unnamed.first()?.ty else { return None };
+    let segment = path.segments.last()?;
+    if segment.ident != "Box" {
+        return Some(segment.ident.clone());
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else { return None };
+    args.args.iter().find_map(|arg| match arg {
+        GenericArgument::Type(Type::Path(TypePath { path, .. })) => {
+            path.segments.last().map(|segment| segment.ident.clone())
+        }
+        _ => None,
+    })
+}
@@ -0,0 +1,104 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Fields, Ident, Variant};
+
+use crate::{
+    schema::{REnum, RStruct, RType},
+    CodegenCtx, Generator, GeneratorOutput,
+};
+
+use super::generated_header;
+
+pub struct DeriveCloneIn;
+
+impl Generator for DeriveCloneIn {
+    fn name(&self) -> &'static str {
+        "DeriveCloneIn"
+    }
+
+    fn generate(&mut self, ctx: &CodegenCtx) -> GeneratorOutput {
+        let impls: Vec<TokenStream> = ctx
+            .ty_table
+            .iter()
+            .map(|it| it.borrow())
+            .filter(|it| matches!(&**it, RType::Enum(_) | RType::Struct(_)))
+            .map(|kind| match &*kind {
+                RType::Enum(it) => derive_enum(it),
+                RType::Struct(it) => derive_struct(it),
+                _ => unreachable!("already filtered out!"),
+            })
+            .collect();
+
+        let header = generated_header!();
+
+        GeneratorOutput::One(quote! {
+            #header
+            endl!();
+
+            use oxc_allocator::{Allocator, CloneIn};
+
+            use crate::ast::*;
+
+            #(#impls)*
+        })
+    }
+}
+
+fn derive_enum(REnum { item, .. }: &REnum) -> TokenStream {
+    let ty_ident = &item.ident;
+    let matches = item.variants.iter().map(|Variant { ident, fields, .. }| {
+        if fields.is_empty() {
+            quote!(Self :: #ident => #ty_ident :: #ident)
+        } else {
+            quote!(Self :: #ident(it) => #ty_ident :: #ident(it.clone_in(alloc)))
+        }
+    });
+    let alloc =
+        if item.variants.iter().all(|it| it.fields.is_empty()) { quote!(_) } else { quote!(alloc) };
+    let body = quote!(match self { #(#matches),* });
+    impl_clone_in(ty_ident, item.generics.lt_token.is_some(), &alloc, &body)
+}
+
+fn derive_struct(RStruct { item, .. }: &RStruct) -> TokenStream {
+    let ty_ident = &item.ident;
+    let (alloc, body) = match &item.fields {
+        Fields::Named(fields) if !fields.named.is_empty() => {
+            let fields = fields.named.iter().map(|field| {
+                let ident = field.ident.as_ref();
+                quote!(#ident: self.#ident.clone_in(alloc))
+            });
+            (quote!(alloc), quote!(#ty_ident { #(#fields),* }))
+        }
+        _ => (quote!(_), quote!(#ty_ident)),
+    };
+    impl_clone_in(ty_ident, item.generics.lt_token.is_some(), &alloc, &body)
+}
+
+fn impl_clone_in(
+    ty_ident: &Ident,
+    has_lifetime: bool,
+    alloc: &TokenStream,
+    body: &TokenStream,
+) -> TokenStream {
+    if has_lifetime {
+        quote! {
+            endl!();
+            impl <'old_alloc, 'new_alloc> CloneIn<'new_alloc> for #ty_ident<'old_alloc> {
+                type Cloned = #ty_ident<'new_alloc>;
+                fn clone_in(&self, #alloc: &'new_alloc Allocator) -> Self::Cloned {
+                    #body
+                }
+            }
+        }
+    } else {
+        quote! {
+            endl!();
+            impl <'alloc> CloneIn<'alloc> for #ty_ident {
+                type Cloned = #ty_ident;
+                fn clone_in(&self, #alloc: &'alloc Allocator) -> Self::Cloned {
+                    #body
+                }
+            }
+        }
+    }
+}
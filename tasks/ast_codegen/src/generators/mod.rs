@@ -1,5 +1,6 @@
 mod ast;
 mod ast_kind;
+mod derive_clone_in;
 mod impl_get_span;
 
 /// Inserts a newline in the `TokenStream`.
@@ -41,4 +42,5 @@ pub(crate) use insert;
 
 pub use ast::AstGenerator;
 pub use ast_kind::AstKindGenerator;
+pub use derive_clone_in::DeriveCloneIn;
 pub use impl_get_span::ImplGetSpanGenerator;
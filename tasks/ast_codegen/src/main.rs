@@ -26,7 +26,7 @@ use generators::{AstGenerator, AstKindGenerator};
 use linker::{linker, Linker};
 use schema::{Inherit, Module, REnum, RStruct, RType, Schema};
 
-use crate::generators::ImplGetSpanGenerator;
+use crate::generators::{DeriveCloneIn, ImplGetSpanGenerator};
 
 type Result<R> = std::result::Result<R, String>;
 type TypeId = usize;
@@ -191,6 +191,7 @@ fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
         .with(AstGenerator)
         .with(AstKindGenerator)
         .with(ImplGetSpanGenerator)
+        .with(DeriveCloneIn)
         .generate()?;
 
     let output_dir = output_dir()?;
@@ -218,6 +219,17 @@ fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
         file.write_all(span_content.as_bytes())?;
     }
 
+    {
+        // write `derive_clone_in.rs` file
+        let output = outputs[DeriveCloneIn.name()].as_one();
+        let content = pprint(output);
+
+        let path = format!("{output_dir}/derive_clone_in.rs");
+        let mut file = fs::File::create(path)?;
+
+        file.write_all(content.as_bytes())?;
+    }
+
     cargo_fmt(".")?;
 
     // let schema = serde_json::to_string_pretty(&schema).map_err(|e| e.to_string())?;
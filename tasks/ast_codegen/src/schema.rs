@@ -40,6 +40,8 @@ impl From<Ident> for Inherit {
 pub struct EnumMeta {
     pub inherits: Vec<Inherit>,
     pub visitable: bool,
+    pub has_kind: bool,
+    pub has_scope: bool,
 }
 
 #[derive(Debug)]
@@ -74,6 +76,8 @@ impl From<ItemEnum> for REnum {
 #[derive(Debug, Default, Clone)]
 pub struct StructMeta {
     pub visitable: bool,
+    pub has_kind: bool,
+    pub has_scope: bool,
 }
 
 #[derive(Debug)]
@@ -159,6 +163,42 @@ impl RType {
         }
         Ok(())
     }
+
+    /// Whether this type gets an `AstKind` variant, visitable types opt out with `#[ast(no_kind)]`.
+    pub fn has_kind(&self) -> bool {
+        match self {
+            RType::Enum(it) => it.meta.has_kind,
+            RType::Struct(it) => it.meta.has_kind,
+            _ => false,
+        }
+    }
+
+    pub fn set_has_kind(&mut self, value: bool) -> Result<()> {
+        match self {
+            RType::Enum(it) => it.meta.has_kind = value,
+            RType::Struct(it) => it.meta.has_kind = value,
+            _ => return Err("Unsupported type!".to_string()),
+        }
+        Ok(())
+    }
+
+    /// Whether this type may create a scope, marked with `#[scope]`.
+    pub fn has_scope(&self) -> bool {
+        match self {
+            RType::Enum(it) => it.meta.has_scope,
+            RType::Struct(it) => it.meta.has_scope,
+            _ => false,
+        }
+    }
+
+    pub fn set_has_scope(&mut self, value: bool) -> Result<()> {
+        match self {
+            RType::Enum(it) => it.meta.has_scope = value,
+            RType::Struct(it) => it.meta.has_scope = value,
+            _ => return Err("Unsupported type!".to_string()),
+        }
+        Ok(())
+    }
 }
 
 impl TryFrom<Item> for RType {
@@ -331,21 +371,42 @@ pub fn expand(type_def: &TypeRef) -> Result<()> {
 }
 
 pub fn analyze(type_def: &TypeRef) -> Result<()> {
-    let is_visitable = match &*type_def.borrow() {
+    let meta = match &*type_def.borrow() {
         RType::Enum(REnum { item: ItemEnum { attrs, .. }, .. })
         | RType::Struct(RStruct { item: ItemStruct { attrs, .. }, .. }) => {
-            Some(attrs.iter().any(|attr| attr.path().is_ident("visited_node")))
+            let is_visitable = attrs.iter().any(|attr| attr.path().is_ident("visited_node"));
+            let no_kind = ast_attr_has(attrs, "no_kind")?;
+            let has_scope = attrs.iter().any(|attr| attr.path().is_ident("scope"));
+            Some((is_visitable, is_visitable && !no_kind, has_scope))
         }
         _ => None,
     };
 
-    if let Some(is_visitable) = is_visitable {
-        type_def.borrow_mut().set_visitable(is_visitable)?;
+    if let Some((is_visitable, has_kind, has_scope)) = meta {
+        let mut type_def = type_def.borrow_mut();
+        type_def.set_visitable(is_visitable)?;
+        type_def.set_has_kind(has_kind)?;
+        type_def.set_has_scope(has_scope)?;
     }
 
     Ok(())
 }
 
+/// Check for `#[ast(<name>)]` in the given attributes.
+fn ast_attr_has(attrs: &[Attribute], name: &str) -> Result<bool> {
+    let mut found = false;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("ast")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident(name) {
+                found = true;
+            }
+            Ok(())
+        })
+        .map_err(|e| e.to_string())?;
+    }
+    Ok(found)
+}
+
 impl From<PathBuf> for Module {
     fn from(path: PathBuf) -> Self {
         Self::with_path(path)
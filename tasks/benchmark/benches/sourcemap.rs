@@ -17,7 +17,7 @@ fn bench_sourcemap(criterion: &mut Criterion) {
             let allocator = Allocator::default();
             let ret = Parser::new(&allocator, source_text, source_type).parse();
             b.iter(|| {
-                let CodegenReturn { source_map, source_text } = CodeGenerator::new()
+                let CodegenReturn { source_map, source_text, .. } = CodeGenerator::new()
                     .enable_source_map(file.file_name.as_str(), source_text)
                     .build(&ret.program);
                 let line = source_text.matches('\n').count() as u32;
@@ -75,6 +75,18 @@ impl VisitMut<'_> for SpecParser {
                                     options.bracket_spacing = literal.value;
                                 } else if name == "singleQuote" {
                                     options.single_quote = literal.value;
+                                } else if name == "useTabs" {
+                                    options.use_tabs = literal.value;
+                                } else if name == "requirePragma" {
+                                    options.require_pragma = literal.value;
+                                } else if name == "insertPragma" {
+                                    options.insert_pragma = literal.value;
+                                } else if name == "bracketSameLine" {
+                                    options.bracket_same_line = literal.value;
+                                } else if name == "singleAttributePerLine" {
+                                    options.single_attribute_per_line = literal.value;
+                                } else if name == "experimentalTernaries" {
+                                    options.experimental_ternaries = literal.value;
                                 }
                             }
                             Expression::NumericLiteral(literal) => match name.as_str() {
@@ -3,7 +3,7 @@ pub(crate) const PLUGINS: &[&str] = &[
     // // ES2024
     // "babel-plugin-transform-unicode-sets-regex",
     // // ES2022
-    // "babel-plugin-transform-class-properties",
+    "babel-plugin-transform-class-properties",
     // "babel-plugin-transform-class-static-block",
     // "babel-plugin-transform-private-methods",
     // "babel-plugin-transform-private-property-in-object",
@@ -15,7 +15,7 @@ pub(crate) const PLUGINS: &[&str] = &[
     // "babel-plugin-transform-export-namespace-from",
     // "babel-plugin-transform-dynamic-import",
     // "babel-plugin-transform-nullish-coalescing-operator",
-    // "babel-plugin-transform-optional-chaining",
+    "babel-plugin-transform-optional-chaining",
     // // [Syntax] "babel-plugin-transform-syntax-bigint",
     // // [Syntax] "babel-plugin-transform-syntax-dynamic-import",
     // // [Syntax] "babel-plugin-transform-syntax-import-meta",
@@ -56,16 +56,15 @@ pub(crate) const PLUGINS: &[&str] = &[
     "babel-plugin-transform-react-jsx-development",
     // // Proposal
     // "babel-plugin-proposal-decorators",
+    "babel-plugin-proposal-pipeline-operator",
 ];
 
 pub(crate) const PLUGINS_NOT_SUPPORTED_YET: &[&str] = &[
     "proposal-decorators",
-    "transform-class-properties",
     "transform-classes",
     "transform-destructuring",
     "transform-modules-commonjs",
     "transform-object-rest-spread",
-    "transform-optional-chaining",
     "transform-parameters",
     "transform-private-methods",
     "transform-property-literals",
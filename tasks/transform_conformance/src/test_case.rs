@@ -169,7 +169,9 @@ pub trait TestCase {
             source_type = source_type.with_typescript(true);
         }
 
-        let ret = Parser::new(&allocator, &source_text, source_type).parse();
+        let ret = Parser::new(&allocator, &source_text, source_type)
+            .enable_pipeline_operator(self.options().has_plugin("proposal-pipeline-operator"))
+            .parse();
         let mut program = ret.program;
         let result = Transformer::new(
             &allocator,
@@ -255,7 +257,9 @@ impl TestCase for ConformanceTestCase {
 
         let transform_options = match self.transform_options() {
             Ok(transform_options) => {
-                let ret = Parser::new(&allocator, &input, source_type).parse();
+                let ret = Parser::new(&allocator, &input, source_type)
+                    .enable_pipeline_operator(self.options.has_plugin("proposal-pipeline-operator"))
+                    .parse();
                 if ret.errors.is_empty() {
                     let mut program = ret.program;
                     let transformer = Transformer::new(
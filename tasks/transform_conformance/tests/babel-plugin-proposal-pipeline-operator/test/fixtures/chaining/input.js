@@ -0,0 +1,6 @@
+function f(x) { return x; }
+function g(x) { return x; }
+const a = 1;
+a |> f(%) |> g(%);
+foo() |> f(%) |> g(%, 1);
+value |> % + 1 |> f(%);
@@ -0,0 +1,11 @@
+var _ref;
+function f(x) {
+  return x;
+}
+function g(x) {
+  return x;
+}
+const a = 1;
+g(f(a));
+g(f(foo()), 1);
+_ref = value, f(_ref + 1);
@@ -0,0 +1,5 @@
+function f(x) { return x; }
+const a = 1;
+a |> f(% |> f(%));
+foo() |> [%, bar() |> %.baz];
+a |> (() => %);
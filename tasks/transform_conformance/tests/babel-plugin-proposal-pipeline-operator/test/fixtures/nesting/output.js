@@ -0,0 +1,8 @@
+var _ref, _ref2, _ref3;
+function f(x) {
+  return x;
+}
+const a = 1;
+f(f(a));
+_ref2 = foo(), [_ref2, (_ref = bar(), _ref.baz)];
+_ref3 = a, () => _ref3;
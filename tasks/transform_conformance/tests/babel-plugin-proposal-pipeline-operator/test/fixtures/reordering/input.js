@@ -0,0 +1,5 @@
+let a = 0;
+a |> (a = 1, console.log(%));
+a |> g() + %;
+function f(x) { return x; }
+a |> f(%);
@@ -0,0 +1,8 @@
+var _ref, _ref2;
+let a = 0;
+_ref = a, (a = 1, console.log(_ref));
+_ref2 = a, g() + _ref2;
+function f(x) {
+  return x;
+}
+f(a);
@@ -0,0 +1,5 @@
+b |> % + %;
+foo() |> % * 2;
+function outer() {
+  return bar() |> [%, %];
+}
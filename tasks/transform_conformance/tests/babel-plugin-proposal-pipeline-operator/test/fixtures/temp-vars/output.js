@@ -0,0 +1,7 @@
+var _ref, _ref2;
+_ref = b, _ref + _ref;
+_ref2 = foo(), _ref2 * 2;
+function outer() {
+  var _ref3;
+  return _ref3 = bar(), [_ref3, _ref3];
+}
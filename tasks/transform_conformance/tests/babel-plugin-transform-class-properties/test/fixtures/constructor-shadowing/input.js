@@ -0,0 +1,9 @@
+const x = 1;
+
+class Foo {
+  y = x;
+
+  constructor(x) {
+    console.log(x);
+  }
+}
@@ -0,0 +1,12 @@
+const x = 1;
+class Foo {
+  constructor(_x) {
+    Object.defineProperty(this, 'y', {
+      value: x,
+      enumerable: true,
+      configurable: true,
+      writable: true
+    });
+    console.log(_x);
+  }
+}
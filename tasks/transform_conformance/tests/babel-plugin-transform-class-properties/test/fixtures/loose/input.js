@@ -0,0 +1,5 @@
+class Foo extends Bar {
+  x = 1;
+  #y = 2;
+  static z = this.name;
+}
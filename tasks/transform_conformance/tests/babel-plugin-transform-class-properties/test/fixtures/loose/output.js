@@ -0,0 +1,9 @@
+var _y = new WeakMap();
+class Foo extends Bar {
+  constructor(..._args) {
+    super(..._args);
+    this.x = 1;
+    _y.set(this, 2);
+  }
+}
+Foo.z = Foo.name;
@@ -0,0 +1,10 @@
+class Foo {
+  #m = function () {
+    return this;
+  };
+
+  run(other) {
+    this.#m(1, 2);
+    return other.obj.#m();
+  }
+}
@@ -0,0 +1,12 @@
+var _m = new WeakMap(), _obj;
+class Foo {
+  constructor() {
+    _m.set(this, function() {
+      return this;
+    });
+  }
+  run(other) {
+    _m.get(this).call(this, 1, 2);
+    return _m.get(_obj = other.obj).call(_obj);
+  }
+}
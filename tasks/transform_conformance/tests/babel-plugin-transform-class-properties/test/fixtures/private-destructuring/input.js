@@ -0,0 +1,10 @@
+class Foo {
+  #x;
+  #y;
+
+  run(arr, obj) {
+    [this.#x, ...this.#y] = arr;
+    ({ a: this.#x = 1 } = obj);
+    for (this.#x of arr);
+  }
+}
@@ -0,0 +1,20 @@
+var _x = new WeakMap(), _y = new WeakMap(), _obj, _obj2, _obj3, _obj4;
+class Foo {
+  constructor() {
+    _x.set(this, void 0);
+    _y.set(this, void 0);
+  }
+  run(arr, obj) {
+    [(_obj = this, {set value(_value) {
+      _x.set(_obj, _value);
+    }}).value,...(_obj2 = this, {set value(_value2) {
+      _y.set(_obj2, _value2);
+    }}).value] = arr;
+    ({a: (_obj3 = this, {set value(_value3) {
+      _x.set(_obj3, _value3);
+    }}).value = 1} = obj);
+    for ((_obj4 = this, {set value(_value4) {
+      _x.set(_obj4, _value4);
+    }}).value of arr);
+  }
+}
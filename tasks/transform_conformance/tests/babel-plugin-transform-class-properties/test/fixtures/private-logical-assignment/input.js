@@ -0,0 +1,9 @@
+class Foo {
+  #x = null;
+
+  run(other) {
+    this.#x ||= 1;
+    this.#x &&= 2;
+    return other.#x ??= 3;
+  }
+}
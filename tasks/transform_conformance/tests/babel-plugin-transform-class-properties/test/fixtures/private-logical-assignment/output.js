@@ -0,0 +1,11 @@
+var _x = new WeakMap(), _obj;
+class Foo {
+  constructor() {
+    _x.set(this, null);
+  }
+  run(other) {
+    _x.get(this) || _x.set(this, 1);
+    _x.get(this) && _x.set(this, 2);
+    return _x.get(_obj = other) ?? _x.set(_obj, 3).get(_obj);
+  }
+}
@@ -0,0 +1,8 @@
+class Foo {
+  #x = 1;
+
+  run(other) {
+    other?.#x;
+    return other?.foo.#x;
+  }
+}
@@ -0,0 +1,10 @@
+var _x = new WeakMap();
+class Foo {
+  constructor() {
+    _x.set(this, 1);
+  }
+  run(other) {
+    other === null || other === void 0 ? void 0 : _x.get(other);
+    return other === null || other === void 0 ? void 0 : _x.get(other.foo);
+  }
+}
@@ -0,0 +1,5 @@
+class Foo extends Bar {
+  x = 1;
+  [key] = 2;
+  static y = this.name;
+}
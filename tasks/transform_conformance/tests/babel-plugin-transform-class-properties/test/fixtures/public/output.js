@@ -0,0 +1,25 @@
+var _key;
+_key = key;
+class Foo extends Bar {
+  constructor(..._args) {
+    super(..._args);
+    Object.defineProperty(this, 'x', {
+      value: 1,
+      enumerable: true,
+      configurable: true,
+      writable: true
+    });
+    Object.defineProperty(this, _key, {
+      value: 2,
+      enumerable: true,
+      configurable: true,
+      writable: true
+    });
+  }
+}
+Object.defineProperty(Foo, 'y', {
+  value: Foo.name,
+  enumerable: true,
+  configurable: true,
+  writable: true
+});
@@ -0,0 +1,5 @@
+class Foo extends Bar {
+  static x = super.x;
+  static y = super.method(1);
+  static z = () => super[key];
+}
@@ -0,0 +1,19 @@
+class Foo extends Bar {}
+Object.defineProperty(Foo, 'x', {
+  value: Reflect.get(Object.getPrototypeOf(Foo), 'x', Foo),
+  enumerable: true,
+  configurable: true,
+  writable: true
+});
+Object.defineProperty(Foo, 'y', {
+  value: Reflect.get(Object.getPrototypeOf(Foo), 'method', Foo).call(Foo, 1),
+  enumerable: true,
+  configurable: true,
+  writable: true
+});
+Object.defineProperty(Foo, 'z', {
+  value: () => Reflect.get(Object.getPrototypeOf(Foo), key, Foo),
+  enumerable: true,
+  configurable: true,
+  writable: true
+});
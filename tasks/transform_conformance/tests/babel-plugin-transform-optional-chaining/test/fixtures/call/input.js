@@ -0,0 +1,9 @@
+foo?.();
+foo.bar?.();
+foo?.bar();
+class A extends B {
+  method() {
+    this.foo?.();
+    super.foo?.();
+  }
+}
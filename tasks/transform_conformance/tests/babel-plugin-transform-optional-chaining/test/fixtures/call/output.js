@@ -0,0 +1,11 @@
+var _foo, _foo2, _foo$bar, _foo3;
+(_foo = foo) === null || _foo === void 0 ? void 0 : _foo();
+(_foo$bar = (_foo2 = foo).bar) === null || _foo$bar === void 0 ? void 0 : _foo$bar.call(_foo2);
+(_foo3 = foo) === null || _foo3 === void 0 ? void 0 : _foo3.bar();
+class A extends B {
+  method() {
+    var _this$foo, _super$foo;
+    (_this$foo = this.foo) === null || _this$foo === void 0 ? void 0 : _this$foo.call(this);
+    (_super$foo = super.foo) === null || _super$foo === void 0 ? void 0 : _super$foo.call(this);
+  }
+}
@@ -0,0 +1,2 @@
+delete foo?.bar;
+delete foo?.bar.baz;
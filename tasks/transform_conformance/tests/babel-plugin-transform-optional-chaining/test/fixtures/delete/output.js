@@ -0,0 +1,3 @@
+var _foo, _foo2;
+(_foo = foo) === null || _foo === void 0 ? true : delete _foo.bar;
+(_foo2 = foo) === null || _foo2 === void 0 ? true : delete _foo2.bar.baz;
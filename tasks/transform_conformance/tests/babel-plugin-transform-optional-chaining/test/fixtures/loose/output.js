@@ -0,0 +1,3 @@
+var _foo, _foo2, _foo$bar;
+(_foo = foo) == null ? void 0 : _foo.bar;
+(_foo$bar = (_foo2 = foo).bar) == null ? void 0 : _foo$bar.call(_foo2);
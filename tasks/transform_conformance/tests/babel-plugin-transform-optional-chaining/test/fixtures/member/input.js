@@ -0,0 +1,5 @@
+foo?.bar;
+foo?.bar.baz;
+foo?.[bar];
+let a;
+a?.b?.c;
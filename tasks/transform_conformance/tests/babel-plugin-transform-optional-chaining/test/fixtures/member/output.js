@@ -0,0 +1,6 @@
+var _foo, _foo2, _foo3, _a$b;
+(_foo = foo) === null || _foo === void 0 ? void 0 : _foo.bar;
+(_foo2 = foo) === null || _foo2 === void 0 ? void 0 : _foo2.bar.baz;
+(_foo3 = foo) === null || _foo3 === void 0 ? void 0 : _foo3[bar];
+let a;
+a === null || a === void 0 ? void 0 : (_a$b = a.b) === null || _a$b === void 0 ? void 0 : _a$b.c;
@@ -0,0 +1,6 @@
+function f(a) {
+  a?.b;
+  a.b.c?.d;
+  this?.a;
+  foo()?.bar;
+}
@@ -0,0 +1,7 @@
+function f(a) {
+  var _a$b$c, _foo;
+  a === null || a === void 0 ? void 0 : a.b;
+  (_a$b$c = a.b.c) === null || _a$b$c === void 0 ? void 0 : _a$b$c.d;
+  this === null || this === void 0 ? void 0 : this.a;
+  (_foo = foo()) === null || _foo === void 0 ? void 0 : _foo.bar;
+}
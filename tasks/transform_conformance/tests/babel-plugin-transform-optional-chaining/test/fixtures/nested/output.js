@@ -0,0 +1,3 @@
+var _foo, _bar, _ref, _foo2;
+(_foo = foo) === null || _foo === void 0 ? void 0 : _foo[(_bar = bar) === null || _bar === void 0 ? void 0 : _bar.baz].qux;
+(_ref = (_foo2 = foo) === null || _foo2 === void 0 ? void 0 : _foo2.bar) === null || _ref === void 0 ? void 0 : _ref.baz;
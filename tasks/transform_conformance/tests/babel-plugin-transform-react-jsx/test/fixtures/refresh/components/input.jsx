@@ -0,0 +1,13 @@
+function Hello() {
+  return <h1>Hi</h1>;
+}
+const Bar = () => <Hello />;
+const Baz = function () {
+  return cond ? <Bar /> : null;
+};
+export function Qux() {
+  return <>{1}</>;
+}
+export default function App() {
+  return (<div />);
+}
@@ -0,0 +1,25 @@
+import { jsx as _jsx, Fragment as _Fragment } from 'react/jsx-runtime';
+function Hello() {
+  return _jsx('h1', {children: 'Hi'});
+}
+_c = Hello;
+const Bar = () => _jsx(Hello, {});
+_c2 = Bar;
+const Baz = function() {
+  return cond ? _jsx(Bar, {}) : null;
+};
+_c3 = Baz;
+export function Qux() {
+  return _jsx(_Fragment, {children: 1});
+}
+_c4 = Qux;
+export default function App() {
+  return _jsx('div', {});
+}
+_c5 = App;
+var _c, _c2, _c3, _c4, _c5;
+$RefreshReg$(_c, 'Hello');
+$RefreshReg$(_c2, 'Bar');
+$RefreshReg$(_c3, 'Baz');
+$RefreshReg$(_c4, 'Qux');
+$RefreshReg$(_c5, 'App');
@@ -0,0 +1,13 @@
+import FancyHook from "fancy";
+
+function useFancyState() {
+  const [foo, setFoo] = React.useState(0);
+  useFancyEffect();
+  return foo;
+}
+
+export default function App() {
+  const bar = useFancyState();
+  const baz = FancyHook.useThing();
+  return <h1>{bar}{baz}</h1>;
+}
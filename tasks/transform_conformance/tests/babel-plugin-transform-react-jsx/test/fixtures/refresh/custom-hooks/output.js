@@ -0,0 +1,24 @@
+var _s = $RefreshSig$(), _s2 = $RefreshSig$();
+import FancyHook from 'fancy';
+import { jsxs as _jsxs } from 'react/jsx-runtime';
+function useFancyState() {
+  _s();
+  const [foo, setFoo] = React.useState(0);
+  useFancyEffect();
+  return foo;
+}
+_s(useFancyState, 'useState{[foo, setFoo](0)}\nuseFancyEffect{}', false, function() {
+  return [useFancyEffect];
+});
+export default function App() {
+  _s2();
+  const bar = useFancyState();
+  const baz = FancyHook.useThing();
+  return _jsxs('h1', {children: [bar, baz]});
+}
+_s2(App, 'useFancyState{bar}\nuseThing{baz}', false, function() {
+  return [useFancyState, FancyHook.useThing];
+});
+_c = App;
+var _c;
+$RefreshReg$(_c, 'App');
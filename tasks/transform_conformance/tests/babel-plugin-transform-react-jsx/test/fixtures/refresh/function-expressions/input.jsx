@@ -0,0 +1,13 @@
+export const A = React.memo(() => {
+  const [foo] = useState(1);
+  return <div>{foo}</div>;
+});
+
+const B = () => useContext(Theme);
+
+const C = {
+  render() {
+    useState();
+    return <div />;
+  },
+};
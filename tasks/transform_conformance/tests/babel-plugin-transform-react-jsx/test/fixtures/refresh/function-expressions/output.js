@@ -0,0 +1,15 @@
+import { jsx as _jsx } from 'react/jsx-runtime';
+var _s = $RefreshSig$(), _s2 = $RefreshSig$();
+export const A = React.memo(_s(() => {
+  _s();
+  const [foo] = useState(1);
+  return _jsx('div', {children: foo});
+}, 'useState{[foo](1)}'));
+const B = _s2(() => {
+  _s2();
+  return useContext(Theme);
+}, 'useContext{}');
+const C = {render() {
+  useState();
+  return _jsx('div', {});
+}};
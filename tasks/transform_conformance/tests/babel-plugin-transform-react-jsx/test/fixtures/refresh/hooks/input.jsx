@@ -0,0 +1,6 @@
+export default function App() {
+  const [foo, setFoo] = useState(0);
+  const [state, dispatch] = React.useReducer(reducer, initialState);
+  useEffect(() => {});
+  return <h1>{foo}</h1>;
+}
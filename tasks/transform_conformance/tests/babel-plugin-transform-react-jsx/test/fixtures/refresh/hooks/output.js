@@ -0,0 +1,13 @@
+import { jsx as _jsx } from 'react/jsx-runtime';
+var _s = $RefreshSig$();
+export default function App() {
+  _s();
+  const [foo, setFoo] = useState(0);
+  const [state, dispatch] = React.useReducer(reducer, initialState);
+  useEffect(() => {});
+  return _jsx('h1', {children: foo});
+}
+_s(App, 'useState{[foo, setFoo](0)}\nuseReducer{[state, dispatch](initialState)}\nuseEffect{}');
+_c = App;
+var _c;
+$RefreshReg$(_c, 'App');
@@ -0,0 +1,13 @@
+function hello() {
+  return <h1>Hi</h1>;
+}
+function Helper() {
+  return 1;
+}
+function Factory() {
+  return () => <div />;
+}
+const Value = <div />;
+export default function () {
+  return <div />;
+}
@@ -0,0 +1,14 @@
+import { jsx as _jsx } from 'react/jsx-runtime';
+function hello() {
+  return _jsx('h1', {children: 'Hi'});
+}
+function Helper() {
+  return 1;
+}
+function Factory() {
+  return () => _jsx('div', {});
+}
+const Value = _jsx('div', {});
+export default function() {
+  return _jsx('div', {});
+}
@@ -0,0 +1,10 @@
+export const enum Flags {
+  None,
+  A = 1 << 0,
+  B = 1 << 1,
+}
+Flags.A | Flags.B;
+export const enum Named {
+  "quoted-key" = "a",
+  Negative = -1,
+}
@@ -0,0 +1,10 @@
+export var Flags = {
+  None: 0,
+  A: 1,
+  B: 2
+};
+1 | 2;
+export var Named = {
+  'quoted-key': 'a',
+  Negative: -1
+};
@@ -0,0 +1,10 @@
+const enum Direction {
+  Up = 1,
+  Down,
+  Left = "LEFT",
+  Right = Up << 3,
+}
+console.log(Direction.Up, Direction.Down, Direction["Left"], Direction.Right);
+function f() {
+  return Direction.Down;
+}
@@ -0,0 +1,4 @@
+console.log(1, 2, 'LEFT', 8);
+function f() {
+  return 2;
+}
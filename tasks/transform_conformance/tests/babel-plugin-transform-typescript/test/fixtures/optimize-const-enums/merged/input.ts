@@ -0,0 +1,7 @@
+const enum Size {
+  Small = 1,
+}
+const enum Size {
+  Large = Small * 10,
+}
+Size.Small + Size.Large;
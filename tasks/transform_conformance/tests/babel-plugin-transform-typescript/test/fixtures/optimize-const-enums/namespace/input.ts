@@ -0,0 +1,7 @@
+namespace N {
+  export const enum G {
+    A = 2,
+  }
+  G.A;
+}
+N.G.A;
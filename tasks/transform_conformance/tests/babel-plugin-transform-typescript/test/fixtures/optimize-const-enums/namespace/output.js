@@ -0,0 +1,10 @@
+let N;
+(function(_N) {
+  let G = function(G) {
+    G[G['A'] = 2] = 'A';
+    return G;
+  }({});
+  _N.G = G;
+  G.A;
+})(N || (N = {}));
+N.G.A;
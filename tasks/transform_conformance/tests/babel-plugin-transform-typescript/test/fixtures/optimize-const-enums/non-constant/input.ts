@@ -0,0 +1,6 @@
+const enum Value {
+  A = 1,
+  B = Math.random(),
+}
+Value.A;
+Value.B;
@@ -0,0 +1,7 @@
+var Value = function(Value) {
+  Value[Value['A'] = 1] = 'A';
+  Value[Value['B'] = Math.random()] = 'B';
+  return Value;
+}(Value || {});
+1;
+Value.B;